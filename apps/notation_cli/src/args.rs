@@ -0,0 +1,74 @@
+use clap::{Parser, Subcommand, ValueEnum};
+
+/// Formats are selected by the extensions of the paths: `rs` for the DSL, `ron`, `json`
+/// and `ntab` for the tab files.
+#[derive(Debug, Parser)]
+#[clap(name = "notation", author, version, about, long_about = None)]
+pub struct NotationArgs {
+    #[clap(subcommand)]
+    pub command: NotationCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum NotationCommand {
+    /// Convert a tab to another format
+    Convert {
+        /// Tab to read
+        input: String,
+        /// Path to write the converted tab to
+        output: String,
+    },
+    /// Check tabs for errors, fails if any of them got errors
+    Validate {
+        /// Tabs to check
+        #[clap(required = true)]
+        inputs: Vec<String>,
+        /// Fail on warnings too
+        #[clap(long)]
+        strict: bool,
+    },
+    /// Transpose a tab, fretted tracks are transposed by moving the capo, frets or tuning
+    Transpose {
+        /// Tab to transpose
+        input: String,
+        /// Path to write the transposed tab to
+        output: String,
+        /// Semitones to transpose by, negative to transpose down
+        #[clap(short, long, allow_hyphen_values = true)]
+        semitones: i8,
+    },
+    /// Print a summary of tabs
+    Info {
+        /// Tabs to summarize
+        #[clap(required = true)]
+        inputs: Vec<String>,
+    },
+    /// Render a tab as text
+    Render {
+        /// Tab to render
+        input: String,
+        /// Notation to render the tab with
+        #[clap(short, long, value_enum, default_value = "jianpu")]
+        format: RenderFormat,
+        /// Print to stdout if not set
+        #[clap(short, long)]
+        output: Option<String>,
+    },
+    /// Export a tab as a standard MIDI file
+    ExportMidi {
+        /// Tab to export
+        input: String,
+        /// Path of the MIDI file to write
+        output: String,
+        /// Only export these tracks
+        #[clap(short, long)]
+        track: Vec<String>,
+    },
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, ValueEnum)]
+pub enum RenderFormat {
+    Jianpu,
+    Tab,
+    Chords,
+}
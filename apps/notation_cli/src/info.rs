@@ -0,0 +1,94 @@
+use std::fmt::Write;
+
+use notation_model::prelude::{Bpm, Chord, Tab, TabTimeMap, Units};
+
+/// Same speed as the player, with fermatas held.
+pub fn calc_duration_seconds(tab: &Tab) -> f64 {
+    let units = tab
+        .bars
+        .iter()
+        .fold(Units::ZERO, |acc, x| acc + x.props.bar_units);
+    TabTimeMap::new(tab).calc_seconds(units) as f64
+}
+
+/// Chords of all the tracks, in the order of the scale.
+pub fn get_chords(tab: &Tab) -> Vec<Chord> {
+    let mut chords: Vec<Chord> = Vec::new();
+    for track in tab.tracks.iter() {
+        for chord in track.get_tab_chords() {
+            if !chords.contains(&chord.chord) {
+                chords.push(chord.chord);
+            }
+        }
+    }
+    chords.sort_by_key(|x| tab.meta.scale.calc_chord_for_sort(x));
+    chords
+}
+
+pub fn format_info(tab: &Tab) -> String {
+    let mut text = String::new();
+    let info = &tab.info;
+    let meta = &tab.meta;
+    let _ = writeln!(text, "title: {}", tab.title());
+    for (name, value) in [
+        ("artist", &info.artist),
+        ("composer", &info.composer),
+        ("lyricist", &info.lyricist),
+        ("album", &info.album),
+    ] {
+        if !value.is_empty() {
+            let _ = writeln!(text, "{}: {}", name, value);
+        }
+    }
+    let _ = writeln!(text, "key: {} {}", meta.key, meta.scale);
+    let _ = writeln!(text, "signature: {}", meta.signature);
+    let _ = match meta.tempo.as_bpm() {
+        Some(bpm) => writeln!(text, "tempo: {} bpm", bpm),
+        None => writeln!(
+            text,
+            "tempo: {} ({} bpm)",
+            meta.tempo,
+            Bpm::from(meta.tempo)
+        ),
+    };
+    let _ = writeln!(text, "tracks: {}", tab.tracks.len());
+    for track in tab.tracks.iter() {
+        let _ = writeln!(
+            text,
+            "  {} <{}> entries: {}",
+            track.id,
+            track.kind,
+            track.entries.len()
+        );
+    }
+    let _ = writeln!(text, "sections: {}", tab.sections.len());
+    for section in tab.sections.iter() {
+        let _ = writeln!(
+            text,
+            "  {} <{}> bars: {}",
+            section.id,
+            section.kind,
+            section.bars.len()
+        );
+    }
+    let form: Vec<&str> = tab.form.sections.iter().map(|x| x.id.as_str()).collect();
+    let _ = writeln!(text, "form: {}", form.join(" "));
+    let _ = writeln!(text, "bars: {}", tab.bars.len());
+    let mut chords: Vec<String> = Vec::new();
+    for chord in get_chords(tab) {
+        let name = chord.calc_name(&meta.scale, &meta.key);
+        if !chords.contains(&name) {
+            chords.push(name);
+        }
+    }
+    let _ = writeln!(text, "chords: {}", chords.join(" "));
+    let seconds = calc_duration_seconds(tab);
+    let _ = writeln!(
+        text,
+        "duration: {}:{:02} ({:.1} seconds)",
+        (seconds / 60.0).floor() as u64,
+        (seconds % 60.0).floor() as u64,
+        seconds
+    );
+    text
+}
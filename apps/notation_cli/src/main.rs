@@ -0,0 +1,108 @@
+pub mod args;
+pub mod info;
+pub mod midi;
+pub mod render;
+pub mod tab_io;
+pub mod transpose;
+pub mod validate;
+
+use std::io::{self, Write};
+use std::process::ExitCode;
+
+use anyhow::{Context, Result};
+use clap::Parser;
+
+use crate::args::{NotationArgs, NotationCommand};
+use crate::info::format_info;
+use crate::midi::MidiExporter;
+use crate::render::TextRenderer;
+use crate::tab_io::{read_model, read_tab, write_output, write_tab};
+use crate::transpose::Transposer;
+use crate::validate::{Severity, TabValidator};
+
+pub fn main() -> ExitCode {
+    let args = NotationArgs::parse();
+    match run(args.command, &mut io::stdout()) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {:#}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Returns `false` if the command finished but got problems to report, e.g. invalid tabs.
+fn run(command: NotationCommand, stdout: &mut dyn Write) -> Result<bool> {
+    match command {
+        NotationCommand::Convert { input, output } => {
+            write_tab(&read_tab(&input)?, &output)?;
+        }
+        NotationCommand::Validate { inputs, strict } => {
+            let mut passed = true;
+            for input in inputs.iter() {
+                let issues = match read_tab(input) {
+                    Ok(tab) => TabValidator::validate(&tab),
+                    Err(err) => {
+                        eprintln!("{}: error: {:#}", input, err);
+                        passed = false;
+                        continue;
+                    }
+                };
+                for issue in issues.iter() {
+                    eprintln!("{}: {}", input, issue);
+                }
+                if issues
+                    .iter()
+                    .any(|x| strict || x.severity == Severity::Error)
+                {
+                    passed = false;
+                } else {
+                    eprintln!("{}: ok", input);
+                }
+            }
+            return Ok(passed);
+        }
+        NotationCommand::Transpose {
+            input,
+            output,
+            semitones,
+        } => {
+            let tab = Transposer::new(semitones)
+                .transpose(&read_tab(&input)?)
+                .with_context(|| format!("transpose {} failed", input))?;
+            write_tab(&tab, &output)?;
+        }
+        NotationCommand::Info { inputs } => {
+            let mut text = String::new();
+            for input in inputs.iter() {
+                if !text.is_empty() {
+                    text.push('\n');
+                }
+                let tab = read_model(input)?;
+                text.push_str(&format!("file: {}\n", input));
+                text.push_str(&format_info(&tab));
+            }
+            write_output(stdout, None, &text)?;
+        }
+        NotationCommand::Render {
+            input,
+            format,
+            output,
+        } => {
+            let tab = read_model(&input)?;
+            let text = TextRenderer::default().render(&tab, format);
+            write_output(stdout, output.as_deref(), &text)?;
+        }
+        NotationCommand::ExportMidi {
+            input,
+            output,
+            track,
+        } => {
+            let tab = read_model(&input)?;
+            let bytes = MidiExporter::default().export(&tab, &track);
+            std::fs::write(&output, bytes).with_context(|| format!("write {} failed", output))?;
+        }
+    }
+    Ok(true)
+}
@@ -0,0 +1,190 @@
+use notation_model::prelude::{Note, Semitones, Tab, TabTimeMap, Track, TrackKind, Units};
+
+/// Events at the same tick are sorted by their order, so that a note is stopped before
+/// it's played again.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum MidiEventOrder {
+    Setup,
+    NoteOff,
+    NoteOn,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct MidiTrack {
+    pub events: Vec<(u64, MidiEventOrder, Vec<u8>)>,
+}
+
+impl MidiTrack {
+    pub fn push(&mut self, tick: u64, order: MidiEventOrder, data: Vec<u8>) {
+        self.events.push((tick, order, data));
+    }
+    pub fn push_meta(&mut self, tick: u64, kind: u8, data: &[u8]) {
+        let mut bytes = vec![0xFF, kind];
+        write_var_len(&mut bytes, data.len() as u64);
+        bytes.extend_from_slice(data);
+        self.push(tick, MidiEventOrder::Setup, bytes);
+    }
+    pub fn push_note(&mut self, channel: u8, key: u8, velocity: u8, begin: u64, end: u64) {
+        self.push(
+            begin,
+            MidiEventOrder::NoteOn,
+            vec![0x90 | channel, key, velocity],
+        );
+        self.push(
+            end.max(begin + 1),
+            MidiEventOrder::NoteOff,
+            vec![0x80 | channel, key, 0],
+        );
+    }
+    pub fn to_chunk(&self) -> Vec<u8> {
+        let mut events: Vec<&(u64, MidiEventOrder, Vec<u8>)> = self.events.iter().collect();
+        events.sort_by_key(|(tick, order, _)| (*tick, *order));
+        let mut data = Vec::new();
+        let mut last_tick = 0;
+        for (tick, _, bytes) in events {
+            write_var_len(&mut data, tick - last_tick);
+            data.extend_from_slice(bytes);
+            last_tick = *tick;
+        }
+        data.extend_from_slice(&[0x00, 0xFF, 0x2F, 0x00]);
+        let mut chunk = b"MTrk".to_vec();
+        chunk.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        chunk.extend_from_slice(&data);
+        chunk
+    }
+}
+
+fn write_var_len(bytes: &mut Vec<u8>, value: u64) {
+    let mut buffer = vec![(value & 0x7F) as u8];
+    let mut value = value >> 7;
+    while value > 0 {
+        buffer.push((value & 0x7F) as u8 | 0x80);
+        value >>= 7;
+    }
+    buffer.reverse();
+    bytes.extend_from_slice(&buffer);
+}
+
+/// Standard MIDI file of the playable tracks, tones and picks are exported the same way
+/// as they are played, articulations are not.
+pub struct MidiExporter {
+    pub ticks_per_quarter: u16,
+}
+
+impl Default for MidiExporter {
+    fn default() -> Self {
+        Self {
+            ticks_per_quarter: 480,
+        }
+    }
+}
+
+impl MidiExporter {
+    pub const DRUMS_CHANNEL: u8 = 9;
+
+    /// Same defaults as the mixer of the player.
+    pub fn default_params(kind: &TrackKind) -> Option<(u8, u8)> {
+        match kind {
+            // GM "Cello"
+            TrackKind::Vocal => Some((42, 110)),
+            // GM "Acoustic Guitar (steel)"
+            TrackKind::Guitar => Some((25, 120)),
+            // GM "Acoustic Grand Piano"
+            TrackKind::Piano => Some((0, 110)),
+            // GM "Acoustic Bass"
+            TrackKind::Bass => Some((32, 120)),
+            // GM "Lead 1 (square)"
+            TrackKind::Synth => Some((80, 100)),
+            _ => None,
+        }
+    }
+    /// Notes are played 12 semitones above `Semitones`, as in the web synth.
+    pub fn note_key_number(note: &Note) -> Option<u8> {
+        let key = Semitones::from(*note).0 as i16 + 12;
+        (0..=127).contains(&key).then_some(key as u8)
+    }
+    pub fn calc_ticks(&self, units: Units) -> u64 {
+        let ticks = units * (self.ticks_per_quarter as i64 * 4) + Units::new(1, 2);
+        ticks.floor().max(0) as u64
+    }
+    /// Exports all the playable tracks if `track_ids` is empty.
+    pub fn export(&self, tab: &Tab, track_ids: &[String]) -> Vec<u8> {
+        let mut tracks = vec![self.tempo_track(tab)];
+        let mut channel = 0;
+        for track in tab.tracks.iter() {
+            if !track_ids.is_empty() && !track_ids.contains(&track.id) {
+                continue;
+            }
+            if let Some(midi_track) = self.note_track(tab, track, channel) {
+                tracks.push(midi_track);
+                channel = (channel + 1) % 16;
+                if channel == Self::DRUMS_CHANNEL {
+                    channel += 1;
+                }
+            }
+        }
+        let mut bytes = b"MThd".to_vec();
+        bytes.extend_from_slice(&6u32.to_be_bytes());
+        bytes.extend_from_slice(&1u16.to_be_bytes());
+        bytes.extend_from_slice(&(tracks.len() as u16).to_be_bytes());
+        bytes.extend_from_slice(&self.ticks_per_quarter.to_be_bytes());
+        for track in tracks.iter() {
+            bytes.extend_from_slice(&track.to_chunk());
+        }
+        bytes
+    }
+    fn tempo_track(&self, tab: &Tab) -> MidiTrack {
+        let mut track = MidiTrack::default();
+        track.push_meta(0, 0x03, tab.title().as_bytes());
+        let time_map = TabTimeMap::new(tab);
+        let tempo = Self::calc_tempo(time_map.seconds_per_unit);
+        track.push_meta(0, 0x51, &tempo.to_be_bytes()[1..]);
+        let held_tempo = Self::calc_tempo(time_map.seconds_per_unit / time_map.fermata_speed);
+        for (begin, end) in time_map.fermatas.iter() {
+            track.push_meta(self.calc_ticks(*begin), 0x51, &held_tempo.to_be_bytes()[1..]);
+            track.push_meta(self.calc_ticks(*end), 0x51, &tempo.to_be_bytes()[1..]);
+        }
+        let signature = tab.meta.signature;
+        let denominator = Units::from(signature.beat_unit).denom() as u32;
+        track.push_meta(
+            0,
+            0x58,
+            &[
+                signature.bar_beats,
+                denominator.trailing_zeros() as u8,
+                24,
+                8,
+            ],
+        );
+        track
+    }
+    /// Microseconds per quarter note.
+    fn calc_tempo(seconds_per_unit: f32) -> u32 {
+        ((seconds_per_unit as f64 * 0.25 * 1_000_000.0).round() as u32).min(0xFFFFFF)
+    }
+    fn note_track(&self, tab: &Tab, track: &Track, channel: u8) -> Option<MidiTrack> {
+        let (program, velocity) = Self::default_params(&track.kind)?;
+        let mut midi_track = MidiTrack::default();
+        for item in tab.timeline() {
+            if item.entry.track_index() != Some(track.props.index) || !item.is_played() {
+                continue;
+            }
+            let pos = item.entry.bar_position().with_delay(item.sounding_delay);
+            let (pos, duration) = tab.meta.apply_groove(&pos, item.sounding_units);
+            let begin = Units::from(pos);
+            let begin_tick = self.calc_ticks(begin);
+            let end_tick = self.calc_ticks(begin + duration);
+            for note in item.notes.iter() {
+                if let Some(key) = Self::note_key_number(note) {
+                    midi_track.push_note(channel, key, velocity, begin_tick, end_tick);
+                }
+            }
+        }
+        if midi_track.events.is_empty() {
+            return None;
+        }
+        midi_track.push_meta(0, 0x03, track.id.as_bytes());
+        midi_track.push(0, MidiEventOrder::Setup, vec![0xC0 | channel, program]);
+        Some(midi_track)
+    }
+}
@@ -0,0 +1,209 @@
+use notation_model::prelude::{
+    BarLane, CoreEntry, Entry, FrettedEntry, JianpuEntry, LaneJianpu, LaneKind, ProtoEntry, Tab,
+    TabBar, Track,
+};
+
+use crate::args::RenderFormat;
+
+/// Plain text renderings, bars are grouped by sections, with a few bars on each line.
+pub struct TextRenderer {
+    pub bars_per_line: usize,
+}
+
+impl Default for TextRenderer {
+    fn default() -> Self {
+        Self { bars_per_line: 4 }
+    }
+}
+
+impl TextRenderer {
+    pub fn render(&self, tab: &Tab, format: RenderFormat) -> String {
+        let mut text = format!("{}\n", tab.title());
+        match format {
+            RenderFormat::Jianpu => {
+                for track in Self::tracks_of_lane(tab, LaneKind::Melody) {
+                    text.push_str(&format!("\n# {}\n", track.id));
+                    text.push_str(&self.render_bars(tab, 1, |bar| {
+                        let lane = bar.get_lane_of_kind(LaneKind::Melody, Some(track.props.index));
+                        vec![lane.map(|x| Self::render_jianpu(&x)).unwrap_or_default()]
+                    }));
+                }
+            }
+            RenderFormat::Tab => {
+                for track in Self::tracks_of_lane(tab, LaneKind::Strings) {
+                    let string_num = match track.get_string_num() {
+                        Some(string_num) => string_num,
+                        None => continue,
+                    };
+                    text.push_str(&format!("\n# {}\n", track.id));
+                    text.push_str(&self.render_bars(tab, string_num, |bar| {
+                        bar.get_lane_of_kind(LaneKind::Strings, Some(track.props.index))
+                            .and_then(|x| Self::render_strings(bar, &x))
+                            .unwrap_or_else(|| vec![String::new(); string_num])
+                    }));
+                }
+            }
+            RenderFormat::Chords => {
+                text.push('\n');
+                text.push_str(&self.render_bars(tab, 1, |bar| {
+                    let names: Vec<String> = bar
+                        .get_chords()
+                        .iter()
+                        .map(|x| x.calc_name(&tab.meta.scale, &tab.meta.key))
+                        .collect();
+                    vec![names.join(" ")]
+                }));
+            }
+        }
+        text
+    }
+    fn tracks_of_lane(tab: &Tab, kind: LaneKind) -> Vec<&Track> {
+        tab.tracks
+            .iter()
+            .filter(|track| {
+                tab.bars.iter().any(|bar| {
+                    bar.get_lane_of_kind(kind, Some(track.props.index))
+                        .is_some()
+                })
+            })
+            .map(|x| x.as_ref())
+            .collect()
+    }
+    /// Each bar got `rows` lines, which are padded to the same width.
+    fn render_bars<F: Fn(&TabBar) -> Vec<String>>(
+        &self,
+        tab: &Tab,
+        rows: usize,
+        render_bar: F,
+    ) -> String {
+        let mut text = String::new();
+        let mut sections: Vec<Vec<&TabBar>> = Vec::new();
+        for bar in tab.bars.iter() {
+            match sections.last_mut() {
+                Some(bars) if bars[0].props.section_ordinal == bar.props.section_ordinal => {
+                    bars.push(bar)
+                }
+                _ => sections.push(vec![bar]),
+            }
+        }
+        for bars in sections.iter() {
+            text.push_str(&format!("[{}]\n", bars[0].section.id));
+            for line_bars in bars.chunks(self.bars_per_line.max(1)) {
+                let mut lines = vec![String::from("|"); rows];
+                for bar in line_bars {
+                    let mut cells = render_bar(bar);
+                    cells.resize(rows, String::new());
+                    let width = cells.iter().map(|x| x.chars().count()).max().unwrap_or(0);
+                    let fill = if rows > 1 { '-' } else { ' ' };
+                    for (line, cell) in lines.iter_mut().zip(cells.iter()) {
+                        let padding = width - cell.chars().count();
+                        line.push(fill);
+                        line.push_str(cell);
+                        line.push_str(&fill.to_string().repeat(padding + 1));
+                        line.push('|');
+                    }
+                }
+                for line in lines {
+                    text.push_str(&line);
+                    text.push('\n');
+                }
+            }
+        }
+        text
+    }
+    /// Octaves are marked with `'` above and `,` below, underlines with `_`,
+    /// chords are written in brackets.
+    pub fn format_jianpu_entry(entry: &JianpuEntry) -> String {
+        let mut text = if entry.is_rest() {
+            "0".to_owned()
+        } else {
+            let notes: Vec<String> = entry
+                .notes
+                .iter()
+                .map(|x| {
+                    let octave = if x.octave > 0 { "'" } else { "," };
+                    format!(
+                        "{}{}",
+                        x.text(),
+                        octave.repeat(x.octave.unsigned_abs() as usize)
+                    )
+                })
+                .collect();
+            match notes.len() {
+                1 => notes[0].clone(),
+                _ => format!("[{}]", notes.join(" ")),
+            }
+        };
+        text.push_str(&"_".repeat(entry.underlines));
+        if entry.dotted {
+            text.push('.');
+        }
+        text.push_str(&" -".repeat(entry.dashes));
+        if entry.tie_to_next {
+            text.push('~');
+        }
+        text
+    }
+    fn render_jianpu(lane: &BarLane) -> String {
+        let jianpu = LaneJianpu::new(lane);
+        let entries: Vec<String> = jianpu
+            .entries
+            .iter()
+            .map(Self::format_jianpu_entry)
+            .collect();
+        entries.join(" ")
+    }
+    /// Rows of the strings from the first one, which is the highest, tied notes are skipped.
+    fn render_strings(bar: &TabBar, lane: &BarLane) -> Option<Vec<String>> {
+        let string_num = lane.track.get_string_num()?;
+        let meta = bar.tab_meta();
+        let mut rows = vec![String::new(); string_num];
+        for entry in lane.entries.iter() {
+            if entry.prev_is_tie() {
+                continue;
+            }
+            let mut frets = vec![None; string_num];
+            match entry.proto() {
+                ProtoEntry::Core(CoreEntry::Rest(_)) => {}
+                ProtoEntry::Fretted(FrettedEntry::Pick(pick, _)) => {
+                    let (fretboard, shape) = bar.get_fretted_shape(entry)?;
+                    for note in pick.get_notes() {
+                        if let Some((fret, _)) =
+                            fretboard.shape_pick_fret_note(&meta.scale, &meta.key, &shape, note)
+                        {
+                            if let Some(x) = (note.string as usize)
+                                .checked_sub(1)
+                                .and_then(|i| frets.get_mut(i))
+                            {
+                                *x = Some(fret);
+                            }
+                        }
+                    }
+                }
+                ProtoEntry::Fretted(FrettedEntry::Strum(_, _)) => {
+                    let (_, shape) = bar.get_fretted_shape(entry)?;
+                    for (index, fret) in frets.iter_mut().enumerate() {
+                        *fret = shape.string_fret_with_barre(index as u8 + 1);
+                    }
+                }
+                _ => continue,
+            }
+            Self::push_column(&mut rows, &frets);
+        }
+        Some(rows)
+    }
+    fn push_column(rows: &mut [String], frets: &[Option<u8>]) {
+        let cells: Vec<String> = frets
+            .iter()
+            .map(|x| x.map(|fret| fret.to_string()).unwrap_or_default())
+            .collect();
+        let width = cells.iter().map(|x| x.len()).max().unwrap_or(0).max(1);
+        for (row, cell) in rows.iter_mut().zip(cells.iter()) {
+            if !row.is_empty() {
+                row.push('-');
+            }
+            row.push_str(cell);
+            row.push_str(&"-".repeat(width - cell.len()));
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use notation_dsl::prelude::{format_tab, parse_get_tab, parse_tab};
+use notation_model::prelude::{ProtoTab, Tab, TabEncoding, TabFile};
+
+/// DSL sources, either a `GetTab` impl as in `apps/notation_tabs`, or a bare `tab! { ... }`.
+pub const DSL_EXTENSION: &str = "rs";
+
+pub fn is_dsl(path: &str) -> bool {
+    Path::new(path).extension().and_then(|x| x.to_str()) == Some(DSL_EXTENSION)
+}
+
+pub fn read_tab(path: &str) -> Result<ProtoTab> {
+    if is_dsl(path) {
+        let content = fs::read_to_string(path).with_context(|| format!("read {} failed", path))?;
+        let tab = if content.trim_start().starts_with("tab!") {
+            parse_tab(&content)
+        } else {
+            parse_get_tab(&content)
+        };
+        tab.with_context(|| format!("parse {} failed", path))
+    } else {
+        let bytes = fs::read(path).with_context(|| format!("read {} failed", path))?;
+        TabFile::decode_path(path, &bytes).with_context(|| format!("decode {} failed", path))
+    }
+}
+
+pub fn read_model(path: &str) -> Result<Arc<Tab>> {
+    let proto = read_tab(path)?;
+    Tab::try_parse_arc(proto, false, None).with_context(|| format!("parse {} failed", path))
+}
+
+pub fn write_tab(tab: &ProtoTab, path: &str) -> Result<()> {
+    let bytes = if is_dsl(path) {
+        format_tab(tab).into_bytes()
+    } else {
+        TabFile::encode(TabEncoding::from_path(path)?, tab)?
+    };
+    fs::write(path, bytes).with_context(|| format!("write {} failed", path))
+}
+
+/// Writes to `stdout` if `output` is not set.
+pub fn write_output(stdout: &mut dyn Write, output: Option<&str>, text: &str) -> Result<()> {
+    match output {
+        Some(path) => fs::write(path, text).with_context(|| format!("write {} failed", path)),
+        None => {
+            stdout.write_all(text.as_bytes())?;
+            Ok(stdout.flush()?)
+        }
+    }
+}
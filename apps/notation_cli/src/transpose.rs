@@ -0,0 +1,213 @@
+use anyhow::{bail, Result};
+use notation_model::prelude::{
+    CoreEntry, Fretboard, FrettedEntry, HandShape, Key, MetaEntry, Note, Pick, PickNote,
+    ProtoEntry, ProtoTab, ProtoTrack, Scale, Semitones, TabMeta, Tone,
+};
+
+/// Notes are moved by the offset and spelled in the new key, chords are relative to the key
+/// so they follow it. Fretted tracks keep the fingerings and move the capo instead, what the
+/// capo can't cover moves the frets, or the tuning if some of the frets can't be moved.
+pub struct Transposer {
+    pub offset: Semitones,
+}
+
+impl Transposer {
+    pub fn new(semitones: i8) -> Self {
+        Self {
+            offset: Semitones(semitones),
+        }
+    }
+    /// The uuid is kept, the result is the same tab in another key.
+    pub fn transpose(&self, tab: &ProtoTab) -> Result<ProtoTab> {
+        let meta = TabMeta {
+            key: self.transpose_key(&tab.meta.key),
+            ..tab.meta
+        };
+        let tracks = tab
+            .tracks
+            .iter()
+            .map(|track| self.transpose_track(&meta, track))
+            .collect::<Result<Vec<ProtoTrack>>>()?;
+        Ok(ProtoTab {
+            meta,
+            tracks,
+            ..tab.clone()
+        })
+    }
+    pub fn transpose_key(&self, key: &Key) -> Key {
+        Key::from(Semitones::from(*key) + self.offset)
+    }
+    pub fn transpose_note(&self, scale: &Scale, key: &Key, note: &Note) -> Note {
+        scale.calc_note_from_semitones(key, Semitones::from(*note) + self.offset)
+    }
+    fn transpose_tone(&self, scale: &Scale, key: &Key, tone: &Tone) -> Tone {
+        Tone::new(tone.iter().map(|x| self.transpose_note(scale, key, x)))
+    }
+    /// Keys set in the middle of the track are transposed too, and used for the notes after them.
+    fn transpose_track(&self, meta: &TabMeta, track: &ProtoTrack) -> Result<ProtoTrack> {
+        let mut key = meta.key;
+        let mut scale = meta.scale;
+        let shift = self.calc_fretted_shift(track)?;
+        let mut entries = Vec::with_capacity(track.entries.len());
+        for entry in track.entries.iter() {
+            let entry = match entry {
+                ProtoEntry::Meta(MetaEntry::Key(x)) => {
+                    key = self.transpose_key(x);
+                    ProtoEntry::Meta(MetaEntry::Key(key))
+                }
+                ProtoEntry::Meta(MetaEntry::Scale(x)) => {
+                    scale = *x;
+                    entry.clone()
+                }
+                ProtoEntry::Extra(name, value) if name == "dsl::context::key" => {
+                    match Key::ALL.iter().find(|x| x.to_string() == *value) {
+                        Some(x) => {
+                            key = self.transpose_key(x);
+                            ProtoEntry::Extra(name.clone(), key.to_string())
+                        }
+                        None => entry.clone(),
+                    }
+                }
+                ProtoEntry::Extra(name, value) if name == "dsl::context::scale" => {
+                    if let Some(x) = Scale::ALL.iter().find(|x| x.to_string() == *value) {
+                        scale = *x;
+                    }
+                    entry.clone()
+                }
+                ProtoEntry::Core(CoreEntry::Tone(tone, duration)) => ProtoEntry::Core(
+                    CoreEntry::Tone(self.transpose_tone(&scale, &key, tone), *duration),
+                ),
+                ProtoEntry::Fretted(x) => {
+                    ProtoEntry::Fretted(shift.transpose_fretted(x))
+                }
+                _ => entry.clone(),
+            };
+            entries.push(entry);
+        }
+        Ok(ProtoTrack {
+            entries,
+            ..track.clone()
+        })
+    }
+}
+
+/// How the offset is split for a fretted track, all in semitones.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+struct FrettedShift {
+    capo: i16,
+    frets: i16,
+    tuning: i16,
+}
+
+impl Transposer {
+    /// The capo is moved as far as it can go, the rest moves the frets if all of them stay
+    /// on the fretboard above the capo, otherwise the strings are tuned instead.
+    fn calc_fretted_shift(&self, track: &ProtoTrack) -> Result<FrettedShift> {
+        let offset = self.offset.0 as i16;
+        let fretboards: Vec<&Fretboard> = track
+            .entries
+            .iter()
+            .filter_map(|x| match x {
+                ProtoEntry::Fretted(FrettedEntry::Fretboard(fretboard)) => Some(fretboard),
+                _ => None,
+            })
+            .collect();
+        let frets: Vec<u8> = track
+            .entries
+            .iter()
+            .flat_map(|x| match x {
+                ProtoEntry::Fretted(FrettedEntry::Shape(shape, _)) => {
+                    shape.frets.iter().flatten().chain(shape.barre.iter()).copied().collect()
+                }
+                ProtoEntry::Fretted(FrettedEntry::Pick(pick, _)) => {
+                    pick.iter().filter_map(|x| x.fret).collect()
+                }
+                _ => vec![],
+            })
+            .collect();
+        if offset == 0 || (fretboards.is_empty() && frets.is_empty()) {
+            return Ok(FrettedShift::default());
+        }
+        let max_fret = fretboards
+            .iter()
+            .map(|x| x.total_fret_num as i16 - 1)
+            .min()
+            .unwrap_or(i16::MAX);
+        let capo = match (
+            fretboards.iter().map(|x| x.capo as i16).min(),
+            fretboards.iter().map(|x| x.capo as i16).max(),
+        ) {
+            (Some(min_capo), Some(max_capo)) => offset.max(-min_capo).min(max_fret - max_capo),
+            _ => 0,
+        };
+        let rest = offset - capo;
+        if rest == 0 {
+            return Ok(FrettedShift {
+                capo,
+                ..FrettedShift::default()
+            });
+        }
+        let fret_limit = fretboards
+            .iter()
+            .map(|x| x.total_fret_num as i16 - 1 - (x.capo as i16 + capo))
+            .min()
+            .unwrap_or(i16::MAX);
+        if frets
+            .iter()
+            .all(|x| (0..=fret_limit).contains(&(*x as i16 + rest)))
+        {
+            return Ok(FrettedShift {
+                capo,
+                frets: rest,
+                ..FrettedShift::default()
+            });
+        }
+        if fretboards.is_empty() {
+            bail!("Can't move frets of {} by {} without a fretboard", track.id, rest);
+        }
+        Ok(FrettedShift {
+            capo,
+            tuning: rest,
+            ..FrettedShift::default()
+        })
+    }
+}
+
+impl FrettedShift {
+    fn move_fret(&self, fret: u8) -> u8 {
+        (fret as i16 + self.frets) as u8
+    }
+    fn transpose_fretted(&self, entry: &FrettedEntry) -> FrettedEntry {
+        match entry {
+            FrettedEntry::Fretboard(fretboard) => {
+                let tuning = Semitones(self.tuning as i8);
+                FrettedEntry::Fretboard(Fretboard {
+                    capo: (fretboard.capo as i16 + self.capo) as u8,
+                    string_notes: fretboard.string_notes.iter().map(|x| *x + tuning).collect(),
+                    course_notes: fretboard
+                        .course_notes
+                        .iter()
+                        .map(|x| x.map(|x| x + tuning))
+                        .collect(),
+                    ..fretboard.clone()
+                })
+            }
+            FrettedEntry::Shape(shape, duration) if self.frets != 0 => FrettedEntry::Shape(
+                HandShape {
+                    barre: shape.barre.map(|x| self.move_fret(x)),
+                    frets: shape.frets.iter().map(|x| x.map(|x| self.move_fret(x))).collect(),
+                    ..shape.clone()
+                },
+                *duration,
+            ),
+            FrettedEntry::Pick(pick, duration) if self.frets != 0 => FrettedEntry::Pick(
+                Pick::new(pick.iter().map(|x| PickNote {
+                    fret: x.fret.map(|x| self.move_fret(x)),
+                    ..*x
+                })),
+                *duration,
+            ),
+            _ => entry.clone(),
+        }
+    }
+}
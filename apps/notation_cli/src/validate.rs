@@ -0,0 +1,135 @@
+use std::fmt::Display;
+
+use notation_model::prelude::{FrettedEntry, ProtoEntry, ProtoTab, SliceBegin, SliceEnd};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Clone, Debug)]
+pub struct TabIssue {
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Display for TabIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.severity {
+            Severity::Error => write!(f, "error: {}", self.message),
+            Severity::Warning => write!(f, "warning: {}", self.message),
+        }
+    }
+}
+
+/// Same checks as the language server, without the source locations, so that they work
+/// for all the formats.
+#[derive(Clone, Debug, Default)]
+pub struct TabValidator {
+    pub issues: Vec<TabIssue>,
+}
+
+impl TabValidator {
+    pub fn validate(tab: &ProtoTab) -> Vec<TabIssue> {
+        let mut validator = Self::default();
+        validator.check_tab(tab);
+        validator.issues
+    }
+    fn add_error(&mut self, message: String) {
+        self.issues.push(TabIssue {
+            severity: Severity::Error,
+            message,
+        });
+    }
+    fn add_warning(&mut self, message: String) {
+        self.issues.push(TabIssue {
+            severity: Severity::Warning,
+            message,
+        });
+    }
+    fn check_tab(&mut self, tab: &ProtoTab) {
+        for (index, track) in tab.tracks.iter().enumerate() {
+            if tab.tracks[..index].iter().any(|x| x.id == track.id) {
+                self.add_error(format!("Duplicated track: {}", track.id));
+            }
+            let mut string_num = None;
+            for entry in track.entries.iter() {
+                match entry.as_fretted() {
+                    Some(FrettedEntry::Fretboard(x)) => string_num = Some(x.string_num()),
+                    Some(FrettedEntry::Shape(x, _)) => string_num = Some(x.string_num()),
+                    _ => (),
+                }
+                self.check_pick(&track.id, string_num, entry);
+            }
+        }
+        for (index, section) in tab.sections.iter().enumerate() {
+            if tab.sections[..index].iter().any(|x| x.id == section.id) {
+                self.add_error(format!("Duplicated section: {}", section.id));
+            }
+            for (bar_index, bar) in section.bars.iter().enumerate() {
+                for layer in bar.layers.iter() {
+                    let track = match tab.tracks.iter().find(|x| x.id == layer.track) {
+                        Some(track) => track,
+                        None => {
+                            self.add_error(format!(
+                                "Track not found in {} bar {}: {}",
+                                section.id,
+                                bar_index + 1,
+                                layer.track
+                            ));
+                            continue;
+                        }
+                    };
+                    for slice in layer.slices.iter() {
+                        let find_mark = |begin: usize, mark: &String| {
+                            (begin..track.entries.len())
+                                .find(|x| track.entries[*x].is_mark_string(mark))
+                        };
+                        let begin = match &slice.begin {
+                            SliceBegin::Mark(mark) => find_mark(0, mark).map(|x| x + 1),
+                            SliceBegin::Index(index) => Some(*index),
+                        };
+                        let found = match (begin, &slice.end) {
+                            (None, _) => false,
+                            (Some(begin), SliceEnd::Mark(mark)) => find_mark(begin, mark).is_some(),
+                            (Some(begin), SliceEnd::Count(_)) => begin <= track.entries.len(),
+                        };
+                        if !found {
+                            self.add_warning(format!(
+                                "Slice not found in {} bar {}: {} {}",
+                                section.id,
+                                bar_index + 1,
+                                track.id,
+                                slice
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        if tab.form.sections.is_empty() {
+            self.add_warning("Form is empty".to_owned());
+        }
+        for section in tab.form.sections.iter() {
+            if !tab.sections.iter().any(|x| &x.id == section) {
+                self.add_error(format!("Section not found in form: {}", section));
+            }
+        }
+    }
+    /// The string number is the one of the last fretboard or shape before the pick.
+    fn check_pick(&mut self, track: &str, string_num: Option<usize>, entry: &ProtoEntry) {
+        let (string_num, pick) = match (string_num, entry.as_fretted().and_then(|x| x.as_pick())) {
+            (Some(string_num), Some(pick)) => (string_num, pick),
+            _ => return,
+        };
+        for note in pick.get_notes() {
+            if note.string == 0 || note.string as usize > string_num {
+                self.add_warning(format!(
+                    "Pick string out of range in {}: {} of {} strings",
+                    track, note.string, string_num
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,220 @@
+# UI texts of Fun Notation, see https://projectfluent.org/ for the syntax.
+#
+# To add a language, copy this file and the markdown files next to it into kb/<lang>/,
+# translate them, then run with `--lang <lang>`, missing messages fall back to en-US.
+
+## Settings of the language, not shown in the ui
+
+locale-lyrics-font = fonts/en-US/FiraMono-Medium.ttf
+# default or bigger
+locale-egui-font-sizes = default
+
+## Control panel
+
+control-load-tab-failed = Load Tab Failed
+control-loading-tab = Loading Tab ...
+control-reload-tab = Reload Tab
+control-help = Help
+control-library = Library
+control-karaoke = Karaoke
+control-export-lyrics = Export Lyrics
+control-open-tab = Open Tab
+
+control-display-options = Display Options
+control-hide-guitar-notes = Hide Guitar Notes
+control-hide-guitar-chords = Hide Guitar Chords
+control-hide-guitar-strings = Hide Guitar Strings
+control-hide-lyrics = Hide Lyrics
+control-hide-melody = Hide Melody
+control-hide-staff = Hide Staff
+control-hide-jianpu = Hide Jianpu
+control-show-chord-analysis = Show Chord Analysis
+control-use-nashville-numbers = Use Nashville Numbers
+control-show-note-pitch = Show Note Pitch
+control-show-note-syllable = Show Note Syllable
+control-show-syllable-as-numbers = Show Syllable as Numbers
+control-hide-bar-number = Hide Bar Number
+control-hide-indicators = Hide Indicators
+control-always-show-fret = Always Show Fret
+control-hide-guitar-view = Hide Guitar View
+control-hide-chords-view = Hide Chords View
+control-hide-mini-map = Hide Mini Map
+
+control-layout-options = Layout Options
+control-video-recording-mode = Video Recording Mode
+control-switch-to-line-mode = Switch to Line Mode
+control-switch-to-grid-mode = Switch to Grid Mode
+control-switch-align-mode = Switch Align Mode
+control-align-top = Top
+control-align-force-top = Force Top
+control-align-center = Center
+control-align-force-center = Force Center
+
+control-override-sizes = Override Sizes
+control-override-tab-width = Override Tab Width
+control-tab-width = Tab Width
+control-override-beat-size = Override Beat Size
+control-beat-size = Beat Size
+control-override-chord-size = Override Chord Size
+control-chord-size = Chord Size
+control-override-guitar-width = Override Guitar Width
+control-guitar-width = Guitar Width
+control-override-focus-offset-y = Override Focus Offset Y
+control-focus-offset-y = Focus Offset Y
+control-override-guitar-y = Override Guitar Y
+control-guitar-y = Guitar Y
+
+control-window-size = Window: { $width } x { $height }
+
+control-override-theme = Override Theme
+control-guitar-tab = Guitar Tab
+control-string-space = String Space
+control-note-height = Note Height
+control-fret-font-size = Fret Font Size
+control-fret-offset-x = Fret Offset X
+control-fret-offset-y = Fret Offset Y
+control-lyrics = Lyrics
+control-line-height-idle = Line Height (Idle)
+control-line-height-current = Line Height (Current)
+control-line-height-played = Line Height (Played)
+control-word-gap = Word Gap
+control-word-font-size = Word Font Size
+control-word-offset-x = Word Offset X
+control-word-offset-y = Word Offset Y
+control-melody = Melody
+control-syllable-font-size = Syllable Font Size
+control-syllable-offset-x = Syllable Offset X
+control-syllable-offset-y = Syllable Offset Y
+control-reset-theme = Reset Theme
+
+control-preset = Preset: { $preset }
+preset-guitar-tab = Guitar Tab
+preset-guitar-chords = Guitar Chords
+preset-guitar-notes = Guitar Notes
+preset-guitar-strings = Guitar Strings
+preset-melody = Melody
+
+## Play control and midi
+
+midi-play-control = Play Control
+midi-add-empty-bar = Add Empty Bar
+midi-new-row-for-section = New Row for Section
+midi-play = Play
+midi-pause = Pause
+midi-stop = Stop
+midi-loop = Loop
+midi-begin-bar = Begin: { $bar }
+midi-end-bar = End: { $bar }
+midi-clear = Clear
+midi-clear-visible-bars = Clear Visible Bars: { $begin } - { $end }
+midi-set-visible-bars = Set Visible Bars: { $begin } - { $end }
+midi-speed = Speed
+
+midi-speed-trainer = Speed Trainer
+midi-trainer-start-percent = Start %
+midi-trainer-step-percent = Step %
+midi-trainer-target-percent = Target %
+midi-trainer-max-loops = Max Loops (0 = Unlimited)
+midi-trainer-start = Start Trainer
+midi-trainer-stop = Stop Trainer
+midi-trainer-restart = Restart
+midi-trainer-loop = Loop { $loop }: { $percent }% ({ $bpm } BPM)
+midi-trainer-finished = Loop { $loop }: { $percent }% ({ $bpm } BPM) Finished
+midi-trainer-best = Best: { $bpm } BPM
+
+midi-audio = Midi & Audio
+midi-bypass-hub = Bypass Midi Hub
+midi-click-octave = Click Octave: { $octave }
+midi-click-octave-lower = lower
+midi-click-octave-higher = higher
+midi-mute = Mute
+midi-click = Click
+midi-accent = Accent
+midi-click-sound = Click Sound
+midi-accent-sound = Accent Sound
+midi-subdivision = Subdivision
+midi-subdivision-none = None
+midi-subdivision-eighth = Eighth
+midi-subdivision-sixteenth = Sixteenth
+midi-count-in-bars = Count-in Bars
+midi-fermata-speed = Fermata Speed
+midi-humanize = Humanize
+midi-humanize-timing = Timing %
+midi-humanize-velocity = Velocity Range
+midi-velocity = Velocity
+midi-reset-audio = Reset Audio
+midi-solo = Solo
+midi-volume = Volume
+midi-pan = Pan
+midi-reverb = Reverb
+midi-chorus = Chorus
+midi-program = Program
+midi-shared-percussion = Program, volume, pan and sends follow the first drums track
+
+## Library
+
+library-window = Library
+library-search = Search
+library-clear = Clear
+library-key = Key
+library-any = Any
+library-chords = Chords
+library-min-bpm = Min BPM
+library-max-bpm = Max BPM
+library-matched-tabs = { $matched } / { $total } Tabs
+library-rescan = Rescan
+library-scanning = Scanning...
+library-title = Title
+library-artist = Artist
+library-bpm = BPM
+library-bars = Bars
+
+## Karaoke
+
+karaoke-no-lyrics = No Lyrics
+karaoke-close = Close Karaoke (K)
+
+## Reloading changed tab file
+
+reload-failed = Reload Tab Failed
+reload-showing-last-version = Showing the last loaded version, save the file to retry.
+reload-dismiss = Dismiss
+
+## Knowledge base
+
+kb-title = Fun Notation - Knowledge Base
+kb-asset-not-found = Asset Not Found: { $path }
+kb-tab-not-loaded = Tab not loaded...
+kb-scale = Scale:
+kb-key = Key:
+kb-chord-root = root
+kb-chord-bass = bass
+kb-with = with
+kb-capo = capo
+kb-at = at
+kb-fret = fret
+
+## Index
+
+index-title = Index (F1, H)
+index-welcome = Welcome
+index-sound = Sound
+index-scale = Scale
+index-guitar = Guitar
+
+sound-size = Size
+sound-speed = Speed
+sound-separate-harmonics = Separate Harmonics
+sound-mute = mute
+sound-clear = clear
+sound-reset = reset
+sound-strength = strength
+sound-harmonics = harmonics
+sound-guitar-fret = guitar fret
+sound-note = note
+sound-math = math
+
+scale-play = play
+scale-stop = stop
+
+guitar-todo = TODO
@@ -0,0 +1,217 @@
+# Fun Notation 的界面文字，语法见 https://projectfluent.org/
+
+## 语言设置，不在界面中显示
+
+locale-lyrics-font = fonts/zh-CN/NotoSansSC-Medium.otf
+# default 或 bigger
+locale-egui-font-sizes = bigger
+
+## 控制面板
+
+control-load-tab-failed = 加载曲谱失败
+control-loading-tab = 正在加载曲谱 ...
+control-reload-tab = 重新加载
+control-help = 帮助
+control-library = 曲库
+control-karaoke = 卡拉OK
+control-export-lyrics = 导出歌词
+control-open-tab = 打开曲谱
+
+control-display-options = 显示选项
+control-hide-guitar-notes = 隐藏吉他音符
+control-hide-guitar-chords = 隐藏吉他和弦
+control-hide-guitar-strings = 隐藏吉他弦
+control-hide-lyrics = 隐藏歌词
+control-hide-melody = 隐藏旋律
+control-hide-staff = 隐藏五线谱
+control-hide-jianpu = 隐藏简谱
+control-show-chord-analysis = 显示和弦级数
+control-use-nashville-numbers = 使用纳什维尔数字
+control-show-note-pitch = 显示音名
+control-show-note-syllable = 显示唱名
+control-show-syllable-as-numbers = 唱名显示为数字
+control-hide-bar-number = 隐藏小节号
+control-hide-indicators = 隐藏指示器
+control-always-show-fret = 总是显示品位
+control-hide-guitar-view = 隐藏吉他视图
+control-hide-chords-view = 隐藏和弦视图
+control-hide-mini-map = 隐藏缩略图
+
+control-layout-options = 布局选项
+control-video-recording-mode = 录像模式
+control-switch-to-line-mode = 切换到单行模式
+control-switch-to-grid-mode = 切换到网格模式
+control-switch-align-mode = 切换对齐方式
+control-align-top = 顶部
+control-align-force-top = 强制顶部
+control-align-center = 居中
+control-align-force-center = 强制居中
+
+control-override-sizes = 自定义尺寸
+control-override-tab-width = 自定义曲谱宽度
+control-tab-width = 曲谱宽度
+control-override-beat-size = 自定义节拍大小
+control-beat-size = 节拍大小
+control-override-chord-size = 自定义和弦大小
+control-chord-size = 和弦大小
+control-override-guitar-width = 自定义吉他宽度
+control-guitar-width = 吉他宽度
+control-override-focus-offset-y = 自定义焦点纵向偏移
+control-focus-offset-y = 焦点纵向偏移
+control-override-guitar-y = 自定义吉他纵向位置
+control-guitar-y = 吉他纵向位置
+
+control-window-size = 窗口: { $width } x { $height }
+
+control-override-theme = 自定义主题
+control-guitar-tab = 吉他谱
+control-string-space = 弦间距
+control-note-height = 音符高度
+control-fret-font-size = 品位字号
+control-fret-offset-x = 品位横向偏移
+control-fret-offset-y = 品位纵向偏移
+control-lyrics = 歌词
+control-line-height-idle = 行高 (未播放)
+control-line-height-current = 行高 (当前)
+control-line-height-played = 行高 (已播放)
+control-word-gap = 字间距
+control-word-font-size = 歌词字号
+control-word-offset-x = 歌词横向偏移
+control-word-offset-y = 歌词纵向偏移
+control-melody = 旋律
+control-syllable-font-size = 唱名字号
+control-syllable-offset-x = 唱名横向偏移
+control-syllable-offset-y = 唱名纵向偏移
+control-reset-theme = 重置主题
+
+control-preset = 预设: { $preset }
+preset-guitar-tab = 吉他谱
+preset-guitar-chords = 吉他和弦
+preset-guitar-notes = 吉他音符
+preset-guitar-strings = 吉他弦
+preset-melody = 旋律
+
+## 播放控制与 Midi
+
+midi-play-control = 播放控制
+midi-add-empty-bar = 添加空小节
+midi-new-row-for-section = 段落另起一行
+midi-play = 播放
+midi-pause = 暂停
+midi-stop = 停止
+midi-loop = 循环
+midi-begin-bar = 开始: { $bar }
+midi-end-bar = 结束: { $bar }
+midi-clear = 清除
+midi-clear-visible-bars = 清除可见小节: { $begin } - { $end }
+midi-set-visible-bars = 设置可见小节: { $begin } - { $end }
+midi-speed = 速度
+
+midi-speed-trainer = 速度练习
+midi-trainer-start-percent = 起始 %
+midi-trainer-step-percent = 步进 %
+midi-trainer-target-percent = 目标 %
+midi-trainer-max-loops = 最多循环次数 (0 = 不限)
+midi-trainer-start = 开始练习
+midi-trainer-stop = 停止练习
+midi-trainer-restart = 重新开始
+midi-trainer-loop = 第 { $loop } 遍: { $percent }% ({ $bpm } BPM)
+midi-trainer-finished = 第 { $loop } 遍: { $percent }% ({ $bpm } BPM) 已完成
+midi-trainer-best = 最佳: { $bpm } BPM
+
+midi-audio = Midi 与音频
+midi-bypass-hub = 绕过 Midi Hub
+midi-click-octave = 节拍器八度: { $octave }
+midi-click-octave-lower = 降低
+midi-click-octave-higher = 升高
+midi-mute = 静音
+midi-click = 节拍器
+midi-accent = 重音
+midi-click-sound = 节拍器音色
+midi-accent-sound = 重音音色
+midi-subdivision = 细分
+midi-subdivision-none = 无
+midi-subdivision-eighth = 八分音符
+midi-subdivision-sixteenth = 十六分音符
+midi-count-in-bars = 预备小节数
+midi-fermata-speed = 延长音速度
+midi-humanize = 人性化
+midi-humanize-timing = 时间 %
+midi-humanize-velocity = 力度范围
+midi-velocity = 力度
+midi-reset-audio = 重置音频
+midi-solo = 独奏
+midi-volume = 音量
+midi-pan = 声像
+midi-reverb = 混响
+midi-chorus = 合唱
+midi-program = 音色
+midi-shared-percussion = 音色、音量、声像和效果跟随第一个鼓轨
+
+## 曲库
+
+library-window = 曲库
+library-search = 搜索
+library-clear = 清除
+library-key = 调
+library-any = 任意
+library-chords = 和弦
+library-min-bpm = 最低 BPM
+library-max-bpm = 最高 BPM
+library-matched-tabs = { $matched } / { $total } 首曲谱
+library-rescan = 重新扫描
+library-scanning = 正在扫描...
+library-title = 标题
+library-artist = 艺术家
+library-bpm = BPM
+library-bars = 小节数
+
+## 卡拉OK
+
+karaoke-no-lyrics = 没有歌词
+karaoke-close = 关闭卡拉OK (K)
+
+## 重新加载修改过的曲谱文件
+
+reload-failed = 重新加载曲谱失败
+reload-showing-last-version = 正在显示上次加载的版本，保存文件后会重试。
+reload-dismiss = 关闭
+
+## 知识库
+
+kb-title = Fun Notation - 知识库
+kb-asset-not-found = 找不到资源: { $path }
+kb-tab-not-loaded = 曲谱尚未加载...
+kb-scale = 音阶:
+kb-key = 调:
+kb-chord-root = 根音
+kb-chord-bass = 低音
+kb-with = 使用
+kb-capo = 变调夹
+kb-at = 在
+kb-fret = 品
+
+## 目录
+
+index-title = 目录 (F1, H)
+index-welcome = 欢迎
+index-sound = 声音
+index-scale = 音阶
+index-guitar = 吉他
+
+sound-size = 大小
+sound-speed = 速度
+sound-separate-harmonics = 分开显示泛音
+sound-mute = 静音
+sound-clear = 清除
+sound-reset = 重置
+sound-strength = 强度
+sound-harmonics = 泛音
+sound-guitar-fret = 吉他品位
+sound-note = 音符
+sound-math = 计算
+
+scale-play = 播放
+scale-stop = 停止
+
+guitar-todo = 待完成
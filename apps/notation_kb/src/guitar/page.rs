@@ -3,7 +3,7 @@ use tab_viewer::edger_bevy::bevy_prelude::*;
 use tab_viewer::edger_bevy::egui::*;
 
 use tab_viewer::kb::markdown_page::MarkDownPage;
-use tab_viewer::prelude::{NotationState, NotationAssets, NotationTheme, MarkDownAsset, KbPage, KbContent, EasyLinkEvent, Syllable};
+use tab_viewer::prelude::{NotationState, NotationAssets, NotationLocale, NotationTheme, MarkDownAsset, KbPage, KbContent, EasyLinkEvent, Syllable};
 
 #[derive(Clone, Debug)]
 pub struct GuitarPage {
@@ -17,10 +17,11 @@ impl KbPage for GuitarPage {
         texts: &Assets<MarkDownAsset>,
         app_state: &AppState,
         state: &NotationState,
+        locale: &NotationLocale,
         theme: &NotationTheme,
         link_evts: &mut EventWriter<EasyLinkEvent>,
     ) {
-        MarkDownPage::markdown_ui(ui, texts, app_state, state, theme, link_evts, self.path.as_str());
+        MarkDownPage::markdown_ui(ui, texts, app_state, state, locale, theme, link_evts, self.path.as_str());
     }
 }
 
@@ -31,10 +32,11 @@ impl KbContent for GuitarPage {
         texts: &Assets<MarkDownAsset>,
         app_state: &AppState,
         state: &NotationState,
+        locale: &NotationLocale,
         theme: &NotationTheme,
         link_evts: &mut EventWriter<EasyLinkEvent>,
     ) {
-        ui.label("TODO");
+        ui.label(locale.text("guitar-todo"));
     }
 }
 
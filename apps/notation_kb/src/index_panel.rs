@@ -4,7 +4,7 @@ use tab_viewer::edger_bevy::bevy_egui::{egui, EguiContext};
 use tab_viewer::prelude::{StereoStream, ProtoTab, NotationSettings, Control, MidiState, PlayControlEvent, MidiControl, EguiContexts};
 
 use tab_viewer::prelude::{MarkDownAsset, KbPageId, KbPage, KbContent, KbPanel, DockSide, EasyLinkEvent};
-use tab_viewer::prelude::{NotationState, NotationAssets, NotationTheme};
+use tab_viewer::prelude::{NotationState, NotationAssets, NotationLocale, NotationTheme};
 
 use tab_viewer::kb::markdown_page::MarkDownPage;
 
@@ -68,7 +68,7 @@ impl IndexPanel {
 
 impl KbPanel for IndexPanel {
     fn get_title(&self) -> &str {
-        "Index (F1, H)"
+        "index-title"
     }
 
     fn get_current_page_id(&self) -> KbPageId {
@@ -81,10 +81,10 @@ impl KbPanel for IndexPanel {
 
     fn get_page_tabs(&self) -> Vec<(KbPageId, &'static str)> {
         vec![
-            (Self::WELCOME, "Welcome"),
-            (Self::SOUND, "Sound"),
-            (Self::SCALE, "Scale"),
-            (Self::GUITAR, "Guitar"),
+            (Self::WELCOME, "index-welcome"),
+            (Self::SOUND, "index-sound"),
+            (Self::SCALE, "index-scale"),
+            (Self::GUITAR, "index-guitar"),
         ]
     }
 
@@ -157,6 +157,7 @@ impl IndexPanel {
         texts: Res<Assets<MarkDownAsset>>,
         app_state: Res<AppState>,
         mut state: ResMut<NotationState>,
+        locale: Res<NotationLocale>,
         theme: Res<NotationTheme>,
         mut link_evts: EventWriter<EasyLinkEvent>,
         mut index: ResMut<IndexPanel>,
@@ -167,12 +168,12 @@ impl IndexPanel {
         }
         if app_state.window_width > app_state.window_height {
             let width = app_state.window_width / 3.0;
-            (&mut index).side_ui(&mut egui_ctx, &texts, &app_state, &mut state, &theme, &mut link_evts, DockSide::Left, (width, width));
+            (&mut index).side_ui(&mut egui_ctx, &texts, &app_state, &mut state, &locale, &theme, &mut link_evts, DockSide::Left, (width, width));
         } else {
             let height = app_state.window_height / 3.0;
-            (&mut index).side_ui(&mut egui_ctx, &texts, &app_state, &mut state, &theme, &mut link_evts, DockSide::Top, (height, height));
+            (&mut index).side_ui(&mut egui_ctx, &texts, &app_state, &mut state, &locale, &theme, &mut link_evts, DockSide::Top, (height, height));
         }
-        (&mut index).content_ui(&mut egui_ctx, &texts, &app_state, &state, &theme, &mut link_evts);
+        (&mut index).content_ui(&mut egui_ctx, &texts, &app_state, &state, &locale, &theme, &mut link_evts);
     }
 
     fn content_ui(
@@ -181,6 +182,7 @@ impl IndexPanel {
         texts: &Assets<MarkDownAsset>,
         app_state: &AppState,
         state: &NotationState,
+        locale: &NotationLocale,
         theme: &NotationTheme,
         link_evts: &mut EventWriter<EasyLinkEvent>,
     ) {
@@ -195,7 +197,7 @@ impl IndexPanel {
             _ => None,
         } {
             egui::CentralPanel::default().show(egui_ctx.ctx_mut(), |ui| {
-                content.content_ui(ui, texts, app_state, state, theme, link_evts);
+                content.content_ui(ui, texts, app_state, state, locale, theme, link_evts);
             });
         }
     }
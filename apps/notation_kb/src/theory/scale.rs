@@ -22,15 +22,16 @@ impl KbPage for ScalePage {
         texts: &Assets<MarkDownAsset>,
         app_state: &AppState,
         state: &NotationState,
+        locale: &NotationLocale,
         theme: &NotationTheme,
         link_evts: &mut EventWriter<EasyLinkEvent>,
     ) {
-        MarkDownPage::markdown_ui(ui, texts, app_state, state, theme, link_evts, self.path.as_str());
+        MarkDownPage::markdown_ui(ui, texts, app_state, state, locale, theme, link_evts, self.path.as_str());
         ui.separator();
         let scale = self.scale.clone();
         let key = self.key.clone();
         ui.horizontal(|ui| {
-            ui.label("Scale:");
+            ui.label(locale.text("kb-scale"));
             egui::ComboBox::from_id_source("scale")
             .width(128.0)
             .selected_text(scale.to_ident())
@@ -42,7 +43,7 @@ impl KbPage for ScalePage {
                     }
                 }
             });
-            ui.label("Key:");
+            ui.label(locale.text("kb-key"));
             egui::ComboBox::from_id_source("key")
             .width(64.0)
             .selected_text(key.to_string())
@@ -55,13 +56,13 @@ impl KbPage for ScalePage {
             });
         });
         ui.separator();
-        NotesPage::notes_ui(ui, texts, app_state, state, theme, link_evts, self.scale, self.key, self.transpose);
+        NotesPage::notes_ui(ui, texts, app_state, state, locale, theme, link_evts, self.scale, self.key, self.transpose);
         ui.separator();
         ui.horizontal(|ui| {
-            if ui.button("play").clicked() {
+            if ui.button(locale.text("scale-play")).clicked() {
                 link_evts.send(EasyLinkEvent::from(IndexPanel::LINK_MIDI_PLAY));
             }
-            if ui.button("stop").clicked() {
+            if ui.button(locale.text("scale-stop")).clicked() {
                 link_evts.send(EasyLinkEvent::from(IndexPanel::LINK_MIDI_STOP));
             }
         });
@@ -75,6 +76,7 @@ impl KbContent for ScalePage {
         texts: &Assets<MarkDownAsset>,
         app_state: &AppState,
         state: &NotationState,
+        locale: &NotationLocale,
         theme: &NotationTheme,
         link_evts: &mut EventWriter<EasyLinkEvent>,
     ) {
@@ -7,7 +7,7 @@ use egui_plot::*;
 use tab_viewer::prelude::{AudioConsts, StereoStream};
 
 use tab_viewer::kb::markdown_page::MarkDownPage;
-use tab_viewer::prelude::{NotationState, NotationAssets, NotationTheme, MarkDownAsset, KbPage, KbContent, EasyLinkEvent, Syllable, PageHelper, color_to_hsva};
+use tab_viewer::prelude::{NotationState, NotationAssets, NotationLocale, NotationTheme, MarkDownAsset, KbPage, KbContent, EasyLinkEvent, Syllable, PageHelper, color_to_hsva};
 
 #[derive(Copy, Clone, Debug)]
 pub enum SoundSection {
@@ -74,14 +74,15 @@ impl KbPage for SoundPage {
         texts: &Assets<MarkDownAsset>,
         app_state: &AppState,
         state: &NotationState,
+        locale: &NotationLocale,
         theme: &NotationTheme,
         link_evts: &mut EventWriter<EasyLinkEvent>,
     ) {
-        MarkDownPage::markdown_ui(ui, texts, app_state, state, theme, link_evts, self.path.as_str());
+        MarkDownPage::markdown_ui(ui, texts, app_state, state, locale, theme, link_evts, self.path.as_str());
         ui.separator();
         match self.section {
             SoundSection::SingleString(ref mut data) => {
-                Self::single_string_settings(ui, theme, data);
+                Self::single_string_settings(ui, locale, theme, data);
             },
         }
     }
@@ -94,12 +95,13 @@ impl KbContent for SoundPage {
         texts: &Assets<MarkDownAsset>,
         app_state: &AppState,
         state: &NotationState,
+        locale: &NotationLocale,
         theme: &NotationTheme,
         link_evts: &mut EventWriter<EasyLinkEvent>,
     ) {
         match self.section {
             SoundSection::SingleString(ref mut data) => {
-                Self::single_string_ui(ui, texts, app_state, state, theme, link_evts, data);
+                Self::single_string_ui(ui, texts, app_state, state, locale, theme, link_evts, data);
             },
         }
     }
@@ -203,6 +205,7 @@ impl SoundPage {
         _texts: &Assets<MarkDownAsset>,
         _app_state: &AppState,
         _state: &NotationState,
+        _locale: &NotationLocale,
         theme: &NotationTheme,
         _link_evts: &mut EventWriter<EasyLinkEvent>,
         data: &mut SingleStringData,
@@ -225,34 +228,35 @@ impl SoundPage {
     }
     pub fn single_string_settings(
         ui: &mut Ui,
+        locale: &NotationLocale,
         theme: &NotationTheme,
         data: &mut SingleStringData,
     ) {
-        ui.add(Slider::new(&mut data.size, 0.25..=10.0).text("Size").logarithmic(true));
-        ui.add(Slider::new(&mut data.speed, 0.1..=10.0).text("Speed").logarithmic(true));
+        ui.add(Slider::new(&mut data.size, 0.25..=10.0).text(locale.text("sound-size")).logarithmic(true));
+        ui.add(Slider::new(&mut data.speed, 0.1..=10.0).text(locale.text("sound-speed")).logarithmic(true));
         ui.separator();
-        ui.checkbox(&mut data.separate_mode, "Separate Harmonics");
+        ui.checkbox(&mut data.separate_mode, locale.text("sound-separate-harmonics"));
         ui.separator();
         ui.horizontal(|ui| {
-            ui.checkbox(&mut data.mute, "mute");
-            if ui.button("clear").clicked() {
+            ui.checkbox(&mut data.mute, locale.text("sound-mute"));
+            if ui.button(locale.text("sound-clear")).clicked() {
                 for i in 0..10 {
                     data.strengths[i] = 0.0;
                 }
             }
-            if ui.button("reset").clicked() {
+            if ui.button(locale.text("sound-reset")).clicked() {
                 for i in 0..10 {
                     data.strengths[i] = 1.0 / (i as f64 + 1.0);
                 }
             }
         });
         egui::Grid::new("notes").show(ui, |ui| {
-            ui.label("strength");
-            ui.label("harmonics");
-            ui.label("guitar fret");
+            ui.label(locale.text("sound-strength"));
+            ui.label(locale.text("sound-harmonics"));
+            ui.label(locale.text("sound-guitar-fret"));
             ui.label("");
-            ui.label("note");
-            ui.label("math");
+            ui.label(locale.text("sound-note"));
+            ui.label(locale.text("sound-math"));
             ui.end_row();
 
             for (segments, fret, syllable, info) in vec![
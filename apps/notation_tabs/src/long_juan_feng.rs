@@ -85,7 +85,7 @@ pub fn new_tab() -> Tab {
                 "2:4" Word [ "我" "被",+ "它",, "拖", "着", "走", "静", ] |
                 $duration = _1_8
                 "p:1" Word [ "静" "悄" "悄" "默" "默" "离" "开" "陷" ] |
-                "p:2" Word [ "入" "了" "危" "险" "边" "缘" "Ba-" "by"] |
+                "p:2" Word [ "入" "了" "危" "险" "边" "缘" "Ba"~Hyphen "by"] |
                 "p:3" Word [ _ "我" "的" "世" "界" "已", "狂"+ "风", "暴", @ ] |
                 "p:4" Word [ @ "" "雨" @ ""** "呜"* ] |
                 "c:1" $duration = T_1_8
@@ -4,16 +4,42 @@ pub mod long_juan_feng;
 pub mod scarborough_fair;
 pub mod test;
 
-pub fn main() {
-    write_tab(&test::new_tab(), "../notation_viewer/assets/tabs/test.ron");
+pub fn main() -> anyhow::Result<()> {
+    write_tab(&test::new_tab(), "../notation_viewer/assets/tabs/test.ron")?;
     write_tab(
         &scarborough_fair::new_tab(),
         "../notation_viewer/assets/tabs/scarborough_fair.ron",
-    );
+    )?;
     write_tab(
         &long_juan_feng::new_tab(),
         "../notation_viewer/assets/tabs/zh-CN/long_juan_feng.ron",
-    );
+    )?;
     let result = parse_get_tab_file("src/test.rs");
     println!("{:?}", result);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use notation_tab::prelude::*;
+    use serde_json::Value;
+
+    use crate::{long_juan_feng, scarborough_fair, test};
+
+    fn to_value(tab: &Tab) -> Value {
+        serde_json::to_value(tab).unwrap()
+    }
+
+    fn assert_round_trip(tab: Tab) {
+        let source = format_tab(&tab);
+        let parsed = parse_tab(&source).unwrap();
+        assert_eq!(to_value(&parsed), to_value(&tab), "{}", source);
+    }
+
+    #[test]
+    fn test_format_round_trip() {
+        assert_round_trip(test::new_tab());
+        assert_round_trip(scarborough_fair::new_tab());
+        assert_round_trip(long_juan_feng::new_tab());
+    }
 }
@@ -8,6 +8,12 @@ pub fn new_tab() -> Tab {
     tab! {
         "06dd7278-cdaf-40dd-abc6-6e66ec2d6b8c"
         Meta: E Dorian 3 _4 110
+        Info: {
+            title: "Scarborough Fair"
+            artist: "Traditional"
+            difficulty: Beginner
+            tags: ["folk"]
+        }
         Tracks: [
             {chord Chord [
                 $duration = D_1_2
@@ -75,15 +81,15 @@ pub fn new_tab() -> Tab {
             {lyrics Lyrics [
                 $duration = _1_4
                 "v1:1" Word [ "are"* "you" ] |
-                "v1:2" Word [ "go-", "ing"* "to", ] |
-                "v1:3" Word [ "scar-"+ "bo-", "rough" ] |
+                "v1:2" Word [ "go"~Hyphen, "ing"* "to", ] |
+                "v1:3" Word [ "scar"~Hyphen+ "bo"~Hyphen, "rough" ] |
                 "v1:4" Word [ "fare"*+ ] |
-                "v:6" Word [ _ "pars-" "ley" ] |
-                "v:7" Word [ "sage"* "rose-" ] |
-                "v:8" Word [ "ma-" "ry" "and" ] |
+                "v:6" Word [ _ "pars"~Hyphen "ley" ] |
+                "v:7" Word [ "sage"* "rose"~Hyphen ] |
+                "v:8" Word [ "ma"~Hyphen "ry" "and" ] |
                 "v:9" Word [ "thyme"*+ ] |
-                "v1:12" Word [ _* "re-" ] |
-                "v1:13" Word [ "mem-"* "ber" ] |
+                "v1:12" Word [ _* "re"~Hyphen ] |
+                "v1:13" Word [ "mem"~Hyphen* "ber" ] |
                 "v1:14" Word [ "me"* "to" ] |
                 "v1:15" Word [ "one" "who" "lives" ] |
                 "v1:16" Word [ "there"*+ ] |
@@ -93,9 +99,9 @@ pub fn new_tab() -> Tab {
                 "v1:21" Word [ "mine"*+ ] |
                 "v2:1" Word [ "tell", "her"+ "to" ] |
                 "v2:2" Word [ "make" "me" "a" ] |
-                "v2:3" Word [ "ca-" "m-" "bric" ] |
+                "v2:3" Word [ "ca"~Hyphen "m"~Hyphen "bric" ] |
                 "v2:4" Word [ "shirt"*+ ] |
-                "v2:12" Word [ _* "with-" ] |
+                "v2:12" Word [ _* "with"~Hyphen ] |
                 "v2:13" Word [ "out"* "no" ] |
                 "v2:14" Word [ "seams"* "nor" ] |
                 "v2:15" Word [ "needle"*+ ] |
@@ -108,20 +114,20 @@ pub fn new_tab() -> Tab {
                 "v3:2" Word [ "find" "me" "an" ] |
                 "v3:3" Word [ "acre"* "of" ] |
                 "v3:4" Word [ "land"*+ ] |
-                "v3:12" Word [ _* "bet-" ] |
+                "v3:12" Word [ _* "bet"~Hyphen ] |
                 "v3:13" Word [ "ween" "the" "salt" ] |
-                "v3:14" Word [ "wa-"* "ter" ] |
+                "v3:14" Word [ "wa"~Hyphen* "ter" ] |
                 "v3:15" Word [ "and" "the" "sea" ] |
                 "v3:16" Word [ "strand"*+ ] |
                 "v4:1" Word [ "tell", "her"+ "to" ] |
                 "v4:2" Word [ "reap", "it"+ "in", "a", ] |
-                "v4:3" Word [ "si-", "ckle"+ "of" ] |
-                "v4:4" Word [ "lea-", "ther", @ ""* ] |
+                "v4:3" Word [ "si"~Hyphen, "ckle"+ "of" ] |
+                "v4:4" Word [ "lea"~Hyphen, "ther", @ ""* ] |
                 "v4:12" Word [ _* "and" ] |
-                "v4:13" Word [ "ga-" "ther" "it" ] |
+                "v4:13" Word [ "ga"~Hyphen "ther" "it" ] |
                 "v4:14" Word [ "all"* "in", "a", ] |
                 "v4:15" Word [ "bunch"* "of" ] |
-                "v4:16" Word [ "hea-", "ther", @ ""* ] |
+                "v4:16" Word [ "hea"~Hyphen, "ther", @ ""* ] |
             ]}
             {vocal Vocal [
                 $duration = _1_4
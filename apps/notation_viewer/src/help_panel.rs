@@ -4,7 +4,7 @@ use tab_viewer::edger_bevy::bevy_egui::EguiContext;
 
 use tab_viewer::notation::args::NotationArgs;
 use tab_viewer::prelude::{MarkDownAsset, KbPageId, KbPage, KbPanel, EasyLinkEvent, NotationSettings, EguiContexts};
-use tab_viewer::prelude::{NotationState, NotationAssets, NotationTheme};
+use tab_viewer::prelude::{NotationState, NotationAssets, NotationLocale, NotationTheme};
 
 use tab_viewer::kb::chords_page::ChordsPage;
 use tab_viewer::kb::notes_page::NotesPage;
@@ -48,7 +48,7 @@ impl HelpPanel {
 
 impl KbPanel for HelpPanel {
     fn get_title(&self) -> &str {
-        "Help (F1, H)"
+        "help-title"
     }
     fn get_current_page_id(&self) -> KbPageId {
         self.current_page_id.clone()
@@ -58,10 +58,10 @@ impl KbPanel for HelpPanel {
     }
     fn get_page_tabs(&self) -> Vec<(KbPageId, &'static str)> {
         vec![
-            (Self::WELCOME, "Welcome"),
-            (KbPageId::Notes, "Notes"),
-            (KbPageId::Chords, "Chords"),
-            (Self::USAGE, "Usage"),
+            (Self::WELCOME, "help-welcome"),
+            (KbPageId::Notes, "help-notes"),
+            (KbPageId::Chords, "help-chords"),
+            (Self::USAGE, "help-usage"),
         ]
     }
     fn get_page_mut(&mut self, page_id: KbPageId) -> &mut dyn KbPage {
@@ -86,6 +86,7 @@ impl HelpPanel {
         texts: Res<Assets<MarkDownAsset>>,
         app_state: Res<AppState>,
         mut state: ResMut<NotationState>,
+        locale: Res<NotationLocale>,
         theme: Res<NotationTheme>,
         mut link_evts: EventWriter<EasyLinkEvent>,
         mut help: ResMut<HelpPanel>,
@@ -94,7 +95,7 @@ impl HelpPanel {
             help.skip_frames -= 1;
             return;
         }
-        (&mut help).window_ui(&mut egui_ctx, &texts, &app_state, &mut state, &theme, &mut link_evts);
+        (&mut help).window_ui(&mut egui_ctx, &texts, &app_state, &mut state, &locale, &theme, &mut link_evts);
     }
     pub fn handle_link_evts(
         mut index: ResMut<HelpPanel>,
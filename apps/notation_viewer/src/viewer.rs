@@ -72,6 +72,9 @@ impl NotationViewer {
         } else if keyboard_input.just_released(KeyCode::F1) || keyboard_input.just_released(KeyCode::KeyH)
         {
             state.show_kb = !state.show_kb;
+        } else if keyboard_input.just_released(KeyCode::KeyK)
+        {
+            Control::toggle_karaoke(&mut state);
         } else if keyboard_input.just_released(KeyCode::F2)
         {
             Control::toggle_hide_guitar_view(&mut state, &mut settings, &mut theme);
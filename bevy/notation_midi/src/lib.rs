@@ -1,6 +1,9 @@
 pub mod midi_hub;
+pub mod midi_humanize;
 pub mod midi_message;
+pub mod midi_mixer;
 pub mod midi_plugin;
+pub mod midi_schedule;
 pub mod midi_settings;
 pub mod midi_state;
 pub mod midi_util;
@@ -21,11 +24,17 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::midi_hub::MidiHub;
     #[doc(hidden)]
+    pub use crate::midi_humanize::Humanize;
+    #[doc(hidden)]
     pub use crate::midi_message::MidiMessage;
     #[doc(hidden)]
-    pub use crate::midi_plugin::MidiPlugin;
+    pub use crate::midi_mixer::{MidiMixer, TrackMixer};
+    #[doc(hidden)]
+    pub use crate::midi_plugin::{MidiClock, MidiPlugin};
+    #[doc(hidden)]
+    pub use crate::midi_schedule::{MidiSchedule, ScheduledMidiMessage};
     #[doc(hidden)]
-    pub use crate::midi_settings::MidiSettings;
+    pub use crate::midi_settings::{ClickKind, ClickSubdivision, MidiSettings};
     #[doc(hidden)]
     pub use crate::midi_state::{MidiChannel, MidiState};
     #[doc(hidden)]
@@ -38,12 +47,16 @@ pub mod prelude {
     pub use crate::wasm::midi_synth::MidiSynth;
 
     #[doc(hidden)]
-    pub use crate::play::play_clock::PlayClock;
+    pub use crate::play::count_in::CountIn;
+    #[doc(hidden)]
+    pub use crate::play::play_clock::{PlayClock, PlayClockSource};
     #[doc(hidden)]
     pub use crate::play::play_control::{PlayControl, PlaySpeed, TickResult};
     #[doc(hidden)]
     pub use crate::play::play_state::{PlayState, PlayingState};
     #[doc(hidden)]
     pub use crate::play::play_events::*;
+    #[doc(hidden)]
+    pub use crate::play::speed_trainer::{SpeedTrainer, SpeedTrainerLog};
 
 }
@@ -3,11 +3,12 @@ use notation_model::prelude::Tab;
 use crate::prelude::PlaySpeed;
 use std::sync::{Arc, Mutex};
 
-use crate::prelude::{MidiMessage, MidiSettings, MidiState, MidiSynth};
+use crate::prelude::{MidiMessage, MidiSchedule, MidiSettings, MidiState, MidiSynth};
 
 pub struct MidiHub {
     pub output_conn: Option<Mutex<MidiOutputConnection>>,
     pub output_synth: Option<MidiSynth>,
+    pub schedule: Option<MidiSchedule>,
 }
 
 impl Default for MidiHub {
@@ -15,6 +16,7 @@ impl Default for MidiHub {
         Self {
             output_conn: None,
             output_synth: None,
+            schedule: None,
         }
     }
 }
@@ -66,11 +68,26 @@ impl MidiHub {
             self.check_output_conn();
         }
     }
-    pub fn switch_tab(&mut self, settings: &MidiSettings, state: &mut MidiState, tab: Arc<Tab>) {
-        state.switch_tab(&settings, self, tab.clone());
+    pub fn start_schedule(&mut self, sample_rate: u32) {
+        self.schedule = Some(MidiSchedule::new(sample_rate));
+    }
+    pub fn take_schedule(&mut self) -> Option<MidiSchedule> {
+        self.schedule.take()
+    }
+    pub fn switch_tab(
+        &mut self,
+        settings: &MidiSettings,
+        state: &mut MidiState,
+        tab: Arc<Tab>,
+        keep_position: bool,
+    ) {
+        state.switch_tab(settings, self, tab.clone(), keep_position);
         self.init_channels(settings, state);
     }
     pub fn init_channels(&mut self, settings: &MidiSettings, state: &MidiState) {
+        if settings.bypass_hub {
+            return;
+        }
         self.check_output(settings);
         if let Some(synth) = &self.output_synth {
             synth.init_channels(settings, state);
@@ -83,6 +100,9 @@ impl MidiHub {
         msg: &MidiMessage,
         velocity: u8,
     ) {
+        if let Some(schedule) = self.schedule.as_mut() {
+            schedule.add(speed, msg, velocity);
+        }
         if settings.bypass_hub {
             return;
         }
@@ -0,0 +1,66 @@
+use notation_model::prelude::{BarPosition, Unit, Units};
+
+use crate::prelude::MidiMessage;
+
+/// Small random offsets of timing and velocity, seeded so the same tab always sounds the same,
+/// and the note on/off of an entry got the same offsets.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Humanize {
+    pub enabled: bool,
+    pub seed: u64,
+    /// Max timing offset, in percentage of a sixteenth note.
+    pub timing_percent: u8,
+    pub velocity: u8,
+}
+
+impl Default for Humanize {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            seed: 0,
+            timing_percent: 10,
+            velocity: 8,
+        }
+    }
+}
+
+impl Humanize {
+    const TIMING_SALT: u64 = 1;
+    const VELOCITY_SALT: u64 = 2;
+
+    /// From -1.0 to 1.0, based on splitmix64.
+    fn random(&self, key: u64) -> f32 {
+        let mut z = self
+            .seed
+            .wrapping_add(key.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        (z >> 11) as f32 / (1u64 << 53) as f32 * 2.0 - 1.0
+    }
+    fn calc_key(channel: u8, pos: &BarPosition, salt: u64) -> u64 {
+        (pos.bar_ordinal as u64)
+            .wrapping_mul(31)
+            .wrapping_add(pos.in_bar_pos.to_f32().to_bits() as u64)
+            .wrapping_mul(31)
+            .wrapping_add(channel as u64)
+            .wrapping_mul(31)
+            .wrapping_add(salt)
+    }
+    pub fn apply(&self, channel: u8, msg: MidiMessage) -> MidiMessage {
+        if !self.enabled {
+            return msg;
+        }
+        let max_offset = Units::from(Unit::Sixteenth).to_f32() * self.timing_percent as f32 / 100.0;
+        let offset = self.random(Self::calc_key(channel, &msg.pos, Self::TIMING_SALT)) * max_offset;
+        let velocity_offset = (self.random(Self::calc_key(channel, &msg.pos, Self::VELOCITY_SALT))
+            * self.velocity as f32)
+            .round() as i8;
+        let in_bar_pos = (msg.pos.in_bar_pos + Units::from(offset)).max(Units::ZERO);
+        MidiMessage {
+            pos: msg.pos.with_in_bar_pos(in_bar_pos),
+            velocity_offset,
+            ..msg
+        }
+    }
+}
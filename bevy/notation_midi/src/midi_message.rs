@@ -8,9 +8,10 @@ pub struct MidiMessage {
     pub duration: Units,
     pub delay: bool,
     pub midi: StructuredShortMessage,
+    pub velocity_offset: i8,
 }
 impl MidiMessage {
-    pub const DELAY_GAP: Units = Units(Units::_MIN_ACCURACY * 2.0);
+    pub const DELAY_GAP: Units = Units::EPSILON;
     pub fn new(
         pass_mode: EntryPassMode,
         pos: BarPosition,
@@ -24,15 +25,33 @@ impl MidiMessage {
             duration,
             delay,
             midi,
+            velocity_offset: 0,
         }
     }
     pub fn of_entry(entry: &LaneEntry, delay: bool, midi: StructuredShortMessage) -> Self {
+        let (pos, duration) = entry.sounding_position();
         Self {
             pass_mode: entry.pass_mode(),
-            pos: entry.bar_position(),
-            duration: entry.tied_units(),
+            pos,
+            duration,
             delay,
             midi,
+            velocity_offset: 0,
+        }
+    }
+    pub fn with_groove(&self, meta: &TabMeta) -> Self {
+        let (pos, duration) = meta.apply_groove(&self.pos, self.duration);
+        Self {
+            pos,
+            duration,
+            ..self.clone()
+        }
+    }
+    pub fn calc_velocity(&self, velocity: u8) -> u8 {
+        if velocity == 0 || self.velocity_offset == 0 {
+            velocity
+        } else {
+            (velocity as i16 + self.velocity_offset as i16).clamp(1, 127) as u8
         }
     }
     pub fn bar_ordinal(&self) -> usize {
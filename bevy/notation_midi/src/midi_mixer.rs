@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+use midi_msg::GMSoundSet;
+use notation_model::prelude::{Track, TrackKind};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct TrackMixer {
+    pub program: u8,
+    pub velocity: u8,
+    pub volume: u8,
+    pub pan: u8,
+    pub reverb: u8,
+    pub chorus: u8,
+    pub mute: bool,
+    pub solo: bool,
+}
+
+impl TrackMixer {
+    pub const CC_VOLUME: u8 = 7;
+    pub const CC_PAN: u8 = 10;
+    pub const CC_REVERB: u8 = 91;
+    pub const CC_CHORUS: u8 = 93;
+    pub const DEFAULT_VOLUME: u8 = 100;
+    pub const PAN_CENTER: u8 = 64;
+    pub const DEFAULT_REVERB: u8 = 40;
+    pub const DEFAULT_CHORUS: u8 = 0;
+    /// GM "Lead 1 (square)"
+    pub const SYNTH_PROGRAM: u8 = 80;
+    /// Drums are played on the GM percussion channel, the program only selects the kit.
+    pub const DRUMS_PROGRAM: u8 = 0;
+
+    /// Default (program, velocity) for the track kinds that can be played.
+    pub fn default_params(kind: &TrackKind) -> Option<(u8, u8)> {
+        match kind {
+            TrackKind::Vocal => Some((GMSoundSet::Cello as u8, 110)),
+            TrackKind::Guitar => Some((GMSoundSet::AcousticGuitarSteel as u8, 120)),
+            TrackKind::Piano => Some((GMSoundSet::AcousticGrandPiano as u8, 110)),
+            TrackKind::Bass => Some((GMSoundSet::AcousticBass as u8, 120)),
+            TrackKind::Synth => Some((Self::SYNTH_PROGRAM, 100)),
+            TrackKind::Drums => Some((Self::DRUMS_PROGRAM, 110)),
+            _ => None,
+        }
+    }
+    pub fn new(kind: &TrackKind) -> Option<Self> {
+        Self::default_params(kind).map(|(program, velocity)| Self {
+            program,
+            velocity,
+            volume: Self::DEFAULT_VOLUME,
+            pan: Self::PAN_CENTER,
+            reverb: Self::DEFAULT_REVERB,
+            chorus: Self::DEFAULT_CHORUS,
+            mute: false,
+            solo: false,
+        })
+    }
+    /// (controller number, value) to be sent when the channel is initialized.
+    pub fn get_control_changes(&self) -> [(u8, u8); 4] {
+        [
+            (Self::CC_VOLUME, self.volume),
+            (Self::CC_PAN, self.pan),
+            (Self::CC_REVERB, self.reverb),
+            (Self::CC_CHORUS, self.chorus),
+        ]
+    }
+}
+
+/// Mixer settings for each track, keyed by `Track::id`, tracks without settings use the
+/// default of their kind.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct MidiMixer {
+    pub tracks: HashMap<String, TrackMixer>,
+}
+
+impl MidiMixer {
+    pub fn get_track(&self, track: &Track) -> Option<TrackMixer> {
+        self.tracks
+            .get(&track.id)
+            .copied()
+            .or_else(|| TrackMixer::new(&track.kind))
+    }
+    pub fn get_track_mut(&mut self, track: &Track) -> Option<&mut TrackMixer> {
+        if !self.tracks.contains_key(&track.id) {
+            let mixer = TrackMixer::new(&track.kind)?;
+            self.tracks.insert(track.id.clone(), mixer);
+        }
+        self.tracks.get_mut(&track.id)
+    }
+    pub fn has_solo(&self) -> bool {
+        self.tracks.values().any(|x| x.solo)
+    }
+    /// Velocity to play the track with, 0 if muted, or other tracks are soloed.
+    pub fn calc_velocity(&self, track: &Track, ignore_mute: bool) -> u8 {
+        match self.get_track(track) {
+            Some(mixer) => {
+                if ignore_mute {
+                    mixer.velocity
+                } else if mixer.mute || (!mixer.solo && self.has_solo()) {
+                    0
+                } else {
+                    mixer.velocity
+                }
+            }
+            None => 0,
+        }
+    }
+    pub fn reset(&mut self) {
+        self.tracks.clear();
+    }
+}
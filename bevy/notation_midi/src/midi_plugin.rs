@@ -1,15 +1,22 @@
 use crate::prelude::{
-    MidiHub, MidiSettings, MidiState,
+    Humanize, MidiHub, MidiMixer, MidiSettings, MidiState,
 };
 use bevy::prelude::*;
 use crate::prelude::{
-    PlayClock, JumpToBarEvent, PlayControlEvent, SwitchTabEvent,
+    PlayClock, PlayClockSource, JumpToBarEvent, MidiScheduleEvent, PlayControlEvent, SpeedTrainer,
+    SpeedTrainerLog, SwitchTabEvent, TickResult,
 };
 
 #[derive(Default, Resource)]
 pub struct MidiClock {
     pub clock: PlayClock,
 }
+impl MidiClock {
+    /// Returns `false` if the source is invalid, the current one is kept then.
+    pub fn set_source(&mut self, source: PlayClockSource) -> bool {
+        self.clock.set_source(source)
+    }
+}
 
 pub struct MidiPlugin;
 
@@ -19,18 +26,52 @@ impl Plugin for MidiPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<MidiClock>();
         app.init_resource::<MidiSettings>();
-        app.init_resource::<MidiState>();
+        let mut state = MidiState::default();
+        state.speed_trainer_log = SpeedTrainerLog::load();
+        app.insert_resource(state);
         app.init_non_send_resource::<MidiHub>();
         app.add_systems(Update, on_switch_tab);
         app.add_systems(Update, on_jump_to_bar);
         app.add_systems(Update, on_play_control_evt);
+        app.add_systems(Update, on_mixer_changed);
+        app.add_systems(Update, on_humanize_changed);
         app.insert_resource(Time::<Fixed>::from_seconds(DO_TICK_TIMESTEP));
         app.add_systems(FixedUpdate, do_tick);
+        app.add_event::<MidiScheduleEvent>();
+        app.add_systems(Update, (sync_schedule, do_fixed_step_tick).chain());
         #[cfg(not(target_arch = "wasm32"))]
         self.build_native(app);
     }
 }
 
+fn on_mixer_changed(
+    settings: Res<MidiSettings>,
+    mut state: ResMut<MidiState>,
+    mut hub: NonSendMut<MidiHub>,
+    mut last_mixer: Local<MidiMixer>,
+) {
+    if settings.mixer != *last_mixer {
+        state.sync_mixer(&settings, &mut hub);
+        *last_mixer = settings.mixer.clone();
+    }
+}
+
+/// Humanize offsets are baked into the scheduled messages, so they are scheduled again
+/// from the current tab, without parsing it again.
+fn on_humanize_changed(
+    settings: Res<MidiSettings>,
+    state: Res<MidiState>,
+    mut switch_tab_evts: EventWriter<SwitchTabEvent>,
+    mut last_humanize: Local<Humanize>,
+) {
+    if settings.humanize != *last_humanize {
+        if let Some(tab) = state.tab.clone() {
+            switch_tab_evts.send(SwitchTabEvent::reload(tab));
+        }
+        *last_humanize = settings.humanize;
+    }
+}
+
 fn on_switch_tab(
     mut evts: EventReader<SwitchTabEvent>,
     settings: Res<MidiSettings>,
@@ -39,7 +80,16 @@ fn on_switch_tab(
     mut play_control_evts: EventWriter<PlayControlEvent>,
 ) {
     for evt in evts.read() {
-        hub.switch_tab(&settings, &mut state, evt.tab.clone());
+        hub.switch_tab(&settings, &mut state, evt.tab.clone(), evt.keep_position);
+        if evt.keep_position {
+            let play_control = &state.play_control;
+            play_control_evts.send(PlayControlEvent::on_begin_end(
+                play_control.begin_bar_ordinal,
+                play_control.end_bar_ordinal,
+            ));
+            play_control_evts.send(PlayControlEvent::on_should_loop(play_control.should_loop));
+            play_control_evts.send(PlayControlEvent::on_play_state(play_control.play_state));
+        }
         _do_tick(
             &settings,
             &mut state,
@@ -94,15 +144,12 @@ fn on_play_control_evt(
     }
 }
 
-fn _do_tick(
-    settings: &MidiSettings,
-    state: &mut MidiState,
-    hub: &mut MidiHub,
+fn _send_tick_evt(
+    state: &MidiState,
     play_control_evts: &mut EventWriter<PlayControlEvent>,
     jumped: bool,
-    delta_seconds: f32,
+    tick_result: TickResult,
 ) {
-    let tick_result = state.tick(settings, hub, jumped, delta_seconds);
     if jumped || tick_result.changed {
         play_control_evts.send(PlayControlEvent::on_tick(
             state.play_control.position,
@@ -111,6 +158,34 @@ fn _do_tick(
     }
 }
 
+fn _send_speed_trainer_evt(
+    state: &MidiState,
+    play_control_evts: &mut EventWriter<PlayControlEvent>,
+    last_speed_trainer: &SpeedTrainer,
+) {
+    let speed_trainer = state.play_control.speed_trainer;
+    if speed_trainer != *last_speed_trainer {
+        play_control_evts.send(PlayControlEvent::on_speed_trainer(speed_trainer));
+        play_control_evts.send(PlayControlEvent::on_speed_factor(
+            state.play_control.play_speed.factor(),
+        ));
+    }
+}
+
+fn _do_tick(
+    settings: &MidiSettings,
+    state: &mut MidiState,
+    hub: &mut MidiHub,
+    play_control_evts: &mut EventWriter<PlayControlEvent>,
+    jumped: bool,
+    delta_seconds: f32,
+) {
+    let speed_trainer = state.play_control.speed_trainer;
+    let tick_result = state.tick(settings, hub, jumped, delta_seconds);
+    _send_tick_evt(state, play_control_evts, jumped, tick_result);
+    _send_speed_trainer_evt(state, play_control_evts, &speed_trainer);
+}
+
 fn do_tick(
     settings: Res<MidiSettings>,
     mut state: ResMut<MidiState>,
@@ -118,6 +193,9 @@ fn do_tick(
     mut clock: ResMut<MidiClock>,
     mut play_control_evts: EventWriter<PlayControlEvent>,
 ) {
+    if clock.clock.source.is_fixed_step() {
+        return;
+    }
     clock.clock.tick();
     //println!("do_tick() -> {}", clock.delta_seconds());
     _do_tick(
@@ -129,3 +207,47 @@ fn do_tick(
         clock.clock.delta_seconds(),
     );
 }
+
+/*
+ * Record the midi messages while a fixed step clock is running, the schedule is sent out
+ * when the clock source is changed again.
+ */
+fn sync_schedule(
+    clock: Res<MidiClock>,
+    mut hub: NonSendMut<MidiHub>,
+    mut last_source: Local<PlayClockSource>,
+    mut schedule_evts: EventWriter<MidiScheduleEvent>,
+) {
+    let source = clock.clock.source;
+    if source == *last_source {
+        return;
+    }
+    if let Some(schedule) = hub.take_schedule() {
+        schedule_evts.send(MidiScheduleEvent::new(schedule));
+    }
+    if let PlayClockSource::FixedStep { fps: _, sample_rate } = source {
+        hub.start_schedule(sample_rate);
+    }
+    *last_source = source;
+}
+
+/*
+ * With fixed step clock, tick once per rendered frame, so each frame got exactly 1/fps seconds
+ * of playing, no matter how long it took to render it.
+ */
+fn do_fixed_step_tick(
+    settings: Res<MidiSettings>,
+    mut state: ResMut<MidiState>,
+    mut hub: NonSendMut<MidiHub>,
+    mut clock: ResMut<MidiClock>,
+    mut play_control_evts: EventWriter<PlayControlEvent>,
+) {
+    if !clock.clock.source.is_fixed_step() {
+        return;
+    }
+    clock.clock.tick();
+    let speed_trainer = state.play_control.speed_trainer;
+    let tick_result = state.tick_with_clock(&settings, &mut hub, &clock.clock, false);
+    _send_tick_evt(&state, &mut play_control_evts, false, tick_result);
+    _send_speed_trainer_evt(&state, &mut play_control_evts, &speed_trainer);
+}
@@ -0,0 +1,77 @@
+use notation_model::prelude::{Position, Units};
+
+use crate::prelude::{MidiMessage, PlaySpeed};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct ScheduledMidiMessage {
+    pub sample: u64,
+    pub velocity: u8,
+    pub midi: [u8; 3],
+}
+
+#[derive(Copy, Clone, Debug)]
+struct MidiScheduleFrame {
+    begin_sample: u64,
+    end_sample: u64,
+    begin_units: Units,
+}
+
+/// Midi messages stamped with the audio sample they should start at, recorded while
+/// playing with a fixed step clock, so the audio can be rendered in sync with the frames.
+#[derive(Clone, Debug)]
+pub struct MidiSchedule {
+    pub sample_rate: u32,
+    pub messages: Vec<ScheduledMidiMessage>,
+    frame: Option<MidiScheduleFrame>,
+    last_sample: u64,
+}
+
+impl MidiSchedule {
+    pub fn new(sample_rate: u32) -> Self {
+        Self {
+            sample_rate,
+            messages: Vec::new(),
+            frame: None,
+            last_sample: 0,
+        }
+    }
+    pub fn begin_frame(&mut self, begin_sample: u64, end_sample: u64, position: &Position) {
+        self.frame = Some(MidiScheduleFrame {
+            begin_sample,
+            end_sample,
+            begin_units: position.tab.in_tab_pos,
+        });
+        self.last_sample = begin_sample;
+    }
+    pub fn end_frame(&mut self) {
+        if let Some(frame) = self.frame.take() {
+            self.last_sample = frame.end_sample;
+        }
+    }
+    pub fn calc_sample(&self, speed: &PlaySpeed, msg: &MidiMessage) -> u64 {
+        match self.frame {
+            Some(frame) => {
+                let offset = msg.effect_units() - frame.begin_units;
+                if offset <= Units::ZERO {
+                    // Jumped or looped within this frame, start at the frame begin.
+                    return frame.begin_sample;
+                }
+                let seconds = speed.calc_seconds(offset) as f64;
+                let sample = frame.begin_sample + (seconds * self.sample_rate as f64).round() as u64;
+                sample.min(frame.end_sample.max(frame.begin_sample + 1) - 1)
+            }
+            None => self.last_sample,
+        }
+    }
+    pub fn add(&mut self, speed: &PlaySpeed, msg: &MidiMessage, velocity: u8) {
+        let sample = self.calc_sample(speed, msg);
+        self.messages.push(ScheduledMidiMessage {
+            sample,
+            velocity,
+            midi: msg.to_midi(),
+        });
+    }
+    pub fn calc_seconds(&self, sample: u64) -> f64 {
+        sample as f64 / self.sample_rate as f64
+    }
+}
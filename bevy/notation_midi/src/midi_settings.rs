@@ -1,24 +1,62 @@
 use bevy::prelude::*;
 use midi_msg::GMSoundSet;
-use notation_model::prelude::{Octave, TrackKind};
+use notation_model::prelude::{Octave, TabTimeMap, Track, TrackKind, Unit, Units};
 
-#[derive(Copy, Clone, PartialEq, Debug, Resource)]
+use crate::prelude::{Humanize, MidiMixer};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ClickKind {
+    Accent,
+    Normal,
+    Subdivision,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum ClickSubdivision {
+    #[default]
+    None,
+    Eighth,
+    Sixteenth,
+}
+impl ClickSubdivision {
+    pub const ALL: [Self; 3] = [Self::None, Self::Eighth, Self::Sixteenth];
+    pub fn unit(&self) -> Option<Unit> {
+        match self {
+            Self::None => None,
+            Self::Eighth => Some(Unit::Eighth),
+            Self::Sixteenth => Some(Unit::Sixteenth),
+        }
+    }
+    /// Offsets of the subdivision clicks within a beat, not including the beat itself.
+    pub fn calc_offsets(&self, beat_unit: Unit) -> Vec<Units> {
+        let beat_units = Units::from(beat_unit);
+        match self.unit().map(Units::from) {
+            Some(step) if step < beat_units => {
+                let steps = (beat_units / step).floor();
+                (1..steps).map(|x| step * x).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Resource)]
 pub struct MidiSettings {
     pub bypass_hub: bool,
     pub click_mute: bool,
     pub click_velocity: u8,
     pub click_octave: Octave,
-    pub vocal_mute: bool,
-    pub vocal_velocity: u8,
-    pub guitar_mute: bool,
-    pub guitar_velocity: u8,
-    pub piano_mute: bool,
-    pub piano_velocity: u8,
+    pub click_accent_velocity: u8,
+    pub click_subdivision: ClickSubdivision,
+    pub click_subdivision_velocity: u8,
+    pub count_in_bars: u8,
+    /// Playing speed factor while a note held by a fermata is sounding.
+    pub fermata_speed: f32,
+    pub mixer: MidiMixer,
+    pub humanize: Humanize,
     pub use_internal_synth: bool,
     pub click_sound: u8,
-    pub vocal_sound: u8,
-    pub guitar_sound: u8,
-    pub piano_sound: u8,
+    pub click_accent_sound: u8,
     pub seeking_track: TrackKind,
     pub seeking_init_channel: bool,
 }
@@ -30,17 +68,16 @@ impl Default for MidiSettings {
             click_mute: true,
             click_velocity: 100,
             click_octave: Octave::P7,
-            vocal_mute: false,
-            vocal_velocity: 110,
-            guitar_mute: false,
-            guitar_velocity: 120,
-            piano_mute: false,
-            piano_velocity: 110,
+            click_accent_velocity: 120,
+            click_subdivision: ClickSubdivision::None,
+            click_subdivision_velocity: 60,
+            count_in_bars: 0,
+            fermata_speed: TabTimeMap::FERMATA_SPEED,
+            mixer: MidiMixer::default(),
+            humanize: Humanize::default(),
             use_internal_synth: Self::default_use_internal_synth(),
             click_sound: GMSoundSet::Dulcimer as u8,
-            vocal_sound: GMSoundSet::Cello as u8,
-            guitar_sound: GMSoundSet::AcousticGuitarSteel as u8,
-            piano_sound: GMSoundSet::AcousticGrandPiano as u8,
+            click_accent_sound: GMSoundSet::Glockenspiel as u8,
             seeking_track: TrackKind::Guitar,
             seeking_init_channel: true,
         }
@@ -56,15 +93,16 @@ impl MidiSettings {
     }
 }
 impl MidiSettings {
-    pub fn get_track_channel_params(&self, kind: &TrackKind) -> Option<(u8, u8)> {
+    pub fn get_track_channel_params(&self, track: &Track) -> Option<(u8, u8)> {
+        self.mixer
+            .get_track(track)
+            .map(|mixer| (mixer.program, mixer.velocity))
+    }
+    pub fn get_click_channel_params(&self, kind: &ClickKind) -> (u8, u8) {
         match kind {
-            TrackKind::Vocal => Some((self.vocal_sound, self.vocal_velocity)),
-            TrackKind::Guitar => Some((self.guitar_sound, self.guitar_velocity)),
-            TrackKind::Piano => Some((self.piano_sound, self.piano_velocity)),
-            _ => None,
+            ClickKind::Accent => (self.click_accent_sound, self.click_accent_velocity),
+            ClickKind::Normal => (self.click_sound, self.click_velocity),
+            ClickKind::Subdivision => (self.click_sound, self.click_subdivision_velocity),
         }
     }
-    pub fn get_click_channel_params(&self) -> (u8, u8) {
-        (self.click_sound, self.click_velocity)
-    }
 }
@@ -2,7 +2,7 @@ use std::convert::TryInto;
 use std::sync::Arc;
 use bevy::prelude::*;
 
-use helgoboss_midi::{controller_numbers, Channel, StructuredShortMessage, U7};
+use helgoboss_midi::{controller_numbers, Channel, ControllerNumber, StructuredShortMessage, U7};
 use notation_model::prelude::*;
 use crate::prelude::*;
 
@@ -11,21 +11,28 @@ use crate::prelude::{MidiMessage, MidiSettings, MidiUtil};
 
 pub const DEFAULT_PROGRAM: u8 = 0;
 pub const DEFAULT_VELOCITY: u8 = 64;
+pub const PERCUSSION_CHANNEL: usize = 9;
 
 #[derive(Debug)]
 pub struct MidiChannel {
     pub track: Option<Arc<Track>>,
+    pub click: Option<ClickKind>,
     pub channel: Channel,
     pub program: U7,
     pub velocity: U7,
     pub messages: Vec<MidiMessage>,
     need_sort: bool,
     next_index: usize,
+    /// Index in `MidiState::channels`, `channel` is reset to it, drums tracks use the
+    /// percussion channel whatever slot they are in.
+    slot: u8,
 }
 impl MidiChannel {
     pub fn new(channel: u8) -> Self {
         Self {
+            slot: channel,
             track: None,
+            click: None,
             channel: Channel::new(channel),
             program: U7::new(DEFAULT_PROGRAM),
             velocity: U7::new(DEFAULT_VELOCITY),
@@ -35,13 +42,22 @@ impl MidiChannel {
         }
     }
     pub fn reset(&mut self) {
+        self.channel = Channel::new(self.slot);
         self.track = None;
+        self.click = None;
         self.program = U7::new(DEFAULT_PROGRAM);
         self.velocity = U7::new(DEFAULT_VELOCITY);
         self.messages.clear();
         self.need_sort = false;
         self.next_index = 0;
     }
+    /// Drums tracks after the first one are played on the percussion channel of the first,
+    /// they don't send the channel wide program and controls, so volume, pan and sends of
+    /// the first drums track are used, only velocity, mute and solo apply per track.
+    pub fn is_shared_percussion(&self) -> bool {
+        self.channel == Channel::new(PERCUSSION_CHANNEL as u8)
+            && self.slot != PERCUSSION_CHANNEL as u8
+    }
     pub fn add_message(&mut self, msg: MidiMessage) {
         self.messages.push(msg);
         self.need_sort = true;
@@ -106,36 +122,14 @@ impl MidiChannel {
             match &self.track {
                 Some(track) => {
                     let seeking = is_seeking && track.kind == settings.seeking_track;
-                    match track.kind {
-                        TrackKind::Vocal => {
-                            velocity = if !seeking && settings.vocal_mute {
-                                0
-                            } else {
-                                settings.vocal_velocity
-                            };
-                        }
-                        TrackKind::Guitar => {
-                            velocity = if !seeking && settings.guitar_mute {
-                                0
-                            } else {
-                                settings.guitar_velocity
-                            };
-                        }
-                        TrackKind::Piano => {
-                            velocity = if !seeking && settings.piano_mute {
-                                0
-                            } else {
-                                settings.piano_velocity
-                            };
-                        }
-                        _ => (),
-                    }
+                    velocity = settings.mixer.calc_velocity(track, seeking);
                 }
                 None => {
                     velocity = if settings.click_mute {
                         0
                     } else {
-                        settings.click_velocity
+                        let kind = self.click.unwrap_or(ClickKind::Normal);
+                        settings.get_click_channel_params(&kind).1
                     };
                 }
             }
@@ -152,7 +146,7 @@ impl MidiChannel {
                     count += 1;
                     if !bypass {
                         if !is_seeking || next.should_send_in_seeking() {
-                            hub.send(settings, speed, next, velocity);
+                            hub.send(settings, speed, next, next.calc_velocity(velocity));
                         }
                     }
                 } else {
@@ -169,6 +163,9 @@ impl MidiChannel {
         count
     }
     fn init_channel(&mut self, settings: &MidiSettings, hub: &mut MidiHub, speed: &PlaySpeed) {
+        if self.is_shared_percussion() {
+            return;
+        }
         if let Some(first_msg) = self.messages.get(0) {
             let msg = StructuredShortMessage::ProgramChange {
                 channel: self.channel,
@@ -203,6 +200,61 @@ impl MidiChannel {
                 ),
                 self.velocity.into(),
             );
+            // A jump or loop in the middle of a bend skips its reset.
+            hub.send(
+                settings,
+                speed,
+                &MidiMessage::new(
+                    first_msg.pass_mode,
+                    first_msg.pos,
+                    first_msg.duration,
+                    false,
+                    MidiUtil::pitch_bend_msg(self.channel, 0.0),
+                ),
+                self.velocity.into(),
+            );
+            self.send_control_changes(settings, hub, speed, first_msg.clone());
+        }
+    }
+    fn send_control_changes(
+        &self,
+        settings: &MidiSettings,
+        hub: &mut MidiHub,
+        speed: &PlaySpeed,
+        first_msg: MidiMessage,
+    ) {
+        if self.is_shared_percussion() {
+            return;
+        }
+        let mixer = match self.track.as_ref().and_then(|x| settings.mixer.get_track(x)) {
+            Some(mixer) => mixer,
+            None => return,
+        };
+        for (controller_number, control_value) in mixer.get_control_changes() {
+            let msg = StructuredShortMessage::ControlChange {
+                channel: self.channel,
+                controller_number: ControllerNumber::new(controller_number),
+                control_value: U7::new(control_value.min(127)),
+            };
+            hub.send(
+                settings,
+                speed,
+                &MidiMessage::new(
+                    first_msg.pass_mode,
+                    first_msg.pos,
+                    first_msg.duration,
+                    false,
+                    msg,
+                ),
+                self.velocity.into(),
+            );
+        }
+    }
+    /// Apply the mixer to the channel without resetting it, e.g. when volume or pan changed
+    /// while playing.
+    pub fn sync_mixer(&mut self, settings: &MidiSettings, hub: &mut MidiHub, speed: &PlaySpeed) {
+        if let Some(first_msg) = self.messages.first().cloned() {
+            self.send_control_changes(settings, hub, speed, first_msg);
         }
     }
     pub fn setup(
@@ -213,6 +265,7 @@ impl MidiChannel {
         track: &Arc<Track>,
     ) {
         self.track = Some(track.clone());
+        self.click = None;
         self.program = U7::new(params.0);
         self.velocity = U7::new(params.1);
     }
@@ -223,9 +276,42 @@ impl MidiChannel {
         params: (u8, u8),
     ) {
         self.track = None;
+        self.click = None;
         self.program = U7::new(params.0);
         self.velocity = U7::new(params.1);
     }
+    pub fn setup_click(
+        &mut self,
+        _settings: &MidiSettings,
+        _hub: &mut MidiHub,
+        params: (u8, u8),
+        click: ClickKind,
+    ) {
+        self.track = None;
+        self.click = Some(click);
+        self.program = U7::new(params.0);
+        self.velocity = U7::new(params.1);
+    }
+    pub fn add_click(&mut self, note: &Note, pos: BarPosition, duration: Units) {
+        if let Some(midi_msg) = MidiUtil::note_midi_on_msg(note, self.channel, self.velocity) {
+            self.add_message(MidiMessage::new(
+                EntryPassMode::Delayed,
+                pos,
+                duration,
+                false,
+                midi_msg,
+            ));
+        }
+        if let Some(midi_msg) = MidiUtil::note_midi_off_msg(note, self.channel, self.velocity) {
+            self.add_message(MidiMessage::new(
+                EntryPassMode::Delayed,
+                pos,
+                duration,
+                true,
+                midi_msg,
+            ));
+        }
+    }
 }
 
 #[derive(Resource)]
@@ -234,6 +320,8 @@ pub struct MidiState {
     pub channels: [MidiChannel; 16],
     pub play_control: PlayControl,
     pub seek_position: Option<BarPosition>,
+    pub speed_trainer_log: SpeedTrainerLog,
+    was_playing: bool,
 }
 
 impl Default for MidiState {
@@ -243,6 +331,8 @@ impl Default for MidiState {
             channels: Self::new_channels(),
             play_control: PlayControl::default(),
             seek_position: None,
+            speed_trainer_log: SpeedTrainerLog::default(),
+            was_playing: false,
         }
     }
 }
@@ -285,100 +375,158 @@ impl MidiState {
         }
         None
     }
-    fn create_click_channel(
+    pub fn is_shared_percussion(&self, track: &Track) -> bool {
+        self.get_channel(&track.id, &track.kind)
+            .map(|x| x.is_shared_percussion())
+            .unwrap_or(false)
+    }
+    pub fn get_click_channel(&self, click: &ClickKind) -> Option<&MidiChannel> {
+        self.channels.iter().find(|x| x.click.as_ref() == Some(click))
+    }
+    fn create_click_channels(
         &mut self,
         settings: &MidiSettings,
         hub: &mut MidiHub,
         tab: &Tab,
         index: &mut usize,
     ) {
-        let params = settings.get_click_channel_params();
-        if let Some(channel) = self.channels.get_mut(*index) {
-            channel.setup_no_track(settings, hub, params);
-            println!(
-                "switch_tab(), setup click channel: [{}] -> {}, {}",
-                index, params.0, params.1
-            );
-            *index += 1;
-            let scale_root = tab.meta.scale.calc_root_syllable();
-            let signature = tab.signature();
-            let bar_units = tab.bar_units();
-            let beat_duration = Units::from(signature.beat_unit);
-            for bar in tab.bars.iter() {
-                for beat in 0..signature.bar_beats {
-                    let in_bar_pos = Units(beat as f32 * Units::from(signature.beat_unit).0);
-                    let root = bar
-                        .get_chord(Some(in_bar_pos))
-                        .map(|x| x.root)
-                        .unwrap_or(scale_root);
-                    let note = tab.meta.scale.calc_click_note(
-                        &tab.meta.key,
-                        &settings.click_octave,
-                        &root,
-                    );
-                    let pos = BarPosition::new(bar_units, bar.props.bar_ordinal, in_bar_pos);
-                    if let Some(midi_msg) =
-                        MidiUtil::note_midi_on_msg(&note, channel.channel, channel.velocity)
-                    {
-                        channel.add_message(MidiMessage::new(
-                            EntryPassMode::Delayed,
-                            pos,
-                            beat_duration,
-                            false,
-                            midi_msg,
-                        ));
-                    }
-                    if let Some(midi_msg) =
-                        MidiUtil::note_midi_off_msg(&note, channel.channel, channel.velocity)
-                    {
-                        channel.add_message(MidiMessage::new(
-                            EntryPassMode::Delayed,
-                            pos,
-                            beat_duration,
-                            true,
-                            midi_msg,
-                        ));
+        let signature = tab.signature();
+        let mut clicks = vec![ClickKind::Accent, ClickKind::Normal];
+        if !settings.click_subdivision.calc_offsets(signature.beat_unit).is_empty() {
+            clicks.push(ClickKind::Subdivision);
+        }
+        for click in clicks {
+            let params = settings.get_click_channel_params(&click);
+            if let Some(channel) = self.channels.get_mut(*index) {
+                channel.setup_click(settings, hub, params, click);
+                println!(
+                    "switch_tab(), setup click channel: [{}] {:?} -> {}, {}",
+                    index, click, params.0, params.1
+                );
+                *index += 1;
+            }
+        }
+        let scale_root = tab.meta.scale.calc_root_syllable();
+        let bar_units = tab.bar_units();
+        let beat_duration = Units::from(signature.beat_unit);
+        let subdivision_offsets = settings.click_subdivision.calc_offsets(signature.beat_unit);
+        let subdivision_duration = settings
+            .click_subdivision
+            .unit()
+            .map(Units::from)
+            .unwrap_or(beat_duration);
+        for bar in tab.bars.iter() {
+            for beat in 0..signature.bar_beats {
+                let in_bar_pos = beat_duration * beat as i64;
+                let root = bar
+                    .get_chord(Some(in_bar_pos))
+                    .map(|x| x.root)
+                    .unwrap_or(scale_root);
+                let note = tab.meta.scale.calc_click_note(
+                    &tab.meta.key,
+                    &settings.click_octave,
+                    &root,
+                );
+                let click = if signature.is_accented_beat(beat) {
+                    ClickKind::Accent
+                } else {
+                    ClickKind::Normal
+                };
+                let pos = BarPosition::new(bar_units, bar.props.bar_ordinal, in_bar_pos);
+                if let Some(channel) = self.channels.iter_mut().find(|x| x.click == Some(click)) {
+                    channel.add_click(&note, pos, beat_duration);
+                }
+                if let Some(channel) = self
+                    .channels
+                    .iter_mut()
+                    .find(|x| x.click == Some(ClickKind::Subdivision))
+                {
+                    for offset in subdivision_offsets.iter() {
+                        let pos = BarPosition::new(
+                            bar_units,
+                            bar.props.bar_ordinal,
+                            in_bar_pos + *offset,
+                        );
+                        let (pos, duration) = tab.meta.apply_groove(&pos, subdivision_duration);
+                        channel.add_click(&note, pos, duration);
                     }
                 }
             }
         }
     }
-    pub fn switch_tab(&mut self, settings: &MidiSettings, hub: &mut MidiHub, tab: Arc<Tab>) {
+    pub fn switch_tab(
+        &mut self,
+        settings: &MidiSettings,
+        hub: &mut MidiHub,
+        tab: Arc<Tab>,
+        keep_position: bool,
+    ) {
         self.tab = Some(tab.clone());
         self.reset_channels();
         let mut index: usize = 0;
-        self.create_click_channel(settings, hub, &tab, &mut index);
+        self.create_click_channels(settings, hub, &tab, &mut index);
+        let mut percussion_used = false;
         for track in tab.tracks.iter() {
-            if index >= self.channels.len() {
-                return;
-            }
-            if let Some(params) = settings.get_track_channel_params(&track.kind) {
-                if let Some(channel) = self.channels.get_mut(index) {
+            if let Some(params) = settings.get_track_channel_params(track) {
+                let is_drums = track.kind == TrackKind::Drums;
+                let channel_index = if is_drums && !percussion_used {
+                    percussion_used = true;
+                    PERCUSSION_CHANNEL
+                } else {
+                    if index == PERCUSSION_CHANNEL {
+                        index += 1;
+                    }
+                    index += 1;
+                    index - 1
+                };
+                if let Some(channel) = self.channels.get_mut(channel_index) {
                     channel.setup(settings, hub, params, track);
+                    if is_drums {
+                        channel.channel = Channel::new(PERCUSSION_CHANNEL as u8);
+                    }
                     println!(
                         "switch_tab(), setup channel: [{}] -> {}, {} - {}",
-                        index, params.0, params.1, track
+                        channel_index, params.0, params.1, track
                     );
-                    index += 1;
                 }
             }
         }
-        for bar in tab.bars.iter() {
-            for ((_k, _i), lane) in bar.lanes.iter() {
-                if let Some(channel) = self.get_channel_mut(&lane.track.id, &lane.track.kind) {
-                    for entry in lane.entries.iter() {
-                        if let Some(msgs) = MidiUtil::get_midi_msgs(channel, bar, &entry) {
-                            for msg in msgs {
-                                channel.add_message(MidiMessage::of_entry(entry, msg.0, msg.1));
-                            }
-                        }
+        for item in tab.timeline() {
+            let track_id = item.entry.track_id();
+            let track_kind = item.entry.track_kind();
+            if let Some(channel) = self.get_channel_mut(&track_id, &track_kind) {
+                let channel_number: u8 = channel.channel.into();
+                if let Some(msgs) = MidiUtil::get_midi_messages(channel, &item.bar, &item.entry) {
+                    for msg in msgs {
+                        let msg = msg.with_groove(&tab.meta);
+                        channel.add_message(settings.humanize.apply(channel_number, msg));
                     }
                 }
             }
         }
-        self.play_control = PlayControl::new(&tab);
+        let mut speed_trainer = self.play_control.speed_trainer;
+        speed_trainer.restart();
+        let old_play_control = self.play_control.clone();
+        self.play_control = PlayControl::new(&tab).with_fermata_speed(settings.fermata_speed);
+        if keep_position {
+            self.play_control.restore(&old_play_control);
+            for channel in self.channels.iter_mut() {
+                channel.calc_next_index(&self.play_control.position.bar);
+            }
+        }
+        self.play_control.speed_trainer = speed_trainer;
+        if speed_trainer.is_running() {
+            self.play_control
+                .play_speed
+                .set_factor(speed_trainer.current_factor());
+        }
         self.init_channels(settings, hub);
     }
+    pub fn get_best_bpm(&self) -> Option<f32> {
+        self.tab
+            .as_ref()
+            .and_then(|tab| self.speed_trainer_log.get_best_bpm(tab))
+    }
     pub fn jump_to_bar(
         &mut self,
         settings: &MidiSettings,
@@ -387,11 +535,14 @@ impl MidiState {
     ) {
         self.play_control
             .position
-            .set_in_bar(bar_props.bar_ordinal, Units(0.0));
+            .set_in_bar(bar_props.bar_ordinal, Units::ZERO);
         if self.play_control.is_bar_in_range(bar_props.bar_ordinal) {
             for channel in self.channels.iter_mut() {
                 channel.calc_next_index(&self.play_control.position.bar);
             }
+            if self.play_control.play_state.is_playing() {
+                self.start_count_in(settings);
+            }
         } else if self.play_control.play_state.is_playing() {
             self.play_control.pause();
             self.init_channels(settings, hub);
@@ -414,10 +565,23 @@ impl MidiState {
                 }
             }
         }
+        if !is_seeking && !self.was_playing && self.play_control.play_state.is_playing() {
+            self.start_count_in(settings);
+        }
+        let trainer_loops = self.play_control.speed_trainer.loops;
+        let trainer_factor = self.play_control.play_speed.factor();
         let tick_result = match self.seek_position {
             Some(pos) => self.play_control._tick_to_position(jumped, pos.into()),
             None => self.play_control.tick(jumped, delta_seconds),
         };
+        if self.play_control.speed_trainer.loops > trainer_loops {
+            if let Some(tab) = &self.tab {
+                let bpm = self.play_control.speed_trainer.calc_bpm(&tab.meta, trainer_factor);
+                if self.speed_trainer_log.record(tab, bpm) {
+                    self.speed_trainer_log.save();
+                }
+            }
+        }
         if tick_result.changed {
             for channel in self.channels.iter_mut() {
                 if is_seeking && settings.seeking_init_channel {
@@ -435,12 +599,113 @@ impl MidiState {
                 );
             }
         }
+        if self.play_control.is_counting_in() {
+            self.send_count_in_clicks(settings, hub);
+        }
         self.seek_position = None;
         if is_seeking {
             self.play_control.pause();
         }
+        self.was_playing = self.play_control.play_state.is_playing();
         tick_result
     }
+    fn start_count_in(&mut self, settings: &MidiSettings) {
+        if let Some(tab) = &self.tab {
+            self.play_control
+                .start_count_in(settings.count_in_bars, tab.signature());
+        }
+    }
+    fn send_count_in_click(
+        &self,
+        settings: &MidiSettings,
+        hub: &mut MidiHub,
+        click: ClickKind,
+        note_on: bool,
+    ) {
+        let (tab, count_in) = match (&self.tab, &self.play_control.count_in) {
+            (Some(tab), Some(count_in)) => (tab, count_in),
+            _ => return,
+        };
+        let channel = match self.get_click_channel(&click) {
+            Some(channel) => channel,
+            None => return,
+        };
+        let note = tab.meta.scale.calc_click_note(
+            &tab.meta.key,
+            &settings.click_octave,
+            &tab.meta.scale.calc_root_syllable(),
+        );
+        let midi_msg = if note_on {
+            MidiUtil::note_midi_on_msg(&note, channel.channel, channel.velocity)
+        } else {
+            MidiUtil::note_midi_off_msg(&note, channel.channel, channel.velocity)
+        };
+        if let Some(midi_msg) = midi_msg {
+            let msg = MidiMessage::new(
+                EntryPassMode::Immediate,
+                self.play_control.position.bar,
+                count_in.beat_units(),
+                false,
+                midi_msg,
+            );
+            // Count-in is not affected by click mute, otherwise it's pointless.
+            let velocity = settings.get_click_channel_params(&click).1;
+            hub.send(settings, &self.play_control.play_speed, &msg, velocity);
+        }
+    }
+    fn send_count_in_clicks(&mut self, settings: &MidiSettings, hub: &mut MidiHub) {
+        loop {
+            let next = self
+                .play_control
+                .count_in
+                .as_mut()
+                .and_then(|x| x.take_next_click());
+            match next {
+                Some((click, last_click)) => {
+                    if let Some(last_click) = last_click {
+                        self.send_count_in_click(settings, hub, last_click, false);
+                    }
+                    self.send_count_in_click(settings, hub, click, true);
+                }
+                None => break,
+            }
+        }
+        let last_click = self
+            .play_control
+            .count_in
+            .as_mut()
+            .and_then(|x| x.take_last_click());
+        if let Some(last_click) = last_click {
+            self.send_count_in_click(settings, hub, last_click, false);
+        }
+    }
+    /// Tick with the delta of the clock, when there is a schedule in the hub, the sent messages
+    /// are stamped within the samples of the current frame.
+    pub fn tick_with_clock(
+        &mut self,
+        settings: &MidiSettings,
+        hub: &mut MidiHub,
+        clock: &PlayClock,
+        jumped: bool,
+    ) -> TickResult {
+        if let (Some(schedule), Some((begin_sample, end_sample))) =
+            (hub.schedule.as_mut(), clock.frame_samples())
+        {
+            schedule.begin_frame(begin_sample, end_sample, &self.play_control.position);
+        }
+        let tick_result = self.tick(settings, hub, jumped, clock.delta_seconds());
+        if let Some(schedule) = hub.schedule.as_mut() {
+            schedule.end_frame();
+        }
+        tick_result
+    }
+    pub fn sync_mixer(&mut self, settings: &MidiSettings, hub: &mut MidiHub) {
+        for channel in self.channels.iter_mut() {
+            if channel.track.is_some() {
+                channel.sync_mixer(settings, hub, &self.play_control.play_speed);
+            }
+        }
+    }
     pub fn init_channels(&mut self, settings: &MidiSettings, hub: &mut MidiHub) {
         for channel in self.channels.iter_mut() {
             if channel.messages.len() > 0 {
@@ -471,14 +736,14 @@ impl MidiState {
                     }
                 }) {
                     self.setup_seek(
-                        pos.with_in_bar_pos(props.in_bar_pos - Units::HALF_MIN_ACCURACY),
+                        pos.with_in_bar_pos(props.in_bar_pos - Units::EPSILON),
                     );
                     return true;
                 } else {
                     self.setup_seek(BarPosition::new(
                         pos.bar_units,
                         pos.bar_ordinal,
-                        pos.bar_units - Units::HALF_MIN_ACCURACY,
+                        pos.bar_units - Units::EPSILON,
                     ));
                     return true;
                 }
@@ -495,8 +760,8 @@ impl MidiState {
     fn seek_passed(&mut self, settings: &MidiSettings) -> bool {
         if self.tab.is_some() {
             let mut pos = self.play_control.position.bar;
-            if pos.in_bar_pos >= pos.bar_units - Units::MIN_ACCURACY {
-                pos = BarPosition::new(pos.bar_units, pos.bar_ordinal + 1, Units::MIN_ACCURACY);
+            if pos.in_bar_pos >= pos.bar_units - Units::EPSILON {
+                pos = BarPosition::new(pos.bar_units, pos.bar_ordinal + 1, Units::ZERO);
                 if let Some(bar) = self.tab.as_ref().unwrap().get_bar(pos) {
                     if let Some(props) = bar.get_next_entry(pos.in_bar_pos, &|x| {
                         if x.track_kind() != settings.seeking_track {
@@ -1,15 +1,52 @@
 use std::convert::TryFrom;
 
-use helgoboss_midi::{Channel, KeyNumber, StructuredShortMessage, U7};
+use helgoboss_midi::{Channel, KeyNumber, StructuredShortMessage, U14, U7};
 use notation_model::prelude::{
-    CoreEntry, Entry, FrettedEntry4, FrettedEntry6, LaneEntry, Note, Pick, Semitones, TabBar, Tone,
+    Articulation, CoreEntry, Entry, FrettedEntry, LaneEntry, Note, Pick, PickNote, Semitones,
+    TabBar, Tone, Unit, Units,
 };
 
-use crate::prelude::MidiChannel;
+use crate::prelude::{MidiChannel, MidiMessage};
 
 pub struct MidiUtil();
 
 impl MidiUtil {
+    pub const PITCH_BEND_CENTER: u16 = 8192;
+    /// Default pitch bend range of GM synths, in semitones.
+    pub const PITCH_BEND_RANGE: f32 = 2.0;
+    pub const LEGATO_VELOCITY_OFFSET: i8 = -30;
+    pub const PALM_MUTE_VELOCITY_OFFSET: i8 = -20;
+    pub const DEAD_NOTE_VELOCITY_OFFSET: i8 = -50;
+    pub fn pitch_bend_value(semitones: f32) -> U14 {
+        let value = Self::PITCH_BEND_CENTER as f32
+            + semitones / Self::PITCH_BEND_RANGE * Self::PITCH_BEND_CENTER as f32;
+        U14::new(value.round().clamp(0.0, 16383.0) as u16)
+    }
+    /// Inverse of [`pitch_bend_value`](Self::pitch_bend_value).
+    pub fn pitch_bend_semitones(value: U14) -> f32 {
+        (value.get() as f32 - Self::PITCH_BEND_CENTER as f32) / Self::PITCH_BEND_CENTER as f32
+            * Self::PITCH_BEND_RANGE
+    }
+    /// Bends wider than the pitch bend range can't be played, they are clamped to it.
+    pub fn clamp_bend_semitones(semitones: u8) -> f32 {
+        let semitones = semitones as f32;
+        if semitones > Self::PITCH_BEND_RANGE {
+            println!(
+                "MidiUtil::clamp_bend_semitones() bend out of range: {} -> {}",
+                semitones,
+                Self::PITCH_BEND_RANGE
+            );
+            Self::PITCH_BEND_RANGE
+        } else {
+            semitones
+        }
+    }
+    pub fn pitch_bend_msg(channel: Channel, semitones: f32) -> StructuredShortMessage {
+        StructuredShortMessage::PitchBendChange {
+            channel,
+            pitch_bend_value: Self::pitch_bend_value(semitones),
+        }
+    }
     pub fn note_midi_key_number(note: &Note) -> Option<KeyNumber> {
         #[cfg(not(target_arch = "wasm32"))]
         let midi_note = Semitones::from(*note).0 + 12 - 1; //Not sure why got a higher pitch when playing, temp fix for get it right in video
@@ -51,13 +88,11 @@ impl MidiUtil {
             return None;
         }
         let mut play_msgs: Vec<(bool, StructuredShortMessage)> = tone
-            .get_notes()
             .iter()
             .flat_map(|x| MidiUtil::note_midi_on_msg(x, channel.channel, channel.velocity))
             .map(|x| (false, x))
             .collect();
         let mut stop_msgs: Vec<(bool, StructuredShortMessage)> = tone
-            .get_notes()
             .iter()
             .flat_map(|x| MidiUtil::note_midi_off_msg(x, channel.channel, channel.velocity))
             .map(|x| (true, x))
@@ -80,6 +115,155 @@ impl MidiUtil {
             _ => None,
         }
     }
+    /// Midi messages of the entry with their own timing, picked notes with articulations
+    /// are not played as simple tones.
+    pub fn get_midi_messages(
+        channel: &MidiChannel,
+        bar: &TabBar,
+        entry: &LaneEntry,
+    ) -> Option<Vec<MidiMessage>> {
+        let pick_msgs = match entry.proto() {
+            notation_model::prelude::ProtoEntry::Fretted(FrettedEntry::Pick(pick, _)) => {
+                Self::get_pick_midi_messages(channel, bar, entry, pick)
+            }
+            _ => None,
+        };
+        if pick_msgs.is_some() {
+            return pick_msgs;
+        }
+        Self::get_midi_msgs(channel, bar, entry).map(|msgs| {
+            msgs.into_iter()
+                .map(|msg| MidiMessage::of_entry(entry, msg.0, msg.1))
+                .collect()
+        })
+    }
+    /// Bends and vibrato are sent as pitch bend, which is channel wide, so other notes sounding
+    /// on the track's channel (e.g. other strings of the same pick) are bent with them. The
+    /// pitch bend is reset to the center at the end of the bent note.
+    fn get_articulation_midi_messages(
+        channel: &MidiChannel,
+        entry: &LaneEntry,
+        note: &Note,
+        articulation: &Articulation,
+    ) -> Vec<MidiMessage> {
+        let mut msgs = Vec::new();
+        let pass_mode = entry.pass_mode();
+        let (pos, duration) = entry.sounding_position();
+        let key_number = match articulation {
+            Articulation::Harmonic => Self::note_midi_key_number(note)
+                .and_then(|x| KeyNumber::try_from(u8::from(x) + 12).ok()),
+            _ => Self::note_midi_key_number(note),
+        };
+        let key_number = match key_number {
+            Some(key_number) => key_number,
+            None => return msgs,
+        };
+        let (note_duration, velocity_offset) = match articulation {
+            Articulation::HammerOn
+            | Articulation::PullOff
+            | Articulation::SlideUp
+            | Articulation::SlideDown => (duration, Self::LEGATO_VELOCITY_OFFSET),
+            Articulation::PalmMute => (
+                duration.min(Units::from(Unit::Eighth)),
+                Self::PALM_MUTE_VELOCITY_OFFSET,
+            ),
+            Articulation::DeadNote => (
+                duration.min(Units::from(Unit::ThirtySecondth)),
+                Self::DEAD_NOTE_VELOCITY_OFFSET,
+            ),
+            _ => (duration, 0),
+        };
+        let bend = |in_duration: f32, semitones: f32| -> MidiMessage {
+            MidiMessage::new(
+                pass_mode,
+                pos.with_delay(duration * Units::from(in_duration)),
+                duration,
+                false,
+                Self::pitch_bend_msg(channel.channel, semitones),
+            )
+        };
+        match articulation {
+            Articulation::SlideUp => msgs.push(bend(0.0, -1.0)),
+            Articulation::SlideDown => msgs.push(bend(0.0, 1.0)),
+            _ => (),
+        }
+        let mut note_on = MidiMessage::new(
+            pass_mode,
+            pos,
+            note_duration,
+            false,
+            StructuredShortMessage::NoteOn {
+                channel: channel.channel,
+                key_number,
+                velocity: channel.velocity,
+            },
+        );
+        note_on.velocity_offset = velocity_offset;
+        msgs.push(note_on);
+        match articulation {
+            Articulation::SlideUp | Articulation::SlideDown => {
+                msgs.push(bend(0.125, 0.0));
+            }
+            Articulation::Bend(semitones) => {
+                let semitones = Self::clamp_bend_semitones(*semitones);
+                msgs.push(bend(0.25, semitones / 2.0));
+                msgs.push(bend(0.5, semitones));
+            }
+            Articulation::Vibrato => {
+                for step in 1..8 {
+                    let semitones = if step % 2 == 1 { 0.25 } else { -0.25 };
+                    msgs.push(bend(step as f32 / 8.0, semitones));
+                }
+            }
+            _ => (),
+        }
+        msgs.push(MidiMessage::new(
+            pass_mode,
+            pos,
+            note_duration,
+            true,
+            StructuredShortMessage::NoteOff {
+                channel: channel.channel,
+                key_number,
+                velocity: channel.velocity,
+            },
+        ));
+        match articulation {
+            Articulation::Bend(_) | Articulation::Vibrato => {
+                msgs.push(MidiMessage::new(
+                    pass_mode,
+                    pos,
+                    duration,
+                    true,
+                    Self::pitch_bend_msg(channel.channel, 0.0),
+                ));
+            }
+            _ => (),
+        }
+        msgs
+    }
+    fn get_pick_note_midi_messages(
+        channel: &MidiChannel,
+        entry: &LaneEntry,
+        note: &Note,
+        pick_note: &PickNote,
+    ) -> Vec<MidiMessage> {
+        match &pick_note.articulation {
+            Some(articulation) => {
+                Self::get_articulation_midi_messages(channel, entry, note, articulation)
+            }
+            None => {
+                let mut msgs = Vec::new();
+                if let Some(msg) = Self::note_midi_on_msg(note, channel.channel, channel.velocity) {
+                    msgs.push(MidiMessage::of_entry(entry, false, msg));
+                }
+                if let Some(msg) = Self::note_midi_off_msg(note, channel.channel, channel.velocity) {
+                    msgs.push(MidiMessage::of_entry(entry, true, msg));
+                }
+                msgs
+            }
+        }
+    }
     pub fn get_midi_msgs(
         channel: &MidiChannel,
         bar: &TabBar,
@@ -89,60 +273,125 @@ impl MidiUtil {
             notation_model::prelude::ProtoEntry::Core(core_entry) => {
                 Self::get_core_midi_msgs(channel, bar, entry, core_entry)
             }
-            notation_model::prelude::ProtoEntry::Fretted6(fretted_entry) => {
-                Self::get_fretted_midi_msgs6(channel, bar, entry, fretted_entry)
-            }
-            notation_model::prelude::ProtoEntry::Fretted4(fretted_entry) => {
-                Self::get_fretted_midi_msgs4(channel, bar, entry, fretted_entry)
+            notation_model::prelude::ProtoEntry::Fretted(fretted_entry) => {
+                Self::get_fretted_midi_msgs(channel, bar, entry, fretted_entry)
             }
             _ => None,
         }
     }
 }
 
-macro_rules! impl_get_pick_midi_msgs {
-    ($name:ident, $get_fretted_shape:ident) => {
-        impl MidiUtil {
-            pub fn $name(
-                channel: &MidiChannel,
-                bar: &TabBar,
-                entry: &LaneEntry,
-                pick: &Pick,
-            ) -> Option<Vec<(bool, StructuredShortMessage)>> {
-                if let Some((fretboard, shape)) = bar.$get_fretted_shape(entry) {
-                    let meta = bar.tab_meta();
-                    let tone = fretboard.pick_tone(&meta.scale, &meta.key, &shape, pick);
-                    Self::get_tone_midi_msgs(channel, bar, entry, &tone)
-                } else {
-                    None
+impl MidiUtil {
+    pub fn get_pick_midi_msgs(
+        channel: &MidiChannel,
+        bar: &TabBar,
+        entry: &LaneEntry,
+        pick: &Pick,
+    ) -> Option<Vec<(bool, StructuredShortMessage)>> {
+        if let Some((fretboard, shape)) = bar.get_fretted_shape(entry) {
+            let meta = bar.tab_meta();
+            let tone = fretboard.pick_tone(&meta.scale, &meta.key, &shape, pick);
+            let mut msgs = Self::get_tone_midi_msgs(channel, bar, entry, &tone)?;
+            for note in fretboard.pick_course_notes(&meta.scale, &meta.key, &shape, pick) {
+                if let Some(msg) = Self::note_midi_on_msg(&note, channel.channel, channel.velocity)
+                {
+                    msgs.push((false, msg));
                 }
-            }
-        }
-    };
-}
-
-macro_rules! impl_get_fretted_midi_msgs {
-    ($name:ident, $get_pick_midi_msgs:ident, $fretted_entry:ident) => {
-        impl MidiUtil {
-            pub fn $name(
-                channel: &MidiChannel,
-                bar: &TabBar,
-                entry: &LaneEntry,
-                fretted_entry: &$fretted_entry,
-            ) -> Option<Vec<(bool, StructuredShortMessage)>> {
-                match fretted_entry {
-                    $fretted_entry::Pick(pick, _) => {
-                        Self::$get_pick_midi_msgs(channel, bar, entry, pick)
-                    }
-                    _ => None,
+                if let Some(msg) = Self::note_midi_off_msg(&note, channel.channel, channel.velocity)
+                {
+                    msgs.push((true, msg));
                 }
             }
+            Some(msgs)
+        } else {
+            None
+        }
+    }
+    pub fn get_fretted_midi_msgs(
+        channel: &MidiChannel,
+        bar: &TabBar,
+        entry: &LaneEntry,
+        fretted_entry: &FrettedEntry,
+    ) -> Option<Vec<(bool, StructuredShortMessage)>> {
+        match fretted_entry {
+            FrettedEntry::Pick(pick, _) => Self::get_pick_midi_msgs(channel, bar, entry, pick),
+            _ => None,
+        }
+    }
+    /// Only handles picks with articulations, others are played as tones.
+    pub fn get_pick_midi_messages(
+        channel: &MidiChannel,
+        bar: &TabBar,
+        entry: &LaneEntry,
+        pick: &Pick,
+    ) -> Option<Vec<MidiMessage>> {
+        let notes = pick.get_notes();
+        if entry.prev_is_tie() || notes.iter().all(|x| x.articulation.is_none()) {
+            return None;
         }
-    };
+        let (fretboard, shape) = bar.get_fretted_shape(entry)?;
+        let meta = bar.tab_meta();
+        let msgs: Vec<MidiMessage> = notes
+            .iter()
+            .flat_map(|pick_note| {
+                fretboard
+                    .shape_pick_note(&meta.scale, &meta.key, &shape, *pick_note)
+                    .into_iter()
+                    .chain(fretboard.shape_pick_course_note(
+                        &meta.scale,
+                        &meta.key,
+                        &shape,
+                        *pick_note,
+                    ))
+                    .flat_map(|note| {
+                        Self::get_pick_note_midi_messages(channel, entry, &note, pick_note)
+                    })
+                    .collect::<Vec<MidiMessage>>()
+            })
+            .collect();
+        if !msgs.is_empty() {
+            Some(msgs)
+        } else {
+            None
+        }
+    }
 }
 
-impl_get_pick_midi_msgs!(get_pick_midi_msgs6, get_fretted_shape6);
-impl_get_pick_midi_msgs!(get_pick_midi_msgs4, get_fretted_shape4);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-impl_get_fretted_midi_msgs!(get_fretted_midi_msgs6, get_pick_midi_msgs6, FrettedEntry6);
-impl_get_fretted_midi_msgs!(get_fretted_midi_msgs4, get_pick_midi_msgs4, FrettedEntry4);
+    #[test]
+    fn test_pitch_bend_value() {
+        assert_eq!(
+            MidiUtil::pitch_bend_value(0.0).get(),
+            MidiUtil::PITCH_BEND_CENTER
+        );
+        assert_eq!(
+            MidiUtil::pitch_bend_value(MidiUtil::PITCH_BEND_RANGE).get(),
+            16383
+        );
+        assert_eq!(
+            MidiUtil::pitch_bend_value(-MidiUtil::PITCH_BEND_RANGE).get(),
+            0
+        );
+        for semitones in [-2.0, -1.0, -0.25, 0.0, 0.25, 0.5, 1.0] {
+            let value = MidiUtil::pitch_bend_value(semitones);
+            assert!((MidiUtil::pitch_bend_semitones(value) - semitones).abs() < 0.001);
+        }
+    }
+
+    #[test]
+    fn test_clamp_bend_semitones() {
+        assert_eq!(MidiUtil::clamp_bend_semitones(1), 1.0);
+        assert_eq!(MidiUtil::clamp_bend_semitones(2), 2.0);
+        assert_eq!(
+            MidiUtil::clamp_bend_semitones(3),
+            MidiUtil::PITCH_BEND_RANGE
+        );
+        assert_eq!(
+            MidiUtil::clamp_bend_semitones(12),
+            MidiUtil::PITCH_BEND_RANGE
+        );
+    }
+}
@@ -146,9 +146,11 @@ impl MidiSynth {
                 pressure_amount: _,
             } => todo!(),
             StructuredShortMessage::PitchBendChange {
-                channel: _,
-                pitch_bend_value: _,
-            } => todo!(),
+                channel,
+                pitch_bend_value,
+            } => self
+                .synth
+                .pitch_bend(channel.into(), u16::from(pitch_bend_value).into()),
             StructuredShortMessage::SystemExclusiveStart => todo!(),
             StructuredShortMessage::TimeCodeQuarterFrame(_) => todo!(),
             StructuredShortMessage::SongPositionPointer { position: _ } => todo!(),
@@ -0,0 +1,65 @@
+use notation_model::prelude::{Signature, Units};
+
+use crate::prelude::ClickKind;
+
+/// Clicks before the actual playing starts, the position is not moving until finished.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CountIn {
+    pub bars: u8,
+    pub signature: Signature,
+    pub passed: Units,
+    next_beat: usize,
+    last_click: Option<ClickKind>,
+}
+
+impl CountIn {
+    pub fn new(bars: u8, signature: Signature) -> Self {
+        Self {
+            bars,
+            signature,
+            passed: Units::ZERO,
+            next_beat: 0,
+            last_click: None,
+        }
+    }
+    pub fn beat_units(&self) -> Units {
+        Units::from(self.signature.beat_unit)
+    }
+    pub fn total_beats(&self) -> usize {
+        self.bars as usize * self.signature.bar_beats as usize
+    }
+    pub fn total_units(&self) -> Units {
+        self.beat_units() * self.total_beats() as i64
+    }
+    pub fn is_finished(&self) -> bool {
+        self.passed >= self.total_units()
+    }
+    pub fn click_kind(&self, beat: usize) -> ClickKind {
+        if self.signature.is_accented_beat((beat % self.signature.bar_beats.max(1) as usize) as u8) {
+            ClickKind::Accent
+        } else {
+            ClickKind::Normal
+        }
+    }
+    /// Returns the kind of the next passed click, and the previous click that should be stopped.
+    pub fn take_next_click(&mut self) -> Option<(ClickKind, Option<ClickKind>)> {
+        if self.next_beat < self.total_beats()
+            && self.beat_units() * self.next_beat as i64 <= self.passed
+        {
+            let kind = self.click_kind(self.next_beat);
+            let last_click = self.last_click.replace(kind);
+            self.next_beat += 1;
+            Some((kind, last_click))
+        } else {
+            None
+        }
+    }
+    /// Returns the last click that should be stopped once finished.
+    pub fn take_last_click(&mut self) -> Option<ClickKind> {
+        if self.is_finished() {
+            self.last_click.take()
+        } else {
+            None
+        }
+    }
+}
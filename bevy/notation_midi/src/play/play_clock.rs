@@ -8,37 +8,136 @@ use std::time::Duration as StdDuration;
 #[cfg(not(target_arch = "wasm32"))]
 use std::time::Instant as StdInstant;
 
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+pub enum PlayClockSource {
+    #[default]
+    WallClock,
+    /// Advance exactly 1/fps seconds per tick, independent of the real time passed,
+    /// used for video capture and headless stepping.
+    FixedStep { fps: u32, sample_rate: u32 },
+}
+impl PlayClockSource {
+    pub const VIDEO_FPS: u32 = 60;
+    pub const VIDEO_SAMPLE_RATE: u32 = 44100;
+    pub fn video() -> Self {
+        Self::FixedStep {
+            fps: Self::VIDEO_FPS,
+            sample_rate: Self::VIDEO_SAMPLE_RATE,
+        }
+    }
+    /// Returns `None` if `fps` or `sample_rate` is 0, since the step can't be calculated.
+    pub fn fixed_step(fps: u32, sample_rate: u32) -> Option<Self> {
+        let source = Self::FixedStep { fps, sample_rate };
+        source.is_valid().then_some(source)
+    }
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Self::WallClock => true,
+            Self::FixedStep { fps, sample_rate } => *fps > 0 && *sample_rate > 0,
+        }
+    }
+    /// Returns `true` if the source is [`FixedStep`].
+    pub fn is_fixed_step(&self) -> bool {
+        matches!(self, Self::FixedStep { .. })
+    }
+}
+
 pub struct PlayClock {
+    pub source: PlayClockSource,
     start: StdInstant,
     last: StdInstant,
+    frame: u64,
     pub delta: StdDuration,
 }
 
 impl Default for PlayClock {
     fn default() -> Self {
+        Self::new(PlayClockSource::default())
+    }
+}
+impl PlayClock {
+    /// Invalid sources are replaced with the wall clock.
+    pub fn new(source: PlayClockSource) -> Self {
+        let source = if source.is_valid() {
+            source
+        } else {
+            log::warn!("PlayClock::new() invalid source: {:?}", source);
+            PlayClockSource::WallClock
+        };
         Self {
+            source,
             start: StdInstant::now(),
             last: StdInstant::now(),
+            frame: 0,
             delta: StdDuration::new(0, 0),
         }
     }
-}
-impl PlayClock {
+    pub fn fixed_step(fps: u32, sample_rate: u32) -> Option<Self> {
+        PlayClockSource::fixed_step(fps, sample_rate).map(Self::new)
+    }
+    /// Returns `false` if the source is invalid, the current one is kept then.
+    pub fn set_source(&mut self, source: PlayClockSource) -> bool {
+        if !source.is_valid() {
+            log::warn!("PlayClock::set_source() invalid source: {:?}", source);
+            return false;
+        }
+        if self.source != source {
+            self.source = source;
+            self.reset();
+        }
+        true
+    }
+    pub fn reset(&mut self) {
+        self.start = StdInstant::now();
+        self.last = self.start;
+        self.frame = 0;
+        self.delta = StdDuration::new(0, 0);
+    }
     pub fn tick(&mut self) {
-        let now = StdInstant::now();
-        self.delta = now.duration_since(self.last);
-        self.last = now;
+        match self.source {
+            PlayClockSource::WallClock => {
+                let now = StdInstant::now();
+                self.delta = now.duration_since(self.last);
+                self.last = now;
+            }
+            PlayClockSource::FixedStep { fps, sample_rate: _ } => {
+                self.delta = StdDuration::from_secs_f64(1.0 / fps as f64);
+            }
+        }
+        self.frame += 1;
+    }
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+    /// The audio samples covered by the last tick, only available for fixed step clock.
+    pub fn frame_samples(&self) -> Option<(u64, u64)> {
+        match self.source {
+            PlayClockSource::WallClock => None,
+            PlayClockSource::FixedStep { fps, sample_rate } => {
+                let calc = |frame: u64| frame * sample_rate as u64 / fps as u64;
+                Some((calc(self.frame.saturating_sub(1)), calc(self.frame)))
+            }
+        }
     }
     pub fn last_seconds(&self) -> f32 {
-        self.last.duration_since(self.start).as_secs_f32()
+        self.last_seconds_f64() as f32
     }
     pub fn delta_seconds(&self) -> f32 {
-        self.delta.as_secs_f32()
+        match self.source {
+            PlayClockSource::WallClock => self.delta.as_secs_f32(),
+            PlayClockSource::FixedStep { fps, sample_rate: _ } => 1.0 / fps as f32,
+        }
     }
     pub fn last_seconds_f64(&self) -> f64 {
-        self.last.duration_since(self.start).as_secs_f64()
+        match self.source {
+            PlayClockSource::WallClock => self.last.duration_since(self.start).as_secs_f64(),
+            PlayClockSource::FixedStep { fps, sample_rate: _ } => self.frame as f64 / fps as f64,
+        }
     }
     pub fn delta_seconds_f64(&self) -> f64 {
-        self.delta.as_secs_f64()
+        match self.source {
+            PlayClockSource::WallClock => self.delta.as_secs_f64(),
+            PlayClockSource::FixedStep { fps, sample_rate: _ } => 1.0 / fps as f64,
+        }
     }
 }
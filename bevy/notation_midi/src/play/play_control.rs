@@ -1,5 +1,5 @@
-use notation_model::prelude::{TabPosition, BarPosition, Bpm, Position, Tab, TabMeta, Units};
-use crate::prelude::PlayState;
+use notation_model::prelude::{TabPosition, BarPosition, Bpm, Position, Signature, Tab, TabMeta, TabTimeMap, Units};
+use crate::prelude::{CountIn, PlayState, SpeedTrainer};
 
 #[derive(Debug)]
 pub struct TabPlayStateChanged();
@@ -14,7 +14,7 @@ pub struct PlaySpeed {
 impl PlaySpeed {
     pub fn new(tab_meta: &TabMeta) -> Self {
         let units_per_second =
-            Bpm::from(tab_meta.tempo) as f32 / 60.0 * Units::from(tab_meta.signature.beat_unit).0;
+            Bpm::from(tab_meta.tempo) as f32 / 60.0 * Units::from(tab_meta.signature.beat_unit).to_f32();
         Self {
             seconds_per_unit: 1.0 / units_per_second,
             units_per_second,
@@ -34,10 +34,10 @@ impl PlaySpeed {
         }
     }
     pub fn calc_units(&self, seconds: f32) -> Units {
-        Units(seconds * self.units_per_second * self.factor)
+        Units::from(seconds * self.units_per_second * self.factor)
     }
     pub fn calc_seconds(&self, units: Units) -> f32 {
-        units.0 * self.seconds_per_unit / self.factor
+        units.to_f32() * self.seconds_per_unit / self.factor
     }
 }
 
@@ -53,6 +53,12 @@ pub struct PlayControl {
     pub should_loop: bool,
     pub play_state: PlayState,
     pub play_speed: PlaySpeed,
+    pub speed_trainer: SpeedTrainer,
+    pub count_in: Option<CountIn>,
+    /// Begin and end of the notes held by fermatas, in tab units.
+    pub fermatas: Vec<(Units, Units)>,
+    /// Playing speed factor while a note held by a fermata is sounding.
+    pub fermata_speed: f32,
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -89,6 +95,10 @@ impl PlayControl {
             should_loop: false,
             play_state: PlayState::default(),
             play_speed: PlaySpeed::new(tab_meta),
+            speed_trainer: SpeedTrainer::default(),
+            count_in: None,
+            fermatas: Vec::new(),
+            fermata_speed: TabTimeMap::FERMATA_SPEED,
         }
     }
     pub fn get_last_bar_ordinal(&self) -> usize {
@@ -99,7 +109,52 @@ impl PlayControl {
         }
     }
     pub fn new(tab: &Tab) -> Self {
-        Self::_new(&tab.meta, tab.bars.len())
+        Self {
+            fermatas: TabTimeMap::new(tab).fermatas,
+            ..Self::_new(&tab.meta, tab.bars.len())
+        }
+    }
+    pub fn with_fermata_speed(self, fermata_speed: f32) -> Self {
+        if fermata_speed <= 0.0 {
+            println!("Invalid Fermata Speed: {}", fermata_speed);
+            return self;
+        }
+        Self {
+            fermata_speed,
+            ..self
+        }
+    }
+    /// Slows down within the notes held by fermatas.
+    pub fn calc_fermata_factor(&self) -> f32 {
+        let pos = self.position.tab.in_tab_pos;
+        if self
+            .fermatas
+            .iter()
+            .any(|(begin, end)| pos >= *begin && pos < *end)
+        {
+            self.fermata_speed
+        } else {
+            1.0
+        }
+    }
+    /// Keep position, loop range and play state from the control of the previous version
+    /// of the tab, clamped to the bars of the new one.
+    pub fn restore(&mut self, old: &PlayControl) {
+        let last_bar_ordinal = self.get_last_bar_ordinal();
+        self.begin_bar_ordinal = old.begin_bar_ordinal.min(last_bar_ordinal);
+        self.end_bar_ordinal = old
+            .end_bar_ordinal
+            .min(last_bar_ordinal)
+            .max(self.begin_bar_ordinal);
+        self.should_loop = old.should_loop;
+        let bar_ordinal = old.position.bar.bar_ordinal.min(last_bar_ordinal);
+        let in_bar_pos = if bar_ordinal == old.position.bar.bar_ordinal {
+            old.position.bar.in_bar_pos
+        } else {
+            Units::ZERO
+        };
+        self.position.set_in_bar(bar_ordinal, in_bar_pos);
+        self.play_state = old.play_state;
     }
     pub fn play(&mut self) -> bool {
         if self.play_state.is_playing() {
@@ -114,8 +169,9 @@ impl PlayControl {
             false
         } else {
             self.play_state = PlayState::Paused;
+            self.count_in = None;
             //self.position
-            //    .set_in_bar(self.position.bar.bar_ordinal, Units(0.0));
+            //    .set_in_bar(self.position.bar.bar_ordinal, Units::ZERO);
             true
         }
     }
@@ -123,7 +179,8 @@ impl PlayControl {
         if !self.play_state.is_stopped() {
             self.play_state = PlayState::Stopped;
         }
-        self.position.set_in_bar(self.begin_bar_ordinal, Units(0.0));
+        self.count_in = None;
+        self.position.set_in_bar(self.begin_bar_ordinal, Units::ZERO);
         true
     }
     pub fn _tick_to_position(&mut self, jumped: bool, pos: TabPosition) -> TickResult {
@@ -133,8 +190,10 @@ impl PlayControl {
             if self.should_loop {
                 self.position
                     .set_in_bar(self.begin_bar_ordinal, self.position.bar.in_bar_pos);
-                if self.position.bar.bar_ordinal > self.end_bar_ordinal {
-                    self.stop() //Corner case for too small range
+                //Corner case for too small range
+                if self.position.bar.bar_ordinal > self.end_bar_ordinal || self.on_loop_completed()
+                {
+                    self.stop()
                 } else {
                     false
                 }
@@ -148,15 +207,23 @@ impl PlayControl {
     }
     pub fn tick(&mut self, jumped: bool, delta_seconds: f32) -> TickResult {
         if self.play_state.is_playing() {
+            if let Some(count_in) = self.count_in.as_mut() {
+                if !count_in.is_finished() {
+                    count_in.passed = count_in.passed + self.play_speed.calc_units(delta_seconds);
+                    return TickResult::new(false, false, false, jumped);
+                }
+                self.count_in = None;
+            }
             let mut jumped = jumped;
             let delta_units = if self.position.bar.bar_ordinal < self.begin_bar_ordinal
                 || self.position.bar.bar_ordinal > self.end_bar_ordinal
             {
-                self.position.set_in_bar(self.begin_bar_ordinal, Units(0.0));
+                self.position.set_in_bar(self.begin_bar_ordinal, Units::ZERO);
                 jumped = true;
-                Units(0.0)
+                Units::ZERO
             } else {
-                self.play_speed.calc_units(delta_seconds)
+                self.play_speed
+                    .calc_units(delta_seconds * self.calc_fermata_factor())
             };
             self._tick_to_position(
                 jumped,
@@ -166,6 +233,37 @@ impl PlayControl {
             TickResult::new(false, false, false, jumped)
         }
     }
+    pub fn start_count_in(&mut self, bars: u8, signature: Signature) {
+        self.count_in = if bars > 0 {
+            Some(CountIn::new(bars, signature))
+        } else {
+            None
+        };
+    }
+    pub fn is_counting_in(&self) -> bool {
+        self.count_in.is_some()
+    }
+    pub fn start_speed_trainer(&mut self, speed_trainer: SpeedTrainer) {
+        self.speed_trainer = speed_trainer;
+        self.speed_trainer.enabled = true;
+        self.speed_trainer.restart();
+        self.should_loop = true;
+        self.play_speed.set_factor(self.speed_trainer.current_factor());
+    }
+    pub fn stop_speed_trainer(&mut self, speed_factor: f32) {
+        self.speed_trainer.enabled = false;
+        self.speed_trainer.restart();
+        self.play_speed.set_factor(speed_factor);
+    }
+    /// Returns `true` if should stop playing since the speed trainer is finished.
+    fn on_loop_completed(&mut self) -> bool {
+        if !self.speed_trainer.is_running() {
+            return false;
+        }
+        let finished = self.speed_trainer.on_loop_completed();
+        self.play_speed.set_factor(self.speed_trainer.current_factor());
+        finished
+    }
     pub fn is_bar_in_range(&self, bar_ordinal: usize) -> bool {
         self.end_bar_ordinal >= self.begin_bar_ordinal
             && bar_ordinal >= self.begin_bar_ordinal
@@ -175,7 +273,7 @@ impl PlayControl {
         BarPosition::new(
             self.position.bar.bar_units,
             self.begin_bar_ordinal,
-            Units(0.0),
+            Units::ZERO,
         )
     }
     pub fn has_selection(&self, add_ready_section: bool) -> bool {
@@ -189,3 +287,47 @@ impl PlayControl {
         self.begin_bar_ordinal > 0 || self.end_bar_ordinal < self.get_last_bar_ordinal()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_control(bars: usize) -> PlayControl {
+        PlayControl::_new(&TabMeta::default(), bars)
+    }
+
+    #[test]
+    fn test_restore_keeps_play_state() {
+        for play_state in [PlayState::Playing, PlayState::Paused, PlayState::Stopped] {
+            let mut old = new_control(8);
+            old.play_state = play_state;
+            let mut control = new_control(8);
+            control.restore(&old);
+            assert_eq!(control.play_state, play_state);
+        }
+    }
+
+    #[test]
+    fn test_restore_clamps_position() {
+        let mut old = new_control(8);
+        old.begin_bar_ordinal = 2;
+        old.end_bar_ordinal = 6;
+        old.should_loop = true;
+        old.position.set_in_bar(5, Units::new(1, 4));
+
+        let mut control = new_control(8);
+        control.restore(&old);
+        assert_eq!(control.begin_bar_ordinal, 2);
+        assert_eq!(control.end_bar_ordinal, 6);
+        assert!(control.should_loop);
+        assert_eq!(control.position.bar.bar_ordinal, 5);
+        assert_eq!(control.position.bar.in_bar_pos, Units::new(1, 4));
+
+        let mut control = new_control(4);
+        control.restore(&old);
+        assert_eq!(control.begin_bar_ordinal, 2);
+        assert_eq!(control.end_bar_ordinal, 3);
+        assert_eq!(control.position.bar.bar_ordinal, 3);
+        assert_eq!(control.position.bar.in_bar_pos, Units::ZERO);
+    }
+}
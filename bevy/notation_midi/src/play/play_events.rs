@@ -1,16 +1,40 @@
 use std::sync::Arc;
 
 use notation_model::prelude::*;
-use crate::prelude::{TickResult, PlayState};
+use crate::prelude::{MidiSchedule, TickResult, PlayState, SpeedTrainer};
 use bevy::prelude::Event;
 
 #[derive(Event, Debug)]
 pub struct SwitchTabEvent {
     pub tab: Arc<Tab>,
+    pub keep_position: bool,
 }
 impl SwitchTabEvent {
     pub fn new(tab: Arc<Tab>) -> Self {
-        Self { tab }
+        Self {
+            tab,
+            keep_position: false,
+        }
+    }
+    /// Same tab reloaded, play position and loop range are kept.
+    pub fn reload(tab: Arc<Tab>) -> Self {
+        Self {
+            tab,
+            keep_position: true,
+        }
+    }
+}
+
+/// Sent when a fixed step clock stops, with the midi messages scheduled while it was running.
+#[derive(Event, Debug)]
+pub struct MidiScheduleEvent {
+    pub schedule: Arc<MidiSchedule>,
+}
+impl MidiScheduleEvent {
+    pub fn new(schedule: MidiSchedule) -> Self {
+        Self {
+            schedule: Arc::new(schedule),
+        }
     }
 }
 
@@ -34,6 +58,7 @@ pub enum PlayControlEvent {
     OnSpeedFactor(f32),
     OnShouldLoop(bool),
     OnBeginEnd(usize, usize),
+    OnSpeedTrainer(SpeedTrainer),
 }
 impl PlayControlEvent {
     pub fn on_tick(position: Position, tick_result: TickResult) -> Self {
@@ -54,4 +79,7 @@ impl PlayControlEvent {
     pub fn on_begin_end(begin_bar_ordinal: usize, end_bar_ordinal: usize) -> Self {
         Self::OnBeginEnd(begin_bar_ordinal, end_bar_ordinal)
     }
+    pub fn on_speed_trainer(speed_trainer: SpeedTrainer) -> Self {
+        Self::OnSpeedTrainer(speed_trainer)
+    }
 }
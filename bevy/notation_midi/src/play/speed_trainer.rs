@@ -0,0 +1,269 @@
+use std::collections::HashMap;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+
+use notation_model::prelude::{Bpm, Tab, TabMeta, Uuid};
+use serde::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SpeedTrainer {
+    pub enabled: bool,
+    pub start_percent: f32,
+    pub step_percent: f32,
+    pub target_percent: f32,
+    pub max_loops: Option<usize>,
+    pub loops: usize,
+    pub finished: bool,
+}
+
+impl Default for SpeedTrainer {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            start_percent: 60.0,
+            step_percent: 5.0,
+            target_percent: 100.0,
+            max_loops: None,
+            loops: 0,
+            finished: false,
+        }
+    }
+}
+
+impl SpeedTrainer {
+    pub fn is_running(&self) -> bool {
+        self.enabled && !self.finished
+    }
+    pub fn restart(&mut self) {
+        self.loops = 0;
+        self.finished = false;
+    }
+    pub fn current_percent(&self) -> f32 {
+        let percent = self.start_percent + self.step_percent * self.loops as f32;
+        if self.step_percent >= 0.0 {
+            percent.min(self.target_percent)
+        } else {
+            percent.max(self.target_percent)
+        }
+    }
+    pub fn current_factor(&self) -> f32 {
+        self.current_percent() / 100.0
+    }
+    pub fn calc_bpm(&self, tab_meta: &TabMeta, factor: f32) -> f32 {
+        Bpm::from(tab_meta.tempo) as f32 * factor
+    }
+    pub fn current_bpm(&self, tab_meta: &TabMeta) -> f32 {
+        self.calc_bpm(tab_meta, self.current_factor())
+    }
+    pub fn is_target_reached(&self) -> bool {
+        self.current_percent() == self.target_percent
+    }
+    pub fn is_max_loops_reached(&self) -> bool {
+        self.max_loops.map(|x| self.loops >= x).unwrap_or(false)
+    }
+    /// From 0.0 to 1.0, by tempo if there is a range to go through, otherwise by loops.
+    pub fn progress(&self) -> f32 {
+        let range = self.target_percent - self.start_percent;
+        let progress = if range.abs() > 0.0 && self.step_percent != 0.0 {
+            (self.current_percent() - self.start_percent) / range
+        } else if let Some(max_loops) = self.max_loops {
+            if max_loops > 0 {
+                self.loops as f32 / max_loops as f32
+            } else {
+                1.0
+            }
+        } else {
+            0.0
+        };
+        progress.clamp(0.0, 1.0)
+    }
+    /// Called after each completed loop, returns `true` if the trainer is finished.
+    pub fn on_loop_completed(&mut self) -> bool {
+        if !self.is_running() {
+            return false;
+        }
+        let target_reached = self.is_target_reached();
+        self.loops += 1;
+        if self.is_max_loops_reached() || (target_reached && self.max_loops.is_none()) {
+            self.finished = true;
+        }
+        self.finished
+    }
+}
+
+/// Best tempo reached with the speed trainer, keyed by the tab's uuid,
+/// saved in the user data folder.
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+pub struct SpeedTrainerLog {
+    pub best_bpms: HashMap<Uuid, f32>,
+}
+
+impl SpeedTrainerLog {
+    pub const DATA_FOLDER: &'static str = "fun_notation";
+    pub const DATA_FILE: &'static str = "speed_trainer.ron";
+
+    pub fn get_best_bpm(&self, tab: &Tab) -> Option<f32> {
+        self.best_bpms.get(&tab.uuid).copied()
+    }
+    /// Returns `true` if the bpm is a new best for the tab.
+    pub fn record(&mut self, tab: &Tab, bpm: f32) -> bool {
+        match self.best_bpms.get(&tab.uuid) {
+            Some(best) if *best >= bpm => false,
+            _ => {
+                self.best_bpms.insert(tab.uuid, bpm);
+                true
+            }
+        }
+    }
+    /// The log is per user, under the platform data folder.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn data_path() -> Option<PathBuf> {
+        let env_path = |key: &str| {
+            std::env::var_os(key)
+                .filter(|x| !x.is_empty())
+                .map(PathBuf::from)
+        };
+        let root = if cfg!(target_os = "windows") {
+            env_path("APPDATA")
+        } else if cfg!(target_os = "macos") {
+            env_path("HOME").map(|x| x.join("Library").join("Application Support"))
+        } else {
+            env_path("XDG_DATA_HOME")
+                .or_else(|| env_path("HOME").map(|x| x.join(".local").join("share")))
+        };
+        root.map(|x| x.join(Self::DATA_FOLDER).join(Self::DATA_FILE))
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load() -> Self {
+        let path = match Self::data_path() {
+            Some(path) => path,
+            None => return Self::default(),
+        };
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(_) => return Self::default(),
+        };
+        ron::from_str::<Self>(&content).unwrap_or_else(|err| {
+            log::warn!("SpeedTrainerLog::load() failed: {:?} -> {}", path, err);
+            Self::default()
+        })
+    }
+    #[cfg(target_arch = "wasm32")]
+    pub fn load() -> Self {
+        Self::default()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save(&self) {
+        let path = match Self::data_path() {
+            Some(path) => path,
+            None => {
+                log::warn!("SpeedTrainerLog::save() no data folder");
+                return;
+            }
+        };
+        let result = ron::to_string(self)
+            .map_err(|e| e.to_string())
+            .and_then(|x| {
+                if let Some(folder) = path.parent() {
+                    std::fs::create_dir_all(folder).map_err(|e| e.to_string())?;
+                }
+                std::fs::write(&path, x).map_err(|e| e.to_string())
+            });
+        if let Err(err) = result {
+            log::warn!("SpeedTrainerLog::save() failed: {:?} -> {}", path, err);
+        }
+    }
+    #[cfg(target_arch = "wasm32")]
+    pub fn save(&self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_trainer(start: f32, step: f32, target: f32, max_loops: Option<usize>) -> SpeedTrainer {
+        SpeedTrainer {
+            enabled: true,
+            start_percent: start,
+            step_percent: step,
+            target_percent: target,
+            max_loops,
+            ..SpeedTrainer::default()
+        }
+    }
+
+    #[test]
+    fn test_current_percent() {
+        let mut trainer = new_trainer(60.0, 15.0, 100.0, None);
+        assert_eq!(trainer.current_percent(), 60.0);
+        trainer.loops = 2;
+        assert_eq!(trainer.current_percent(), 90.0);
+        trainer.loops = 3;
+        assert_eq!(trainer.current_percent(), 100.0);
+
+        let mut trainer = new_trainer(120.0, -10.0, 100.0, None);
+        trainer.loops = 1;
+        assert_eq!(trainer.current_percent(), 110.0);
+        trainer.loops = 5;
+        assert_eq!(trainer.current_percent(), 100.0);
+    }
+
+    #[test]
+    fn test_progress() {
+        let mut trainer = new_trainer(60.0, 10.0, 100.0, None);
+        assert_eq!(trainer.progress(), 0.0);
+        trainer.loops = 2;
+        assert_eq!(trainer.progress(), 0.5);
+        trainer.loops = 10;
+        assert_eq!(trainer.progress(), 1.0);
+
+        let mut trainer = new_trainer(120.0, -10.0, 100.0, None);
+        trainer.loops = 1;
+        assert_eq!(trainer.progress(), 0.5);
+
+        let mut trainer = new_trainer(100.0, 10.0, 100.0, Some(4));
+        trainer.loops = 1;
+        assert_eq!(trainer.progress(), 0.25);
+
+        let trainer = new_trainer(100.0, 10.0, 100.0, Some(0));
+        assert_eq!(trainer.progress(), 1.0);
+    }
+
+    #[test]
+    fn test_on_loop_completed_at_target() {
+        let mut trainer = new_trainer(80.0, 10.0, 100.0, None);
+        assert!(!trainer.on_loop_completed());
+        assert!(!trainer.on_loop_completed());
+        assert!(trainer.is_target_reached());
+        assert!(trainer.on_loop_completed());
+        assert!(trainer.finished);
+        assert!(!trainer.on_loop_completed());
+        assert_eq!(trainer.loops, 3);
+    }
+
+    #[test]
+    fn test_on_loop_completed_negative_step() {
+        let mut trainer = new_trainer(120.0, -10.0, 100.0, None);
+        assert!(!trainer.on_loop_completed());
+        assert!(!trainer.on_loop_completed());
+        assert!(trainer.on_loop_completed());
+        assert_eq!(trainer.current_percent(), 100.0);
+    }
+
+    #[test]
+    fn test_on_loop_completed_max_loops() {
+        let mut trainer = new_trainer(60.0, 5.0, 100.0, Some(2));
+        assert!(!trainer.on_loop_completed());
+        assert!(trainer.on_loop_completed());
+        assert_eq!(trainer.current_percent(), 70.0);
+
+        let mut trainer = new_trainer(60.0, 5.0, 100.0, Some(0));
+        assert!(trainer.on_loop_completed());
+        assert_eq!(trainer.loops, 1);
+
+        let mut trainer = new_trainer(60.0, 5.0, 100.0, Some(2));
+        trainer.enabled = false;
+        assert!(!trainer.on_loop_completed());
+        assert_eq!(trainer.loops, 0);
+    }
+}
@@ -1,7 +1,7 @@
-use helgoboss_midi::StructuredShortMessage;
+use helgoboss_midi::{controller_numbers, StructuredShortMessage};
 use wasm_bindgen::prelude::*;
 
-use crate::prelude::{MidiMessage, MidiSettings, MidiState};
+use crate::prelude::{MidiMessage, MidiSettings, MidiState, MidiUtil, TrackMixer};
 use notation_model::prelude::Entry;
 use crate::prelude::PlaySpeed;
 
@@ -48,6 +48,47 @@ impl MidiSynth {
                     Ok(())
                 }
             }
+            StructuredShortMessage::PitchBendChange {
+                channel,
+                pitch_bend_value,
+            } => {
+                pitch_bend(
+                    channel.into(),
+                    MidiUtil::pitch_bend_semitones(pitch_bend_value),
+                );
+                Ok(())
+            }
+            StructuredShortMessage::ProgramChange {
+                channel,
+                program_number,
+            } => {
+                init_channel(channel.into(), program_number.into());
+                load_instruments();
+                Ok(())
+            }
+            StructuredShortMessage::ControlChange {
+                channel,
+                controller_number,
+                control_value,
+            } => {
+                let value: u8 = control_value.into();
+                match controller_number.get() {
+                    TrackMixer::CC_VOLUME => {
+                        set_channel_volume(channel.into(), value as f32 / 127.0)
+                    }
+                    TrackMixer::CC_PAN => set_channel_pan(
+                        channel.into(),
+                        (value as f32 - TrackMixer::PAN_CENTER as f32)
+                            / TrackMixer::PAN_CENTER as f32,
+                    ),
+                    x if x == controller_numbers::ALL_SOUND_OFF.get() => {
+                        stop_channel(channel.into())
+                    }
+                    // No reverb or chorus in the browser, skipped.
+                    _ => (),
+                }
+                Ok(())
+            }
             _ => Err("NOT_IMPLEMENTED".to_owned()),
         }
     }
@@ -70,3 +111,27 @@ extern "C" {
     #[wasm_bindgen(js_namespace = window)]
     fn play_note(channel: u8, seminones: u8, seconds: f32, volume: f32);
 }
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = window)]
+    fn set_channel_volume(channel: u8, volume: f32);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = window)]
+    fn set_channel_pan(channel: u8, pan: f32);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = window)]
+    fn stop_channel(channel: u8);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = window)]
+    fn pitch_bend(channel: u8, semitones: f32);
+}
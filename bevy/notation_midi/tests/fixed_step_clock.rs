@@ -0,0 +1,162 @@
+use std::sync::Arc;
+
+use notation_dsl::prelude::parse_tab;
+use notation_midi::prelude::{
+    MidiHub, MidiSettings, MidiState, PlayClock, PlayClockSource, ScheduledMidiMessage,
+};
+use notation_model::prelude::Tab;
+
+const FPS: u32 = 60;
+const SAMPLE_RATE: u32 = 44100;
+
+/// 4 quarter notes per bar at 60 bpm, so each note starts at a full second.
+const TAB: &str = r#"
+tab! {
+    "5d3b8c3e-3a52-4c4e-9d6e-2c5f4a1b7e90"
+    Meta: C Major 4 _4 60
+    Tracks: [
+        {guitar Guitar [
+            Fretboard
+            $duration = _1
+            "C" Shape ( _ 3 2 0 1 0 )
+            $duration = _1_4
+            "picks" Pick [ 5 4 3 2 ] |
+        ]}
+    ]
+    Sections: [
+        {"A" Verse [
+            {
+                guitar [ "C" 1 ; "picks" | ]
+            }
+        ]}
+    ]
+    Form: "A"
+}
+"#;
+
+fn new_tab() -> Arc<Tab> {
+    Tab::try_parse_arc(parse_tab(TAB).unwrap(), false, None).unwrap()
+}
+
+fn new_state(hub: &mut MidiHub, settings: &MidiSettings) -> MidiState {
+    let mut state = MidiState::default();
+    hub.switch_tab(settings, &mut state, new_tab(), false);
+    state.play_control.should_loop = false;
+    assert!(state.play_control.play());
+    state
+}
+
+fn headless_settings() -> MidiSettings {
+    MidiSettings {
+        bypass_hub: true,
+        ..MidiSettings::default()
+    }
+}
+
+fn guitar_channel(state: &MidiState) -> u8 {
+    state
+        .channels
+        .iter()
+        .find(|x| x.track.is_some())
+        .unwrap()
+        .channel
+        .into()
+}
+
+/// Samples of the guitar note ons, the clicks are on their own channels.
+fn note_ons(messages: &[ScheduledMidiMessage], channel: u8) -> Vec<u64> {
+    messages
+        .iter()
+        .filter(|x| x.midi[0] == 0x90 | channel && x.midi[2] > 0)
+        .map(|x| x.sample)
+        .collect()
+}
+
+#[test]
+fn test_zero_fps_rejected() {
+    assert!(PlayClockSource::fixed_step(0, SAMPLE_RATE).is_none());
+    assert!(PlayClockSource::fixed_step(FPS, 0).is_none());
+    assert!(PlayClock::fixed_step(0, SAMPLE_RATE).is_none());
+
+    let mut clock = PlayClock::default();
+    let source = PlayClockSource::FixedStep {
+        fps: 0,
+        sample_rate: SAMPLE_RATE,
+    };
+    assert!(!clock.set_source(source));
+    assert_eq!(clock.source, PlayClockSource::WallClock);
+    assert_eq!(PlayClock::new(source).source, PlayClockSource::WallClock);
+}
+
+#[test]
+fn test_fixed_step_frames() {
+    let mut clock = PlayClock::fixed_step(FPS, SAMPLE_RATE).unwrap();
+    let samples_per_frame = (SAMPLE_RATE / FPS) as u64;
+    for frame in 1..=FPS as u64 {
+        clock.tick();
+        assert_eq!(clock.frame(), frame);
+        assert_eq!(
+            clock.frame_samples(),
+            Some(((frame - 1) * samples_per_frame, frame * samples_per_frame))
+        );
+        assert_eq!(clock.delta_seconds(), 1.0 / FPS as f32);
+    }
+    assert!((clock.last_seconds_f64() - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_step_position_per_frame() {
+    let settings = headless_settings();
+    let mut hub = MidiHub::default();
+    let mut state = new_state(&mut hub, &settings);
+    let mut clock = PlayClock::fixed_step(FPS, SAMPLE_RATE).unwrap();
+    let units_per_frame = state.play_control.play_speed.calc_units(1.0 / FPS as f32);
+    for frame in 1..=FPS {
+        clock.tick();
+        state.tick_with_clock(&settings, &mut hub, &clock, false);
+        let expected = units_per_frame.to_f32() * frame as f32;
+        let actual = state.play_control.position.tab.in_tab_pos.to_f32();
+        assert!(
+            (actual - expected).abs() < 1e-4,
+            "frame {}: {} != {}",
+            frame,
+            actual,
+            expected
+        );
+    }
+    assert_eq!(
+        state
+            .play_control
+            .play_speed
+            .calc_seconds(state.play_control.position.tab.in_tab_pos),
+        1.0
+    );
+}
+
+#[test]
+fn test_schedule_samples() {
+    let settings = headless_settings();
+    let mut hub = MidiHub::default();
+    let mut state = new_state(&mut hub, &settings);
+    let mut clock = PlayClock::fixed_step(FPS, SAMPLE_RATE).unwrap();
+    hub.start_schedule(SAMPLE_RATE);
+    while state.play_control.play_state.is_playing() && clock.frame() < 10 * FPS as u64 {
+        clock.tick();
+        state.tick_with_clock(&settings, &mut hub, &clock, false);
+    }
+    assert!(!state.play_control.play_state.is_playing());
+    let schedule = hub.take_schedule().unwrap();
+    assert!(hub.take_schedule().is_none());
+    let samples = note_ons(&schedule.messages, guitar_channel(&state));
+    assert_eq!(samples.len(), 4);
+    for (index, sample) in samples.iter().enumerate() {
+        let expected = index as u64 * SAMPLE_RATE as u64;
+        assert!(
+            sample.abs_diff(expected) <= 1,
+            "note {}: {} != {}",
+            index,
+            sample,
+            expected
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use helgoboss_midi::{Channel, ShortMessage};
+use notation_dsl::prelude::parse_tab;
+use notation_midi::prelude::{MidiHub, MidiSettings, MidiState};
+use notation_model::prelude::{Tab, TrackKind};
+
+const TAB: &str = r#"
+tab! {
+    "0f6b6a52-9f0e-4a57-8f43-2f1d8e6c9a10"
+    Meta: C Major 4 _4 120
+    Tracks: [
+        {kick Drums [
+            $duration = _1_4
+            "kick" Tone [ 1 1 1 1 ] |
+        ]}
+        {vocal Vocal [
+            $duration = _1
+            "v" Tone [ 1 ]
+        ]}
+        {snare Drums [
+            $duration = _1_2
+            "snare" Tone [ _ 3 ] |
+        ]}
+    ]
+    Sections: [
+        {"A" Verse [
+            {
+                kick [ "kick" | ]
+                vocal [ "v" 1 ]
+                snare [ "snare" | ]
+            }
+        ]}
+    ]
+    Form: "A"
+}
+"#;
+
+fn new_tab(content: &str) -> Arc<Tab> {
+    Tab::try_parse_arc(parse_tab(content).unwrap(), false, None).unwrap()
+}
+
+#[test]
+fn test_drums_on_percussion_channel() {
+    let settings = MidiSettings {
+        bypass_hub: true,
+        ..MidiSettings::default()
+    };
+    let mut hub = MidiHub::default();
+    let mut state = MidiState::default();
+    hub.switch_tab(&settings, &mut state, new_tab(TAB), false);
+    let percussion = Channel::new(9);
+    let mut drums = 0;
+    let mut shared = 0;
+    for channel in state.channels.iter() {
+        let Some(track) = channel.track.as_ref() else {
+            continue;
+        };
+        assert!(!channel.messages.is_empty(), "{}", track);
+        let is_drums = track.kind == TrackKind::Drums;
+        if is_drums {
+            drums += 1;
+            assert_eq!(channel.channel, percussion, "{}", track);
+            if channel.is_shared_percussion() {
+                shared += 1;
+                assert!(state.is_shared_percussion(track));
+            }
+        } else {
+            assert_ne!(channel.channel, percussion, "{}", track);
+            assert!(!channel.is_shared_percussion(), "{}", track);
+        }
+        for msg in channel.messages.iter() {
+            assert_eq!(msg.midi.channel(), Some(channel.channel), "{}", track);
+        }
+    }
+    assert_eq!(drums, 2);
+    // Only the first drums track controls the percussion channel.
+    assert_eq!(shared, 1);
+
+    // Channel numbers are restored when switched to a tab without drums.
+    hub.switch_tab(&settings, &mut state, new_tab(&TAB.replace("Drums", "Piano")), false);
+    for (index, channel) in state.channels.iter().enumerate() {
+        assert_eq!(channel.channel, Channel::new(index as u8));
+    }
+}
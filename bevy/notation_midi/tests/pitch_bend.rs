@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use notation_dsl::prelude::parse_tab;
+use notation_midi::prelude::{MidiHub, MidiSettings, MidiState, PlayClock, ScheduledMidiMessage};
+use notation_model::prelude::Tab;
+
+const FPS: u32 = 60;
+const SAMPLE_RATE: u32 = 44100;
+
+/// A bent note on the B string, followed by a plain note on the G string.
+const TAB: &str = r#"
+tab! {
+    "8e2f7c1a-5b3d-4f6e-9a0c-1d2b3c4e5f60"
+    Meta: C Major 4 _4 60
+    Tracks: [
+        {guitar Guitar [
+            Fretboard
+            $duration = _1
+            "C" Shape ( _ 3 2 0 1 0 )
+            $duration = _1_2
+            "picks" Pick [ 2@3~b 3 ] |
+        ]}
+    ]
+    Sections: [
+        {"A" Verse [
+            {
+                guitar [ "C" 1 ; "picks" | ]
+            }
+        ]}
+    ]
+    Form: "A"
+}
+"#;
+
+fn is_note_on(msg: &ScheduledMidiMessage, channel: u8) -> bool {
+    msg.midi[0] == 0x90 | channel && msg.midi[2] > 0
+}
+
+fn is_pitch_bend(msg: &ScheduledMidiMessage, channel: u8) -> bool {
+    msg.midi[0] == 0xE0 | channel
+}
+
+fn is_pitch_bend_center(msg: &ScheduledMidiMessage, channel: u8) -> bool {
+    is_pitch_bend(msg, channel) && msg.midi[1] == 0 && msg.midi[2] == 64
+}
+
+#[test]
+fn test_bend_reset_before_next_note() {
+    let settings = MidiSettings {
+        bypass_hub: true,
+        ..MidiSettings::default()
+    };
+    let mut hub = MidiHub::default();
+    let mut state = MidiState::default();
+    let tab = Tab::try_parse_arc(parse_tab(TAB).unwrap(), false, None).unwrap();
+    hub.switch_tab(&settings, &mut state, Arc::clone(&tab), false);
+    let channel: u8 = state
+        .channels
+        .iter()
+        .find(|x| x.track.is_some())
+        .unwrap()
+        .channel
+        .into();
+    state.play_control.should_loop = false;
+    assert!(state.play_control.play());
+    let mut clock = PlayClock::fixed_step(FPS, SAMPLE_RATE).unwrap();
+    hub.start_schedule(SAMPLE_RATE);
+    while state.play_control.play_state.is_playing() && clock.frame() < 10 * FPS as u64 {
+        clock.tick();
+        state.tick_with_clock(&settings, &mut hub, &clock, false);
+    }
+    let messages = hub.take_schedule().unwrap().messages;
+    let note_ons: Vec<&ScheduledMidiMessage> =
+        messages.iter().filter(|x| is_note_on(x, channel)).collect();
+    assert_eq!(note_ons.len(), 2);
+    let bends: Vec<&ScheduledMidiMessage> = messages
+        .iter()
+        .filter(|x| is_pitch_bend(x, channel) && x.sample < note_ons[1].sample)
+        .collect();
+    assert!(bends.iter().any(|x| !is_pitch_bend_center(x, channel)));
+    assert!(is_pitch_bend_center(bends.last().unwrap(), channel));
+}
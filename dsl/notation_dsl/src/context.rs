@@ -27,36 +27,6 @@ impl Default for FrettedContext {
         }
     }
 }
-impl FrettedContext {
-    pub fn fretted_entry_quote(&self) -> TokenStream {
-        if self.string_num == 6 {
-            quote! { FrettedEntry6 }
-        } else if self.string_num == 4 {
-            quote! { FrettedEntry4 }
-        } else {
-            panic!("Unsupported string_num: {}", self.string_num);
-        }
-    }
-    pub fn fretboard_quote(&self) -> TokenStream {
-        if self.string_num == 6 {
-            quote! { Fretboard6 }
-        } else if self.string_num == 4 {
-            quote! { Fretboard4 }
-        } else {
-            panic!("Unsupported string_num: {}", self.string_num);
-        }
-    }
-    pub fn hand_shape_quote(&self) -> TokenStream {
-        if self.string_num == 6 {
-            quote! { HandShape6 }
-        } else if self.string_num == 4 {
-            quote! { HandShape4 }
-        } else {
-            panic!("Unsupported string_num: {}", self.string_num);
-        }
-    }
-}
-
 #[derive(Debug)]
 pub struct Context {
     pub key: Key,
@@ -94,6 +64,14 @@ impl Context {
     pub fn fretted() -> FrettedContext {
         CONTEXT.read().unwrap().fretted
     }
+    /// Each tab starts from the default context, with the key and scale from its meta.
+    pub fn reset(key: Key, scale: Scale) {
+        *CONTEXT.write().unwrap() = Context {
+            key,
+            scale,
+            ..Context::default()
+        };
+    }
     pub fn set_key(key: Key) {
         CONTEXT.write().unwrap().key = key;
     }
@@ -175,7 +153,11 @@ impl Parse for ContextDsl {
             }
             "string_num" => {
                 input.parse::<Token![=]>()?;
-                let string_num = input.parse::<LitInt>()?.base10_parse::<usize>()?;
+                let lit = input.parse::<LitInt>()?;
+                let string_num = lit.base10_parse::<usize>()?;
+                if string_num == 0 {
+                    throw!(Error::new(lit.span(), "Invalid string_num: 0"));
+                }
                 Self::StringNum(string_num)
             }
             _ => throw!(Error::new(input.span(), "Invalid Context")),
@@ -67,7 +67,7 @@ impl ChordDsl {
         } else {
             None
         };
-        let duration_tweak = DurationTweakDsl::try_parse(input);
+        let duration_tweak = DurationTweakDsl::try_parse(input)?;
         ChordDsl::chord(root, intervals, base, duration_tweak)
     }
 }
@@ -1,15 +1,20 @@
 use std::cmp::{max, min};
 
-use fehler::throws;
+use fehler::{throw, throws};
 use notation_proto::prelude::Duration;
 use syn::parse::{Error, Parse, ParseStream};
-use syn::Token;
+use syn::{LitInt, Token};
 
 #[derive(Debug)]
 pub struct DurationTweakDsl {
     pub half_num: i8,
     pub dotted: bool,
+    pub double_dotted: bool,
     pub triplet: bool,
+    /// n notes in the time of m, m defaults to the largest power of 2 below n.
+    pub tuplet: Option<(u8, Option<u8>)>,
+    pub acciaccatura: bool,
+    pub appoggiatura: bool,
 }
 
 impl Parse for DurationTweakDsl {
@@ -27,10 +32,27 @@ impl Parse for DurationTweakDsl {
         }
         half_num = min(max(half_num, -4), 4);
         let mut dotted = false;
+        let mut double_dotted = false;
         let mut triplet = false;
-        if input.peek(Token![+]) {
+        let mut tuplet = None;
+        let mut acciaccatura = false;
+        let mut appoggiatura = false;
+        if input.peek(Token![?]) {
+            input.parse::<Token![?]>()?;
+            if input.peek(Token![?]) {
+                input.parse::<Token![?]>()?;
+                appoggiatura = true;
+            } else {
+                acciaccatura = true;
+            }
+        } else if input.peek(Token![+]) {
             input.parse::<Token![+]>()?;
-            dotted = true;
+            if input.peek(Token![+]) {
+                input.parse::<Token![+]>()?;
+                double_dotted = true;
+            } else {
+                dotted = true;
+            }
         } else if input.peek(Token![=]) {
             input.parse::<Token![=]>()?;
             dotted = true;
@@ -38,11 +60,25 @@ impl Parse for DurationTweakDsl {
         } else if input.peek(Token![-]) {
             input.parse::<Token![-]>()?;
             triplet = true;
+        } else if input.peek(Token![&]) {
+            input.parse::<Token![&]>()?;
+            let n = Self::parse_tuplet_num(input)?;
+            let m = if input.peek(Token![:]) {
+                input.parse::<Token![:]>()?;
+                Some(Self::parse_tuplet_num(input)?)
+            } else {
+                None
+            };
+            tuplet = Some((n, m));
         }
         DurationTweakDsl {
             half_num,
             dotted,
+            double_dotted,
             triplet,
+            tuplet,
+            acciaccatura,
+            appoggiatura,
         }
     }
 }
@@ -54,14 +90,25 @@ impl DurationTweakDsl {
             || input.peek(Token![+])
             || input.peek(Token![=])
             || input.peek(Token![-])
+            || input.peek(Token![&])
+            || input.peek(Token![?])
     }
+    #[throws(Error)]
     pub fn try_parse(input: ParseStream) -> Option<Self> {
         if Self::peek(input) {
-            Self::parse(input).ok()
+            Some(Self::parse(input)?)
         } else {
             None
         }
     }
+    #[throws(Error)]
+    fn parse_tuplet_num(input: ParseStream) -> u8 {
+        let lit = input.parse::<LitInt>()?;
+        match lit.base10_parse::<u8>()? {
+            0 => throw!(Error::new(lit.span(), "Invalid tuplet: 0")),
+            n => n,
+        }
+    }
     pub fn tweak(&self, base: &Duration) -> Duration {
         if let Some(base_unit) = base.as_simple() {
             let mut unit = base_unit.clone();
@@ -74,6 +121,15 @@ impl DurationTweakDsl {
                     unit = unit.doubled();
                 }
             }
+            if self.acciaccatura {
+                return Duration::Acciaccatura(unit);
+            } else if self.appoggiatura {
+                return Duration::Appoggiatura(unit);
+            } else if self.double_dotted {
+                return Duration::DoubleDotted(unit);
+            } else if let Some((n, m)) = self.tuplet {
+                return Duration::new_tuplet(unit, n, m);
+            }
             match (self.dotted, self.triplet) {
                 (false, false) => Duration::Simple(unit),
                 (true, false) => Duration::Dotted(unit),
@@ -81,8 +137,32 @@ impl DurationTweakDsl {
                 (true, true) => Duration::DottedTriplet(unit),
             }
         } else {
-            println!("Can only tweak simple duration: {} - {:?}", base, self);
+            log::warn!("Can only tweak simple duration: {} - {:?}", base, self);
             return base.clone();
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use notation_proto::prelude::{Duration, Unit};
+
+    use super::DurationTweakDsl;
+
+    fn tweak(text: &str) -> Result<Duration, String> {
+        syn::parse_str::<DurationTweakDsl>(text)
+            .map(|x| x.tweak(&Duration::_1_4))
+            .map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn test_tuplet() {
+        assert_eq!(tweak(",,&5"), Ok(Duration::Tuplet(Unit::Sixteenth, 5, 4)));
+        assert_eq!(tweak("&3"), Ok(Duration::Triplet(Unit::Quarter)));
+        assert_eq!(tweak("&7:6"), Ok(Duration::Tuplet(Unit::Quarter, 7, 6)));
+        assert_eq!(tweak("&255"), Ok(Duration::Tuplet(Unit::Quarter, 255, 128)));
+        assert_eq!(tweak("&0"), Err("Invalid tuplet: 0".to_owned()));
+        assert_eq!(tweak("&5:0"), Err("Invalid tuplet: 0".to_owned()));
+        assert!(tweak("&256").is_err());
+    }
+}
@@ -9,6 +9,7 @@ use syn::Token;
 pub enum EmptyDsl {
     Tie,
     Rest,
+    Fermata,
 }
 
 impl Parse for EmptyDsl {
@@ -20,6 +21,9 @@ impl Parse for EmptyDsl {
         } else if input.peek(Token![_]) {
             input.parse::<Token![_]>()?;
             Self::Rest
+        } else if input.peek(Token![!]) {
+            input.parse::<Token![!]>()?;
+            Self::Fermata
         } else {
             throw!(Error::new(input.span(), "Invalid EmptyDsl"))
         }
@@ -28,7 +32,7 @@ impl Parse for EmptyDsl {
 
 impl EmptyDsl {
     pub fn peek(input: ParseStream) -> bool {
-        input.peek(Token![@]) || input.peek(Token![_])
+        input.peek(Token![@]) || input.peek(Token![_]) || input.peek(Token![!])
     }
     pub fn quote(&self, duration_quote: TokenStream) -> TokenStream {
         match self {
@@ -38,6 +42,9 @@ impl EmptyDsl {
             Self::Rest => quote! {
                 ProtoEntry::from(CoreEntry::from(#duration_quote))
             },
+            Self::Fermata => quote! {
+                ProtoEntry::from(CoreEntry::Fermata)
+            },
         }
     }
 }
@@ -47,6 +54,7 @@ impl EmptyDsl {
         match self {
             Self::Tie => ProtoEntry::from(CoreEntry::from(())),
             Self::Rest => ProtoEntry::from(CoreEntry::from(duration)),
+            Self::Fermata => ProtoEntry::from(CoreEntry::Fermata),
         }
     }
 }
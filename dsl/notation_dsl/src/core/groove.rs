@@ -0,0 +1,124 @@
+use fehler::throws;
+
+use notation_proto::prelude::Groove;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::parse::{Error, Parse, ParseStream};
+use syn::LitInt;
+
+pub struct GrooveDsl {
+    pub groove: Groove,
+}
+
+mod kw {
+    syn::custom_keyword!(Straight);
+    syn::custom_keyword!(Swing);
+    syn::custom_keyword!(Swing8);
+    syn::custom_keyword!(Swing16);
+    syn::custom_keyword!(Shuffle);
+}
+
+impl Parse for GrooveDsl {
+    #[throws(Error)]
+    fn parse(input: ParseStream) -> Self {
+        let parse_ratio = |input: ParseStream| -> Result<u8, Error> {
+            if input.peek(LitInt) {
+                let lit = input.parse::<LitInt>()?;
+                let ratio = lit.base10_parse::<u8>()?;
+                if !Groove::is_valid_ratio(ratio) {
+                    return Err(Error::new(
+                        lit.span(),
+                        format!(
+                            "Invalid Swing Ratio: {}, should be from {} to {}",
+                            ratio,
+                            Groove::STRAIGHT_RATIO,
+                            Groove::MAX_RATIO
+                        ),
+                    ));
+                }
+                Ok(ratio)
+            } else {
+                Ok(Groove::SHUFFLE_RATIO)
+            }
+        };
+        let groove = if input.peek(kw::Straight) {
+            input.parse::<kw::Straight>()?;
+            Groove::Straight
+        } else if input.peek(kw::Shuffle) {
+            input.parse::<kw::Shuffle>()?;
+            Groove::Shuffle
+        } else if input.peek(kw::Swing16) {
+            input.parse::<kw::Swing16>()?;
+            Groove::Swing16(parse_ratio(input)?)
+        } else if input.peek(kw::Swing8) {
+            input.parse::<kw::Swing8>()?;
+            Groove::Swing8(parse_ratio(input)?)
+        } else {
+            input.parse::<kw::Swing>()?;
+            Groove::Swing8(parse_ratio(input)?)
+        };
+        GrooveDsl { groove }
+    }
+}
+
+impl GrooveDsl {
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(kw::Straight)
+            || input.peek(kw::Swing)
+            || input.peek(kw::Swing8)
+            || input.peek(kw::Swing16)
+            || input.peek(kw::Shuffle)
+    }
+}
+
+impl ToTokens for GrooveDsl {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let GrooveDsl { groove } = self;
+        let groove_ident = groove.to_ident();
+        tokens.extend(quote! {
+            Groove::from_ident(#groove_ident)
+        });
+    }
+}
+
+impl GrooveDsl {
+    pub fn to_proto(&self) -> Groove {
+        self.groove
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use notation_proto::prelude::Groove;
+
+    use crate::prelude::parse_tab;
+
+    fn parse_groove(groove: &str) -> Result<Groove, String> {
+        let content = format!(
+            r#"
+            tab! {{
+                "3e7a1c5d-2b4f-4d8e-a6c9-0f1b2d3e4a5b"
+                Meta: C Major 4 _4 100 {}
+                Tracks: [ ]
+                Sections: [ ]
+                Form: ""
+            }}
+            "#,
+            groove
+        );
+        parse_tab(&content)
+            .map(|tab| tab.meta.groove)
+            .map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn test_swing_ratio() {
+        assert_eq!(parse_groove("Swing").unwrap(), Groove::Swing8(Groove::SHUFFLE_RATIO));
+        assert_eq!(parse_groove("Swing16 60").unwrap(), Groove::Swing16(60));
+        assert_eq!(parse_groove("Swing8 80").unwrap(), Groove::Swing8(80));
+        let err = parse_groove("Swing8 90").unwrap_err();
+        assert!(err.contains("Invalid Swing Ratio: 90"), "{}", err);
+        let err = parse_groove("Swing16 40").unwrap_err();
+        assert!(err.contains("Invalid Swing Ratio: 40"), "{}", err);
+    }
+}
@@ -1,6 +1,7 @@
 pub mod chord;
 pub mod duration;
 pub mod empty;
+pub mod groove;
 pub mod interval;
 pub mod note;
 pub mod octave;
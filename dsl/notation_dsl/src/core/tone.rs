@@ -32,7 +32,7 @@ impl ToneDsl {
                 }
             }
         }
-        let duration_tweak = DurationTweakDsl::try_parse(input);
+        let duration_tweak = DurationTweakDsl::try_parse(input)?;
         ToneDsl {
             empty,
             notes,
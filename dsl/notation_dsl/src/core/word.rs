@@ -1,10 +1,10 @@
-use fehler::throws;
-use notation_proto::prelude::LyricEntry;
+use fehler::{throw, throws};
+use notation_proto::prelude::{LyricEntry, LyricJoin, LyricWord};
 use notation_proto::proto_entry::ProtoEntry;
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use syn::parse::{Error, ParseStream};
-use syn::LitStr;
+use syn::{Ident, LitStr, Token};
 
 use crate::context::Context;
 
@@ -13,24 +13,51 @@ use super::empty::EmptyDsl;
 
 pub struct WordDsl {
     pub empty: Option<EmptyDsl>,
+    pub melisma: bool,
     pub word: Option<LitStr>,
+    pub join: LyricJoin,
     pub duration_tweak: Option<DurationTweakDsl>,
 }
 
 impl WordDsl {
+    fn peek_join(input: ParseStream) -> bool {
+        input.peek(Token![~]) && input.peek2(Ident)
+    }
+    #[throws(Error)]
+    fn parse_join(input: ParseStream) -> LyricJoin {
+        input.parse::<Token![~]>()?;
+        let ident = input.parse::<Ident>()?;
+        match LyricJoin::from_ident(ident.to_string().as_str()) {
+            Some(join) => join,
+            None => throw!(Error::new(ident.span(), "Invalid LyricJoin")),
+        }
+    }
     #[throws(Error)]
     pub fn parse_without_paren(input: ParseStream, _multied: bool, _with_paren: bool) -> Self {
         let mut empty = None;
+        let mut melisma = false;
         let mut word = None;
+        let mut join = LyricJoin::None;
         if EmptyDsl::peek(input) {
             empty = Some(input.parse()?);
+        } else if input.peek(Token![~]) {
+            input.parse::<Token![~]>()?;
+            melisma = true;
         } else {
             word = Some(input.parse()?);
+            if Self::peek_join(input) {
+                join = Self::parse_join(input)?;
+            }
+        }
+        let duration_tweak = DurationTweakDsl::try_parse(input)?;
+        if word.is_some() && join == LyricJoin::None && Self::peek_join(input) {
+            join = Self::parse_join(input)?;
         }
-        let duration_tweak = DurationTweakDsl::try_parse(input);
         WordDsl {
             empty,
+            melisma,
             word,
+            join,
             duration_tweak,
         }
     }
@@ -40,19 +67,36 @@ impl ToTokens for WordDsl {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let WordDsl {
             empty,
+            melisma,
             word,
+            join,
             duration_tweak,
         } = self;
         let duration_quote = Context::duration_quote(duration_tweak);
         if empty.is_some() {
             tokens.extend(empty.as_ref().unwrap().quote(duration_quote));
-        } else {
-            let word = word.as_ref().unwrap();
+        } else if *melisma {
             tokens.extend(quote! {
-                ProtoEntry::from(LyricEntry::from(
-                    (#word, #duration_quote)
-                ))
+                ProtoEntry::from(LyricEntry::Melisma(#duration_quote))
             });
+        } else {
+            let word = word.as_ref().unwrap();
+            if *join == LyricJoin::None {
+                tokens.extend(quote! {
+                    ProtoEntry::from(LyricEntry::from(
+                        (#word, #duration_quote)
+                    ))
+                });
+            } else {
+                let join_ident = join.to_ident();
+                tokens.extend(quote! {
+                    ProtoEntry::from(LyricEntry::from((
+                        LyricWord::from(#word)
+                            .with_join(LyricJoin::from_ident(#join_ident).unwrap()),
+                        #duration_quote
+                    )))
+                });
+            }
         }
     }
 }
@@ -61,15 +105,20 @@ impl WordDsl {
     pub fn to_proto(&self) -> ProtoEntry {
         let WordDsl {
             empty,
+            melisma,
             word,
+            join,
             duration_tweak,
         } = self;
         let duration = Context::tweaked_duration(duration_tweak);
         if empty.is_some() {
             empty.as_ref().unwrap().to_proto(duration)
+        } else if *melisma {
+            ProtoEntry::from(LyricEntry::Melisma(duration))
         } else {
             let word = word.as_ref().unwrap();
-            ProtoEntry::from(LyricEntry::from((word.value(), duration)))
+            let word = LyricWord::from(word.value()).with_join(*join);
+            ProtoEntry::from(LyricEntry::from((word, duration)))
         }
     }
 }
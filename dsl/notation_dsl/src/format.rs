@@ -0,0 +1,684 @@
+use notation_proto::prelude::{
+    BarLayer, CoreEntry, Duration, FrettedEntry, Groove, GuitarTuning, GuitarUtil, Interval, Key,
+    LyricEntry, LyricJoin, Note, Octave, Pick, PickNote, PitchSign, ProtoEntry, Scale, Section,
+    SectionKind, Slice, SliceBegin, SliceEnd, Syllable, Tab, TabInfo, TabMeta, Track, TrackKind,
+    GUITAR_FRET_NUM_ACOUSTIC, GUITAR_STRING_NUM,
+};
+use syn::Ident;
+
+use crate::core::duration::DurationTweakDsl;
+use crate::core::octave::OctaveTweakDsl;
+
+/// Source of the tab in the DSL, which parses back into the same tab.
+pub fn format_tab(tab: &Tab) -> String {
+    TabFormatter::new(&tab.meta).format(tab)
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+enum GroupKind {
+    Tone,
+    Chord,
+    Word,
+    Pick,
+}
+
+impl GroupKind {
+    fn to_ident(self) -> &'static str {
+        match self {
+            Self::Tone => "Tone",
+            Self::Chord => "Chord",
+            Self::Word => "Word",
+            Self::Pick => "Pick",
+        }
+    }
+}
+
+/// Emits canonical `tab! { ... }` source, tracking the context the same way as parsing.
+///
+/// Context entries of the tab are kept as `$key`, `$duration`, etc., other durations are
+/// written as tweaks, new `$duration` and `$string_num` are only added when no tweak can express
+/// the entry. Strums, meta entries and other extra entries got no syntax, they are written as
+/// comments, so are lost when parsed again.
+pub struct TabFormatter {
+    key: Key,
+    scale: Scale,
+    duration: Duration,
+    octave: Octave,
+    string_num: usize,
+    track_kind: TrackKind,
+    indent: usize,
+    output: String,
+    line: Vec<String>,
+    group: Option<(GroupKind, Vec<String>)>,
+}
+
+impl TabFormatter {
+    pub const INDENT: &'static str = "    ";
+    const OCTAVE_OFFSETS: [i8; 9] = [0, -1, 1, -2, 2, -3, 3, -4, 4];
+    const HALF_NUMS: [i8; 9] = [0, 1, -1, 2, -2, 3, -3, 4, -4];
+
+    /// Starts from the context that parsing is using after the meta of the tab.
+    pub fn new(meta: &TabMeta) -> Self {
+        Self {
+            key: meta.key,
+            scale: meta.scale,
+            duration: Duration::default(),
+            octave: Octave::default(),
+            string_num: GUITAR_STRING_NUM,
+            track_kind: TrackKind::Unsupported,
+            indent: 0,
+            output: String::new(),
+            line: Vec::new(),
+            group: None,
+        }
+    }
+    pub fn format(mut self, tab: &Tab) -> String {
+        self.push_line("tab! {");
+        self.indent += 1;
+        self.push_line(format!("{:?}", tab.uuid.to_string()));
+        self.push_line(format!("Meta: {}", Self::format_meta(&tab.meta)));
+        if tab.info != TabInfo::default() {
+            self.format_info(&tab.info);
+        }
+        self.push_line("Tracks: [");
+        self.indent += 1;
+        for track in tab.tracks.iter() {
+            self.format_track(track);
+        }
+        self.indent -= 1;
+        self.push_line("]");
+        self.push_line("Sections: [");
+        self.indent += 1;
+        for section in tab.sections.iter() {
+            self.format_section(section);
+        }
+        self.indent -= 1;
+        self.push_line("]");
+        let form: Vec<String> = tab
+            .form
+            .sections
+            .iter()
+            .map(|x| Self::format_id(x))
+            .collect();
+        self.push_line(format!("Form: {}", form.join(" ")).trim_end());
+        self.indent -= 1;
+        self.push_line("}");
+        self.output
+    }
+}
+
+impl TabFormatter {
+    fn push_line<S: AsRef<str>>(&mut self, text: S) {
+        for _ in 0..self.indent {
+            self.output.push_str(Self::INDENT);
+        }
+        self.output.push_str(text.as_ref());
+        self.output.push('\n');
+    }
+    fn close_group(&mut self) {
+        if let Some((kind, items)) = self.group.take() {
+            let text = if items.len() == 1 && items[0].starts_with('(') {
+                let item = &items[0];
+                format!("{} ( {} )", kind.to_ident(), &item[1..item.len() - 1])
+            } else {
+                format!("{} [ {} ]", kind.to_ident(), items.join(" "))
+            };
+            self.line.push(text);
+        }
+    }
+    fn flush_line(&mut self) {
+        self.close_group();
+        if !self.line.is_empty() {
+            let line = self.line.join(" ");
+            self.line.clear();
+            self.push_line(line);
+        }
+    }
+    fn push_item(&mut self, kind: GroupKind, item: String) {
+        if self.group.as_ref().map(|x| x.0 != kind).unwrap_or(false) {
+            self.close_group();
+        }
+        self.group
+            .get_or_insert_with(|| (kind, Vec::new()))
+            .1
+            .push(item);
+    }
+    fn push_comment(&mut self, entry: &ProtoEntry) {
+        self.flush_line();
+        self.push_line(format!("// {}", entry));
+    }
+}
+
+impl TabFormatter {
+    /// Ids are written as idents when possible.
+    pub fn format_id(id: &str) -> String {
+        if syn::parse_str::<Ident>(id).is_ok() {
+            id.to_owned()
+        } else {
+            format!("{:?}", id)
+        }
+    }
+    pub fn format_mark(mark: &str) -> String {
+        if mark == "|" {
+            mark.to_owned()
+        } else {
+            format!("{:?}", mark)
+        }
+    }
+    pub fn format_meta(meta: &TabMeta) -> String {
+        let groove = match meta.groove {
+            Groove::Straight => "".to_owned(),
+            Groove::Shuffle => " Shuffle".to_owned(),
+            Groove::Swing8(ratio) => format!(" Swing8 {}", ratio),
+            Groove::Swing16(ratio) => format!(" Swing16 {}", ratio),
+        };
+        format!(
+            "{} {} {} {} {}{}",
+            meta.key.to_ident(),
+            meta.scale.to_ident(),
+            meta.signature.bar_beats,
+            meta.signature.beat_unit.to_ident(),
+            meta.tempo,
+            groove
+        )
+    }
+    fn format_info(&mut self, info: &TabInfo) {
+        self.push_line("Info: {");
+        self.indent += 1;
+        for (name, value) in [
+            ("title", &info.title),
+            ("artist", &info.artist),
+            ("composer", &info.composer),
+            ("lyricist", &info.lyricist),
+            ("album", &info.album),
+        ] {
+            if !value.is_empty() {
+                self.push_line(format!("{}: {:?}", name, value));
+            }
+        }
+        if let Some(year) = info.year {
+            self.push_line(format!("year: {}", year));
+        }
+        for (name, value) in [
+            ("source_url", &info.source_url),
+            ("copyright", &info.copyright),
+        ] {
+            if !value.is_empty() {
+                self.push_line(format!("{}: {:?}", name, value));
+            }
+        }
+        if let Some(difficulty) = info.difficulty {
+            self.push_line(format!("difficulty: {}", difficulty.to_ident()));
+        }
+        if !info.tags.is_empty() {
+            let tags: Vec<String> = info.tags.iter().map(|x| format!("{:?}", x)).collect();
+            self.push_line(format!("tags: [{}]", tags.join(" ")));
+        }
+        self.indent -= 1;
+        self.push_line("}");
+    }
+}
+
+impl TabFormatter {
+    fn format_track(&mut self, track: &Track) {
+        self.push_line(format!("{{{} {} [", Self::format_id(&track.id), track.kind));
+        self.indent += 1;
+        self.track_kind = track.kind;
+        for entry in track.entries.iter() {
+            self.format_entry(entry);
+        }
+        self.flush_line();
+        self.indent -= 1;
+        self.push_line("]}");
+    }
+    fn format_entry(&mut self, entry: &ProtoEntry) {
+        match entry {
+            ProtoEntry::Mark(mark) => {
+                if mark == "|" {
+                    self.close_group();
+                    self.line.push(Self::format_mark(mark));
+                    self.flush_line();
+                } else {
+                    self.flush_line();
+                    self.line.push(Self::format_mark(mark));
+                }
+            }
+            ProtoEntry::Meta(_) => self.push_comment(entry),
+            ProtoEntry::Core(core) => self.format_core(core),
+            ProtoEntry::Lyric(lyric) => self.format_lyric(lyric),
+            ProtoEntry::Fretted(fretted) => self.format_fretted(entry, fretted),
+            ProtoEntry::Extra(key, value) => match self.format_context(key, value) {
+                Some(text) => {
+                    self.flush_line();
+                    self.push_line(text);
+                }
+                None => self.push_comment(entry),
+            },
+        }
+    }
+    /// Context entries are added as `("dsl::context::<name>", <value>)` when parsing.
+    fn format_context(&mut self, key: &str, value: &str) -> Option<String> {
+        match key.strip_prefix("dsl::context::")? {
+            "key" => {
+                let key = Key::ALL.iter().find(|x| x.to_string() == value)?;
+                self.key = *key;
+                Some(format!("$key = {}", key.to_ident()))
+            }
+            "scale" => {
+                let scale = Scale::ALL.iter().find(|x| x.to_string() == value)?;
+                self.scale = *scale;
+                Some(format!("$scale = {}", scale.to_ident()))
+            }
+            "duration" => {
+                let duration = Duration::from_ident(value);
+                if duration.to_ident() != value {
+                    return None;
+                }
+                self.duration = duration;
+                Some(format!("$duration = {}", duration.to_ident()))
+            }
+            "octave" => {
+                let octave = Octave::from_ident(value);
+                if octave.to_ident() != value {
+                    return None;
+                }
+                self.octave = octave;
+                Some(format!("$octave = {}", octave.to_ident()))
+            }
+            "string_num" => {
+                let string_num = value.parse::<usize>().ok()?;
+                if string_num == 0 {
+                    return None;
+                }
+                self.string_num = string_num;
+                Some(format!("$string_num = {}", string_num))
+            }
+            _ => None,
+        }
+    }
+    fn switch_string_num(&mut self, string_num: usize) {
+        if self.string_num != string_num {
+            self.flush_line();
+            self.push_line(format!("$string_num = {}", string_num));
+            self.string_num = string_num;
+        }
+    }
+}
+
+impl TabFormatter {
+    /// Tweak of the context duration, switches the context when not possible.
+    fn format_duration(&mut self, duration: &Duration) -> String {
+        if let Some(tweak) = Self::calc_duration_tweak(&self.duration, duration) {
+            return tweak;
+        }
+        self.flush_line();
+        self.push_line(format!("$duration = {}", duration.to_ident()));
+        self.duration = *duration;
+        "".to_owned()
+    }
+    pub fn calc_duration_tweak(base: &Duration, duration: &Duration) -> Option<String> {
+        if base == duration {
+            return Some("".to_owned());
+        }
+        base.as_simple()?;
+        let mut suffixes = vec!["", "+", "++", "-", "=", "?", "??"]
+            .into_iter()
+            .map(|x| x.to_owned())
+            .collect::<Vec<String>>();
+        if let Some((n, m)) = duration.tuplet() {
+            suffixes.push(format!("&{}", n));
+            suffixes.push(format!("&{}:{}", n, m));
+        }
+        for half_num in Self::HALF_NUMS {
+            for suffix in suffixes.iter() {
+                let tweak = DurationTweakDsl {
+                    half_num,
+                    dotted: suffix == "+" || suffix == "=",
+                    double_dotted: suffix == "++",
+                    triplet: suffix == "-" || suffix == "=",
+                    tuplet: duration.tuplet().and_then(|(n, m)| {
+                        if suffix.starts_with('&') {
+                            Some((n, if suffix.contains(':') { Some(m) } else { None }))
+                        } else {
+                            None
+                        }
+                    }),
+                    acciaccatura: suffix == "?",
+                    appoggiatura: suffix == "??",
+                };
+                if tweak.tweak(base) == *duration {
+                    let marks = if half_num > 0 { "," } else { "*" }.repeat(half_num.unsigned_abs() as usize);
+                    return Some(format!("{}{}", marks, suffix));
+                }
+            }
+        }
+        None
+    }
+    pub fn format_syllable(syllable: &Syllable) -> String {
+        let text = syllable.to_text();
+        match text.strip_suffix('b') {
+            Some(natural) => format!("{} b", natural),
+            None => text,
+        }
+    }
+    fn format_octave_tweak(offset: i8) -> String {
+        if offset < 0 { "." } else { "^" }.repeat(offset.unsigned_abs() as usize)
+    }
+    fn format_note(&self, note: &Note) -> String {
+        let tweaked = |offset: i8| OctaveTweakDsl { offset }.tweak(&self.octave);
+        for offset in Self::OCTAVE_OFFSETS {
+            let octave = tweaked(offset);
+            if self.scale.calc_note_from_syllable(&self.key, &note.syllable, &octave) == *note {
+                return format!(
+                    "{}{}",
+                    Self::format_octave_tweak(offset),
+                    Self::format_syllable(&note.syllable)
+                );
+            }
+        }
+        for offset in Self::OCTAVE_OFFSETS {
+            let octave = tweaked(offset);
+            if self.scale.calc_note_from_pitch(&self.key, &note.pitch, &octave) == *note {
+                let sign = match note.pitch.sign {
+                    PitchSign::Natural => "",
+                    PitchSign::Sharp => "#",
+                    PitchSign::DoubleSharp => "##",
+                    PitchSign::Flat => " b",
+                    PitchSign::DoubleFlat => " b b",
+                };
+                return format!(
+                    "{}{}{}",
+                    Self::format_octave_tweak(offset),
+                    note.pitch.name,
+                    sign
+                );
+            }
+        }
+        log::warn!("TabFormatter::format_note() Not matched: {}", note);
+        Self::format_syllable(&note.syllable)
+    }
+    pub fn format_interval(interval: &Interval) -> &'static str {
+        match interval {
+            Interval::Unison => "1",
+            Interval::Minor2nd => "2-",
+            Interval::Major2nd => "2",
+            Interval::Minor3nd => "3-",
+            Interval::Major3nd => "3",
+            Interval::Perfect4th => "4",
+            Interval::Augmented4th | Interval::Tritone => "4+",
+            Interval::Diminished5th => "5%",
+            Interval::Perfect5th => "5",
+            Interval::Augmented5th => "5+",
+            Interval::Minor6th => "6-",
+            Interval::Major6th => "6",
+            Interval::Diminished7th => "7%",
+            Interval::Minor7th => "7-",
+            Interval::Major7th => "7",
+            Interval::Perfect8ve => "8",
+        }
+    }
+}
+
+impl TabFormatter {
+    /// Rests, ties and fermatas are added to the current list when supported there.
+    fn push_empty(&mut self, empty: &str, duration: Option<&Duration>) {
+        let tweak = duration.map(|x| self.format_duration(x)).unwrap_or_default();
+        let kind = match self.group.as_ref().map(|x| x.0) {
+            Some(GroupKind::Tone) => GroupKind::Tone,
+            Some(GroupKind::Word) => GroupKind::Word,
+            Some(GroupKind::Pick) if empty != "@" => GroupKind::Pick,
+            Some(_) => GroupKind::Tone,
+            None => match self.track_kind {
+                TrackKind::Guitar | TrackKind::Bass if empty != "@" => GroupKind::Pick,
+                TrackKind::Lyrics => GroupKind::Word,
+                _ => GroupKind::Tone,
+            },
+        };
+        self.push_item(kind, format!("{}{}", empty, tweak));
+    }
+    fn format_core(&mut self, entry: &CoreEntry) {
+        match entry {
+            CoreEntry::Tie => self.push_empty("@", None),
+            CoreEntry::Rest(duration) => self.push_empty("_", Some(duration)),
+            CoreEntry::Fermata => self.push_empty("!", None),
+            CoreEntry::Tone(tone, duration) => {
+                let tweak = self.format_duration(duration);
+                let notes: Vec<String> = tone.iter().map(|x| self.format_note(x)).collect();
+                let item = if notes.len() == 1 {
+                    format!("{}{}", notes[0], tweak)
+                } else {
+                    format!("({}{})", notes.join(" "), tweak)
+                };
+                self.push_item(GroupKind::Tone, item);
+            }
+            CoreEntry::Chord(chord, duration) => {
+                let tweak = self.format_duration(duration);
+                let mut text = format!("{}:", Self::format_syllable(&chord.root));
+                let mut last = "";
+                for interval in chord.intervals.iter() {
+                    last = Self::format_interval(interval);
+                    text.push(' ');
+                    text.push_str(last);
+                }
+                if let Some(bass) = chord.bass {
+                    last = Self::format_interval(&bass);
+                    text.push_str(" /");
+                    text.push_str(last);
+                }
+                // Intervals like `7-` and `5+` would take the tweak, empty halving goes between.
+                let absorbed = match last {
+                    "2" | "3" | "6" | "7" => tweak.starts_with('-'),
+                    "4" | "5" => tweak.starts_with('+'),
+                    _ => false,
+                };
+                if absorbed {
+                    text.push_str(",*");
+                }
+                text.push_str(&tweak);
+                self.push_item(GroupKind::Chord, format!("({})", text));
+            }
+        }
+    }
+    fn format_lyric(&mut self, entry: &LyricEntry) {
+        match entry {
+            LyricEntry::Word(word, duration) => {
+                let tweak = self.format_duration(duration);
+                let join = match word.join {
+                    LyricJoin::None => "".to_owned(),
+                    join => format!("~{}", join.to_ident()),
+                };
+                self.push_item(
+                    GroupKind::Word,
+                    format!("{:?}{}{}", word.text, join, tweak),
+                );
+            }
+            LyricEntry::Melisma(duration) => {
+                let tweak = self.format_duration(duration);
+                self.push_item(GroupKind::Word, format!("~{}", tweak));
+            }
+            LyricEntry::Verse(verse) => {
+                self.close_group();
+                self.line.push(format!("Verse {}", verse));
+            }
+        }
+    }
+    pub fn format_pick_note(note: &PickNote) -> String {
+        let mut text = note.string.to_string();
+        if let Some(fret) = note.fret {
+            text.push_str(&format!("@{}", fret));
+        }
+        if let Some(articulation) = note.articulation {
+            text.push_str(&format!("~{}", articulation.to_ident()));
+        }
+        text
+    }
+    fn format_pick(&mut self, pick: &Pick, duration: &Duration) {
+        let tweak = self.format_duration(duration);
+        let notes: Vec<String> = pick.iter().map(Self::format_pick_note).collect();
+        let item = if notes.len() == 1 {
+            format!("{}{}", notes[0], tweak)
+        } else {
+            format!("({}{})", notes.join(" "), tweak)
+        };
+        self.push_item(GroupKind::Pick, item);
+    }
+    fn format_shape(&mut self, frets: &[Option<u8>], barre: Option<u8>, duration: &Duration) {
+        let tweak = self.format_duration(duration);
+        let frets: Vec<String> = frets
+            .iter()
+            .rev()
+            .map(|x| x.map(|fret| fret.to_string()).unwrap_or("_".to_owned()))
+            .collect();
+        let mut text = format!("Shape ( {} )", frets.join(" "));
+        match barre {
+            Some(barre) => text.push_str(&format!(" +{}", barre)),
+            None if tweak.starts_with('+') => text.push_str(" +0"),
+            None => (),
+        }
+        if !tweak.is_empty() {
+            text.push(' ');
+            text.push_str(&tweak);
+        }
+        self.close_group();
+        if self.line.iter().any(|x| !x.starts_with('"')) {
+            self.flush_line();
+        }
+        self.line.push(text);
+    }
+    fn format_fretboard(
+        &mut self,
+        string_num: usize,
+        tuning: GuitarTuning,
+        total_fret_num: usize,
+        capo: u8,
+    ) {
+        let mut text = "Fretboard".to_owned();
+        if GuitarTuning::default_of(string_num) != Some(tuning.clone()) {
+            text.push_str(&format!(" tuning: {}", tuning.to_ident()));
+        }
+        if total_fret_num != GUITAR_FRET_NUM_ACOUSTIC {
+            text.push_str(&format!(" fret_num: {}", total_fret_num));
+        }
+        if capo != 0 {
+            text.push_str(&format!(" capo: {}", capo));
+        }
+        self.flush_line();
+        self.push_line(text);
+    }
+    /// The default tuning of the string number first, so that it's not written out.
+    fn preset_tunings(string_num: usize) -> Vec<GuitarTuning> {
+        GuitarTuning::default_of(string_num)
+            .into_iter()
+            .chain([
+                GuitarTuning::Standard,
+                GuitarTuning::StandardFlat,
+                GuitarTuning::DropD,
+                GuitarTuning::DropC,
+                GuitarTuning::OpenG,
+                GuitarTuning::OpenD,
+                GuitarTuning::Dadgad,
+                GuitarTuning::Nashville,
+                GuitarTuning::Ukulele,
+                GuitarTuning::Banjo,
+                GuitarTuning::Mandolin,
+                GuitarTuning::Bass,
+                GuitarTuning::FiveStringBass,
+                GuitarTuning::SevenString,
+                GuitarTuning::EightString,
+                GuitarTuning::TwelveString,
+            ])
+            .filter(|x| x.get_notes().len() == string_num)
+            .collect()
+    }
+}
+
+impl TabFormatter {
+    /// Picks don't depend on the string number, only fretboards and shapes switch it.
+    fn format_fretted(&mut self, entry: &ProtoEntry, fretted: &FrettedEntry) {
+        match fretted {
+            FrettedEntry::Pick(pick, duration) => self.format_pick(pick, duration),
+            FrettedEntry::Strum(_, _) => self.push_comment(entry),
+            FrettedEntry::Shape(shape, duration) => {
+                self.switch_string_num(shape.string_num());
+                self.format_shape(&shape.frets, shape.barre, duration)
+            }
+            FrettedEntry::Fretboard(fretboard) => {
+                let string_num = fretboard.string_num();
+                self.switch_string_num(string_num);
+                let matched = |tuning: &GuitarTuning| {
+                    GuitarUtil::new_fretboard(fretboard.total_fret_num, tuning, fretboard.capo)
+                        == *fretboard
+                };
+                let tuning = Self::preset_tunings(string_num)
+                    .into_iter()
+                    .find(matched)
+                    .unwrap_or_else(|| {
+                        GuitarTuning::Custom(
+                            fretboard
+                                .string_notes
+                                .iter()
+                                .map(|x| x.as_pitch_octave())
+                                .collect(),
+                        )
+                    });
+                self.format_fretboard(
+                    string_num,
+                    tuning,
+                    fretboard.total_fret_num,
+                    fretboard.capo,
+                );
+            }
+        }
+    }
+}
+
+impl TabFormatter {
+    fn format_section(&mut self, section: &Section) {
+        let kind = match &section.kind {
+            SectionKind::Custom(kind) => kind.clone(),
+            kind => kind.to_string(),
+        };
+        if section.bars.is_empty() {
+            self.push_line(format!("{{{} {} [ ]}}", Self::format_id(&section.id), kind));
+            return;
+        }
+        self.push_line(format!("{{{} {} [", Self::format_id(&section.id), kind));
+        self.indent += 1;
+        for (index, bar) in section.bars.iter().enumerate() {
+            self.push_line(if index == 0 { "{" } else { "} {" });
+            self.indent += 1;
+            for layer in bar.layers.iter() {
+                self.push_line(Self::format_layer(layer));
+            }
+            self.indent -= 1;
+        }
+        self.push_line("}");
+        self.indent -= 1;
+        self.push_line("]}");
+    }
+    pub fn format_layer(layer: &BarLayer) -> String {
+        let slices: Vec<String> = layer.slices.iter().map(Self::format_slice).collect();
+        if slices.is_empty() {
+            format!("{} [ ]", Self::format_id(&layer.track))
+        } else {
+            format!("{} [ {} ]", Self::format_id(&layer.track), slices.join(" ; "))
+        }
+    }
+    pub fn format_slice(slice: &Slice) -> String {
+        let begin = match &slice.begin {
+            SliceBegin::Mark(mark) => Self::format_mark(mark),
+            SliceBegin::Index(index) => index.to_string(),
+        };
+        let end = match &slice.end {
+            SliceEnd::Mark(mark) => Self::format_mark(mark),
+            SliceEnd::Count(count) => count.to_string(),
+        };
+        match &slice.rounds {
+            Some(rounds) => {
+                let rounds: Vec<String> = rounds.iter().map(|x| x.to_string()).collect();
+                format!("{} {} @ {}", begin, end, rounds.join(" ")).trim_end().to_owned()
+            }
+            None => format!("{} {}", begin, end),
+        }
+    }
+}
@@ -1,18 +1,115 @@
-use fehler::throws;
+use fehler::{throw, throws};
 use notation_proto::prelude::{
-    Fretboard4, Fretboard6, FrettedEntry4, FrettedEntry6, GuitarTuning,
-    GUITAR_FRET_NUM_ACOUSTIC, Pitch, Octave,
+    FrettedEntry, GuitarTuning, GuitarUtil, GUITAR_FRET_NUM_ACOUSTIC, Pitch, Octave,
 };
 use notation_proto::proto_entry::ProtoEntry;
-use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
 use syn::parse::{Error, Parse, ParseStream};
-use syn::{Ident, LitInt, Token};
+use syn::{bracketed, token, Ident, LitInt, Token};
 
 use crate::context::Context;
 
+/// Either a preset as `tuning: DropD`, or the open notes from string 1 as
+/// `tuning: [E P4, B P3, G P3, D P3, A P2, D P2]`.
+pub struct TuningDsl {
+    pub span: Span,
+    pub tuning: GuitarTuning,
+}
+
+impl Parse for TuningDsl {
+    #[throws(Error)]
+    fn parse(input: ParseStream) -> Self {
+        let span = input.span();
+        if input.peek(token::Bracket) {
+            let content;
+            bracketed!(content in input);
+            let mut notes = Vec::new();
+            while !content.is_empty() {
+                let ident = content.parse::<Ident>()?;
+                let mut pitch_text = ident.to_string();
+                while content.peek(Token![#]) {
+                    content.parse::<Token![#]>()?;
+                    pitch_text.push('#');
+                }
+                let pitch = Pitch::from_text(pitch_text.as_str());
+                if pitch.to_text() != pitch_text {
+                    throw!(Error::new(ident.span(), "Invalid Pitch"));
+                }
+                let octave_ident = content.parse::<Ident>()?;
+                let octave = Octave::from_ident(octave_ident.to_string().as_str());
+                if octave_ident != octave.to_ident() {
+                    throw!(Error::new(octave_ident.span(), "Invalid Octave"));
+                }
+                notes.push((pitch, octave));
+                if content.peek(Token![,]) {
+                    content.parse::<Token![,]>()?;
+                }
+            }
+            TuningDsl {
+                span,
+                tuning: GuitarTuning::Custom(notes),
+            }
+        } else {
+            let ident = input.parse::<Ident>()?;
+            match GuitarTuning::from_ident(ident.to_string().as_str()) {
+                Some(tuning) => TuningDsl { span, tuning },
+                None => throw!(Error::new(ident.span(), "Invalid Tuning")),
+            }
+        }
+    }
+}
+
+impl ToTokens for TuningDsl {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(Self::quote_tuning(&self.tuning));
+    }
+}
+
+impl TuningDsl {
+    pub fn quote_tuning(tuning: &GuitarTuning) -> TokenStream {
+        match tuning {
+            GuitarTuning::Custom(notes) => {
+                let notes_quote: Vec<TokenStream> = notes
+                    .iter()
+                    .map(|(pitch, octave)| {
+                        let pitch_text = pitch.to_text();
+                        let octave_ident = octave.to_ident();
+                        quote! { (Pitch::from_text(#pitch_text), Octave::from_ident(#octave_ident)) }
+                    })
+                    .collect();
+                quote! { GuitarTuning::Custom(vec![#(#notes_quote),*]) }
+            }
+            _ => {
+                let ident = format_ident!("{}", tuning.to_ident());
+                quote! { GuitarTuning::#ident }
+            }
+        }
+    }
+    pub fn to_proto(&self) -> GuitarTuning {
+        self.tuning.clone()
+    }
+    /// Tunings of other string numbers are not replaced silently.
+    #[throws(Error)]
+    pub fn check_string_num(&self, string_num: usize) {
+        let len = self.tuning.get_notes().len();
+        if len != string_num {
+            throw!(Error::new(
+                self.span,
+                format!(
+                    "Tuning {} got {} strings, expecting {}",
+                    self.tuning.to_ident(),
+                    len,
+                    string_num
+                )
+            ));
+        }
+    }
+}
+
 pub struct FretboardDsl {
-    pub tuning: Option<Ident>,
+    pub span: Span,
+    pub tuning: Option<TuningDsl>,
     pub fret_num: Option<usize>,
     pub capo: Option<u8>,
 }
@@ -26,6 +123,7 @@ mod kw {
 impl Parse for FretboardDsl {
     #[throws(Error)]
     fn parse(input: ParseStream) -> Self {
+        let span = input.span();
         let mut fret_num = None;
         let mut tuning = None;
         let mut capo = None;
@@ -33,7 +131,7 @@ impl Parse for FretboardDsl {
             if input.peek(kw::tuning) {
                 input.parse::<kw::tuning>()?;
                 input.parse::<Token![:]>()?;
-                tuning = Some(input.parse::<Ident>()?);
+                tuning = Some(input.parse::<TuningDsl>()?);
             } else if input.peek(kw::fret_num) {
                 input.parse::<kw::fret_num>()?;
                 input.parse::<Token![:]>()?;
@@ -47,6 +145,7 @@ impl Parse for FretboardDsl {
             }
         }
         FretboardDsl {
+            span,
             tuning,
             fret_num,
             capo,
@@ -57,6 +156,7 @@ impl Parse for FretboardDsl {
 impl ToTokens for FretboardDsl {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let FretboardDsl {
+            span: _,
             tuning,
             fret_num,
             capo,
@@ -68,29 +168,36 @@ impl ToTokens for FretboardDsl {
         });
         let capo = capo.unwrap_or(0);
         let tuning_quote = match tuning {
-            Some(tuning) => {
-                let tuning_quote = tuning.to_string();
-                match string_num {
-                    _ => quote! { GuitarTuning::from_ident(#tuning_quote)},
-                }
-            }
-            None => match string_num {
-                _ => quote! { GuitarTuning::Standard },
-            },
+            Some(tuning) => quote! { #tuning },
+            None => TuningDsl::quote_tuning(&Self::default_tuning(string_num)),
         };
-        let fretted_entry_quote = Context::fretted().fretted_entry_quote();
-        let fretboard_quote = Context::fretted().fretboard_quote();
         tokens.extend(quote! {
-            ProtoEntry::from(#fretted_entry_quote::from(
-                #fretboard_quote::new(#fret_num, #tuning_quote.into(), #capo)
+            ProtoEntry::from(FrettedEntry::from(
+                GuitarUtil::new_fretboard(#fret_num, &#tuning_quote, #capo)
             ))
         });
     }
 }
 
 impl FretboardDsl {
+    fn default_tuning(string_num: usize) -> GuitarTuning {
+        GuitarTuning::default_of(string_num).unwrap_or_default()
+    }
+    /// String numbers without a default tuning need one given.
+    #[throws(Error)]
+    pub fn check_string_num(&self, string_num: usize) {
+        match &self.tuning {
+            Some(tuning) => tuning.check_string_num(string_num)?,
+            None if GuitarTuning::default_of(string_num).is_none() => throw!(Error::new(
+                self.span,
+                format!("No default tuning for {} strings", string_num)
+            )),
+            None => (),
+        }
+    }
     pub fn to_proto(&self) -> ProtoEntry {
         let FretboardDsl {
+            span: _,
             tuning,
             fret_num,
             capo,
@@ -101,22 +208,97 @@ impl FretboardDsl {
             _ => GUITAR_FRET_NUM_ACOUSTIC,
         });
         let capo = capo.unwrap_or(0);
-        match string_num {
-            4 => {
-                let tuning = [(Pitch::E, Octave::P2).into(); 4]; //todo;
-                ProtoEntry::from(FrettedEntry4::from(Fretboard4::new(fret_num, tuning, capo)))
-            }
-            _ => {
-                let tuning = match tuning {
-                    Some(ident) => GuitarTuning::from_ident(ident.to_string().as_str()),
-                    None => GuitarTuning::Standard,
-                };
-                ProtoEntry::from(FrettedEntry6::from(Fretboard6::new(
-                    fret_num,
-                    tuning.into(),
-                    capo,
-                )))
-            }
+        let tuning = tuning
+            .as_ref()
+            .map(|x| x.to_proto())
+            .unwrap_or_else(|| Self::default_tuning(string_num));
+        ProtoEntry::from(FrettedEntry::from(GuitarUtil::new_fretboard(
+            fret_num, &tuning, capo,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use notation_proto::prelude::{FrettedEntry, Octave, Pitch, Semitones};
+    use notation_proto::proto_entry::ProtoEntry;
+
+    use crate::prelude::parse_tab;
+
+    fn parse_fretboard(entries: &str) -> Result<Vec<ProtoEntry>, String> {
+        let content = format!(
+            r#"
+            tab! {{
+                "5b2d8e1f-7c3a-4e6b-9f0d-2a1c4b3e5d7f"
+                Meta: E Minor 4 _4 60
+                Tracks: [
+                    {{guitar Guitar [
+                        {}
+                    ]}}
+                ]
+                Sections: [ ]
+                Form: ""
+            }}
+            "#,
+            entries
+        );
+        parse_tab(&content)
+            .map(|tab| tab.tracks[0].entries.clone())
+            .map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn test_unknown_tuning() {
+        let err = parse_fretboard("Fretboard tuning: DropB").unwrap_err();
+        assert!(err.contains("Invalid Tuning"), "{}", err);
+    }
+
+    #[test]
+    fn test_string_num_mismatch() {
+        let err = parse_fretboard("$string_num = 7 Fretboard tuning: DropD").unwrap_err();
+        assert!(
+            err.contains("Tuning DropD got 6 strings, expecting 7"),
+            "{}",
+            err
+        );
+        let err = parse_fretboard("Fretboard tuning: [E P4, B P3, G P3]").unwrap_err();
+        assert!(err.contains("got 3 strings, expecting 6"), "{}", err);
+    }
+
+    #[test]
+    fn test_default_tunings() {
+        let entries = parse_fretboard("$string_num = 7 Fretboard").unwrap();
+        match entries.last() {
+            Some(ProtoEntry::Fretted(FrettedEntry::Fretboard(fretboard))) => assert_eq!(
+                fretboard.string_notes[6],
+                Semitones::from((Pitch::B, Octave::P1))
+            ),
+            entry => panic!("unexpected entry: {:?}", entry),
         }
+        let entries = parse_fretboard("$string_num = 4 Fretboard").unwrap();
+        match entries.last() {
+            Some(ProtoEntry::Fretted(FrettedEntry::Fretboard(fretboard))) => assert_eq!(
+                fretboard.string_notes[3],
+                Semitones::from((Pitch::E, Octave::P1))
+            ),
+            entry => panic!("unexpected entry: {:?}", entry),
+        }
+    }
+
+    #[test]
+    fn test_any_string_num() {
+        let entries = parse_fretboard(
+            "$string_num = 3 Fretboard tuning: [D P4, A P3, D P3] Shape (0 2 2)",
+        )
+        .unwrap();
+        let fretted: Vec<&FrettedEntry> = entries.iter().filter_map(|x| x.as_fretted()).collect();
+        assert_eq!(fretted[0].as_fretboard().map(|x| x.string_num()), Some(3));
+        assert_eq!(fretted[1].as_shape().map(|x| x.string_num()), Some(3));
+        let err = parse_fretboard("$string_num = 9 Fretboard").unwrap_err();
+        assert!(err.contains("No default tuning for 9 strings"), "{}", err);
+        let err = parse_fretboard("$string_num = 0").unwrap_err();
+        assert!(err.contains("Invalid string_num: 0"), "{}", err);
+        let err = parse_fretboard("Shape (0 2 2)").unwrap_err();
+        assert!(err.contains("Shape got 3 strings, expecting 6"), "{}", err);
     }
 }
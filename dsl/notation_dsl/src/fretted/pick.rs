@@ -1,5 +1,5 @@
 use fehler::throws;
-use notation_proto::prelude::{CoreEntry, FrettedEntry4, FrettedEntry6, Pick};
+use notation_proto::prelude::{CoreEntry, FrettedEntry, Pick};
 use notation_proto::proto_entry::ProtoEntry;
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
@@ -12,6 +12,7 @@ use crate::core::duration::DurationTweakDsl;
 use super::pick_note::PickNoteDsl;
 
 pub struct PickDsl {
+    pub fermata: bool,
     pub notes: Vec<PickNoteDsl>,
     pub duration_tweak: Option<DurationTweakDsl>,
 }
@@ -20,6 +21,14 @@ impl PickDsl {
     #[throws(Error)]
     pub fn parse_without_paren(input: ParseStream, multied: bool, with_paren: bool) -> Self {
         let mut notes = vec![];
+        if input.peek(Token![!]) {
+            input.parse::<Token![!]>()?;
+            return PickDsl {
+                fermata: true,
+                notes,
+                duration_tweak: None,
+            };
+        }
         if input.peek(Token![_]) {
             input.parse::<Token![_]>()?;
         } else {
@@ -30,8 +39,9 @@ impl PickDsl {
                 }
             }
         }
-        let duration_tweak = DurationTweakDsl::try_parse(input);
+        let duration_tweak = DurationTweakDsl::try_parse(input)?;
         PickDsl {
+            fermata: false,
             notes,
             duration_tweak,
         }
@@ -41,20 +51,23 @@ impl PickDsl {
 impl ToTokens for PickDsl {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let PickDsl {
+            fermata,
             notes,
             duration_tweak,
         } = self;
         let duration_quote = Context::duration_quote(duration_tweak);
-        if notes.len() == 0 {
+        if *fermata {
+            tokens.extend(quote! {
+                ProtoEntry::from(CoreEntry::Fermata)
+            });
+        } else if notes.is_empty() {
             tokens.extend(quote! {
                 ProtoEntry::from(CoreEntry::from(#duration_quote))
             });
         } else {
-            let _string_num = Context::fretted().string_num;
             let notes_quote: Vec<_> = notes.iter().map(|x| quote! { #x }).collect();
-            let fretted_entry_quote = Context::fretted().fretted_entry_quote();
             tokens.extend(quote! {
-                ProtoEntry::from(#fretted_entry_quote::from(
+                ProtoEntry::from(FrettedEntry::from(
                     (Pick::from(vec![
                         #(#notes_quote),*
                     ]), #duration_quote)
@@ -67,19 +80,18 @@ impl ToTokens for PickDsl {
 impl PickDsl {
     pub fn to_proto(&self) -> ProtoEntry {
         let PickDsl {
+            fermata,
             notes,
             duration_tweak,
         } = self;
         let duration = Context::tweaked_duration(duration_tweak);
-        if notes.len() == 0 {
+        if *fermata {
+            ProtoEntry::from(CoreEntry::Fermata)
+        } else if notes.is_empty() {
             ProtoEntry::from(CoreEntry::from(duration))
         } else {
-            let _string_num = Context::fretted().string_num;
             let notes: Vec<_> = notes.iter().map(|x| x.to_proto()).collect();
-            match Context::fretted().string_num {
-                4 => ProtoEntry::from(FrettedEntry4::from((Pick::from(notes), duration))),
-                _ => ProtoEntry::from(FrettedEntry6::from((Pick::from(notes), duration))),
-            }
+            ProtoEntry::from(FrettedEntry::from((Pick::from(notes), duration)))
         }
     }
 }
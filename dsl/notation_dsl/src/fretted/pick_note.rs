@@ -1,41 +1,81 @@
-use fehler::throws;
-use notation_proto::prelude::PickNote;
-use proc_macro2::TokenStream;
+use fehler::{throw, throws};
+use notation_proto::prelude::{Articulation, PickNote};
+use proc_macro2::{Span, TokenStream};
 use quote::{quote, ToTokens};
 use syn::parse::{Error, Parse, ParseStream};
-use syn::{LitInt, Token};
+use syn::{Ident, LitInt, Token};
 
 pub struct PickNoteDsl {
     pub string: u8,
     pub fret: Option<u8>,
+    pub articulation: Option<Articulation>,
+    /// Covers the whole note, e.g. `3@2~Hammer`.
+    pub span: Span,
 }
 
 impl Parse for PickNoteDsl {
     #[throws(Error)]
     fn parse(input: ParseStream) -> Self {
-        let string = input.parse::<LitInt>()?.base10_parse::<u8>()?;
+        let string_lit = input.parse::<LitInt>()?;
+        let string = string_lit.base10_parse::<u8>()?;
+        let mut span = string_lit.span();
         let fret = if input.peek(Token![@]) {
             input.parse::<Token![@]>()?;
-            Some(input.parse::<LitInt>()?.base10_parse::<u8>()?)
+            let fret_lit = input.parse::<LitInt>()?;
+            span = span.join(fret_lit.span()).unwrap_or(span);
+            Some(fret_lit.base10_parse::<u8>()?)
         } else {
             None
         };
-        PickNoteDsl { string, fret }
+        let articulation = if input.peek(Token![~]) {
+            input.parse::<Token![~]>()?;
+            let ident = input.parse::<Ident>()?;
+            span = span.join(ident.span()).unwrap_or(span);
+            match Articulation::from_ident(ident.to_string().as_str()) {
+                Some(articulation) => Some(articulation),
+                None => throw!(Error::new(ident.span(), "Invalid Articulation")),
+            }
+        } else {
+            None
+        };
+        PickNoteDsl {
+            string,
+            fret,
+            articulation,
+            span,
+        }
     }
 }
 
 impl ToTokens for PickNoteDsl {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let PickNoteDsl { string, fret } = self;
+        let PickNoteDsl {
+            string,
+            fret,
+            articulation,
+            ..
+        } = self;
         let fret_quote = fret.map(|f| quote! { Some(#f) }).unwrap_or(quote! {None});
-        tokens.extend(quote! {
-            PickNote::new(#string, #fret_quote, None, None, None)
-        });
+        match articulation {
+            Some(articulation) => {
+                let articulation_ident = articulation.to_ident();
+                tokens.extend(quote! {
+                    PickNote::new(#string, #fret_quote, None, None, None)
+                        .with_articulation(Articulation::from_ident(#articulation_ident))
+                });
+            }
+            None => {
+                tokens.extend(quote! {
+                    PickNote::new(#string, #fret_quote, None, None, None)
+                });
+            }
+        }
     }
 }
 
 impl PickNoteDsl {
     pub fn to_proto(&self) -> PickNote {
         PickNote::new(self.string, self.fret, None, None, None)
+            .with_articulation(self.articulation)
     }
 }
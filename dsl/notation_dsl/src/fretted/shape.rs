@@ -1,7 +1,7 @@
-use fehler::throws;
-use notation_proto::prelude::{FrettedEntry4, FrettedEntry6, HandShape4, HandShape6};
+use fehler::{throw, throws};
+use notation_proto::prelude::{FrettedEntry, HandShape};
 use notation_proto::proto_entry::ProtoEntry;
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::{quote, ToTokens};
 use syn::parse::{Error, Parse, ParseStream};
 use syn::{parenthesized, token, LitInt, Token};
@@ -10,6 +10,7 @@ use crate::context::Context;
 use crate::core::duration::DurationTweakDsl;
 
 pub struct ShapeDsl {
+    pub span: Span,
     pub barre: Option<u8>,
     pub frets: Vec<Option<u8>>,
     pub duration_tweak: Option<DurationTweakDsl>,
@@ -18,6 +19,7 @@ pub struct ShapeDsl {
 impl Parse for ShapeDsl {
     #[throws(Error)]
     fn parse(input: ParseStream) -> Self {
+        let span = input.span();
         let mut frets = vec![];
         if input.peek(token::Paren) {
             let content;
@@ -38,8 +40,9 @@ impl Parse for ShapeDsl {
             barre = Some(input.parse::<LitInt>()?.base10_parse::<u8>()?);
         }
 
-        let duration_tweak = DurationTweakDsl::try_parse(input);
+        let duration_tweak = DurationTweakDsl::try_parse(input)?;
         ShapeDsl {
+            span,
             barre,
             frets,
             duration_tweak,
@@ -50,11 +53,11 @@ impl Parse for ShapeDsl {
 impl ToTokens for ShapeDsl {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let ShapeDsl {
+            span: _,
             barre,
             frets,
             duration_tweak,
         } = self;
-        //let string_num = Context::fretted().string_num;
         let mut frets_quote: Vec<TokenStream> = vec![];
         let mut fingers_quote: Vec<TokenStream> = vec![];
         for fret in frets {
@@ -65,15 +68,13 @@ impl ToTokens for ShapeDsl {
             fingers_quote.push(quote! { None });
         }
         let duration_quote = Context::duration_quote(duration_tweak);
-        let fretted_entry_quote = Context::fretted().fretted_entry_quote();
-        let hand_shape_quote = Context::fretted().hand_shape_quote();
         let barre = barre.unwrap_or(0);
         tokens.extend(quote! {
-            ProtoEntry::from(#fretted_entry_quote::from(
-                (#hand_shape_quote::new_barre(
-                    #barre, [
+            ProtoEntry::from(FrettedEntry::from(
+                (HandShape::new_barre(
+                    #barre, vec![
                     #(#frets_quote),*
-                ], [
+                ], vec![
                     #(#fingers_quote),*
                 ]), #duration_quote)
             ))
@@ -82,37 +83,25 @@ impl ToTokens for ShapeDsl {
 }
 
 impl ShapeDsl {
-    pub fn to_proto(&self) -> ProtoEntry {
-        let ShapeDsl {
-            barre,
-            frets: _,
-            duration_tweak,
-        } = self;
-        let barre = barre.unwrap_or(0);
-        let duration = Context::tweaked_duration(duration_tweak);
-        match Context::fretted().string_num {
-            4 => {
-                let mut frets = [None; 4];
-                for i in 0..4 {
-                    frets[i] = self.frets.get(i).unwrap().clone();
-                }
-                let fingers = [None; 4];
-                ProtoEntry::from(FrettedEntry4::from((
-                    HandShape4::new_barre(barre, frets, fingers),
-                    duration,
-                )))
-            }
-            _ => {
-                let mut frets = [None; 6];
-                for i in 0..6 {
-                    frets[i] = self.frets.get(i).unwrap().clone();
-                }
-                let fingers = [None; 6];
-                ProtoEntry::from(FrettedEntry6::from((
-                    HandShape6::new_barre(barre, frets, fingers),
-                    duration,
-                )))
-            }
+    #[throws(Error)]
+    pub fn check_string_num(&self, string_num: usize) {
+        if self.frets.len() != string_num {
+            throw!(Error::new(
+                self.span,
+                format!(
+                    "Shape got {} strings, expecting {}",
+                    self.frets.len(),
+                    string_num
+                )
+            ));
         }
     }
+    pub fn to_proto(&self) -> ProtoEntry {
+        let barre = self.barre.unwrap_or(0);
+        let duration = Context::tweaked_duration(&self.duration_tweak);
+        ProtoEntry::from(FrettedEntry::from((
+            HandShape::new_barre(barre, self.frets.clone(), vec![]),
+            duration,
+        )))
+    }
 }
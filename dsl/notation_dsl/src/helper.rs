@@ -4,6 +4,8 @@ use quote::ToTokens;
 use std::fs::File;
 use std::io::Read;
 use syn;
+use syn::parse::{ParseStream, Parser};
+use syn::{braced, Ident, Token};
 
 use notation_proto::prelude::*;
 
@@ -21,12 +23,27 @@ pub fn parse_get_tab_file(path: &str) -> Result<Tab, Error> {
     let mut file = File::open(path)?;
     let mut content = String::new();
     file.read_to_string(&mut content)?;
-    println!("parse_get_tab_file: {} -> [{}]", path, content.len());
+    log::info!("parse_get_tab_file: {} -> [{}]", path, content.len());
     parse_get_tab(&content)
 }
 
+/// Both the content of the macro and the whole `tab! { ... }` are accepted.
+pub fn parse_tab_dsl(content: &str) -> syn::Result<TabDsl> {
+    if content.trim_start().starts_with("tab!") {
+        let parser = |input: ParseStream| -> syn::Result<TabDsl> {
+            input.parse::<Ident>()?;
+            input.parse::<Token![!]>()?;
+            let tab_content;
+            braced!(tab_content in input);
+            tab_content.parse()
+        };
+        parser.parse_str(content)
+    } else {
+        syn::parse_str::<TabDsl>(content)
+    }
+}
 pub fn parse_tab(content: &str) -> Result<Tab, Error> {
-    let tab = syn::parse_str::<TabDsl>(content)?;
+    let tab = parse_tab_dsl(content)?;
     //println!("Tab: T:{}, S:{}", tab.tracks.len(), tab.sections.len());
     Ok(tab.to_proto())
 }
@@ -34,6 +51,6 @@ pub fn parse_tab_file(path: &str) -> Result<Tab, Error> {
     let mut file = File::open(path)?;
     let mut content = String::new();
     file.read_to_string(&mut content)?;
-    println!("parse_tab_file: {} -> [{}]", path, content.len());
+    log::info!("parse_tab_file: {} -> [{}]", path, content.len());
     parse_tab(&content)
 }
@@ -9,6 +9,7 @@ pub use {proc_macro2, quote, syn};
 
 pub mod context;
 pub mod core;
+pub mod format;
 pub mod fretted;
 pub mod get_tab;
 pub mod helper;
@@ -17,6 +18,8 @@ pub mod util;
 
 
 pub mod prelude {
+    #[doc(hidden)]
+    pub use crate::format::{format_tab, TabFormatter};
     #[doc(hidden)]
     pub use crate::get_tab::GetTabDsl;
     #[doc(hidden)]
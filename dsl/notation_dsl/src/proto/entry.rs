@@ -1,9 +1,10 @@
 use fehler::{throw, throws};
+use notation_proto::prelude::LyricEntry;
 use notation_proto::proto_entry::ProtoEntry;
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use syn::parse::{Error, ParseStream};
-use syn::Ident;
+use syn::{Ident, LitInt};
 
 use crate::context::ContextDsl;
 
@@ -25,6 +26,7 @@ pub enum EntryDsl {
     Tone(MultibleDsl<ToneDsl>),
     Chord(MultibleDsl<ChordDsl>),
     Word(MultibleDsl<WordDsl>),
+    Verse(u8),
     Pick(MultibleDsl<PickDsl>),
     Shape(ShapeDsl),
     Fretboard(FretboardDsl),
@@ -38,14 +40,16 @@ impl EntryDsl {
         } else if MarkDsl::peek(input) {
             Self::Mark(input.parse()?)
         } else {
-            match input.parse::<Ident>()?.to_string().as_str() {
+            let ident = input.parse::<Ident>()?;
+            match ident.to_string().as_str() {
                 "Tone" => Self::Tone(input.parse()?),
                 "Chord" => Self::Chord(input.parse()?),
                 "Word" => Self::Word(input.parse()?),
+                "Verse" => Self::Verse(input.parse::<LitInt>()?.base10_parse::<u8>()?),
                 "Pick" => Self::Pick(input.parse()?),
                 "Shape" => Self::Shape(input.parse()?),
                 "Fretboard" => Self::Fretboard(input.parse()?),
-                _ => throw!(Error::new(input.span(), "Invalid Entry")),
+                _ => throw!(Error::new(ident.span(), "Invalid Entry")),
             }
         }
     }
@@ -59,6 +63,7 @@ impl ToTokens for EntryDsl {
             Self::Tone(x) => quote! { #x },
             Self::Chord(x) => quote! { #x },
             Self::Word(x) => quote! { #x },
+            Self::Verse(x) => quote! { ProtoEntry::from(LyricEntry::Verse(#x)) },
             Self::Pick(x) => quote! { #x },
             Self::Shape(x) => quote! { #x },
             Self::Fretboard(x) => quote! { #x },
@@ -73,6 +78,7 @@ impl EntryDsl {
             EntryDsl::Tone(x) => x.add_proto(entries),
             EntryDsl::Chord(x) => x.add_proto(entries),
             EntryDsl::Word(x) => x.add_proto(entries),
+            EntryDsl::Verse(x) => entries.push(ProtoEntry::from(LyricEntry::Verse(*x))),
             EntryDsl::Pick(x) => x.add_proto(entries),
             EntryDsl::Shape(x) => entries.push(x.to_proto()),
             EntryDsl::Fretboard(x) => entries.push(x.to_proto()),
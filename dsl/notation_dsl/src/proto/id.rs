@@ -1,16 +1,18 @@
 use fehler::{throw, throws};
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::{quote, ToTokens};
 use syn::parse::{Error, Parse, ParseStream};
 use syn::{Ident, LitStr};
 
 pub struct IdDsl {
     pub id: String,
+    pub span: Span,
 }
 
 impl Parse for IdDsl {
     #[throws(Error)]
     fn parse(input: ParseStream) -> Self {
+        let span = input.span();
         let key = if input.peek(Ident) {
             input.parse::<Ident>()?.to_string()
         } else if input.peek(LitStr) {
@@ -18,7 +20,7 @@ impl Parse for IdDsl {
         } else {
             throw!(Error::new(input.span(), "Invalid Key"))
         };
-        Self { id: key }
+        Self { id: key, span }
     }
 }
 
@@ -30,7 +32,7 @@ impl IdDsl {
 
 impl ToTokens for IdDsl {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let IdDsl { id } = self;
+        let IdDsl { id, .. } = self;
         tokens.extend(quote! {
             #id
         });
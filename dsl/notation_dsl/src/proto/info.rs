@@ -0,0 +1,170 @@
+use fehler::{throw, throws};
+
+use notation_proto::prelude::{Difficulty, TabInfo};
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::parse::{Error, Parse, ParseStream};
+use syn::{bracketed, braced, Ident, LitInt, LitStr, Token};
+
+pub struct InfoDsl {
+    pub title: Option<String>,
+    pub artist: Option<String>,
+    pub composer: Option<String>,
+    pub lyricist: Option<String>,
+    pub album: Option<String>,
+    pub year: Option<u16>,
+    pub source_url: Option<String>,
+    pub copyright: Option<String>,
+    pub difficulty: Option<Difficulty>,
+    pub tags: Vec<String>,
+}
+
+mod kw {
+    syn::custom_keyword!(title);
+    syn::custom_keyword!(artist);
+    syn::custom_keyword!(composer);
+    syn::custom_keyword!(lyricist);
+    syn::custom_keyword!(album);
+    syn::custom_keyword!(year);
+    syn::custom_keyword!(source_url);
+    syn::custom_keyword!(copyright);
+    syn::custom_keyword!(difficulty);
+    syn::custom_keyword!(tags);
+}
+
+impl InfoDsl {
+    #[throws(Error)]
+    fn parse_str(input: ParseStream) -> Option<String> {
+        input.parse::<Token![:]>()?;
+        Some(input.parse::<LitStr>()?.value())
+    }
+}
+
+impl Parse for InfoDsl {
+    #[throws(Error)]
+    fn parse(input: ParseStream) -> Self {
+        let content;
+        braced!(content in input);
+        let mut info = InfoDsl {
+            title: None,
+            artist: None,
+            composer: None,
+            lyricist: None,
+            album: None,
+            year: None,
+            source_url: None,
+            copyright: None,
+            difficulty: None,
+            tags: vec![],
+        };
+        while !content.is_empty() {
+            if content.peek(kw::title) {
+                content.parse::<kw::title>()?;
+                info.title = Self::parse_str(&content)?;
+            } else if content.peek(kw::artist) {
+                content.parse::<kw::artist>()?;
+                info.artist = Self::parse_str(&content)?;
+            } else if content.peek(kw::composer) {
+                content.parse::<kw::composer>()?;
+                info.composer = Self::parse_str(&content)?;
+            } else if content.peek(kw::lyricist) {
+                content.parse::<kw::lyricist>()?;
+                info.lyricist = Self::parse_str(&content)?;
+            } else if content.peek(kw::album) {
+                content.parse::<kw::album>()?;
+                info.album = Self::parse_str(&content)?;
+            } else if content.peek(kw::year) {
+                content.parse::<kw::year>()?;
+                content.parse::<Token![:]>()?;
+                info.year = Some(content.parse::<LitInt>()?.base10_parse::<u16>()?);
+            } else if content.peek(kw::source_url) {
+                content.parse::<kw::source_url>()?;
+                info.source_url = Self::parse_str(&content)?;
+            } else if content.peek(kw::copyright) {
+                content.parse::<kw::copyright>()?;
+                info.copyright = Self::parse_str(&content)?;
+            } else if content.peek(kw::difficulty) {
+                content.parse::<kw::difficulty>()?;
+                content.parse::<Token![:]>()?;
+                let ident = content.parse::<Ident>()?;
+                match Difficulty::from_ident(ident.to_string().as_str()) {
+                    Some(difficulty) => info.difficulty = Some(difficulty),
+                    None => throw!(Error::new(ident.span(), "Invalid Difficulty")),
+                }
+            } else if content.peek(kw::tags) {
+                content.parse::<kw::tags>()?;
+                content.parse::<Token![:]>()?;
+                let tags;
+                bracketed!(tags in content);
+                while !tags.is_empty() {
+                    info.tags.push(tags.parse::<LitStr>()?.value());
+                }
+            } else {
+                throw!(content.error("Invalid Info"));
+            }
+        }
+        info
+    }
+}
+
+impl ToTokens for InfoDsl {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let InfoDsl {
+            title,
+            artist,
+            composer,
+            lyricist,
+            album,
+            year,
+            source_url,
+            copyright,
+            difficulty,
+            tags,
+        } = self;
+        let title = title.clone().unwrap_or_default();
+        let artist = artist.clone().unwrap_or_default();
+        let composer = composer.clone().unwrap_or_default();
+        let lyricist = lyricist.clone().unwrap_or_default();
+        let album = album.clone().unwrap_or_default();
+        let source_url = source_url.clone().unwrap_or_default();
+        let copyright = copyright.clone().unwrap_or_default();
+        let year_quote = year.map(|x| quote! { Some(#x) }).unwrap_or(quote! { None });
+        let difficulty_quote = difficulty
+            .map(|x| {
+                let ident = x.to_ident();
+                quote! { Difficulty::from_ident(#ident) }
+            })
+            .unwrap_or(quote! { None });
+        tokens.extend(quote! {
+            TabInfo {
+                title: #title.to_owned(),
+                artist: #artist.to_owned(),
+                composer: #composer.to_owned(),
+                lyricist: #lyricist.to_owned(),
+                album: #album.to_owned(),
+                year: #year_quote,
+                source_url: #source_url.to_owned(),
+                copyright: #copyright.to_owned(),
+                difficulty: #difficulty_quote,
+                tags: vec![#(#tags.to_owned()),*],
+            }
+        });
+    }
+}
+
+impl InfoDsl {
+    pub fn to_proto(&self) -> TabInfo {
+        TabInfo {
+            title: self.title.clone().unwrap_or_default(),
+            artist: self.artist.clone().unwrap_or_default(),
+            composer: self.composer.clone().unwrap_or_default(),
+            lyricist: self.lyricist.clone().unwrap_or_default(),
+            album: self.album.clone().unwrap_or_default(),
+            year: self.year,
+            source_url: self.source_url.clone().unwrap_or_default(),
+            copyright: self.copyright.clone().unwrap_or_default(),
+            difficulty: self.difficulty,
+            tags: self.tags.clone(),
+        }
+    }
+}
@@ -1,16 +1,18 @@
 use fehler::{throw, throws};
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::{quote, ToTokens};
 use syn::parse::{Error, Parse, ParseStream};
 use syn::{LitStr, Token};
 
 pub struct MarkDsl {
     pub mark: String,
+    pub span: Span,
 }
 
 impl Parse for MarkDsl {
     #[throws(Error)]
     fn parse(input: ParseStream) -> Self {
+        let span = input.span();
         let mark = if input.peek(Token![|]) {
             input.parse::<Token![|]>()?;
             "|".to_owned()
@@ -19,7 +21,7 @@ impl Parse for MarkDsl {
         } else {
             throw!(Error::new(input.span(), "Invalid Mark"))
         };
-        Self { mark }
+        Self { mark, span }
     }
 }
 
@@ -31,7 +33,7 @@ impl MarkDsl {
 
 impl ToTokens for MarkDsl {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let MarkDsl { mark } = self;
+        let MarkDsl { mark, .. } = self;
         tokens.extend(quote! {
             #mark
         });
@@ -7,6 +7,7 @@ use syn::parse::{Error, Parse, ParseStream};
 use syn::Ident;
 
 use crate::context::Context;
+use crate::core::groove::GrooveDsl;
 use crate::core::signature::SignatureDsl;
 use crate::core::tempo::TempoDsl;
 
@@ -15,6 +16,7 @@ pub struct MetaDsl {
     pub scale: Ident,
     pub signature: SignatureDsl,
     pub tempo: TempoDsl,
+    pub groove: Option<GrooveDsl>,
 }
 
 impl Parse for MetaDsl {
@@ -24,11 +26,17 @@ impl Parse for MetaDsl {
         let scale = input.parse()?;
         let signature = input.parse()?;
         let tempo = input.parse()?;
+        let groove = if GrooveDsl::peek(input) {
+            Some(input.parse()?)
+        } else {
+            None
+        };
         MetaDsl {
             key,
             scale,
             signature,
             tempo,
+            groove,
         }
     }
 }
@@ -39,18 +47,27 @@ impl ToTokens for MetaDsl {
             scale,
             signature,
             tempo,
+            groove,
         } = self;
         let key_ident = key.to_string();
         let scale_ident = scale.to_string();
-        Context::set_key(Key::from_ident(key_ident.as_str()));
-        Context::set_scale(Scale::from_ident(scale_ident.as_str()));
-        tokens.extend(quote! {
+        Context::reset(
+            Key::from_ident(key_ident.as_str()),
+            Scale::from_ident(scale_ident.as_str()),
+        );
+        let meta_quote = quote! {
             TabMeta::new(
                 Key::from_ident(#key_ident),
                 Scale::from_ident(#scale_ident),
                 #signature,
                 #tempo,
             )
+        };
+        tokens.extend(match groove {
+            Some(groove) => quote! {
+                #meta_quote.with_groove(#groove)
+            },
+            None => meta_quote,
         });
     }
 }
@@ -58,8 +75,11 @@ impl MetaDsl {
     pub fn to_proto(&self) -> TabMeta {
         let key = Key::from_ident(self.key.to_string().as_str());
         let scale = Scale::from_ident(self.scale.to_string().as_str());
-        Context::set_key(key);
-        Context::set_scale(scale);
-        TabMeta::new(key, scale, self.signature.to_proto(), self.tempo.to_proto())
+        Context::reset(key, scale);
+        let meta = TabMeta::new(key, scale, self.signature.to_proto(), self.tempo.to_proto());
+        match &self.groove {
+            Some(groove) => meta.with_groove(groove.to_proto()),
+            None => meta,
+        }
     }
 }
@@ -2,6 +2,7 @@ pub mod bar;
 pub mod entry;
 pub mod form;
 pub mod id;
+pub mod info;
 pub mod layer;
 pub mod mark;
 pub mod meta;
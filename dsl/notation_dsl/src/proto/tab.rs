@@ -1,5 +1,5 @@
 use fehler::throws;
-use notation_proto::prelude::Tab;
+use notation_proto::prelude::{Tab, GUITAR_STRING_NUM};
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use syn::parse::{Error, Parse, ParseStream};
@@ -10,11 +10,13 @@ use crate::proto::form::FormDsl;
 use crate::proto::section::SectionDsl;
 use crate::proto::track::TrackDsl;
 
+use super::info::InfoDsl;
 use super::meta::MetaDsl;
 
 pub struct TabDsl {
     pub uuid: String,
     pub meta: MetaDsl,
+    pub info: Option<InfoDsl>,
     pub tracks: Vec<TrackDsl>,
     pub sections: Vec<SectionDsl>,
     pub form: FormDsl,
@@ -22,6 +24,7 @@ pub struct TabDsl {
 
 mod kw {
     syn::custom_keyword!(Meta);
+    syn::custom_keyword!(Info);
     syn::custom_keyword!(Tracks);
     syn::custom_keyword!(Sections);
     syn::custom_keyword!(Form);
@@ -36,9 +39,21 @@ impl Parse for TabDsl {
         input.parse::<Token![:]>()?;
         let meta = input.parse()?;
 
+        let info = if input.peek(kw::Info) {
+            input.parse::<kw::Info>()?;
+            input.parse::<Token![:]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
         input.parse::<kw::Tracks>()?;
         input.parse::<Token![:]>()?;
         let tracks = TrackDsl::parse_vec(input)?;
+        let mut string_num = GUITAR_STRING_NUM;
+        for track in tracks.iter() {
+            track.check_string_num(&mut string_num)?;
+        }
 
         input.parse::<kw::Sections>()?;
         input.parse::<Token![:]>()?;
@@ -51,6 +66,7 @@ impl Parse for TabDsl {
         TabDsl {
             uuid,
             meta,
+            info,
             tracks,
             sections,
             form,
@@ -63,6 +79,7 @@ impl ToTokens for TabDsl {
         let TabDsl {
             uuid,
             meta,
+            info,
             tracks,
             sections,
             form,
@@ -70,7 +87,7 @@ impl ToTokens for TabDsl {
         let mata_quote = meta.to_token_stream();
         let tracks_quote = TrackDsl::quote_vec(tracks);
         let sections_quote = SectionDsl::quote_vec(sections);
-        tokens.extend(quote! {
+        let tab_quote = quote! {
             Tab::new(
                 #uuid,
                 #mata_quote,
@@ -78,6 +95,12 @@ impl ToTokens for TabDsl {
                 #sections_quote,
                 #form
             )
+        };
+        tokens.extend(match info {
+            Some(info) => quote! {
+                #tab_quote.with_info(#info)
+            },
+            None => tab_quote,
         });
     }
 }
@@ -87,6 +110,10 @@ impl TabDsl {
         let meta = self.meta.to_proto();
         let tracks = self.tracks.iter().map(|x| x.to_proto()).collect();
         let sections = self.sections.iter().map(|x| x.to_proto()).collect();
-        Tab::new(&self.uuid, meta, tracks, sections, self.form.to_proto())
+        let tab = Tab::new(&self.uuid, meta, tracks, sections, self.form.to_proto());
+        match &self.info {
+            Some(info) => tab.with_info(info.to_proto()),
+            None => tab,
+        }
     }
 }
@@ -6,6 +6,7 @@ use quote::{quote, ToTokens};
 use syn::parse::{Error, ParseStream};
 use syn::Ident;
 
+use crate::context::ContextDsl;
 use crate::proto::entry::EntryDsl;
 
 use super::id::IdDsl;
@@ -24,6 +25,19 @@ impl TrackDsl {
         let entries = EntryDsl::parse_vec(input)?;
         TrackDsl { id, kind, entries }
     }
+    /// Fretboards and shapes are checked against the string number of the context, which is kept
+    /// from the previous tracks, same as when generating the tab.
+    #[throws(Error)]
+    pub fn check_string_num(&self, string_num: &mut usize) {
+        for entry in self.entries.iter() {
+            match entry {
+                EntryDsl::Context(ContextDsl::StringNum(x)) => *string_num = *x,
+                EntryDsl::Fretboard(x) => x.check_string_num(*string_num)?,
+                EntryDsl::Shape(x) => x.check_string_num(*string_num)?,
+                _ => (),
+            }
+        }
+    }
 }
 
 impl ToTokens for TrackDsl {
@@ -0,0 +1,473 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use notation_dsl::prelude::{parse_tab_dsl, TabDsl};
+use notation_dsl::proto::entry::EntryDsl;
+use notation_dsl::proto::slice::{SliceBeginDsl, SliceEndDsl};
+use notation_proto::prelude::{HandShape, PickNote, ProtoEntry, TabMeta};
+use proc_macro2::{Delimiter, LineColumn, Span, TokenStream, TokenTree};
+
+/// Zero-based line, the character is counted in UTF-16 code units as in LSP.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Default)]
+pub struct TextPos {
+    pub line: u32,
+    pub character: u32,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct TextRange {
+    pub start: TextPos,
+    pub end: TextPos,
+}
+
+impl TextRange {
+    pub fn contains(&self, pos: TextPos) -> bool {
+        self.start <= pos && pos <= self.end
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Clone, Debug)]
+pub struct TabDiagnostic {
+    pub range: TextRange,
+    pub severity: Severity,
+    pub message: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct IdSymbol {
+    pub id: String,
+    pub kind: String,
+    pub range: TextRange,
+}
+
+#[derive(Clone, Debug)]
+pub struct MarkSymbol {
+    pub track: String,
+    pub mark: String,
+    /// Index of the mark in the entries of the track, slices are resolved with it.
+    pub index: usize,
+    pub range: TextRange,
+}
+
+/// From a reference in `Form` or a bar layer to where it's defined.
+#[derive(Clone, Debug)]
+pub struct TabLink {
+    pub range: TextRange,
+    pub target: TextRange,
+}
+
+#[derive(Clone, Debug)]
+pub struct TabHover {
+    pub range: TextRange,
+    pub text: String,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum TabRegion {
+    Meta,
+    Info,
+    Tracks,
+    Sections,
+    Form,
+}
+
+impl TabRegion {
+    pub fn from_ident(ident: &str) -> Option<Self> {
+        match ident {
+            "Meta" => Some(Self::Meta),
+            "Info" => Some(Self::Info),
+            "Tracks" => Some(Self::Tracks),
+            "Sections" => Some(Self::Sections),
+            "Form" => Some(Self::Form),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum CompletionKind {
+    Track,
+    Section,
+    Mark,
+}
+
+#[derive(Clone, Debug)]
+pub struct TabCompletion {
+    pub kind: CompletionKind,
+    pub label: String,
+    pub insert_text: String,
+    pub detail: String,
+}
+
+/// Maps the line/column of `proc_macro2` spans to LSP positions.
+struct LineIndex<'a> {
+    lines: Vec<&'a str>,
+}
+
+impl<'a> LineIndex<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            lines: text.split('\n').collect(),
+        }
+    }
+    fn pos(&self, v: LineColumn) -> TextPos {
+        let line = v.line.saturating_sub(1);
+        let character = self
+            .lines
+            .get(line)
+            .map(|x| x.chars().take(v.column).map(char::len_utf16).sum::<usize>())
+            .unwrap_or(0);
+        TextPos {
+            line: line as u32,
+            character: character as u32,
+        }
+    }
+    fn range(&self, span: Span) -> TextRange {
+        TextRange {
+            start: self.pos(span.start()),
+            end: self.pos(span.end()),
+        }
+    }
+    fn prefix(&self, pos: TextPos) -> String {
+        let mut utf16 = 0;
+        self.lines
+            .get(pos.line as usize)
+            .map(|x| {
+                x.chars()
+                    .take_while(|c| {
+                        utf16 += c.len_utf16();
+                        utf16 <= pos.character as usize
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct TabAnalysis {
+    /// `false` if the text failed to parse, only the token based info is available then.
+    pub parsed: bool,
+    pub diagnostics: Vec<TabDiagnostic>,
+    pub tracks: Vec<IdSymbol>,
+    pub sections: Vec<IdSymbol>,
+    pub marks: Vec<MarkSymbol>,
+    pub links: Vec<TabLink>,
+    pub hovers: Vec<TabHover>,
+    /// Zero-based start and end lines of tracks and sections.
+    pub folds: Vec<(u32, u32)>,
+    pub regions: Vec<(TabRegion, TextPos)>,
+}
+
+impl TabAnalysis {
+    pub fn new(text: &str) -> Self {
+        let lines = LineIndex::new(text);
+        let mut analysis = Self::default();
+        analysis.scan_tokens(&lines, text);
+        match parse_tab_dsl(text) {
+            Ok(tab) => {
+                analysis.parsed = true;
+                analysis.check_tab(&lines, &tab);
+            }
+            Err(err) => {
+                for err in err.into_iter() {
+                    analysis.add_error(lines.range(err.span()), err.to_string());
+                }
+            }
+        }
+        // Spans are all converted to ranges by now, the server parses on every change.
+        proc_macro2::extra::invalidate_current_thread_spans();
+        analysis
+    }
+    fn add_error(&mut self, range: TextRange, message: String) {
+        self.diagnostics.push(TabDiagnostic {
+            range,
+            severity: Severity::Error,
+            message,
+        });
+    }
+    fn add_warning(&mut self, range: TextRange, message: String) {
+        self.diagnostics.push(TabDiagnostic {
+            range,
+            severity: Severity::Warning,
+            message,
+        });
+    }
+    /// Regions and folds only need the tokens, so they still work while the tab is being edited.
+    fn scan_tokens(&mut self, lines: &LineIndex, text: &str) {
+        let mut tokens: Vec<TokenTree> = match TokenStream::from_str(text) {
+            Ok(tokens) => tokens.into_iter().collect(),
+            Err(_) => return,
+        };
+        if let Some(TokenTree::Group(group)) = tokens.get(2) {
+            if matches!(tokens.first(), Some(TokenTree::Ident(x)) if x == "tab") {
+                tokens = group.stream().into_iter().collect();
+            }
+        }
+        let mut region = None;
+        for token in tokens.iter() {
+            match token {
+                TokenTree::Ident(ident) => {
+                    if let Some(x) = TabRegion::from_ident(ident.to_string().as_str()) {
+                        region = Some(x);
+                        self.regions.push((x, lines.pos(ident.span().start())));
+                    }
+                }
+                TokenTree::Group(group) if group.delimiter() == Delimiter::Bracket => {
+                    if matches!(region, Some(TabRegion::Tracks) | Some(TabRegion::Sections)) {
+                        for item in group.stream() {
+                            if let TokenTree::Group(x) = item {
+                                let range = lines.range(x.span());
+                                if range.end.line > range.start.line {
+                                    self.folds.push((range.start.line, range.end.line));
+                                }
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    fn check_tab(&mut self, lines: &LineIndex, tab: &TabDsl) {
+        let meta = tab.meta.to_proto();
+        for track in tab.tracks.iter() {
+            let range = lines.range(track.id.span);
+            if self.tracks.iter().any(|x| x.id == track.id.id) {
+                self.add_error(range, format!("Duplicated track: {}", track.id.id));
+            }
+            self.tracks.push(IdSymbol {
+                id: track.id.id.clone(),
+                kind: track.kind.to_string(),
+                range,
+            });
+            let mut entries = Vec::new();
+            for entry in track.entries.iter() {
+                let index = entries.len();
+                entry.add_proto(&mut entries);
+                match entry {
+                    EntryDsl::Mark(x) => self.marks.push(MarkSymbol {
+                        track: track.id.id.clone(),
+                        mark: x.mark.clone(),
+                        index,
+                        range: lines.range(x.span),
+                    }),
+                    EntryDsl::Pick(x) => {
+                        for (offset, pick) in x.items.iter().enumerate() {
+                            for note in pick.notes.iter() {
+                                self.check_pick_note(
+                                    &meta,
+                                    &entries,
+                                    index + offset,
+                                    note.to_proto(),
+                                    lines.range(note.span),
+                                );
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        for section in tab.sections.iter() {
+            let range = lines.range(section.id.span);
+            if self.sections.iter().any(|x| x.id == section.id.id) {
+                self.add_error(range, format!("Duplicated section: {}", section.id.id));
+            }
+            self.sections.push(IdSymbol {
+                id: section.id.id.clone(),
+                kind: section.kind.to_string(),
+                range,
+            });
+        }
+        for layer in tab.sections.iter().flat_map(|x| x.bars.iter()).flat_map(|x| x.layers.iter()) {
+            let range = lines.range(layer.track.span);
+            let track = match self.tracks.iter().find(|x| x.id == layer.track.id) {
+                Some(track) => track.clone(),
+                None => {
+                    self.add_error(range, format!("Track not found: {}", layer.track.id));
+                    continue;
+                }
+            };
+            self.links.push(TabLink {
+                range,
+                target: track.range,
+            });
+            for slice in layer.slices.iter() {
+                let begin = match &slice.begin {
+                    SliceBeginDsl::Mark(x) => match self.find_mark(&track.id, 0, &x.mark) {
+                        Some(mark) => {
+                            self.add_mark_link(lines.range(x.span), &mark);
+                            Some(mark.index + 1)
+                        }
+                        None => {
+                            let message = format!("Mark not found in {}: {}", track.id, x.mark);
+                            self.add_warning(lines.range(x.span), message);
+                            None
+                        }
+                    },
+                    SliceBeginDsl::Index(x) => Some(*x),
+                };
+                if let (Some(begin), SliceEndDsl::Mark(x)) = (begin, &slice.end) {
+                    match self.find_mark(&track.id, begin, &x.mark) {
+                        Some(mark) => self.add_mark_link(lines.range(x.span), &mark),
+                        None => {
+                            let message = format!("Mark not found in {}: {}", track.id, x.mark);
+                            self.add_warning(lines.range(x.span), message);
+                        }
+                    }
+                }
+            }
+        }
+        for section in tab.form.sections.iter() {
+            let range = lines.range(section.span);
+            match self.sections.iter().find(|x| x.id == section.id) {
+                Some(target) => {
+                    let target = target.range;
+                    self.links.push(TabLink { range, target });
+                }
+                None => self.add_error(range, format!("Section not found: {}", section.id)),
+            }
+        }
+    }
+    /// Same as `Track::index_of_mark()` in the model.
+    fn find_mark(&self, track: &str, begin: usize, mark: &str) -> Option<MarkSymbol> {
+        self.marks
+            .iter()
+            .find(|x| x.track == track && x.index >= begin && x.mark == mark)
+            .cloned()
+    }
+    fn add_mark_link(&mut self, range: TextRange, mark: &MarkSymbol) {
+        self.links.push(TabLink {
+            range,
+            target: mark.range,
+        });
+    }
+    /// Uses the first fretboard of the track and the last shape before the pick.
+    fn check_pick_note(
+        &mut self,
+        meta: &TabMeta,
+        entries: &[ProtoEntry],
+        index: usize,
+        pick_note: PickNote,
+        range: TextRange,
+    ) {
+        if !matches!(entries.get(index), Some(ProtoEntry::Fretted(_))) {
+            return;
+        }
+        let fretboard = match entries
+            .iter()
+            .find_map(|x| x.as_fretted().and_then(|x| x.as_fretboard()))
+        {
+            Some(fretboard) => fretboard,
+            None => {
+                let text = "No fretboard in the track".to_owned();
+                self.hovers.push(TabHover { range, text });
+                return;
+            }
+        };
+        let string_num = fretboard.string_num();
+        if pick_note.string == 0 || pick_note.string as usize > string_num {
+            let message = format!("String {} out of range, {} strings", pick_note.string, string_num);
+            self.add_warning(range, message);
+            return;
+        }
+        let shape = entries[..index]
+            .iter()
+            .rev()
+            .find_map(|x| x.as_fretted().and_then(|x| x.as_shape()))
+            .cloned()
+            .unwrap_or_else(|| HandShape::open(string_num));
+        let text = match fretboard.shape_pick_fret_note(&meta.scale, &meta.key, &shape, pick_note) {
+            Some((fret, note)) => format!(
+                "String {}, fret {}: **{}** ({}, {})",
+                pick_note.string,
+                fret,
+                note.pitch,
+                note.octave,
+                note.syllable.to_text(),
+            ),
+            None => format!("String {}: not fretted by the shape", pick_note.string),
+        };
+        self.hovers.push(TabHover { range, text });
+    }
+}
+
+impl TabAnalysis {
+    pub fn region_at(&self, pos: TextPos) -> Option<TabRegion> {
+        self.regions
+            .iter()
+            .rev()
+            .find(|(_, start)| *start <= pos)
+            .map(|(region, _)| *region)
+    }
+    pub fn definition(&self, pos: TextPos) -> Option<TextRange> {
+        self.links
+            .iter()
+            .find(|x| x.range.contains(pos))
+            .map(|x| x.target)
+    }
+    pub fn hover(&self, pos: TextPos) -> Option<&TabHover> {
+        self.hovers.iter().find(|x| x.range.contains(pos))
+    }
+    /// Symbols come from `symbols`, which can be an earlier analysis if the text fails to parse now.
+    pub fn completions(&self, symbols: &TabAnalysis, text: &str, pos: TextPos) -> Vec<TabCompletion> {
+        let mut result = Vec::new();
+        match self.region_at(pos) {
+            Some(TabRegion::Form) => {
+                for section in symbols.sections.iter() {
+                    result.push(TabCompletion {
+                        kind: CompletionKind::Section,
+                        label: section.id.clone(),
+                        insert_text: section.id.clone(),
+                        detail: section.kind.clone(),
+                    });
+                }
+            }
+            Some(TabRegion::Sections) => {
+                let prefix = LineIndex::new(text).prefix(pos);
+                let in_string = prefix.matches('"').count() % 2 == 1;
+                if !in_string {
+                    for track in symbols.tracks.iter() {
+                        result.push(TabCompletion {
+                            kind: CompletionKind::Track,
+                            label: track.id.clone(),
+                            insert_text: track.id.clone(),
+                            detail: track.kind.clone(),
+                        });
+                    }
+                }
+                let mut added = HashSet::new();
+                for mark in symbols.marks.iter().filter(|x| x.mark != "|") {
+                    if !added.insert(mark.mark.clone()) {
+                        continue;
+                    }
+                    let tracks: Vec<&str> = symbols
+                        .marks
+                        .iter()
+                        .filter(|x| x.mark == mark.mark)
+                        .map(|x| x.track.as_str())
+                        .collect();
+                    result.push(TabCompletion {
+                        kind: CompletionKind::Mark,
+                        label: mark.mark.clone(),
+                        insert_text: if in_string {
+                            mark.mark.clone()
+                        } else {
+                            format!("\"{}\"", mark.mark)
+                        },
+                        detail: tracks.join(", "),
+                    });
+                }
+            }
+            _ => {}
+        }
+        result
+    }
+}
@@ -0,0 +1,17 @@
+pub mod analysis;
+pub mod server;
+
+use lsp_server::Connection;
+
+use crate::server::TabServer;
+
+pub fn main() -> anyhow::Result<()> {
+    let (connection, io_threads) = Connection::stdio();
+    let capabilities = serde_json::to_value(TabServer::capabilities())?;
+    connection.initialize(capabilities)?;
+    eprintln!("notation_lsp: initialized");
+    TabServer::default().run(&connection)?;
+    drop(connection);
+    io_threads.join()?;
+    Ok(())
+}
@@ -0,0 +1,240 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+    Notification as LspNotification, PublishDiagnostics,
+};
+use lsp_types::request::{
+    Completion, FoldingRangeRequest, GotoDefinition, HoverRequest, Request as LspRequest,
+};
+use lsp_types::{
+    CompletionItem, CompletionItemKind, CompletionOptions, CompletionParams, CompletionResponse,
+    Diagnostic, DiagnosticSeverity, DidChangeTextDocumentParams, DidCloseTextDocumentParams,
+    DidOpenTextDocumentParams, FoldingRange, FoldingRangeKind, FoldingRangeParams,
+    FoldingRangeProviderCapability, GotoDefinitionParams, GotoDefinitionResponse, Hover,
+    HoverContents, HoverParams, HoverProviderCapability, Location, MarkupContent, MarkupKind,
+    OneOf, Position, PublishDiagnosticsParams, Range, ServerCapabilities,
+    TextDocumentSyncCapability, TextDocumentSyncKind, Url,
+};
+
+use crate::analysis::{CompletionKind, Severity, TabAnalysis, TextPos, TextRange};
+
+pub struct TabDocument {
+    pub text: String,
+    pub version: i32,
+    pub analysis: TabAnalysis,
+    /// Last analysis that parsed, completions still work while the text is broken.
+    pub symbols: TabAnalysis,
+}
+
+impl TabDocument {
+    pub fn new(text: String, version: i32) -> Self {
+        let analysis = TabAnalysis::new(&text);
+        Self {
+            text,
+            version,
+            symbols: analysis.clone(),
+            analysis,
+        }
+    }
+    pub fn update(&mut self, text: String, version: i32) {
+        self.analysis = TabAnalysis::new(&text);
+        if self.analysis.parsed {
+            self.symbols = self.analysis.clone();
+        }
+        self.text = text;
+        self.version = version;
+    }
+}
+
+#[derive(Default)]
+pub struct TabServer {
+    pub documents: HashMap<Url, TabDocument>,
+}
+
+impl TabServer {
+    pub fn capabilities() -> ServerCapabilities {
+        ServerCapabilities {
+            text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+            completion_provider: Some(CompletionOptions {
+                trigger_characters: Some(vec!["\"".to_owned()]),
+                ..Default::default()
+            }),
+            hover_provider: Some(HoverProviderCapability::Simple(true)),
+            definition_provider: Some(OneOf::Left(true)),
+            folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
+            ..Default::default()
+        }
+    }
+    pub fn run(&mut self, connection: &Connection) -> Result<()> {
+        for msg in &connection.receiver {
+            match msg {
+                Message::Request(req) => {
+                    if connection.handle_shutdown(&req)? {
+                        return Ok(());
+                    }
+                    let response = self.on_request(req);
+                    connection.sender.send(Message::Response(response))?;
+                }
+                Message::Notification(not) => {
+                    if let Some(diagnostics) = self.on_notification(not) {
+                        let not = Notification::new(PublishDiagnostics::METHOD.to_owned(), diagnostics);
+                        connection.sender.send(Message::Notification(not))?;
+                    }
+                }
+                Message::Response(_) => {}
+            }
+        }
+        Ok(())
+    }
+    fn on_request(&self, req: Request) -> Response {
+        let id = req.id.clone();
+        let result = match req.method.as_str() {
+            Completion::METHOD => serde_json::from_value(req.params)
+                .map_err(anyhow::Error::from)
+                .and_then(|x| Ok(serde_json::to_value(self.completion(x))?)),
+            HoverRequest::METHOD => serde_json::from_value(req.params)
+                .map_err(anyhow::Error::from)
+                .and_then(|x| Ok(serde_json::to_value(self.hover(x))?)),
+            GotoDefinition::METHOD => serde_json::from_value(req.params)
+                .map_err(anyhow::Error::from)
+                .and_then(|x| Ok(serde_json::to_value(self.definition(x))?)),
+            FoldingRangeRequest::METHOD => serde_json::from_value(req.params)
+                .map_err(anyhow::Error::from)
+                .and_then(|x| Ok(serde_json::to_value(self.folding_range(x))?)),
+            _ => {
+                let message = format!("Unsupported request: {}", req.method);
+                return Response::new_err(id, ErrorCode::MethodNotFound as i32, message);
+            }
+        };
+        match result {
+            Ok(result) => Response::new_ok(id, result),
+            Err(err) => Response::new_err(id, ErrorCode::InvalidParams as i32, err.to_string()),
+        }
+    }
+    /// Returns the diagnostics to publish if a document is changed.
+    fn on_notification(&mut self, not: Notification) -> Option<PublishDiagnosticsParams> {
+        match not.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params: DidOpenTextDocumentParams = serde_json::from_value(not.params).ok()?;
+                let doc = params.text_document;
+                self.documents
+                    .insert(doc.uri.clone(), TabDocument::new(doc.text, doc.version));
+                self.diagnostics(doc.uri)
+            }
+            DidChangeTextDocument::METHOD => {
+                let params: DidChangeTextDocumentParams =
+                    serde_json::from_value(not.params).ok()?;
+                let uri = params.text_document.uri;
+                // Synced with full text, the last change got the latest content.
+                let text = params.content_changes.into_iter().last()?.text;
+                let version = params.text_document.version;
+                match self.documents.get_mut(&uri) {
+                    Some(doc) => doc.update(text, version),
+                    None => {
+                        self.documents.insert(uri.clone(), TabDocument::new(text, version));
+                    }
+                }
+                self.diagnostics(uri)
+            }
+            DidCloseTextDocument::METHOD => {
+                let params: DidCloseTextDocumentParams = serde_json::from_value(not.params).ok()?;
+                let uri = params.text_document.uri;
+                self.documents.remove(&uri);
+                Some(PublishDiagnosticsParams::new(uri, vec![], None))
+            }
+            _ => None,
+        }
+    }
+    fn diagnostics(&self, uri: Url) -> Option<PublishDiagnosticsParams> {
+        let doc = self.documents.get(&uri)?;
+        let diagnostics = doc
+            .analysis
+            .diagnostics
+            .iter()
+            .map(|x| Diagnostic {
+                range: to_range(x.range),
+                severity: Some(match x.severity {
+                    Severity::Error => DiagnosticSeverity::ERROR,
+                    Severity::Warning => DiagnosticSeverity::WARNING,
+                }),
+                source: Some("notation".to_owned()),
+                message: x.message.clone(),
+                ..Default::default()
+            })
+            .collect();
+        Some(PublishDiagnosticsParams::new(uri, diagnostics, Some(doc.version)))
+    }
+    fn completion(&self, params: CompletionParams) -> Option<CompletionResponse> {
+        let position = params.text_document_position;
+        let doc = self.documents.get(&position.text_document.uri)?;
+        let items = doc
+            .analysis
+            .completions(&doc.symbols, &doc.text, to_pos(position.position))
+            .into_iter()
+            .map(|x| CompletionItem {
+                label: x.label,
+                kind: Some(match x.kind {
+                    CompletionKind::Track => CompletionItemKind::VARIABLE,
+                    CompletionKind::Section => CompletionItemKind::MODULE,
+                    CompletionKind::Mark => CompletionItemKind::REFERENCE,
+                }),
+                detail: Some(x.detail),
+                insert_text: Some(x.insert_text),
+                ..Default::default()
+            })
+            .collect();
+        Some(CompletionResponse::Array(items))
+    }
+    fn hover(&self, params: HoverParams) -> Option<Hover> {
+        let position = params.text_document_position_params;
+        let doc = self.documents.get(&position.text_document.uri)?;
+        let hover = doc.analysis.hover(to_pos(position.position))?;
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: hover.text.clone(),
+            }),
+            range: Some(to_range(hover.range)),
+        })
+    }
+    fn definition(&self, params: GotoDefinitionParams) -> Option<GotoDefinitionResponse> {
+        let position = params.text_document_position_params;
+        let uri = position.text_document.uri;
+        let doc = self.documents.get(&uri)?;
+        let target = doc.analysis.definition(to_pos(position.position))?;
+        Some(GotoDefinitionResponse::Scalar(Location::new(uri, to_range(target))))
+    }
+    fn folding_range(&self, params: FoldingRangeParams) -> Option<Vec<FoldingRange>> {
+        let doc = self.documents.get(&params.text_document.uri)?;
+        let ranges = doc
+            .analysis
+            .folds
+            .iter()
+            .map(|(start_line, end_line)| FoldingRange {
+                start_line: *start_line,
+                end_line: *end_line,
+                kind: Some(FoldingRangeKind::Region),
+                ..Default::default()
+            })
+            .collect();
+        Some(ranges)
+    }
+}
+
+fn to_pos(v: Position) -> TextPos {
+    TextPos {
+        line: v.line,
+        character: v.character,
+    }
+}
+
+fn to_position(v: TextPos) -> Position {
+    Position::new(v.line, v.character)
+}
+
+fn to_range(v: TextRange) -> Range {
+    Range::new(to_position(v.start), to_position(v.end))
+}
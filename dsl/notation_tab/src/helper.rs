@@ -1,15 +1,12 @@
 use std::fs::File;
 use std::io::Write;
 
-use ron::ser::{to_string_pretty, PrettyConfig};
-
+use notation_dsl::prelude::format_tab;
 use notation_proto::prelude::*;
 
 pub fn convert_tab(tab: &Tab) -> String {
-    let pretty = PrettyConfig::new()
-        .separate_tuple_members(true)
-        .enumerate_arrays(true);
-    to_string_pretty(&tab, pretty).expect("Serialization failed")
+    let bytes = TabFile::encode(TabEncoding::Ron, tab).expect("Serialization failed");
+    String::from_utf8(bytes).expect("Serialization failed")
 }
 
 pub fn print_tab(tab: &Tab) {
@@ -17,9 +14,19 @@ pub fn print_tab(tab: &Tab) {
     println!("{}", s);
 }
 
-pub fn write_tab(tab: &Tab, path: &'static str) {
-    let s = convert_tab(tab);
+/// The encoding is selected by the extension of the path.
+pub fn write_tab(tab: &Tab, path: &str) -> anyhow::Result<()> {
+    let encoding = TabEncoding::from_path(path)?;
+    let bytes = TabFile::encode(encoding, tab)?;
+    let mut file = File::create(path)?;
+    file.write_all(&bytes)?;
+    println!("Tab written to: `{}` [{}] - {}", path, bytes.len(), tab);
+    Ok(())
+}
+
+pub fn write_tab_dsl(tab: &Tab, path: &str) {
+    let s = format_tab(tab);
     let mut file = File::create(path).unwrap();
     file.write_all(s.as_bytes()).unwrap();
-    println!("Tab written to: `{}` [{}] - {}", path, s.len(), tab);
+    println!("Tab DSL written to: `{}` [{}] - {}", path, s.len(), tab);
 }
@@ -0,0 +1,445 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::{Chord, Interval, PitchSign, Scale, Semitones};
+
+// https://en.wikipedia.org/wiki/Roman_numeral_analysis
+// https://en.wikipedia.org/wiki/Nashville_Number_System
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub enum ChordFunction {
+    Diatonic,
+    /// Dominant of the scale degree, e.g. `V7/V`.
+    SecondaryDominant(usize),
+    /// Diatonic in the parallel major or minor.
+    Borrowed(Scale),
+    Chromatic,
+}
+impl Display for ChordFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChordFunction::SecondaryDominant(degree) => {
+                write!(f, "SecondaryDominant({})", degree + 1)
+            }
+            ChordFunction::Borrowed(scale) => write!(f, "Borrowed({})", scale),
+            _ => write!(f, "{:?}", self),
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum Triad {
+    Major,
+    Minor,
+    Diminished,
+    Augmented,
+    Suspended2nd,
+    Suspended4th,
+    Power,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum Seventh {
+    None,
+    Minor,
+    Major,
+    Diminished,
+    AddedSixth,
+}
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct ChordAnalysis {
+    /// Roman numeral, e.g. `ii7`, `V7/V`, `bVII`.
+    pub roman: String,
+    /// Nashville number, e.g. `2m7`, `4/5`.
+    pub nashville: String,
+    pub function: ChordFunction,
+}
+impl Display for ChordAnalysis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.roman, self.nashville, self.function)
+    }
+}
+
+impl ChordAnalysis {
+    pub const NUMERALS: [&'static str; 7] = ["I", "II", "III", "IV", "V", "VI", "VII"];
+
+    pub fn text(&self, nashville: bool) -> &str {
+        if nashville {
+            self.nashville.as_str()
+        } else {
+            self.roman.as_str()
+        }
+    }
+
+    fn wrap(semitones: i8) -> i8 {
+        semitones.rem_euclid(12)
+    }
+    /// Semitones of each degree of the scale, counted from its root.
+    pub fn calc_degrees(scale: &Scale) -> Vec<i8> {
+        let syllables = scale.get_syllables();
+        let root = Semitones::from(syllables[0]).0;
+        syllables
+            .iter()
+            .map(|x| Self::wrap(Semitones::from(*x).0 - root))
+            .collect()
+    }
+    /// Non-diatonic roots are written as the flat of the degree above, unless the degree
+    /// below is lowered from the major scale, then it's raised back with a sharp.
+    fn calc_degree(degrees: &[i8], semitones: i8) -> (Option<PitchSign>, usize) {
+        if let Some(degree) = degrees.iter().position(|x| *x == semitones) {
+            return (None, degree);
+        }
+        let major = Self::calc_degrees(&Scale::Ionian);
+        let below = degrees.iter().position(|x| *x == Self::wrap(semitones - 1));
+        let above = degrees.iter().position(|x| *x == Self::wrap(semitones + 1));
+        match (below, above) {
+            (Some(below), _) if degrees[below] < major[below] => (Some(PitchSign::Sharp), below),
+            (_, Some(above)) => (Some(PitchSign::Flat), above),
+            (below, None) => (Some(PitchSign::Sharp), below.unwrap_or(0)),
+        }
+    }
+    fn sign_text(sign: Option<PitchSign>) -> &'static str {
+        match sign {
+            Some(PitchSign::Flat) => "b",
+            Some(PitchSign::Sharp) => "#",
+            _ => "",
+        }
+    }
+    fn calc_triad(intervals: &[Interval]) -> Triad {
+        let has = |interval: Interval| intervals.contains(&interval);
+        if has(Interval::Minor3nd) && has(Interval::Diminished5th) {
+            Triad::Diminished
+        } else if has(Interval::Major3nd) && has(Interval::Augmented5th) {
+            Triad::Augmented
+        } else if has(Interval::Minor3nd) {
+            Triad::Minor
+        } else if has(Interval::Major3nd) {
+            Triad::Major
+        } else if has(Interval::Perfect4th) {
+            Triad::Suspended4th
+        } else if has(Interval::Major2nd) {
+            Triad::Suspended2nd
+        } else {
+            Triad::Power
+        }
+    }
+    fn calc_seventh(intervals: &[Interval]) -> Seventh {
+        let has = |interval: Interval| intervals.contains(&interval);
+        if has(Interval::Minor7th) {
+            Seventh::Minor
+        } else if has(Interval::Major7th) {
+            Seventh::Major
+        } else if has(Interval::Diminished7th) {
+            Seventh::Diminished
+        } else if has(Interval::Major6th) {
+            Seventh::AddedSixth
+        } else {
+            Seventh::None
+        }
+    }
+    /// Figured bass of inversions, other bass notes are not written.
+    fn calc_figures(bass: Option<Interval>, seventh: Seventh) -> Option<&'static str> {
+        let bass = Semitones::from(bass?).0;
+        let has_seventh = matches!(
+            seventh,
+            Seventh::Minor | Seventh::Major | Seventh::Diminished
+        );
+        match (bass, has_seventh) {
+            (3..=4, false) => Some("6"),
+            (6..=8, false) => Some("64"),
+            (3..=4, true) => Some("65"),
+            (6..=8, true) => Some("43"),
+            (9..=11, true) => Some("42"),
+            _ => None,
+        }
+    }
+    fn calc_roman_numeral(
+        sign: Option<PitchSign>,
+        degree: usize,
+        triad: Triad,
+        seventh: Seventh,
+        figures: Option<&str>,
+    ) -> String {
+        let numeral = Self::NUMERALS[degree];
+        let numeral = match triad {
+            Triad::Minor | Triad::Diminished => numeral.to_lowercase(),
+            _ => numeral.to_owned(),
+        };
+        let quality = match (triad, seventh) {
+            (Triad::Diminished, Seventh::Minor) => "ø",
+            (Triad::Diminished, _) => "°",
+            (Triad::Augmented, _) => "+",
+            (Triad::Suspended2nd, _) => "sus2",
+            (Triad::Suspended4th, _) => "sus4",
+            (Triad::Power, _) => "5",
+            _ => "",
+        };
+        let seventh = match (seventh, figures) {
+            (Seventh::Major, Some(figures)) => format!("maj{}", figures),
+            (_, Some(figures)) => figures.to_owned(),
+            (Seventh::Major, None) => "maj7".to_owned(),
+            (Seventh::None, None) => "".to_owned(),
+            (Seventh::AddedSixth, None) => "6".to_owned(),
+            (_, None) => "7".to_owned(),
+        };
+        format!("{}{}{}{}", Self::sign_text(sign), numeral, quality, seventh)
+    }
+    fn calc_nashville_number(
+        sign: Option<PitchSign>,
+        degree: usize,
+        triad: Triad,
+        seventh: Seventh,
+    ) -> String {
+        let quality = match (triad, seventh) {
+            (Triad::Diminished, Seventh::Minor) => "m7b5",
+            (Triad::Diminished, Seventh::Diminished) => "°7",
+            (Triad::Diminished, _) => "°",
+            (Triad::Augmented, _) => "+",
+            (Triad::Minor, _) => "m",
+            (Triad::Suspended2nd, _) => "sus2",
+            (Triad::Suspended4th, _) => "sus4",
+            (Triad::Power, _) => "5",
+            _ => "",
+        };
+        let seventh = match (triad, seventh) {
+            (Triad::Diminished, Seventh::Minor | Seventh::Diminished) => "",
+            (_, Seventh::Minor) => "7",
+            (_, Seventh::Major) => "maj7",
+            (_, Seventh::Diminished) => "7",
+            (_, Seventh::AddedSixth) => "6",
+            (_, Seventh::None) => "",
+        };
+        format!(
+            "{}{}{}{}",
+            Self::sign_text(sign),
+            degree + 1,
+            quality,
+            seventh
+        )
+    }
+    /// A major triad or dominant seventh resolving a fifth down to a degree other than the
+    /// tonic, which is not diminished.
+    fn calc_secondary_target(
+        degrees: &[i8],
+        root: i8,
+        triad: Triad,
+        seventh: Seventh,
+    ) -> Option<usize> {
+        if triad != Triad::Major || !matches!(seventh, Seventh::None | Seventh::Minor) {
+            return None;
+        }
+        let target = degrees.iter().position(|x| *x == Self::wrap(root + 5))?;
+        let fifth = Self::wrap(degrees[(target + 4) % 7] - degrees[target]);
+        if target == 0 || fifth != 7 {
+            None
+        } else {
+            Some(target)
+        }
+    }
+    pub fn new(chord: &Chord, scale: &Scale) -> Self {
+        let degrees = Self::calc_degrees(scale);
+        let root = Self::wrap(
+            Semitones::from(chord.root).0 - Semitones::from(scale.calc_root_syllable()).0,
+        );
+        let intervals = chord.intervals.get_intervals();
+        let triad = Self::calc_triad(&intervals);
+        let seventh = Self::calc_seventh(&intervals);
+        let figures = Self::calc_figures(chord.bass, seventh);
+        let tones: Vec<i8> = intervals
+            .iter()
+            .map(|x| Self::wrap(root + Semitones::from(*x).0))
+            .chain(std::iter::once(root))
+            .collect();
+        let (sign, degree) = Self::calc_degree(&degrees, root);
+        let mut nashville = Self::calc_nashville_number(sign, degree, triad, seventh);
+        if let Some(bass) = chord.bass {
+            let (bass_sign, bass_degree) =
+                Self::calc_degree(&degrees, Self::wrap(root + Semitones::from(bass).0));
+            nashville = format!(
+                "{}/{}{}",
+                nashville,
+                Self::sign_text(bass_sign),
+                bass_degree + 1
+            );
+        }
+        let roman = Self::calc_roman_numeral(sign, degree, triad, seventh, figures);
+        if tones.iter().all(|x| degrees.contains(x)) {
+            return Self {
+                roman,
+                nashville,
+                function: ChordFunction::Diatonic,
+            };
+        }
+        if let Some(target) = Self::calc_secondary_target(&degrees, root, triad, seventh) {
+            let third = Self::wrap(degrees[(target + 2) % 7] - degrees[target]);
+            let target_numeral = if third == 4 {
+                Self::NUMERALS[target].to_owned()
+            } else {
+                Self::NUMERALS[target].to_lowercase()
+            };
+            let dominant = Self::calc_roman_numeral(None, 4, triad, seventh, figures);
+            return Self {
+                roman: format!("{}/{}", dominant, target_numeral),
+                nashville,
+                function: ChordFunction::SecondaryDominant(target),
+            };
+        }
+        let parallel = if degrees[2] == 4 {
+            Scale::Aeolian
+        } else {
+            Scale::Ionian
+        };
+        let parallel_degrees = Self::calc_degrees(&parallel);
+        let function = if tones.iter().all(|x| parallel_degrees.contains(x)) {
+            ChordFunction::Borrowed(parallel)
+        } else {
+            ChordFunction::Chromatic
+        };
+        Self {
+            roman,
+            nashville,
+            function,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::{
+        Chord, ChordAnalysis, ChordFunction, Interval, Intervals, Scale, Syllable,
+    };
+
+    fn analyze(
+        scale: Scale,
+        root: Syllable,
+        intervals: &[Interval],
+        bass: Option<Interval>,
+    ) -> (String, String, ChordFunction) {
+        let chord = Chord::new(root, Intervals::new(intervals.iter().copied()), bass);
+        let analysis = ChordAnalysis::new(&chord, &scale);
+        (analysis.roman, analysis.nashville, analysis.function)
+    }
+
+    fn assert_analysis(
+        actual: (String, String, ChordFunction),
+        roman: &str,
+        nashville: &str,
+        function: ChordFunction,
+    ) {
+        assert_eq!(actual, (roman.to_owned(), nashville.to_owned(), function));
+    }
+
+    const MAJOR: [Interval; 2] = [Interval::Major3nd, Interval::Perfect5th];
+    const DOMINANT_7TH: [Interval; 3] =
+        [Interval::Major3nd, Interval::Perfect5th, Interval::Minor7th];
+
+    #[test]
+    fn test_diatonic() {
+        use ChordFunction::Diatonic;
+        assert_analysis(
+            analyze(Scale::Ionian, Syllable::Do, &MAJOR, None),
+            "I",
+            "1",
+            Diatonic,
+        );
+        assert_analysis(
+            analyze(
+                Scale::Ionian,
+                Syllable::Re,
+                &[Interval::Minor3nd, Interval::Perfect5th, Interval::Minor7th],
+                None,
+            ),
+            "ii7",
+            "2m7",
+            Diatonic,
+        );
+        assert_analysis(
+            analyze(
+                Scale::Ionian,
+                Syllable::Ti,
+                &[
+                    Interval::Minor3nd,
+                    Interval::Diminished5th,
+                    Interval::Minor7th,
+                ],
+                None,
+            ),
+            "viiø7",
+            "7m7b5",
+            Diatonic,
+        );
+        assert_analysis(
+            analyze(Scale::Aeolian, Syllable::So, &MAJOR, None),
+            "VII",
+            "7",
+            Diatonic,
+        );
+    }
+
+    #[test]
+    fn test_inversions() {
+        use ChordFunction::Diatonic;
+        assert_analysis(
+            analyze(
+                Scale::Ionian,
+                Syllable::Do,
+                &MAJOR,
+                Some(Interval::Major3nd),
+            ),
+            "I6",
+            "1/3",
+            Diatonic,
+        );
+        assert_analysis(
+            analyze(
+                Scale::Ionian,
+                Syllable::So,
+                &DOMINANT_7TH,
+                Some(Interval::Major3nd),
+            ),
+            "V65",
+            "57/7",
+            Diatonic,
+        );
+        assert_analysis(
+            analyze(
+                Scale::Ionian,
+                Syllable::So,
+                &DOMINANT_7TH,
+                Some(Interval::Minor7th),
+            ),
+            "V42",
+            "57/4",
+            Diatonic,
+        );
+    }
+
+    #[test]
+    fn test_non_diatonic() {
+        assert_analysis(
+            analyze(Scale::Ionian, Syllable::Re, &DOMINANT_7TH, None),
+            "V7/V",
+            "27",
+            ChordFunction::SecondaryDominant(4),
+        );
+        assert_analysis(
+            analyze(Scale::Ionian, Syllable::Te, &MAJOR, None),
+            "bVII",
+            "b7",
+            ChordFunction::Borrowed(Scale::Aeolian),
+        );
+        assert_analysis(
+            analyze(Scale::Aeolian, Syllable::Mi, &MAJOR, None),
+            "V",
+            "5",
+            ChordFunction::Borrowed(Scale::Ionian),
+        );
+        assert_analysis(
+            analyze(Scale::Ionian, Syllable::Fi, &MAJOR, None),
+            "bV",
+            "b5",
+            ChordFunction::Chromatic,
+        );
+    }
+}
@@ -4,7 +4,7 @@ use std::hash::Hash;
 use serde::{Deserialize, Serialize};
 
 use crate::interval::Interval;
-use crate::prelude::{Intervals, Semitones, Syllable};
+use crate::prelude::{Intervals, Key, Scale, Semitones, Syllable};
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Debug)]
 pub struct Chord {
@@ -45,6 +45,44 @@ impl Chord {
         }
         None
     }
+    /// Quality part of the chord name, e.g. "m7", bass is not included.
+    pub fn calc_quality(&self) -> String {
+        let intervals = self.intervals.get_intervals();
+        let has = |interval: Interval| intervals.contains(&interval);
+        let seventh = if has(Interval::Diminished7th) || has(Interval::Minor7th) {
+            "7"
+        } else if has(Interval::Major7th) {
+            "maj7"
+        } else if has(Interval::Major6th) {
+            "6"
+        } else {
+            ""
+        };
+        if has(Interval::Minor3nd) && has(Interval::Diminished5th) {
+            match has(Interval::Minor7th) {
+                true => "m7b5".to_owned(),
+                false => format!("dim{}", seventh),
+            }
+        } else if has(Interval::Major3nd) && has(Interval::Augmented5th) {
+            format!("aug{}", seventh)
+        } else if has(Interval::Minor3nd) {
+            format!("m{}", seventh)
+        } else if has(Interval::Major3nd) {
+            seventh.to_owned()
+        } else if has(Interval::Perfect4th) {
+            format!("{}sus4", seventh)
+        } else if has(Interval::Major2nd) {
+            format!("{}sus2", seventh)
+        } else if intervals == vec![Interval::Perfect5th] {
+            "5".to_owned()
+        } else {
+            seventh.to_owned()
+        }
+    }
+    /// Name of the chord with the root pitch in the key, e.g. "Am7".
+    pub fn calc_name(&self, scale: &Scale, key: &Key) -> String {
+        format!("{}{}", scale.calc_pitch(key, &self.root), self.calc_quality())
+    }
 }
 impl Hash for Chord {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
@@ -4,12 +4,14 @@ use serde::{Deserialize, Serialize};
 
 use crate::prelude::{Chord, Duration, Entry, EntryPassMode, Tone};
 
-#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub enum CoreEntry {
     Tie,
     Rest(Duration),
     Tone(Tone, Duration),
     Chord(Chord, Duration),
+    /// Holds the previous entry longer than its duration when playing, takes no bar time.
+    Fermata,
 }
 impl Display for CoreEntry {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -18,6 +20,7 @@ impl Display for CoreEntry {
             CoreEntry::Rest(duration) => write!(f, "Rest({})", duration),
             CoreEntry::Tone(tone, duration) => write!(f, "Tone({}, {})", tone, duration),
             CoreEntry::Chord(chord, duration) => write!(f, "Chord({}, {})", chord, duration),
+            CoreEntry::Fermata => write!(f, "Fermata()"),
         }
     }
 }
@@ -28,6 +31,7 @@ impl CoreEntry {
             CoreEntry::Rest(duration) => *duration,
             CoreEntry::Tone(_, duration) => *duration,
             CoreEntry::Chord(_, duration) => *duration,
+            CoreEntry::Fermata => Duration::Zero,
         }
     }
     pub fn pass_mode(&self) -> EntryPassMode {
@@ -68,6 +72,11 @@ impl CoreEntry {
     pub fn is_chord(&self) -> bool {
         matches!(self, Self::Chord(..))
     }
+
+    /// Returns `true` if the entry is [`Fermata`].
+    pub fn is_fermata(&self) -> bool {
+        matches!(self, Self::Fermata)
+    }
 }
 
 impl CoreEntry {
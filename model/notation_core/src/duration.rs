@@ -1,8 +1,9 @@
 use std::cmp::Ordering;
 use std::fmt::Display;
-use std::ops::{Add, Sub};
+use std::ops::{Add, Div, Mul, Neg, Sub};
 
-use serde::{Deserialize, Serialize};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize};
 
 #[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub enum Unit {
@@ -12,6 +13,7 @@ pub enum Unit {
     Eighth,
     Sixteenth,
     ThirtySecondth,
+    SixtyFourth,
 }
 
 impl Default for Unit {
@@ -29,6 +31,7 @@ impl Display for Unit {
             Unit::Eighth => "_1_8",
             Unit::Sixteenth => "_1_16",
             Unit::ThirtySecondth => "_1_32",
+            Unit::SixtyFourth => "_1_64",
         })
     }
 }
@@ -41,7 +44,8 @@ impl Unit {
             Self::Quarter => Self::Eighth,
             Self::Eighth => Self::Sixteenth,
             Self::Sixteenth => Self::ThirtySecondth,
-            Self::ThirtySecondth => Self::ThirtySecondth,
+            Self::ThirtySecondth => Self::SixtyFourth,
+            Self::SixtyFourth => Self::SixtyFourth,
         }
     }
     pub fn doubled(&self) -> Self {
@@ -52,6 +56,7 @@ impl Unit {
             Self::Eighth => Self::Quarter,
             Self::Sixteenth => Self::Eighth,
             Self::ThirtySecondth => Self::Sixteenth,
+            Self::SixtyFourth => Self::ThirtySecondth,
         }
     }
 }
@@ -63,42 +68,144 @@ impl Unit {
     pub const _1_8: Self = Unit::Eighth;
     pub const _1_16: Self = Unit::Sixteenth;
     pub const _1_32: Self = Unit::ThirtySecondth;
+    pub const _1_64: Self = Unit::SixtyFourth;
     pub fn to_ident(&self) -> String {
         format!("{}", self)
     }
-    pub fn from_ident(ident: &str) -> Self {
+    pub fn try_from_ident(ident: &str) -> Option<Self> {
         match ident {
-            "_1" => Self::_1,
-            "_1_2" => Self::_1_2,
-            "_1_4" => Self::_1_4,
-            "_1_8" => Self::_1_8,
-            "_1_16" => Self::_1_16,
-            "_1_32" => Self::_1_32,
-            _ => Self::_1_4,
+            "_1" => Some(Self::_1),
+            "_1_2" => Some(Self::_1_2),
+            "_1_4" => Some(Self::_1_4),
+            "_1_8" => Some(Self::_1_8),
+            "_1_16" => Some(Self::_1_16),
+            "_1_32" => Some(Self::_1_32),
+            "_1_64" => Some(Self::_1_64),
+            _ => None,
         }
     }
+    pub fn from_ident(ident: &str) -> Self {
+        Self::try_from_ident(ident).unwrap_or(Self::_1_4)
+    }
 }
 
-#[derive(Copy, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Debug, Default)]
-pub struct Units(pub f32);
+/// Exact time in whole notes, kept as a reduced fraction so that positions can be
+/// compared without accuracy fudging, float is only used at the layout and seconds boundary.
+///
+/// Serialized as `[num, den]`, floats written by older versions are still accepted.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Debug)]
+#[serde(try_from = "UnitsData", into = "(i64, i64)")]
+pub struct Units {
+    num: i64,
+    den: i64,
+}
 
 impl Units {
-    pub const _MIN_ACCURACY: f32 = 0.00001;
-    pub const MIN_ACCURACY: Units = Units(Self::_MIN_ACCURACY);
-    /*
-     * This is quite messy here, the pasition passed checking needs some cleanup,
-     * mainly for control the guitar view timing, and seeking next note
-     */
-    pub const HALF_MIN_ACCURACY: Units = Units(Self::_MIN_ACCURACY * 0.5);
+    pub const ZERO: Units = Units { num: 0, den: 1 };
+    pub const ONE: Units = Units { num: 1, den: 1 };
+    pub const MAX: Units = Units { num: i64::MAX, den: 1 };
+    /// Smallest step of float conversion, for ordering things at the same position.
+    pub const EPSILON: Units = Units {
+        num: 1,
+        den: Self::FLOAT_DENOM,
+    };
+    /// Used when converting from float, covers all the tuplets up to 7 and 1/1024 of a whole.
+    pub const FLOAT_DENOM: i64 = 1024 * 9 * 5 * 7;
 
-    pub fn is_bigger_than(&self, other: &Units) -> bool {
-        self.0 > other.0 + Self::_MIN_ACCURACY
+    /// The denominator must not be zero, same as dividing by zero, it's a bug of the caller,
+    /// so it only panics in debug builds, and gives zero otherwise.
+    pub fn new(num: i64, den: i64) -> Self {
+        Self::reduced(num as i128, den as i128)
+    }
+    pub fn try_new(num: i64, den: i64) -> Option<Self> {
+        if den == 0 {
+            None
+        } else {
+            Some(Self::new(num, den))
+        }
+    }
+    fn reduced(num: i128, den: i128) -> Self {
+        debug_assert!(den != 0, "Units with zero denominator: {}/{}", num, den);
+        if den == 0 {
+            return Self::ZERO;
+        }
+        let (num, den) = if den < 0 { (-num, -den) } else { (num, den) };
+        let gcd = Self::gcd(num.abs(), den).max(1);
+        match (i64::try_from(num / gcd), i64::try_from(den / gcd)) {
+            (Ok(num), Ok(den)) => Self { num, den },
+            _ => Self::from_f64(num as f64 / den as f64),
+        }
+    }
+    /// Rounded to `FLOAT_DENOM`, values too large for it are rounded to integers, and
+    /// saturated at `MAX` and `-MAX`.
+    pub fn from_f64(v: f64) -> Self {
+        if !v.is_finite() {
+            return Self::ZERO;
+        }
+        let scaled = v * Self::FLOAT_DENOM as f64;
+        if scaled.abs() < i64::MAX as f64 {
+            Self::new(scaled.round() as i64, Self::FLOAT_DENOM)
+        } else if v.abs() < i64::MAX as f64 {
+            Self::new(v.round() as i64, 1)
+        } else if v > 0.0 {
+            Self::MAX
+        } else {
+            -Self::MAX
+        }
+    }
+    fn gcd(a: i128, b: i128) -> i128 {
+        if b == 0 {
+            a
+        } else {
+            Self::gcd(b, a % b)
+        }
+    }
+    pub fn numer(&self) -> i64 {
+        self.num
+    }
+    pub fn denom(&self) -> i64 {
+        self.den
+    }
+    pub fn is_zero(&self) -> bool {
+        self.num == 0
+    }
+    pub fn to_f32(&self) -> f32 {
+        (self.num as f64 / self.den as f64) as f32
+    }
+    pub fn to_f64(&self) -> f64 {
+        self.num as f64 / self.den as f64
+    }
+    /// Largest integer not bigger than the value.
+    pub fn floor(&self) -> i64 {
+        self.num.div_euclid(self.den)
+    }
+}
+
+impl Default for Units {
+    fn default() -> Self {
+        Self::ZERO
     }
 }
 
 impl Display for Units {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "<Units>({})", self.0)
+        if self.den == 1 {
+            write!(f, "<Units>({})", self.num)
+        } else {
+            write!(f, "<Units>({}/{})", self.num, self.den)
+        }
+    }
+}
+
+impl PartialOrd for Units {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Units {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.num as i128 * other.den as i128).cmp(&(other.num as i128 * self.den as i128))
     }
 }
 
@@ -106,7 +213,10 @@ impl Add for Units {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        Units(self.0 + rhs.0)
+        Self::reduced(
+            self.num as i128 * rhs.den as i128 + rhs.num as i128 * self.den as i128,
+            self.den as i128 * rhs.den as i128,
+        )
     }
 }
 
@@ -114,19 +224,76 @@ impl Sub for Units {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        Units(self.0 - rhs.0)
+        Self::reduced(
+            self.num as i128 * rhs.den as i128 - rhs.num as i128 * self.den as i128,
+            self.den as i128 * rhs.den as i128,
+        )
     }
 }
 
-impl Units {
-    pub fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        if self.0 == other.0 {
-            Ordering::Equal
-        } else if self.0 < other.0 {
-            Ordering::Less
-        } else {
-            Ordering::Greater
-        }
+impl Neg for Units {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::reduced(-(self.num as i128), self.den as i128)
+    }
+}
+
+impl Mul for Units {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::reduced(
+            self.num as i128 * rhs.num as i128,
+            self.den as i128 * rhs.den as i128,
+        )
+    }
+}
+
+impl Div for Units {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::reduced(
+            self.num as i128 * rhs.den as i128,
+            self.den as i128 * rhs.num as i128,
+        )
+    }
+}
+
+impl Mul<i64> for Units {
+    type Output = Self;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Self::reduced(self.num as i128 * rhs as i128, self.den as i128)
+    }
+}
+
+impl Div<i64> for Units {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: i64) -> Self::Output {
+        Self::reduced(self.num as i128, self.den as i128 * rhs as i128)
+    }
+}
+
+impl From<i64> for Units {
+    fn from(v: i64) -> Self {
+        Self::new(v, 1)
+    }
+}
+
+impl From<(i64, i64)> for Units {
+    fn from(v: (i64, i64)) -> Self {
+        Self::new(v.0, v.1)
+    }
+}
+
+impl From<Units> for f32 {
+    fn from(v: Units) -> Self {
+        v.to_f32()
     }
 }
 
@@ -138,6 +305,14 @@ pub enum Duration {
     Dotted(Unit),
     Triplet(Unit),
     DottedTriplet(Unit),
+    DoubleDotted(Unit),
+    /// n notes in the time of m, e.g. `Tuplet(Unit::Sixteenth, 5, 4)` for quintuplets.
+    #[serde(deserialize_with = "deserialize_tuplet")]
+    Tuplet(Unit, u8, u8),
+    /// Grace notes take no bar time, acciaccatura is played quickly right before the beat,
+    /// appoggiatura takes its unit from the following note.
+    Acciaccatura(Unit),
+    Appoggiatura(Unit),
 }
 
 impl Display for Duration {
@@ -157,6 +332,22 @@ impl Display for Duration {
                 write!(f, "DT")?;
                 d.fmt(f)
             }
+            Duration::DoubleDotted(d) => {
+                write!(f, "DD")?;
+                d.fmt(f)
+            }
+            Duration::Tuplet(d, n, m) => {
+                write!(f, "T{}_{}", n, m)?;
+                d.fmt(f)
+            }
+            Duration::Acciaccatura(d) => {
+                write!(f, "AC")?;
+                d.fmt(f)
+            }
+            Duration::Appoggiatura(d) => {
+                write!(f, "AP")?;
+                d.fmt(f)
+            }
         }
     }
 }
@@ -187,6 +378,10 @@ impl Duration {
     pub const DT_1_8: Self = Duration::DottedTriplet(Unit::Eighth);
     pub const DT_1_16: Self = Duration::DottedTriplet(Unit::Sixteenth);
     pub const DT_1_32: Self = Duration::DottedTriplet(Unit::ThirtySecondth);
+    pub const _1_64: Self = Duration::Simple(Unit::SixtyFourth);
+    pub const D_1_64: Self = Duration::Dotted(Unit::SixtyFourth);
+    pub const T_1_64: Self = Duration::Triplet(Unit::SixtyFourth);
+    pub const DT_1_64: Self = Duration::DottedTriplet(Unit::SixtyFourth);
 
     /// Returns `true` if the duration is [`Simple`].
     pub fn is_simple(&self) -> bool {
@@ -200,6 +395,72 @@ impl Duration {
             None
         }
     }
+
+    /// Returns `true` if the duration is a grace note, which takes no bar time.
+    pub fn is_grace(&self) -> bool {
+        matches!(self, Self::Acciaccatura(..) | Self::Appoggiatura(..))
+    }
+
+    pub fn unit(&self) -> Option<Unit> {
+        match self {
+            Self::Zero => None,
+            Self::Simple(unit)
+            | Self::Dotted(unit)
+            | Self::Triplet(unit)
+            | Self::DottedTriplet(unit)
+            | Self::DoubleDotted(unit)
+            | Self::Tuplet(unit, _, _)
+            | Self::Acciaccatura(unit)
+            | Self::Appoggiatura(unit) => Some(*unit),
+        }
+    }
+
+    pub fn dots(&self) -> u8 {
+        match self {
+            Self::Dotted(_) | Self::DottedTriplet(_) => 1,
+            Self::DoubleDotted(_) => 2,
+            _ => 0,
+        }
+    }
+
+    /// Returns (n, m) for n notes in the time of m.
+    pub fn tuplet(&self) -> Option<(u8, u8)> {
+        match self {
+            Self::Triplet(_) | Self::DottedTriplet(_) => Some((3, 2)),
+            Self::Tuplet(_, n, m) => Some((*n, *m)),
+            _ => None,
+        }
+    }
+
+    /// Uses `Triplet` for 3:2, the span defaults to the largest power of 2 below n.
+    pub fn new_tuplet(unit: Unit, n: u8, m: Option<u8>) -> Self {
+        let m = m.unwrap_or_else(|| Self::default_tuplet_span(n));
+        if n == 3 && m == 2 {
+            Self::Triplet(unit)
+        } else if n == 0 || m == 0 || n == m {
+            Self::Simple(unit)
+        } else {
+            Self::Tuplet(unit, n, m)
+        }
+    }
+
+    pub fn default_tuplet_span(n: u8) -> u8 {
+        let mut m: u16 = 1;
+        while m * 2 < n as u16 {
+            m *= 2;
+        }
+        m as u8
+    }
+
+    /// How long a grace note is played, the following note is delayed by this
+    /// for appoggiatura.
+    pub fn grace_units(&self) -> Units {
+        match self {
+            Self::Acciaccatura(unit) => Units::from(*unit).min(Units::from(Unit::ThirtySecondth)),
+            Self::Appoggiatura(unit) => Units::from(*unit),
+            _ => Units::ZERO,
+        }
+    }
 }
 
 impl Duration {
@@ -207,35 +468,50 @@ impl Duration {
         format!("{}", self)
     }
     pub fn from_ident(ident: &str) -> Self {
-        match ident {
-            "_0" => Self::_0,
-            "_1" => Self::_1,
-            "_1_2" => Self::_1_2,
-            "_1_4" => Self::_1_4,
-            "_1_8" => Self::_1_8,
-            "_1_16" => Self::_1_16,
-            "_1_32" => Self::_1_32,
-            "D_1" => Self::D_1,
-            "D_1_2" => Self::D_1_2,
-            "D_1_4" => Self::D_1_4,
-            "D_1_8" => Self::D_1_8,
-            "D_1_16" => Self::D_1_16,
-            "D_1_32" => Self::D_1_32,
-            "T_1" => Self::T_1,
-            "T_1_2" => Self::T_1_2,
-            "T_1_4" => Self::T_1_4,
-            "T_1_8" => Self::T_1_8,
-            "T_1_16" => Self::T_1_16,
-            "T_1_32" => Self::T_1_32,
-            "DT_1" => Self::DT_1,
-            "DT_1_2" => Self::DT_1_2,
-            "DT_1_4" => Self::DT_1_4,
-            "DT_1_8" => Self::DT_1_8,
-            "DT_1_16" => Self::DT_1_16,
-            "DT_1_32" => Self::DT_1_32,
+        if ident == "_0" {
+            return Self::_0;
+        }
+        let (prefix, rest) = ident.split_at(ident.find('_').unwrap_or(ident.len()));
+        if prefix.len() > 1 && prefix.starts_with('T') {
+            return Self::tuplet_from_ident(&prefix[1..], rest).unwrap_or(Self::_0);
+        }
+        let unit = match Unit::try_from_ident(rest) {
+            Some(unit) => unit,
+            None => return Self::_0,
+        };
+        match prefix {
+            "" => Self::Simple(unit),
+            "D" => Self::Dotted(unit),
+            "T" => Self::Triplet(unit),
+            "DT" => Self::DottedTriplet(unit),
+            "DD" => Self::DoubleDotted(unit),
+            "AC" => Self::Acciaccatura(unit),
+            "AP" => Self::Appoggiatura(unit),
             _ => Self::_0,
         }
     }
+    /// Parses `T5_4_1_16` as `n`: "5", `rest`: "_4_1_16".
+    fn tuplet_from_ident(n: &str, rest: &str) -> Option<Self> {
+        let n = n.parse::<u8>().ok()?;
+        let (m, unit) = rest.get(1..)?.split_once('_')?;
+        let m = m.parse::<u8>().ok()?;
+        if n == 0 || m == 0 {
+            return None;
+        }
+        let unit = Unit::try_from_ident(&format!("_{}", unit))?;
+        Some(Self::Tuplet(unit, n, m))
+    }
+}
+
+fn deserialize_tuplet<'de, D>(deserializer: D) -> Result<(Unit, u8, u8), D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let (unit, n, m) = <(Unit, u8, u8)>::deserialize(deserializer)?;
+    if n == 0 || m == 0 {
+        return Err(D::Error::custom(format!("invalid tuplet: {}_{}", n, m)));
+    }
+    Ok((unit, n, m))
 }
 
 impl Default for Duration {
@@ -252,33 +528,154 @@ impl From<Unit> for Duration {
 
 impl From<f32> for Units {
     fn from(v: f32) -> Self {
-        Self(v)
+        Self::from_f64(v as f64)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum UnitsData {
+    Fraction(i64, i64),
+    /// Written by versions before units were fractions.
+    Float(f32),
+}
+
+impl TryFrom<UnitsData> for Units {
+    type Error = String;
+
+    fn try_from(v: UnitsData) -> Result<Self, Self::Error> {
+        match v {
+            UnitsData::Fraction(num, den) => Self::try_new(num, den)
+                .ok_or_else(|| format!("invalid units: {}/{}", num, den)),
+            UnitsData::Float(v) => Ok(Self::from(v)),
+        }
+    }
+}
+
+impl From<Units> for (i64, i64) {
+    fn from(v: Units) -> Self {
+        (v.num, v.den)
     }
 }
 
 impl From<Unit> for Units {
     fn from(v: Unit) -> Self {
         match v {
-            Unit::Whole => 1.0,
-            Unit::Half => 1.0 / 2.0,
-            Unit::Quarter => 1.0 / 4.0,
-            Unit::Eighth => 1.0 / 8.0,
-            Unit::Sixteenth => 1.0 / 16.0,
-            Unit::ThirtySecondth => 1.0 / 32.0,
+            Unit::Whole => Self::new(1, 1),
+            Unit::Half => Self::new(1, 2),
+            Unit::Quarter => Self::new(1, 4),
+            Unit::Eighth => Self::new(1, 8),
+            Unit::Sixteenth => Self::new(1, 16),
+            Unit::ThirtySecondth => Self::new(1, 32),
+            Unit::SixtyFourth => Self::new(1, 64),
         }
-        .into()
     }
 }
 
 impl From<Duration> for Units {
     fn from(v: Duration) -> Self {
         match v {
-            Duration::Zero => 0.0,
-            Duration::Simple(v) => Units::from(v).0,
-            Duration::Dotted(v) => Units::from(v).0 * 1.5,
-            Duration::Triplet(v) => Units::from(v).0 * 2.0 / 3.0,
-            Duration::DottedTriplet(v) => Units::from(v).0 * 4.0 / 3.0,
+            Duration::Zero => Self::ZERO,
+            Duration::Simple(v) => Units::from(v),
+            Duration::Dotted(v) => Units::from(v) * Self::new(3, 2),
+            Duration::Triplet(v) => Units::from(v) * Self::new(2, 3),
+            Duration::DottedTriplet(v) => Units::from(v) * Self::new(4, 3),
+            Duration::DoubleDotted(v) => Units::from(v) * Self::new(7, 4),
+            Duration::Tuplet(v, n, m) => Units::from(v) * Self::new(m as i64, n as i64),
+            Duration::Acciaccatura(_) | Duration::Appoggiatura(_) => Self::ZERO,
         }
-        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::{Duration, Unit, Units};
+
+    #[test]
+    fn test_units_arithmetic() {
+        let third = Units::new(1, 3);
+        let quarter = Units::from(Unit::Quarter);
+        assert_eq!(third + quarter, Units::new(7, 12));
+        assert_eq!(third - quarter, Units::new(1, 12));
+        assert_eq!(quarter - third, Units::new(-1, 12));
+        assert_eq!(third * quarter, Units::new(1, 12));
+        assert_eq!(third / quarter, Units::new(4, 3));
+        assert_eq!(third * 3, Units::ONE);
+        assert_eq!(quarter / 2, Units::from(Unit::Eighth));
+        assert_eq!(-third, Units::new(-1, 3));
+        assert_eq!(Units::new(2, -6), Units::new(-1, 3));
+        assert_eq!(Units::new(6, 8).numer(), 3);
+        assert_eq!(Units::new(6, 8).denom(), 4);
+        assert_eq!(Units::new(-7, 2).floor(), -4);
+        assert!(third > quarter);
+        assert!(-third < Units::ZERO);
+        assert_eq!(third.max(quarter), third);
+        assert_eq!(Units::new(1, 3) * 3 - Units::ONE, Units::ZERO);
+    }
+
+    #[test]
+    fn test_units_overflow() {
+        assert_eq!(Units::MAX + Units::ONE, Units::MAX);
+        assert_eq!(-Units::MAX - Units::MAX, -Units::MAX);
+        assert_eq!(-(-Units::MAX - Units::ONE), Units::MAX);
+        assert!(Units::MAX * Units::new(1, 2) < Units::MAX);
+        let tiny = Units::new(1, i64::MAX) * Units::new(1, 3);
+        assert_eq!(tiny, Units::ZERO);
+        assert_eq!(Units::from(f32::NAN), Units::ZERO);
+        assert_eq!(Units::from(0.25), Units::new(1, 4));
+        assert_eq!(Units::try_new(1, 0), None);
+    }
+
+    #[test]
+    fn test_units_serde() {
+        let units = Units::new(2, 3);
+        let json = serde_json::to_string(&units).unwrap();
+        assert_eq!(json, "[2,3]");
+        assert_eq!(serde_json::from_str::<Units>(&json).unwrap(), units);
+        assert_eq!(serde_json::from_str::<Units>("0.75").unwrap(), Units::new(3, 4));
+        assert!(serde_json::from_str::<Units>("[1,0]").is_err());
+    }
+
+    #[test]
+    fn test_default_tuplet_span() {
+        assert_eq!(Duration::default_tuplet_span(2), 1);
+        assert_eq!(Duration::default_tuplet_span(5), 4);
+        assert_eq!(Duration::default_tuplet_span(8), 4);
+        assert_eq!(Duration::default_tuplet_span(9), 8);
+        assert_eq!(Duration::default_tuplet_span(129), 128);
+        assert_eq!(Duration::default_tuplet_span(255), 128);
+    }
+
+    #[test]
+    fn test_tuplet_units() {
+        assert_eq!(
+            Units::from(Duration::new_tuplet(Unit::Sixteenth, 5, None)),
+            Units::new(1, 20)
+        );
+        assert_eq!(
+            Units::from(Duration::new_tuplet(Unit::Quarter, 255, None)),
+            Units::new(128, 1020)
+        );
+        assert_eq!(Duration::new_tuplet(Unit::Eighth, 3, None), Duration::T_1_8);
+        assert_eq!(Duration::new_tuplet(Unit::Eighth, 0, None), Duration::_1_8);
+    }
+
+    #[test]
+    fn test_tuplet_ident() {
+        let quintuplet = Duration::Tuplet(Unit::Sixteenth, 5, 4);
+        assert_eq!(quintuplet.to_ident(), "T5_4_1_16");
+        assert_eq!(Duration::from_ident("T5_4_1_16"), quintuplet);
+        assert_eq!(Duration::from_ident("T0_4_1_16"), Duration::_0);
+        assert_eq!(Duration::from_ident("T5_0_1_16"), Duration::_0);
+    }
+
+    #[test]
+    fn test_tuplet_serde() {
+        let quintuplet = Duration::Tuplet(Unit::Sixteenth, 5, 4);
+        let json = serde_json::to_string(&quintuplet).unwrap();
+        assert_eq!(serde_json::from_str::<Duration>(&json).unwrap(), quintuplet);
+        let err = serde_json::from_str::<Duration>(r#"{"Tuplet":["Sixteenth",0,4]}"#)
+            .unwrap_err();
+        assert!(err.to_string().contains("invalid tuplet: 0_4"), "{}", err);
     }
 }
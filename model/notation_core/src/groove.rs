@@ -0,0 +1,143 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::{Unit, Units};
+
+/// How the notes in a pair of eighths (or sixteenths) are played, the ratio is the percentage
+/// of the pair taken by the first note, 50 is straight, 67 is the triplet feel.
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug, Default)]
+pub enum Groove {
+    #[default]
+    Straight,
+    Swing8(u8),
+    Swing16(u8),
+    Shuffle,
+}
+impl Display for Groove {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Swing8(ratio) => write!(f, "Swing8({})", ratio),
+            Self::Swing16(ratio) => write!(f, "Swing16({})", ratio),
+            _ => write!(f, "{:?}", self),
+        }
+    }
+}
+
+impl Groove {
+    pub const STRAIGHT_RATIO: u8 = 50;
+    pub const SHUFFLE_RATIO: u8 = 67;
+    pub const MAX_RATIO: u8 = 80;
+
+    /// Returns `true` if the groove is [`Straight`].
+    pub fn is_straight(&self) -> bool {
+        self.unit().is_none() || self.ratio() == Self::STRAIGHT_RATIO
+    }
+    pub fn unit(&self) -> Option<Unit> {
+        match self {
+            Self::Straight => None,
+            Self::Swing8(_) | Self::Shuffle => Some(Unit::Eighth),
+            Self::Swing16(_) => Some(Unit::Sixteenth),
+        }
+    }
+    pub fn is_valid_ratio(ratio: u8) -> bool {
+        (Self::STRAIGHT_RATIO..=Self::MAX_RATIO).contains(&ratio)
+    }
+    /// Out of range ratios are rejected by the DSL, they are only clamped here to guard
+    /// against tabs loaded from files.
+    pub fn ratio(&self) -> u8 {
+        match self {
+            Self::Straight => Self::STRAIGHT_RATIO,
+            Self::Swing8(ratio) | Self::Swing16(ratio) => {
+                (*ratio).clamp(Self::STRAIGHT_RATIO, Self::MAX_RATIO)
+            }
+            Self::Shuffle => Self::SHUFFLE_RATIO,
+        }
+    }
+    /// Move the position within the bar to where it should be played, positions on the
+    /// grid of the swing pairs are not changed.
+    pub fn apply(&self, in_bar_pos: Units) -> Units {
+        self.map(in_bar_pos, false)
+    }
+    /// Reverse of `apply()`, from the played position to the written one.
+    pub fn revert(&self, in_bar_pos: Units) -> Units {
+        self.map(in_bar_pos, true)
+    }
+    fn map(&self, in_bar_pos: Units, revert: bool) -> Units {
+        let unit = match self.unit() {
+            Some(unit) if !self.is_straight() => Units::from(unit),
+            _ => return in_bar_pos,
+        };
+        let pair = unit * 2;
+        let first = pair * Units::new(self.ratio() as i64, 100);
+        let (from, to) = if revert { (first, unit) } else { (unit, first) };
+        let pair_begin = pair * (in_bar_pos / pair).floor();
+        let offset = in_bar_pos - pair_begin;
+        let offset = if offset < from {
+            offset * to / from
+        } else {
+            to + (offset - from) * (pair - to) / (pair - from)
+        };
+        pair_begin + offset
+    }
+    pub fn to_ident(&self) -> String {
+        format!("{:?}", self)
+            .replace("(", "_")
+            .replace(")", "")
+    }
+    pub fn from_ident(ident: &str) -> Self {
+        let parse_ratio = |prefix: &str| -> u8 {
+            ident
+                .trim_start_matches(prefix)
+                .trim_start_matches('_')
+                .parse::<u8>()
+                .unwrap_or(Self::SHUFFLE_RATIO)
+        };
+        if ident.starts_with("Swing16") {
+            Self::Swing16(parse_ratio("Swing16"))
+        } else if ident.starts_with("Swing8") || ident == "Swing" {
+            Self::Swing8(parse_ratio("Swing8"))
+        } else if ident == "Shuffle" {
+            Self::Shuffle
+        } else {
+            Self::Straight
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::{Groove, Unit, Units};
+
+    #[test]
+    fn test_apply_revert_round_trip() {
+        let grooves = [
+            Groove::Shuffle,
+            Groove::Swing8(60),
+            Groove::Swing8(Groove::MAX_RATIO),
+            Groove::Swing16(75),
+        ];
+        for groove in grooves {
+            for index in 0..48 {
+                let pos = Units::from(Unit::ThirtySecondth) * index / 3;
+                let played = groove.apply(pos);
+                assert_eq!(groove.revert(played), pos, "{} {}", groove, pos);
+            }
+        }
+    }
+
+    #[test]
+    fn test_apply() {
+        let eighth = Units::from(Unit::Eighth);
+        let quarter = Units::from(Unit::Quarter);
+        let groove = Groove::Shuffle;
+        assert_eq!(groove.apply(Units::ZERO), Units::ZERO);
+        assert_eq!(groove.apply(quarter), quarter);
+        assert_eq!(groove.apply(eighth), quarter * Units::new(67, 100));
+        assert_eq!(groove.apply(quarter + eighth), quarter + quarter * Units::new(67, 100));
+        let straight = Groove::Swing8(Groove::STRAIGHT_RATIO);
+        assert!(straight.is_straight());
+        assert_eq!(straight.apply(eighth), eighth);
+        assert_eq!(Groove::Swing16(60).apply(eighth), eighth);
+    }
+}
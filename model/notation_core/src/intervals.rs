@@ -5,95 +5,171 @@ use serde::{Deserialize, Serialize};
 use crate::interval::Interval;
 
 //https://en.wikipedia.org/wiki/Chord_(music)
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Debug)]
-pub enum Intervals {
+/// Intervals above the root, stored inline so that chords stay `Copy`, the capacity
+/// covers every distinct `Interval`, duplicated ones are ignored.
+#[derive(Copy, Clone, Serialize, Deserialize, Debug)]
+#[serde(from = "IntervalsData", into = "IntervalsData")]
+pub struct Intervals {
+    len: u8,
+    intervals: [Interval; Intervals::CAPACITY],
+}
+
+/// Serialized form, the fixed variants are kept so that existing tabs can still be loaded.
+#[derive(Clone, Serialize, Deserialize)]
+enum IntervalsData {
     Monad,
     Dyad(Interval),
     Triad(Interval, Interval),
     Tetrad(Interval, Interval, Interval),
     Pentad(Interval, Interval, Interval, Interval),
+    Intervals(Vec<Interval>),
+}
+
+impl From<IntervalsData> for Intervals {
+    fn from(v: IntervalsData) -> Self {
+        match v {
+            IntervalsData::Monad => Self::from(()),
+            IntervalsData::Dyad(n1) => Self::from(n1),
+            IntervalsData::Triad(n1, n2) => Self::from((n1, n2)),
+            IntervalsData::Tetrad(n1, n2, n3) => Self::from((n1, n2, n3)),
+            IntervalsData::Pentad(n1, n2, n3, n4) => Self::from((n1, n2, n3, n4)),
+            IntervalsData::Intervals(intervals) => Self::from(intervals),
+        }
+    }
+}
+
+impl From<Intervals> for IntervalsData {
+    fn from(v: Intervals) -> Self {
+        match v.as_slice() {
+            [] => Self::Monad,
+            [n1] => Self::Dyad(*n1),
+            [n1, n2] => Self::Triad(*n1, *n2),
+            [n1, n2, n3] => Self::Tetrad(*n1, *n2, *n3),
+            [n1, n2, n3, n4] => Self::Pentad(*n1, *n2, *n3, *n4),
+            intervals => Self::Intervals(intervals.to_vec()),
+        }
+    }
+}
+
+impl PartialEq for Intervals {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+impl Eq for Intervals {}
+
+impl PartialOrd for Intervals {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
 }
+/// Fewer intervals first, same as the order of the old `Monad` to `Pentad` variants.
+impl Ord for Intervals {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.len
+            .cmp(&other.len)
+            .then_with(|| self.as_slice().cmp(other.as_slice()))
+    }
+}
+
+impl Default for Intervals {
+    fn default() -> Self {
+        Self {
+            len: 0,
+            intervals: [Interval::Unison; Self::CAPACITY],
+        }
+    }
+}
+
 impl Display for Intervals {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "<Intervals>({})", self.to_text())
     }
 }
 impl Intervals {
-    fn to_text(&self) -> String {
-        match self {
-            Self::Monad => format!(""),
-            Self::Dyad(n1) => format!("{}", n1),
-            Self::Triad(n1, n2) => format!("{}, {}", n1, n2),
-            Self::Tetrad(n1, n2, n3) => format!("{}, {}, {}", n1, n2, n3),
-            Self::Pentad(n1, n2, n3, n4) => format!("{}, {}, {}, {}", n1, n2, n3, n4),
+    /// Number of distinct `Interval` values.
+    pub const CAPACITY: usize = 17;
+    pub fn new<I: IntoIterator<Item = Interval>>(intervals: I) -> Self {
+        let mut result = Self::default();
+        for interval in intervals {
+            result.push(interval);
+        }
+        result
+    }
+    pub fn push(&mut self, interval: Interval) {
+        if self.contains(&interval) {
+            return;
         }
+        self.intervals[self.len as usize] = interval;
+        self.len += 1;
+    }
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn contains(&self, interval: &Interval) -> bool {
+        self.as_slice().contains(interval)
+    }
+    pub fn as_slice(&self) -> &[Interval] {
+        &self.intervals[..self.len as usize]
+    }
+    pub fn iter(&self) -> std::slice::Iter<'_, Interval> {
+        self.as_slice().iter()
+    }
+    fn to_text(self) -> String {
+        self.iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
     }
 }
 impl From<()> for Intervals {
     fn from(_: ()) -> Self {
-        Self::Monad
+        Self::default()
     }
 }
 
 impl From<Interval> for Intervals {
     fn from(v: Interval) -> Self {
-        Intervals::Dyad(v)
+        Self::new([v])
     }
 }
 
 impl From<(Interval, Interval)> for Intervals {
     fn from(v: (Interval, Interval)) -> Self {
-        Intervals::Triad(v.0, v.1)
+        Self::new([v.0, v.1])
     }
 }
 
 impl From<(Interval, Interval, Interval)> for Intervals {
     fn from(v: (Interval, Interval, Interval)) -> Self {
-        Intervals::Tetrad(v.0, v.1, v.2)
+        Self::new([v.0, v.1, v.2])
     }
 }
 
 impl From<(Interval, Interval, Interval, Interval)> for Intervals {
     fn from(v: (Interval, Interval, Interval, Interval)) -> Self {
-        Intervals::Pentad(v.0, v.1, v.2, v.3)
+        Self::new([v.0, v.1, v.2, v.3])
     }
 }
 
 impl From<Vec<Interval>> for Intervals {
     fn from(v: Vec<Interval>) -> Self {
-        match v.len() {
-            1 => Self::from(v[0]),
-            2 => Self::from((v[0], v[1])),
-            3 => Self::from((v[0], v[1], v[2])),
-            4 => Self::from((v[0], v[1], v[2], v[3])),
-            _ => {
-                println!("Unsupported Intervals: {}", v.len());
-                Self::Monad
-            }
-        }
+        Self::new(v)
     }
 }
 
 impl From<Vec<Option<Interval>>> for Intervals {
     fn from(v: Vec<Option<Interval>>) -> Self {
-        let notes = v
-            .iter()
-            .filter(|x| x.is_some())
-            .map(|x| x.unwrap())
-            .collect::<Vec<Interval>>();
-        notes.into()
+        Self::new(v.into_iter().flatten())
     }
 }
 
 impl Intervals {
     pub fn get_intervals(&self) -> Vec<Interval> {
-        match *self {
-            Self::Monad => vec![],
-            Self::Dyad(n1) => vec![n1],
-            Self::Triad(n1, n2) => vec![n1, n2],
-            Self::Tetrad(n1, n2, n3) => vec![n1, n2, n3],
-            Self::Pentad(n1, n2, n3, n4) => vec![n1, n2, n3, n4],
-        }
+        self.as_slice().to_vec()
     }
 }
 
@@ -1,8 +1,10 @@
+pub mod analysis;
 pub mod chord;
 pub mod chord_symbol;
 pub mod core_entry;
 pub mod duration;
 pub mod entry;
+pub mod groove;
 pub mod interval;
 pub mod intervals;
 pub mod key;
@@ -13,11 +15,14 @@ pub mod pitch;
 pub mod semitones;
 pub mod scale;
 pub mod signature;
+pub mod staff;
 pub mod syllable;
 pub mod tempo;
 pub mod tone;
 
 pub mod prelude {
+    #[doc(hidden)]
+    pub use crate::analysis::{ChordAnalysis, ChordFunction};
     #[doc(hidden)]
     pub use crate::chord::Chord;
     #[doc(hidden)]
@@ -29,6 +34,8 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::entry::{Entry, EntryPassMode};
     #[doc(hidden)]
+    pub use crate::groove::Groove;
+    #[doc(hidden)]
     pub use crate::interval::{Interval, IntervalQuality};
     #[doc(hidden)]
     pub use crate::intervals::Intervals;
@@ -49,6 +56,8 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::signature::{Beats, Signature};
     #[doc(hidden)]
+    pub use crate::staff::{Clef, KeySignature, StaffAccidentals};
+    #[doc(hidden)]
     pub use crate::syllable::Syllable;
     #[doc(hidden)]
     pub use crate::tempo::{Bpm, BpmRange, Tempo};
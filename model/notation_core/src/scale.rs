@@ -314,6 +314,6 @@ impl Scale {
         Note::new(*octave, pitch, *syllable)
     }
     pub fn calc_click_tone(&self, key: &Key, octave: &Octave, syllable: &Syllable) -> Tone {
-        Tone::Single(self.calc_click_note(key, octave, syllable))
+        Tone::from(self.calc_click_note(key, octave, syllable))
     }
 }
@@ -21,6 +21,7 @@ impl Display for Signature {
             Unit::Eighth => 8,
             Unit::Sixteenth => 16,
             Unit::ThirtySecondth => 32,
+            Unit::SixtyFourth => 64,
         })
     }
 }
@@ -32,6 +33,38 @@ impl Signature {
             bar_beats,
         }
     }
+    /// Returns `true` for compound meters like 6/8, 9/8 and 12/8, which are felt in groups of 3 beats.
+    pub fn is_compound(&self) -> bool {
+        self.bar_beats > 3
+            && self.bar_beats.is_multiple_of(3)
+            && matches!(
+                self.beat_unit,
+                Unit::Eighth | Unit::Sixteenth | Unit::ThirtySecondth | Unit::SixtyFourth
+            )
+    }
+    /// Beats in each group of the bar, the first beat of each group is accented.
+    pub fn beat_groups(&self) -> Vec<u8> {
+        if self.is_compound() {
+            vec![3; (self.bar_beats / 3) as usize]
+        } else {
+            match self.bar_beats {
+                5 => vec![3, 2],
+                7 => vec![2, 2, 3],
+                _ => vec![self.bar_beats],
+            }
+        }
+    }
+    pub fn is_accented_beat(&self, beat: u8) -> bool {
+        let beat = beat % self.bar_beats.max(1);
+        let mut group_begin = 0;
+        for group in self.beat_groups() {
+            if beat == group_begin {
+                return true;
+            }
+            group_begin += group;
+        }
+        false
+    }
 }
 
 impl Signature {
@@ -61,7 +94,7 @@ impl From<f32> for Beats {
 
 impl From<Signature> for Units {
     fn from(v: Signature) -> Self {
-        Self::from(Units::from(v.beat_unit).0 * (v.bar_beats as f32))
+        Units::from(v.beat_unit) * v.bar_beats as i64
     }
 }
 
@@ -73,6 +106,6 @@ impl From<Signature> for Beats {
 
 impl From<(Signature, Units)> for Beats {
     fn from((signature, units): (Signature, Units)) -> Self {
-        Self::from(units.0 / Units::from(signature.beat_unit).0)
+        Self::from((units / Units::from(signature.beat_unit)).to_f32())
     }
 }
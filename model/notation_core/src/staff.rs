@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::{Key, Note, Octave, Pitch, PitchName, PitchSign, Scale, Syllable};
+
+// https://en.wikipedia.org/wiki/Clef
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug, Default)]
+pub enum Clef {
+    #[default]
+    Treble,
+    /// Treble clef sounding an octave lower, as guitar music is written.
+    Treble8vb,
+    Bass,
+}
+impl Display for Clef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl PitchName {
+    /// Index within the octave, counted in staff steps from C.
+    pub fn to_step(&self) -> i16 {
+        match self {
+            PitchName::C => 0,
+            PitchName::D => 1,
+            PitchName::E => 2,
+            PitchName::F => 3,
+            PitchName::G => 4,
+            PitchName::A => 5,
+            PitchName::B => 6,
+        }
+    }
+    /// Position in the circle of fifths, relative to C.
+    pub fn to_fifths(&self) -> i8 {
+        match self {
+            PitchName::F => -1,
+            PitchName::C => 0,
+            PitchName::G => 1,
+            PitchName::D => 2,
+            PitchName::A => 3,
+            PitchName::E => 4,
+            PitchName::B => 5,
+        }
+    }
+}
+
+impl Note {
+    /// Lines and spaces from C of octave 0, the letter of the pitch decides the step,
+    /// so `C#` and `Db` are on different steps.
+    pub fn staff_step(&self) -> i16 {
+        let octave = match self.octave {
+            Octave::N1 => -1,
+            Octave::P0 => 0,
+            Octave::P1 => 1,
+            Octave::P2 => 2,
+            Octave::P3 => 3,
+            Octave::P4 => 4,
+            Octave::P5 => 5,
+            Octave::P6 => 6,
+            Octave::P7 => 7,
+            Octave::P8 => 8,
+            Octave::P9 => 9,
+            Octave::P10 => 10,
+        };
+        octave * 7 + self.pitch.name.to_step()
+    }
+}
+
+impl Clef {
+    pub const MIDDLE_C_STEP: i16 = 4 * 7;
+
+    /// Step of the middle line, B4 for treble, D3 for bass.
+    pub fn middle_step(&self) -> i16 {
+        match self {
+            Clef::Treble => 4 * 7 + 6,
+            Clef::Treble8vb => 3 * 7 + 6,
+            Clef::Bass => 3 * 7 + 1,
+        }
+    }
+    /// Steps above the middle line, each line or space is one step.
+    pub fn calc_step(&self, note: &Note) -> i16 {
+        note.staff_step() - self.middle_step()
+    }
+    /// Bass clef if most of the notes are below middle C.
+    pub fn of_notes(notes: &[Note]) -> Self {
+        let below = notes
+            .iter()
+            .filter(|x| x.staff_step() < Self::MIDDLE_C_STEP)
+            .count();
+        if below * 2 > notes.len() {
+            Self::Bass
+        } else {
+            Self::Treble
+        }
+    }
+}
+
+// https://en.wikipedia.org/wiki/Key_signature
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug, Default)]
+pub struct KeySignature {
+    /// Number of sharps if positive, number of flats if negative.
+    pub fifths: i8,
+}
+impl Display for KeySignature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.fifths >= 0 {
+            write!(f, "{}#", self.fifths)
+        } else {
+            write!(f, "{}b", -self.fifths)
+        }
+    }
+}
+
+impl KeySignature {
+    pub const SHARPS: [PitchName; 7] = [
+        PitchName::F,
+        PitchName::C,
+        PitchName::G,
+        PitchName::D,
+        PitchName::A,
+        PitchName::E,
+        PitchName::B,
+    ];
+    pub const FLATS: [PitchName; 7] = [
+        PitchName::B,
+        PitchName::E,
+        PitchName::A,
+        PitchName::D,
+        PitchName::G,
+        PitchName::C,
+        PitchName::F,
+    ];
+    pub fn new(scale: &Scale, key: &Key) -> Self {
+        let do_pitch = scale.calc_pitch(key, &Syllable::Do);
+        Self::of_major(&do_pitch)
+    }
+    /// Keys beyond 7 sharps or flats are written as their enharmonic keys.
+    pub fn of_major(pitch: &Pitch) -> Self {
+        let sign = match pitch.sign {
+            PitchSign::DoubleFlat => -2,
+            PitchSign::Flat => -1,
+            PitchSign::Natural => 0,
+            PitchSign::Sharp => 1,
+            PitchSign::DoubleSharp => 2,
+        };
+        let mut fifths = pitch.name.to_fifths() + sign * 7;
+        while fifths > 7 {
+            fifths -= 12;
+        }
+        while fifths < -7 {
+            fifths += 12;
+        }
+        Self { fifths }
+    }
+    pub fn sign_of(&self, name: &PitchName) -> PitchSign {
+        if self.fifths > 0 && Self::SHARPS[0..self.fifths as usize].contains(name) {
+            PitchSign::Sharp
+        } else if self.fifths < 0 && Self::FLATS[0..(-self.fifths) as usize].contains(name) {
+            PitchSign::Flat
+        } else {
+            PitchSign::Natural
+        }
+    }
+    /// The signs in the order they are written.
+    pub fn get_signs(&self) -> Vec<(PitchName, PitchSign)> {
+        if self.fifths > 0 {
+            Self::SHARPS[0..self.fifths as usize]
+                .iter()
+                .map(|x| (*x, PitchSign::Sharp))
+                .collect()
+        } else {
+            Self::FLATS[0..(-self.fifths) as usize]
+                .iter()
+                .map(|x| (*x, PitchSign::Flat))
+                .collect()
+        }
+    }
+}
+
+/// Accidentals within a bar, a sign stays in effect for the same step until the bar ends.
+#[derive(Clone, Debug, Default)]
+pub struct StaffAccidentals {
+    pub key_signature: KeySignature,
+    pub altered: HashMap<i16, PitchSign>,
+}
+impl StaffAccidentals {
+    pub fn new(key_signature: KeySignature) -> Self {
+        Self {
+            key_signature,
+            altered: HashMap::new(),
+        }
+    }
+    /// Returns the sign to be written before the note, if needed.
+    pub fn calc_accidental(&mut self, note: &Note) -> Option<PitchSign> {
+        let step = note.staff_step();
+        let current = self
+            .altered
+            .get(&step)
+            .copied()
+            .unwrap_or(self.key_signature.sign_of(&note.pitch.name));
+        if current == note.pitch.sign {
+            None
+        } else {
+            self.altered.insert(step, note.pitch.sign);
+            Some(note.pitch.sign)
+        }
+    }
+}
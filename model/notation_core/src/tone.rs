@@ -1,11 +1,21 @@
 use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
+use smallvec::SmallVec;
 
 use super::note::Note;
 
-#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
-pub enum Tone {
+/// Notes sounding together, kept inline up to `INLINE_NUM`, spills to heap above that.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, Default)]
+#[serde(from = "ToneData", into = "ToneData")]
+pub struct Tone {
+    notes: SmallVec<[Note; Tone::INLINE_NUM]>,
+}
+
+/// Serialized form, the fixed variants are kept so that existing tabs can still be loaded,
+/// and tones with no more than 6 notes are still readable by older versions.
+#[derive(Clone, Serialize, Deserialize)]
+enum ToneData {
     None,
     Single(Note),
     Double(Note, Note),
@@ -13,121 +23,147 @@ pub enum Tone {
     Tetra(Note, Note, Note, Note),
     Penta(Note, Note, Note, Note, Note),
     Hexa(Note, Note, Note, Note, Note, Note),
+    Notes(Vec<Note>),
+}
+
+impl From<ToneData> for Tone {
+    fn from(v: ToneData) -> Self {
+        match v {
+            ToneData::None => Self::from(()),
+            ToneData::Single(n1) => Self::from(n1),
+            ToneData::Double(n1, n2) => Self::from((n1, n2)),
+            ToneData::Triple(n1, n2, n3) => Self::from((n1, n2, n3)),
+            ToneData::Tetra(n1, n2, n3, n4) => Self::from((n1, n2, n3, n4)),
+            ToneData::Penta(n1, n2, n3, n4, n5) => Self::from((n1, n2, n3, n4, n5)),
+            ToneData::Hexa(n1, n2, n3, n4, n5, n6) => Self::from((n1, n2, n3, n4, n5, n6)),
+            ToneData::Notes(notes) => Self::from(notes),
+        }
+    }
+}
+
+impl From<Tone> for ToneData {
+    fn from(v: Tone) -> Self {
+        match v.notes.as_slice() {
+            [] => Self::None,
+            [n1] => Self::Single(*n1),
+            [n1, n2] => Self::Double(*n1, *n2),
+            [n1, n2, n3] => Self::Triple(*n1, *n2, *n3),
+            [n1, n2, n3, n4] => Self::Tetra(*n1, *n2, *n3, *n4),
+            [n1, n2, n3, n4, n5] => Self::Penta(*n1, *n2, *n3, *n4, *n5),
+            [n1, n2, n3, n4, n5, n6] => Self::Hexa(*n1, *n2, *n3, *n4, *n5, *n6),
+            notes => Self::Notes(notes.to_vec()),
+        }
+    }
 }
 
 impl Tone {
-    /// Returns `true` if the tone is [`None`].
+    pub const INLINE_NUM: usize = 6;
+    pub fn new<I: IntoIterator<Item = Note>>(notes: I) -> Self {
+        Self {
+            notes: notes.into_iter().collect(),
+        }
+    }
+    /// Returns `true` if the tone got no notes.
     pub fn is_none(&self) -> bool {
-        matches!(self, Self::None)
+        self.is_empty()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.notes.is_empty()
+    }
+    pub fn len(&self) -> usize {
+        self.notes.len()
+    }
+    pub fn notes(&self) -> &[Note] {
+        self.notes.as_slice()
+    }
+    pub fn iter(&self) -> std::slice::Iter<'_, Note> {
+        self.notes.iter()
+    }
+    pub fn push(&mut self, note: Note) {
+        self.notes.push(note);
     }
 }
 impl Display for Tone {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match *self {
-            Tone::None => write!(f, "<Tone>()"),
-            Tone::Single(n1) => write!(f, "<Tone>({})", n1),
-            Tone::Double(n1, n2) => write!(f, "<Tone>({}, {})", n1, n2),
-            Tone::Triple(n1, n2, n3) => write!(f, "<Tone>({}, {}, {})", n1, n2, n3),
-            Tone::Tetra(n1, n2, n3, n4) => {
-                write!(f, "<Tone>({}, {}, {}, {})", n1, n2, n3, n4)
-            }
-            Tone::Penta(n1, n2, n3, n4, n5) => {
-                write!(f, "<Tone>({}, {}, {}, {}, {})", n1, n2, n3, n4, n5)
-            }
-            Tone::Hexa(n1, n2, n3, n4, n5, n6) => {
-                write!(f, "<Tone>({}, {}, {}, {}, {}, {})", n1, n2, n3, n4, n5, n6)
-            }
-        }
+        let notes = self
+            .notes
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<String>>();
+        write!(f, "<Tone>({})", notes.join(", "))
     }
 }
 
 impl From<()> for Tone {
     fn from(_: ()) -> Self {
-        Self::None
+        Self::default()
     }
 }
 
 impl From<Note> for Tone {
     fn from(v: Note) -> Self {
-        Tone::Single(v)
+        Self::new([v])
     }
 }
 
 impl From<(Note, Note)> for Tone {
     fn from(v: (Note, Note)) -> Self {
-        Tone::Double(v.0, v.1)
+        Self::new([v.0, v.1])
     }
 }
 
 impl From<(Note, Note, Note)> for Tone {
     fn from(v: (Note, Note, Note)) -> Self {
-        Tone::Triple(v.0, v.1, v.2)
+        Self::new([v.0, v.1, v.2])
     }
 }
 
 impl From<(Note, Note, Note, Note)> for Tone {
     fn from(v: (Note, Note, Note, Note)) -> Self {
-        Tone::Tetra(v.0, v.1, v.2, v.3)
+        Self::new([v.0, v.1, v.2, v.3])
     }
 }
 
 impl From<(Note, Note, Note, Note, Note)> for Tone {
     fn from(v: (Note, Note, Note, Note, Note)) -> Self {
-        Tone::Penta(v.0, v.1, v.2, v.3, v.4)
+        Self::new([v.0, v.1, v.2, v.3, v.4])
     }
 }
 
 impl From<(Note, Note, Note, Note, Note, Note)> for Tone {
     fn from(v: (Note, Note, Note, Note, Note, Note)) -> Self {
-        Tone::Hexa(v.0, v.1, v.2, v.3, v.4, v.5)
+        Self::new([v.0, v.1, v.2, v.3, v.4, v.5])
     }
 }
 
 impl From<Vec<Note>> for Tone {
     fn from(v: Vec<Note>) -> Self {
-        match v.len() {
-            0 => Self::None,
-            1 => Self::from(v[0]),
-            2 => Self::from((v[0], v[1])),
-            3 => Self::from((v[0], v[1], v[2])),
-            4 => Self::from((v[0], v[1], v[2], v[3])),
-            5 => Self::from((v[0], v[1], v[2], v[3], v[4])),
-            6 => Self::from((v[0], v[1], v[2], v[3], v[4], v[5])),
-            _ => {
-                println!("ToneNote lost: {}", v.len() - 6);
-                Self::from((v[0], v[1], v[2], v[3], v[4], v[5]))
-            }
-        }
+        Self::new(v)
     }
 }
 
 impl From<Vec<Option<Note>>> for Tone {
     fn from(v: Vec<Option<Note>>) -> Self {
-        let notes = v
-            .iter()
-            .filter(|x| x.is_some())
-            .map(|x| x.unwrap())
-            .collect::<Vec<Note>>();
-        notes.into()
+        Self::new(v.into_iter().flatten())
     }
 }
 
 impl Tone {
     pub fn get_notes(&self) -> Vec<Note> {
-        match *self {
-            Self::None => vec![],
-            Self::Single(n1) => vec![n1],
-            Self::Double(n1, n2) => vec![n1, n2],
-            Self::Triple(n1, n2, n3) => vec![n1, n2, n3],
-            Self::Tetra(n1, n2, n3, n4) => vec![n1, n2, n3, n4],
-            Self::Penta(n1, n2, n3, n4, n5) => vec![n1, n2, n3, n4, n5],
-            Self::Hexa(n1, n2, n3, n4, n5, n6) => vec![n1, n2, n3, n4, n5, n6],
-        }
+        self.notes.to_vec()
     }
 }
 
 impl From<Tone> for Vec<Note> {
     fn from(v: Tone) -> Self {
-        v.get_notes()
+        v.notes.into_vec()
+    }
+}
+
+impl<'a> IntoIterator for &'a Tone {
+    type Item = &'a Note;
+    type IntoIter = std::slice::Iter<'a, Note>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.notes.iter()
     }
 }
@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// Guitar techniques on a single picked note.
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub enum Articulation {
+    HammerOn,
+    PullOff,
+    SlideUp,
+    SlideDown,
+    /// Bend up by semitones.
+    Bend(u8),
+    Vibrato,
+    PalmMute,
+    Harmonic,
+    DeadNote,
+}
+impl Display for Articulation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_ident())
+    }
+}
+impl Articulation {
+    pub fn to_ident(&self) -> String {
+        match self {
+            Self::HammerOn => "h".to_owned(),
+            Self::PullOff => "p".to_owned(),
+            Self::SlideUp => "su".to_owned(),
+            Self::SlideDown => "sd".to_owned(),
+            Self::Bend(semitones) => format!("b{}", semitones),
+            Self::Vibrato => "v".to_owned(),
+            Self::PalmMute => "pm".to_owned(),
+            Self::Harmonic => "nh".to_owned(),
+            Self::DeadNote => "x".to_owned(),
+        }
+    }
+    pub fn from_ident(ident: &str) -> Option<Self> {
+        match ident {
+            "h" => Some(Self::HammerOn),
+            "p" => Some(Self::PullOff),
+            "su" => Some(Self::SlideUp),
+            "sd" => Some(Self::SlideDown),
+            "b" => Some(Self::Bend(2)),
+            "v" => Some(Self::Vibrato),
+            "pm" => Some(Self::PalmMute),
+            "nh" => Some(Self::Harmonic),
+            "x" => Some(Self::DeadNote),
+            _ => ident
+                .strip_prefix('b')
+                .and_then(|x| x.parse::<u8>().ok())
+                .map(Self::Bend),
+        }
+    }
+    /// Short mark to be drawn beside the fret number.
+    pub fn to_mark(&self) -> String {
+        match self {
+            Self::HammerOn => "h".to_owned(),
+            Self::PullOff => "p".to_owned(),
+            Self::SlideUp => "/".to_owned(),
+            Self::SlideDown => "\\".to_owned(),
+            Self::Bend(semitones) => match semitones {
+                1 => "b½".to_owned(),
+                2 => "b".to_owned(),
+                _ => format!("b{}", *semitones as f32 / 2.0),
+            },
+            Self::Vibrato => "~".to_owned(),
+            Self::PalmMute => "PM".to_owned(),
+            Self::Harmonic => "<>".to_owned(),
+            Self::DeadNote => "x".to_owned(),
+        }
+    }
+    /// Legato notes are sounded by the fretting hand, not picked again.
+    pub fn is_legato(&self) -> bool {
+        matches!(
+            self,
+            Self::HammerOn | Self::PullOff | Self::SlideUp | Self::SlideDown
+        )
+    }
+    /// Returns `true` if the articulation is [`DeadNote`].
+    pub fn is_dead_note(&self) -> bool {
+        matches!(self, Self::DeadNote)
+    }
+}
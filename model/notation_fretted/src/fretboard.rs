@@ -4,116 +4,214 @@ use std::fmt::Display;
 use crate::pick::PickNote;
 use crate::prelude::Pick;
 
-use super::prelude::{HandShape4, HandShape6};
-use notation_core::prelude::{Note, Semitones, Tone, Scale, Key};
+use super::prelude::HandShape;
+use notation_core::prelude::{Key, Note, Scale, Semitones, Tone};
 
-macro_rules! impl_fretboard {
-    ($type:ident, $strings:literal, $hand_shape:ident) => {
-        #[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
-        pub struct $type {
-            pub total_fret_num: usize,
-            #[serde(with = "serde_arrays")]
-            pub string_notes: [Semitones; $strings],
-            pub capo: u8,
+/// Strings are 1-based, string 1 is the first one of `string_notes`.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct Fretboard {
+    pub total_fret_num: usize,
+    pub string_notes: Vec<Semitones>,
+    pub capo: u8,
+    /// Paired strings of courses, e.g. the octave strings of 12 string guitars.
+    #[serde(default)]
+    pub course_notes: Vec<Option<Semitones>>,
+    /// Fret of the nut for short strings, e.g. 5 for the fifth string of banjos.
+    #[serde(default)]
+    pub start_frets: Vec<u8>,
+}
+impl Display for Fretboard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "F: {}, C: {}, {:?})",
+            self.total_fret_num, self.capo, self.string_notes
+        )
+    }
+}
+
+impl Fretboard {
+    pub fn new(total_fret_num: usize, string_notes: Vec<Semitones>, capo: u8) -> Self {
+        let string_num = string_notes.len();
+        Self {
+            total_fret_num,
+            string_notes,
+            capo,
+            course_notes: vec![None; string_num],
+            start_frets: vec![0; string_num],
         }
-        impl Display for $type {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(
-                    f,
-                    "F: {}, C: {}, {:?})",
-                    self.total_fret_num, self.capo, self.string_notes
-                )
-            }
+    }
+    pub fn with_capo(&self, capo: u8) -> Self {
+        Self {
+            capo,
+            ..self.clone()
         }
-
-        impl $type {
-            pub fn new(total_fret_num: usize, string_notes: [Semitones; $strings], capo: u8) -> Self {
-                Self {
-                    total_fret_num,
-                    string_notes,
-                    capo,
-                }
-            }
-            pub fn with_capo(&self, capo: u8) -> Self {
-                Self { capo, ..*self }
-            }
-            pub fn fretted_note(&self, scale: &Scale, key: &Key, string: u8, fret: u8) -> Option<Note> {
-                if fret as usize >= self.fret_num() {
-                    None
-                } else if fret == 0 {
-                    self.open_note(scale, key, string)
-                } else {
-                    if string == 0 || string as usize > self.string_notes.len() {
-                        None
-                    } else {
-                        let semitones = self.string_notes[(string - 1) as usize];
-                        Some(self.get_capo_note(scale, key, semitones + Semitones(fret as i8)))
-                    }
-                }
-            }
-            pub fn string_num(&self) -> usize {
-                self.string_notes.len()
-            }
-            pub fn fret_num(&self) -> usize {
-                self.total_fret_num - self.capo as usize
-            }
-            fn get_capo_note(&self, scale: &Scale, key: &Key, note: Semitones) -> Note {
-                scale.calc_note_from_semitones(key, note + Semitones(self.capo as i8))
-            }
-            pub fn open_notes(&self, scale: &Scale, key: &Key) -> [Note; $strings] {
-                self.string_notes.map(|x| self.get_capo_note(scale, key, x))
-            }
-            /// string is 1-based.
-            pub fn open_note(&self, scale: &Scale, key: &Key, string: u8) -> Option<Note> {
-                if string == 0 || string as usize > self.string_notes.len() {
-                    None
-                } else {
-                    Some(self.get_capo_note(scale, key, self.string_notes[(string - 1) as usize]))
-                }
-            }
-            pub fn shape_note(&self, scale: &Scale, key: &Key, shape: &$hand_shape, string: u8) -> Option<Note> {
-                shape
-                    .string_fret_with_barre(string)
-                    .and_then(|fret| self.fretted_note(scale, key, string, fret))
-            }
-            pub fn shape_fret_note(&self, scale: &Scale, key: &Key, shape: &$hand_shape, string: u8) -> Option<(u8, Note)> {
-                shape
-                    .string_fret_with_barre(string)
-                    .and_then(|fret| self.fretted_note(scale, key, string, fret).map(|n| (fret, n)))
-            }
-            pub fn shape_pick_note(
-                &self, scale: &Scale, key: &Key,
-                shape: &$hand_shape,
-                pick_note: PickNote,
-            ) -> Option<Note> {
-                match pick_note.fret {
-                    Some(fret) => self.fretted_note(scale, key, pick_note.string, fret),
-                    None => self.shape_note(scale, key, shape, pick_note.string),
-                }
-            }
-            pub fn shape_pick_fret_note(
-                &self, scale: &Scale, key: &Key,
-                shape: &$hand_shape,
-                pick_note: PickNote,
-            ) -> Option<(u8, Note)> {
-                match pick_note.fret {
-                    Some(fret) => self
-                        .fretted_note(scale, key, pick_note.string, fret)
-                        .map(|note| (fret, note)),
-                    None => self.shape_fret_note(scale, key, shape, pick_note.string),
-                }
-            }
-            pub fn pick_tone(&self, scale: &Scale, key: &Key, shape: &$hand_shape, pick: &Pick) -> Tone {
-                let notes: Vec<Option<Note>> = pick
-                    .get_notes()
-                    .into_iter()
-                    .map(|x| self.shape_pick_note(scale, key, shape, x))
-                    .collect();
-                notes.into()
-            }
+    }
+    pub fn with_course_notes(&self, course_notes: Vec<Option<Semitones>>) -> Self {
+        Self {
+            course_notes,
+            ..self.clone()
+        }
+    }
+    pub fn with_start_frets(&self, start_frets: Vec<u8>) -> Self {
+        Self {
+            start_frets,
+            ..self.clone()
+        }
+    }
+    /// Semitones above the open string, string is 1-based.
+    fn fret_semitones(&self, string: u8, fret: u8) -> Option<Semitones> {
+        if string == 0 || string as usize > self.string_notes.len() {
+            return None;
+        }
+        let start_fret = self.start_fret(string);
+        if fret == 0 {
+            Some(Semitones(0))
+        } else if fret as usize >= self.fret_num() || fret < start_fret {
+            None
+        } else {
+            Some(Semitones((fret - start_fret) as i8))
         }
-    };
+    }
+    /// string is 1-based.
+    pub fn start_fret(&self, string: u8) -> u8 {
+        if string == 0 {
+            return 0;
+        }
+        self.start_frets
+            .get((string - 1) as usize)
+            .copied()
+            .unwrap_or(0)
+    }
+    pub fn fretted_note(&self, scale: &Scale, key: &Key, string: u8, fret: u8) -> Option<Note> {
+        self.fret_semitones(string, fret).map(|semitones| {
+            let open = self.string_notes[(string - 1) as usize];
+            self.get_capo_note(scale, key, open + semitones)
+        })
+    }
+    /// Note of the paired string in the course, if there is one.
+    pub fn course_note(&self, scale: &Scale, key: &Key, string: u8, fret: u8) -> Option<Note> {
+        self.fret_semitones(string, fret).and_then(|semitones| {
+            self.course_notes
+                .get((string - 1) as usize)
+                .copied()
+                .flatten()
+                .map(|open| self.get_capo_note(scale, key, open + semitones))
+        })
+    }
+    pub fn has_courses(&self) -> bool {
+        self.course_notes.iter().any(|x| x.is_some())
+    }
+    pub fn string_num(&self) -> usize {
+        self.string_notes.len()
+    }
+    pub fn fret_num(&self) -> usize {
+        self.total_fret_num - self.capo as usize
+    }
+    fn get_capo_note(&self, scale: &Scale, key: &Key, note: Semitones) -> Note {
+        scale.calc_note_from_semitones(key, note + Semitones(self.capo as i8))
+    }
+    pub fn open_notes(&self, scale: &Scale, key: &Key) -> Vec<Note> {
+        self.string_notes
+            .iter()
+            .map(|x| self.get_capo_note(scale, key, *x))
+            .collect()
+    }
+    /// string is 1-based.
+    pub fn open_note(&self, scale: &Scale, key: &Key, string: u8) -> Option<Note> {
+        if string == 0 || string as usize > self.string_notes.len() {
+            None
+        } else {
+            Some(self.get_capo_note(scale, key, self.string_notes[(string - 1) as usize]))
+        }
+    }
+    /// Open note of the tuning without the capo, string is 1-based.
+    pub fn tuning_note(&self, scale: &Scale, key: &Key, string: u8) -> Option<Note> {
+        if string == 0 || string as usize > self.string_notes.len() {
+            None
+        } else {
+            Some(scale.calc_note_from_semitones(key, self.string_notes[(string - 1) as usize]))
+        }
+    }
+    pub fn shape_note(
+        &self,
+        scale: &Scale,
+        key: &Key,
+        shape: &HandShape,
+        string: u8,
+    ) -> Option<Note> {
+        shape
+            .string_fret_with_barre(string)
+            .and_then(|fret| self.fretted_note(scale, key, string, fret))
+    }
+    pub fn shape_fret_note(
+        &self,
+        scale: &Scale,
+        key: &Key,
+        shape: &HandShape,
+        string: u8,
+    ) -> Option<(u8, Note)> {
+        shape.string_fret_with_barre(string).and_then(|fret| {
+            self.fretted_note(scale, key, string, fret)
+                .map(|n| (fret, n))
+        })
+    }
+    pub fn shape_pick_note(
+        &self,
+        scale: &Scale,
+        key: &Key,
+        shape: &HandShape,
+        pick_note: PickNote,
+    ) -> Option<Note> {
+        match pick_note.fret {
+            Some(fret) => self.fretted_note(scale, key, pick_note.string, fret),
+            None => self.shape_note(scale, key, shape, pick_note.string),
+        }
+    }
+    pub fn shape_pick_fret_note(
+        &self,
+        scale: &Scale,
+        key: &Key,
+        shape: &HandShape,
+        pick_note: PickNote,
+    ) -> Option<(u8, Note)> {
+        match pick_note.fret {
+            Some(fret) => self
+                .fretted_note(scale, key, pick_note.string, fret)
+                .map(|note| (fret, note)),
+            None => self.shape_fret_note(scale, key, shape, pick_note.string),
+        }
+    }
+    pub fn shape_pick_course_note(
+        &self,
+        scale: &Scale,
+        key: &Key,
+        shape: &HandShape,
+        pick_note: PickNote,
+    ) -> Option<Note> {
+        pick_note
+            .fret
+            .or_else(|| shape.string_fret_with_barre(pick_note.string))
+            .and_then(|fret| self.course_note(scale, key, pick_note.string, fret))
+    }
+    pub fn pick_course_notes(
+        &self,
+        scale: &Scale,
+        key: &Key,
+        shape: &HandShape,
+        pick: &Pick,
+    ) -> Vec<Note> {
+        pick.get_notes()
+            .into_iter()
+            .flat_map(|x| self.shape_pick_course_note(scale, key, shape, x))
+            .collect()
+    }
+    pub fn pick_tone(&self, scale: &Scale, key: &Key, shape: &HandShape, pick: &Pick) -> Tone {
+        let notes: Vec<Option<Note>> = pick
+            .get_notes()
+            .into_iter()
+            .map(|x| self.shape_pick_note(scale, key, shape, x))
+            .collect();
+        notes.into()
+    }
 }
-
-impl_fretboard!(Fretboard6, 6, HandShape6);
-impl_fretboard!(Fretboard4, 4, HandShape4);
@@ -21,118 +21,99 @@ impl Display for Finger {
     }
 }
 
-macro_rules! impl_hand_shape {
-    ($type:ident, $strings:literal) => {
-        #[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
-        pub struct $type {
-            pub barre: Option<u8>,
-            #[serde(with = "serde_arrays")]
-            pub frets: [Option<u8>; $strings],
-            #[serde(with = "serde_arrays")]
-            pub fingers: [Option<Finger>; $strings],
-        }
-        impl Display for $type {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "[")?;
-                for index in 0..self.frets.len() {
-                    let fret = self.frets[self.frets.len() - 1 - index];
-                    match fret {
-                        Some(fret) => write!(f, "{}", fret)?,
-                        None => write!(f, "x")?,
-                    }
-                }
-                write!(f, "]")?;
-                Ok(())
+/// Frets and fingers of the strings, the first one is the highest string.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct HandShape {
+    pub barre: Option<u8>,
+    pub frets: Vec<Option<u8>>,
+    pub fingers: Vec<Option<Finger>>,
+}
+impl Display for HandShape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[")?;
+        for fret in self.frets.iter().rev() {
+            match fret {
+                Some(fret) => write!(f, "{}", fret)?,
+                None => write!(f, "x")?,
             }
         }
-        impl Default for $type {
-            fn default() -> Self {
-                let frets = [Some(0); $strings];
-                let fingers = [None; $strings];
-                Self::new(frets, fingers)
-            }
+        write!(f, "]")?;
+        Ok(())
+    }
+}
+impl HandShape {
+    pub fn new_barre(barre: u8, frets: Vec<Option<u8>>, fingers: Vec<Option<Finger>>) -> Self {
+        let mut shape = Self::new(frets, fingers);
+        if barre > 0 {
+            shape.barre = Some(barre);
         }
-        impl $type {
-            pub fn new_barre(
-                barre: u8,
-                frets: [Option<u8>; $strings],
-                fingers: [Option<Finger>; $strings],
-            ) -> Self {
-                if barre == 0 {
-                    Self {
-                        barre: None,
-                        frets,
-                        fingers,
-                    }
-                } else {
-                    Self {
-                        barre: Some(barre),
-                        frets,
-                        fingers,
-                    }
-                }
-            }
-            pub fn new(frets: [Option<u8>; $strings], fingers: [Option<Finger>; $strings]) -> Self {
-                Self {
-                    barre: None,
-                    frets,
-                    fingers,
-                }
-            }
-            pub fn barre(&self) -> u8 {
-                self.barre.unwrap_or(0)
-            }
-            pub fn string_fret(&self, string: u8) -> Option<u8> {
-                if string == 0 || string as usize > self.frets.len() {
-                    None
-                } else {
-                    self.frets[string as usize - 1]
-                }
-            }
-            pub fn string_fret_with_barre(&self, string: u8) -> Option<u8> {
-                self.string_fret(string).map(|x| x + self.barre())
-            }
-            pub fn max_fret(&self) -> u8 {
-                let mut max = 0;
-                for index in 0..self.frets.len() {
-                    if let Some(fret) = self.frets[index] {
-                        if fret > max {
-                            max = fret
-                        }
-                    }
-                }
-                max
-            }
-            pub fn max_fret_with_barre(&self) -> u8 {
-                self.max_fret() + self.barre()
-            }
+        shape
+    }
+    /// Missing fingers are filled with `None`.
+    pub fn new(frets: Vec<Option<u8>>, fingers: Vec<Option<Finger>>) -> Self {
+        let mut fingers = fingers;
+        fingers.resize(frets.len(), None);
+        Self {
+            barre: None,
+            frets,
+            fingers,
         }
-
-        impl From<([Option<u8>; $strings], [Option<Finger>; $strings])> for $type {
-            fn from(v: ([Option<u8>; $strings], [Option<Finger>; $strings])) -> Self {
-                Self::new(v.0, v.1)
-            }
+    }
+    /// All strings open.
+    pub fn open(string_num: usize) -> Self {
+        Self::new(vec![Some(0); string_num], vec![])
+    }
+    pub fn string_num(&self) -> usize {
+        self.frets.len()
+    }
+    pub fn barre(&self) -> u8 {
+        self.barre.unwrap_or(0)
+    }
+    pub fn string_fret(&self, string: u8) -> Option<u8> {
+        if string == 0 {
+            None
+        } else {
+            self.frets.get(string as usize - 1).copied().flatten()
         }
-
-        impl From<[Option<u8>; $strings]> for $type {
-            fn from(v: [Option<u8>; $strings]) -> Self {
-                Self::new(v, [None; $strings])
-            }
+    }
+    pub fn string_fret_with_barre(&self, string: u8) -> Option<u8> {
+        self.string_fret(string).map(|x| x + self.barre())
+    }
+    pub fn string_finger(&self, string: u8) -> Option<Finger> {
+        if string == 0 {
+            None
+        } else {
+            self.fingers.get(string as usize - 1).copied().flatten()
         }
+    }
+    pub fn max_fret(&self) -> u8 {
+        self.frets.iter().flatten().copied().max().unwrap_or(0)
+    }
+    pub fn max_fret_with_barre(&self) -> u8 {
+        self.max_fret() + self.barre()
+    }
+}
 
-        impl From<(u8, [Option<u8>; $strings], [Option<Finger>; $strings])> for $type {
-            fn from(v: (u8, [Option<u8>; $strings], [Option<Finger>; $strings])) -> Self {
-                Self::new_barre(v.0, v.1, v.2)
-            }
-        }
+impl From<(Vec<Option<u8>>, Vec<Option<Finger>>)> for HandShape {
+    fn from(v: (Vec<Option<u8>>, Vec<Option<Finger>>)) -> Self {
+        Self::new(v.0, v.1)
+    }
+}
 
-        impl From<(u8, [Option<u8>; $strings])> for $type {
-            fn from(v: (u8, [Option<u8>; $strings])) -> Self {
-                Self::new_barre(v.0, v.1, [None; $strings])
-            }
-        }
-    };
+impl From<Vec<Option<u8>>> for HandShape {
+    fn from(v: Vec<Option<u8>>) -> Self {
+        Self::new(v, vec![])
+    }
 }
 
-impl_hand_shape!(HandShape6, 6);
-impl_hand_shape!(HandShape4, 4);
+impl From<(u8, Vec<Option<u8>>, Vec<Option<Finger>>)> for HandShape {
+    fn from(v: (u8, Vec<Option<u8>>, Vec<Option<Finger>>)) -> Self {
+        Self::new_barre(v.0, v.1, v.2)
+    }
+}
+
+impl From<(u8, Vec<Option<u8>>)> for HandShape {
+    fn from(v: (u8, Vec<Option<u8>>)) -> Self {
+        Self::new_barre(v.0, v.1, vec![])
+    }
+}
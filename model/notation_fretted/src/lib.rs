@@ -1,3 +1,4 @@
+pub mod articulation;
 pub mod fretboard;
 pub mod fretted_entry;
 pub mod hand;
@@ -6,11 +7,13 @@ pub mod strum;
 
 pub mod prelude {
     #[doc(hidden)]
-    pub use crate::fretboard::{Fretboard4, Fretboard6};
+    pub use crate::articulation::Articulation;
     #[doc(hidden)]
-    pub use crate::fretted_entry::{FrettedEntry4, FrettedEntry6};
+    pub use crate::fretboard::Fretboard;
     #[doc(hidden)]
-    pub use crate::hand::{Finger, HandShape4, HandShape6};
+    pub use crate::fretted_entry::FrettedEntry;
+    #[doc(hidden)]
+    pub use crate::hand::{Finger, HandShape};
     #[doc(hidden)]
     pub use crate::pick::{Pick, PickNote};
     #[doc(hidden)]
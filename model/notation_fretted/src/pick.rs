@@ -1,8 +1,9 @@
 use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
+use smallvec::SmallVec;
 
-use crate::prelude::Finger;
+use crate::prelude::{Articulation, Finger};
 use crate::strum::StrumDirection;
 
 #[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
@@ -12,12 +13,14 @@ pub struct PickNote {
     pub fret_finger: Option<Finger>,
     pub pick_finger: Option<Finger>,
     pub pick_direction: Option<StrumDirection>,
+    #[serde(default)]
+    pub articulation: Option<Articulation>,
 }
 impl Display for PickNote {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}{}{}{}{}",
+            "{}{}{}{}{}{}",
             self.string,
             self.fret
                 .map(|x| format!("@{}", x))
@@ -31,6 +34,9 @@ impl Display for PickNote {
             self.pick_direction
                 .map(|x| format!("*{}", x))
                 .unwrap_or("".to_string()),
+            self.articulation
+                .map(|x| format!("~{}", x))
+                .unwrap_or("".to_string()),
         )
     }
 }
@@ -48,6 +54,13 @@ impl PickNote {
             fret_finger,
             pick_finger,
             pick_direction,
+            articulation: None,
+        }
+    }
+    pub fn with_articulation(&self, articulation: Option<Articulation>) -> Self {
+        Self {
+            articulation,
+            ..*self
         }
     }
     pub fn new_string(string: u8) -> Self {
@@ -58,8 +71,17 @@ impl PickNote {
     }
 }
 
-#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
-pub enum Pick {
+/// Strings picked together, kept inline up to `INLINE_NUM`, spills to heap above that.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, Default)]
+#[serde(from = "PickData", into = "PickData")]
+pub struct Pick {
+    notes: SmallVec<[PickNote; Pick::INLINE_NUM]>,
+}
+
+/// Serialized form, the fixed variants are kept so that existing tabs can still be loaded,
+/// and picks with no more than 6 notes are still readable by older versions.
+#[derive(Clone, Serialize, Deserialize)]
+enum PickData {
     None,
     Single(PickNote),
     Double(PickNote, PickNote),
@@ -67,124 +89,149 @@ pub enum Pick {
     Tetra(PickNote, PickNote, PickNote, PickNote),
     Penta(PickNote, PickNote, PickNote, PickNote, PickNote),
     Hexa(PickNote, PickNote, PickNote, PickNote, PickNote, PickNote),
+    Notes(Vec<PickNote>),
+}
+
+impl From<PickData> for Pick {
+    fn from(v: PickData) -> Self {
+        match v {
+            PickData::None => Self::from(()),
+            PickData::Single(p1) => Self::from(p1),
+            PickData::Double(p1, p2) => Self::from((p1, p2)),
+            PickData::Triple(p1, p2, p3) => Self::from((p1, p2, p3)),
+            PickData::Tetra(p1, p2, p3, p4) => Self::from((p1, p2, p3, p4)),
+            PickData::Penta(p1, p2, p3, p4, p5) => Self::from((p1, p2, p3, p4, p5)),
+            PickData::Hexa(p1, p2, p3, p4, p5, p6) => Self::from((p1, p2, p3, p4, p5, p6)),
+            PickData::Notes(notes) => Self::from(notes),
+        }
+    }
+}
+
+impl From<Pick> for PickData {
+    fn from(v: Pick) -> Self {
+        match v.notes.as_slice() {
+            [] => Self::None,
+            [p1] => Self::Single(*p1),
+            [p1, p2] => Self::Double(*p1, *p2),
+            [p1, p2, p3] => Self::Triple(*p1, *p2, *p3),
+            [p1, p2, p3, p4] => Self::Tetra(*p1, *p2, *p3, *p4),
+            [p1, p2, p3, p4, p5] => Self::Penta(*p1, *p2, *p3, *p4, *p5),
+            [p1, p2, p3, p4, p5, p6] => Self::Hexa(*p1, *p2, *p3, *p4, *p5, *p6),
+            notes => Self::Notes(notes.to_vec()),
+        }
+    }
 }
 
 impl Display for Pick {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match *self {
-            Pick::None => write!(f, "<Pick>()"),
-            Pick::Single(p1) => write!(f, "<Pick>({})", p1),
-            Pick::Double(p1, p2) => write!(f, "<Pick>({}, {})", p1, p2),
-            Pick::Triple(p1, p2, p3) => write!(f, "<Pick>({}, {}, {})", p1, p2, p3),
-            Pick::Tetra(p1, p2, p3, p4) => {
-                write!(f, "<Pick>({}, {}, {}, {})", p1, p2, p3, p4)
-            }
-            Pick::Penta(p1, p2, p3, p4, p5) => {
-                write!(f, "<Pick>({}, {}, {}, {}, {})", p1, p2, p3, p4, p5)
-            }
-            Pick::Hexa(p1, p2, p3, p4, p5, p6) => {
-                write!(f, "<Pick>({}, {}, {}, {}, {}, {})", p1, p2, p3, p4, p5, p6)
-            }
-        }
+        let notes = self
+            .notes
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<String>>();
+        write!(f, "<Pick>({})", notes.join(", "))
     }
 }
 
 impl From<()> for Pick {
     fn from(_: ()) -> Self {
-        Self::None
+        Self::default()
     }
 }
 
 impl From<PickNote> for Pick {
     fn from(v: PickNote) -> Self {
-        Self::Single(v)
+        Self::new([v])
     }
 }
 
 impl From<(PickNote, PickNote)> for Pick {
     fn from(v: (PickNote, PickNote)) -> Self {
-        Self::Double(v.0, v.1)
+        Self::new([v.0, v.1])
     }
 }
 
 impl From<(PickNote, PickNote, PickNote)> for Pick {
     fn from(v: (PickNote, PickNote, PickNote)) -> Self {
-        Self::Triple(v.0, v.1, v.2)
+        Self::new([v.0, v.1, v.2])
     }
 }
 
 impl From<(PickNote, PickNote, PickNote, PickNote)> for Pick {
     fn from(v: (PickNote, PickNote, PickNote, PickNote)) -> Self {
-        Self::Tetra(v.0, v.1, v.2, v.3)
+        Self::new([v.0, v.1, v.2, v.3])
     }
 }
 
 impl From<(PickNote, PickNote, PickNote, PickNote, PickNote)> for Pick {
     fn from(v: (PickNote, PickNote, PickNote, PickNote, PickNote)) -> Self {
-        Self::Penta(v.0, v.1, v.2, v.3, v.4)
+        Self::new([v.0, v.1, v.2, v.3, v.4])
     }
 }
 
 impl From<(PickNote, PickNote, PickNote, PickNote, PickNote, PickNote)> for Pick {
     fn from(v: (PickNote, PickNote, PickNote, PickNote, PickNote, PickNote)) -> Self {
-        Self::Hexa(v.0, v.1, v.2, v.3, v.4, v.5)
+        Self::new([v.0, v.1, v.2, v.3, v.4, v.5])
     }
 }
 
 impl From<Vec<PickNote>> for Pick {
     fn from(v: Vec<PickNote>) -> Self {
-        match v.len() {
-            0 => Self::None,
-            1 => Self::from(v[0]),
-            2 => Self::from((v[0], v[1])),
-            3 => Self::from((v[0], v[1], v[2])),
-            4 => Self::from((v[0], v[1], v[2], v[3])),
-            5 => Self::from((v[0], v[1], v[2], v[3], v[4])),
-            6 => Self::from((v[0], v[1], v[2], v[3], v[4], v[5])),
-            _ => {
-                println!("PickNote lost: {}", v.len() - 6);
-                Self::from((v[0], v[1], v[2], v[3], v[4], v[5]))
-            }
-        }
+        Self::new(v)
     }
 }
 
 impl Pick {
-    pub fn get_notes(&self) -> Vec<PickNote> {
-        match *self {
-            Self::None => vec![],
-            Self::Single(p1) => vec![p1],
-            Self::Double(p1, p2) => vec![p1, p2],
-            Self::Triple(p1, p2, p3) => vec![p1, p2, p3],
-            Self::Tetra(p1, p2, p3, p4) => vec![p1, p2, p3, p4],
-            Self::Penta(p1, p2, p3, p4, p5) => vec![p1, p2, p3, p4, p5],
-            Self::Hexa(p1, p2, p3, p4, p5, p6) => vec![p1, p2, p3, p4, p5, p6],
+    pub const INLINE_NUM: usize = 6;
+    pub fn new<I: IntoIterator<Item = PickNote>>(notes: I) -> Self {
+        Self {
+            notes: notes.into_iter().collect(),
         }
     }
+    /// Returns `true` if the pick got no notes.
+    pub fn is_none(&self) -> bool {
+        self.is_empty()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.notes.is_empty()
+    }
+    pub fn len(&self) -> usize {
+        self.notes.len()
+    }
+    pub fn notes(&self) -> &[PickNote] {
+        self.notes.as_slice()
+    }
+    pub fn iter(&self) -> std::slice::Iter<'_, PickNote> {
+        self.notes.iter()
+    }
+    pub fn push(&mut self, note: PickNote) {
+        self.notes.push(note);
+    }
+    pub fn get_notes(&self) -> Vec<PickNote> {
+        self.notes.to_vec()
+    }
     pub fn get_pick_note(&self, string: u8) -> Option<PickNote> {
-        for note in self.get_notes().iter() {
-            if note.string == string {
-                return Some(note.clone());
-            }
-        }
-        None
+        self.notes.iter().find(|x| x.string == string).copied()
     }
     pub fn max_fret(&self) -> u8 {
-        let mut max = 0;
-        for note in self.get_notes().iter() {
-            if note.fret.is_some() {
-                let fret = note.fret.unwrap();
-                if fret > max {
-                    max = fret;
-                }
-            }
-        }
-        max
+        self.notes
+            .iter()
+            .filter_map(|x| x.fret)
+            .max()
+            .unwrap_or(0)
     }
 }
 
 impl From<Pick> for Vec<PickNote> {
     fn from(v: Pick) -> Self {
-        v.get_notes()
+        v.notes.into_vec()
+    }
+}
+
+impl<'a> IntoIterator for &'a Pick {
+    type Item = &'a PickNote;
+    type IntoIter = std::slice::Iter<'a, PickNote>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.notes.iter()
     }
 }
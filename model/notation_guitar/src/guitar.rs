@@ -1,6 +1,6 @@
 use crate::tuning::GuitarTuning;
 use notation_core::prelude::Semitones;
-use notation_fretted::prelude::Fretboard6;
+use notation_fretted::prelude::Fretboard;
 
 pub const GUITAR_STRING_NUM: usize = 6;
 
@@ -11,27 +11,32 @@ pub const GUITAR_FRET_NUM_ELECTRIC: usize = 22;
 pub struct GuitarUtil();
 
 impl GuitarUtil {
-    pub fn new_guitar_fretboard(total_fret_num: usize, strings: [Semitones; 6]) -> Fretboard6 {
-        Fretboard6 {
-            total_fret_num,
-            string_notes: strings,
-            capo: 0,
-        }
+    pub fn new_guitar_fretboard(total_fret_num: usize, strings: Vec<Semitones>) -> Fretboard {
+        Fretboard::new(total_fret_num, strings, 0)
     }
 
-    pub fn new_acoustic_guitar_fretboard(tuning: Option<GuitarTuning>) -> Fretboard6 {
+    pub fn new_acoustic_guitar_fretboard(tuning: Option<GuitarTuning>) -> Fretboard {
         Self::new_guitar_fretboard(GUITAR_FRET_NUM_ACOUSTIC, tuning.unwrap_or_default().into())
     }
 
-    pub fn new_classical_guitar_fretboard(tuning: Option<GuitarTuning>) -> Fretboard6 {
+    pub fn new_classical_guitar_fretboard(tuning: Option<GuitarTuning>) -> Fretboard {
         Self::new_guitar_fretboard(GUITAR_FRET_NUM_CLASSICAL, tuning.unwrap_or_default().into())
     }
 
-    pub fn new_electric_guitar_fretboard(tuning: Option<GuitarTuning>) -> Fretboard6 {
+    pub fn new_electric_guitar_fretboard(tuning: Option<GuitarTuning>) -> Fretboard {
         Self::new_guitar_fretboard(GUITAR_FRET_NUM_ELECTRIC, tuning.unwrap_or_default().into())
     }
 
-    pub fn new_default_fretboard() -> Fretboard6 {
+    pub fn new_default_fretboard() -> Fretboard {
         GuitarUtil::new_acoustic_guitar_fretboard(None)
     }
+
+    /// Applies the courses and short strings of the tuning as well, the string number is
+    /// the one of the tuning.
+    pub fn new_fretboard(total_fret_num: usize, tuning: &GuitarTuning, capo: u8) -> Fretboard {
+        let string_num = tuning.get_notes().len();
+        Fretboard::new(total_fret_num, tuning.clone().into(), capo)
+            .with_course_notes(tuning.get_course_notes(string_num))
+            .with_start_frets(tuning.get_start_frets(string_num))
+    }
 }
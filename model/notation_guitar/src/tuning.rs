@@ -2,59 +2,315 @@ use serde::{Deserialize, Serialize};
 
 use notation_core::prelude::{Semitones, Pitch, Octave};
 
-#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+/// Open notes of the strings, from string 1 (the highest) to the lowest.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, Default)]
 pub enum GuitarTuning {
+    #[default]
     Standard,
+    /// Half step down.
     StandardFlat,
     DropD,
+    DropC,
+    OpenG,
+    OpenD,
+    Dadgad,
+    /// Standard with the lower four strings one octave higher.
+    Nashville,
+    Ukulele,
+    /// Open G, with the short fifth string.
+    Banjo,
+    Mandolin,
+    Bass,
+    /// Bass with a low B string.
+    FiveStringBass,
+    SevenString,
+    EightString,
+    /// Standard with octave courses on the lower four strings.
+    TwelveString,
+    Custom(Vec<(Pitch, Octave)>),
 }
 
-impl Default for GuitarTuning {
-    fn default() -> Self {
-        Self::Standard
+impl GuitarTuning {
+    pub fn get_notes(&self) -> Vec<(Pitch, Octave)> {
+        match self {
+            GuitarTuning::Standard => vec![
+                (Pitch::E, Octave::P4),
+                (Pitch::B, Octave::P3),
+                (Pitch::G, Octave::P3),
+                (Pitch::D, Octave::P3),
+                (Pitch::A, Octave::P2),
+                (Pitch::E, Octave::P2),
+            ],
+            GuitarTuning::StandardFlat => vec![
+                (Pitch::E_FLAT, Octave::P4),
+                (Pitch::B_FLAT, Octave::P3),
+                (Pitch::G_FLAT, Octave::P3),
+                (Pitch::D_FLAT, Octave::P3),
+                (Pitch::A_FLAT, Octave::P2),
+                (Pitch::E_FLAT, Octave::P2),
+            ],
+            GuitarTuning::DropD => vec![
+                (Pitch::E, Octave::P4),
+                (Pitch::B, Octave::P3),
+                (Pitch::G, Octave::P3),
+                (Pitch::D, Octave::P3),
+                (Pitch::A, Octave::P2),
+                (Pitch::D, Octave::P2),
+            ],
+            GuitarTuning::DropC => vec![
+                (Pitch::D, Octave::P4),
+                (Pitch::A, Octave::P3),
+                (Pitch::F, Octave::P3),
+                (Pitch::C, Octave::P3),
+                (Pitch::G, Octave::P2),
+                (Pitch::C, Octave::P2),
+            ],
+            GuitarTuning::OpenG => vec![
+                (Pitch::D, Octave::P4),
+                (Pitch::B, Octave::P3),
+                (Pitch::G, Octave::P3),
+                (Pitch::D, Octave::P3),
+                (Pitch::G, Octave::P2),
+                (Pitch::D, Octave::P2),
+            ],
+            GuitarTuning::OpenD => vec![
+                (Pitch::D, Octave::P4),
+                (Pitch::A, Octave::P3),
+                (Pitch::F_SHARP, Octave::P3),
+                (Pitch::D, Octave::P3),
+                (Pitch::A, Octave::P2),
+                (Pitch::D, Octave::P2),
+            ],
+            GuitarTuning::Dadgad => vec![
+                (Pitch::D, Octave::P4),
+                (Pitch::A, Octave::P3),
+                (Pitch::G, Octave::P3),
+                (Pitch::D, Octave::P3),
+                (Pitch::A, Octave::P2),
+                (Pitch::D, Octave::P2),
+            ],
+            GuitarTuning::Nashville => vec![
+                (Pitch::E, Octave::P4),
+                (Pitch::B, Octave::P3),
+                (Pitch::G, Octave::P4),
+                (Pitch::D, Octave::P4),
+                (Pitch::A, Octave::P3),
+                (Pitch::E, Octave::P3),
+            ],
+            GuitarTuning::Ukulele => vec![
+                (Pitch::A, Octave::P4),
+                (Pitch::E, Octave::P4),
+                (Pitch::C, Octave::P4),
+                (Pitch::G, Octave::P4),
+            ],
+            GuitarTuning::Banjo => vec![
+                (Pitch::D, Octave::P4),
+                (Pitch::B, Octave::P3),
+                (Pitch::G, Octave::P3),
+                (Pitch::D, Octave::P3),
+                (Pitch::G, Octave::P4),
+            ],
+            GuitarTuning::Mandolin => vec![
+                (Pitch::E, Octave::P5),
+                (Pitch::A, Octave::P4),
+                (Pitch::D, Octave::P4),
+                (Pitch::G, Octave::P3),
+            ],
+            GuitarTuning::Bass => vec![
+                (Pitch::G, Octave::P2),
+                (Pitch::D, Octave::P2),
+                (Pitch::A, Octave::P1),
+                (Pitch::E, Octave::P1),
+            ],
+            GuitarTuning::FiveStringBass => {
+                let mut notes = Self::Bass.get_notes();
+                notes.push((Pitch::B, Octave::P0));
+                notes
+            }
+            GuitarTuning::SevenString => {
+                let mut notes = Self::Standard.get_notes();
+                notes.push((Pitch::B, Octave::P1));
+                notes
+            }
+            GuitarTuning::EightString => {
+                let mut notes = Self::SevenString.get_notes();
+                notes.push((Pitch::F_SHARP, Octave::P1));
+                notes
+            }
+            GuitarTuning::TwelveString => Self::Standard.get_notes(),
+            GuitarTuning::Custom(notes) => notes.clone(),
+        }
+    }
+    /// Tuning used when none is given for the string number.
+    pub fn default_of(string_num: usize) -> Option<Self> {
+        match string_num {
+            4 => Some(Self::Bass),
+            5 => Some(Self::FiveStringBass),
+            6 => Some(Self::Standard),
+            7 => Some(Self::SevenString),
+            8 => Some(Self::EightString),
+            _ => None,
+        }
+    }
+    /// The tuning itself if it got the string number, otherwise the default tuning of it.
+    pub fn or_default_of(&self, string_num: usize) -> Self {
+        if self.get_notes().len() == string_num {
+            return self.clone();
+        }
+        log::warn!(
+            "<GuitarTuning>.or_default_of({}) mismatched: {:?}",
+            string_num, self
+        );
+        Self::default_of(string_num).unwrap_or_else(|| self.clone())
+    }
+    /// Open notes of the strings, `None` if the tuning is for another string number.
+    pub fn get_string_notes(&self, string_num: usize) -> Option<Vec<Semitones>> {
+        let notes = self.get_notes();
+        if notes.len() != string_num {
+            return None;
+        }
+        Some(notes.into_iter().map(Semitones::from).collect())
+    }
+    pub fn get_course_notes(&self, string_num: usize) -> Vec<Option<Semitones>> {
+        let notes = match (self, self.get_string_notes(string_num)) {
+            (Self::TwelveString | Self::Mandolin, Some(notes)) => notes,
+            _ => return vec![None; string_num],
+        };
+        match self {
+            Self::TwelveString => notes
+                .iter()
+                .enumerate()
+                .map(|(index, x)| Some(if index < 2 { *x } else { *x + Semitones(12) }))
+                .collect(),
+            _ => notes.into_iter().map(Some).collect(),
+        }
+    }
+    pub fn get_start_frets(&self, string_num: usize) -> Vec<u8> {
+        match (self, string_num) {
+            (Self::Banjo, 5) => vec![0, 0, 0, 0, 5],
+            _ => vec![0; string_num],
+        }
     }
 }
 
-impl From<GuitarTuning> for [Semitones; 6] {
+impl From<GuitarTuning> for Vec<Semitones> {
     fn from(v: GuitarTuning) -> Self {
-        match v {
-            GuitarTuning::Standard => [
-                (Pitch::E, Octave::P4).into(),
-                (Pitch::B, Octave::P3).into(),
-                (Pitch::G, Octave::P3).into(),
-                (Pitch::D, Octave::P3).into(),
-                (Pitch::A, Octave::P2).into(),
-                (Pitch::E, Octave::P2).into(),
-            ],
-            GuitarTuning::StandardFlat => [
-                (Pitch::E_FLAT, Octave::P4).into(),
-                (Pitch::B_FLAT, Octave::P3).into(),
-                (Pitch::G_FLAT, Octave::P3).into(),
-                (Pitch::D_FLAT, Octave::P3).into(),
-                (Pitch::A_FLAT, Octave::P2).into(),
-                (Pitch::E_FLAT, Octave::P2).into(),
-            ],
-            GuitarTuning::DropD => [
-                (Pitch::E, Octave::P4).into(),
-                (Pitch::B, Octave::P3).into(),
-                (Pitch::G, Octave::P3).into(),
-                (Pitch::D, Octave::P3).into(),
-                (Pitch::A, Octave::P2).into(),
-                (Pitch::D, Octave::P2).into(),
-            ],
-        }
+        v.get_notes().into_iter().map(Semitones::from).collect()
     }
 }
 
 impl GuitarTuning {
     pub fn to_ident(&self) -> String {
-        format!("{:?}", self)
+        match self {
+            Self::Custom(notes) => format!(
+                "[{}]",
+                notes
+                    .iter()
+                    .map(|(pitch, octave)| format!("{} {}", pitch.to_text(), octave.to_ident()))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            _ => format!("{:?}", self),
+        }
     }
-    pub fn from_ident(ident: &str) -> Self {
-        match ident {
-            "StandardFlat" => Self::StandardFlat,
+    /// Presets by their names, custom tunings are not parsed here.
+    pub fn from_ident(ident: &str) -> Option<Self> {
+        Some(match ident {
+            "Standard" => Self::Standard,
+            "StandardFlat" | "HalfStepDown" => Self::StandardFlat,
             "DropD" => Self::DropD,
-            _ => Self::Standard,
+            "DropC" => Self::DropC,
+            "OpenG" => Self::OpenG,
+            "OpenD" => Self::OpenD,
+            "Dadgad" | "DADGAD" => Self::Dadgad,
+            "Nashville" => Self::Nashville,
+            "Ukulele" => Self::Ukulele,
+            "Banjo" => Self::Banjo,
+            "Mandolin" => Self::Mandolin,
+            "Bass" => Self::Bass,
+            "FiveStringBass" => Self::FiveStringBass,
+            "SevenString" => Self::SevenString,
+            "EightString" => Self::EightString,
+            "TwelveString" => Self::TwelveString,
+            _ => return None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use notation_core::prelude::{Octave, Pitch, Semitones};
+
+    use crate::prelude::GuitarTuning;
+
+    #[test]
+    fn test_from_ident() {
+        assert_eq!(
+            GuitarTuning::from_ident("Standard"),
+            Some(GuitarTuning::Standard)
+        );
+        assert_eq!(
+            GuitarTuning::from_ident("DADGAD"),
+            Some(GuitarTuning::Dadgad)
+        );
+        assert_eq!(
+            GuitarTuning::from_ident("HalfStepDown"),
+            Some(GuitarTuning::StandardFlat)
+        );
+        assert_eq!(GuitarTuning::from_ident("DropB"), None);
+        for string_num in 4..=8 {
+            let tuning = GuitarTuning::default_of(string_num).unwrap();
+            assert_eq!(GuitarTuning::from_ident(&tuning.to_ident()), Some(tuning));
         }
     }
+
+    #[test]
+    fn test_default_of() {
+        for string_num in 4..=8 {
+            let tuning = GuitarTuning::default_of(string_num).unwrap();
+            assert_eq!(tuning.get_notes().len(), string_num);
+        }
+        assert_eq!(GuitarTuning::default_of(3), None);
+        let lowest = |string_num| {
+            GuitarTuning::default_of(string_num)
+                .unwrap()
+                .get_notes()
+                .last()
+                .copied()
+        };
+        assert_eq!(lowest(4), Some((Pitch::E, Octave::P1)));
+        assert_eq!(lowest(5), Some((Pitch::B, Octave::P0)));
+        assert_eq!(lowest(6), Some((Pitch::E, Octave::P2)));
+        assert_eq!(lowest(7), Some((Pitch::B, Octave::P1)));
+        assert_eq!(lowest(8), Some((Pitch::F_SHARP, Octave::P1)));
+        assert_eq!(
+            GuitarTuning::FiveStringBass.get_notes().first(),
+            Some(&(Pitch::G, Octave::P2))
+        );
+    }
+
+    #[test]
+    fn test_string_notes() {
+        assert_eq!(GuitarTuning::Standard.get_string_notes(7), None);
+        assert_eq!(GuitarTuning::SevenString.get_string_notes(6), None);
+        assert_eq!(
+            GuitarTuning::DropD.or_default_of(7),
+            GuitarTuning::SevenString
+        );
+        assert_eq!(
+            GuitarTuning::Ukulele.or_default_of(4),
+            GuitarTuning::Ukulele
+        );
+        let notes: Vec<Semitones> = GuitarTuning::Standard.into();
+        assert_eq!(notes.len(), 6);
+        assert_eq!(notes[5], Semitones::from((Pitch::E, Octave::P2)));
+        assert_eq!(
+            GuitarTuning::TwelveString.get_course_notes(6)[2],
+            Some(Semitones::from((Pitch::G, Octave::P4)))
+        );
+        assert_eq!(
+            GuitarTuning::TwelveString.get_course_notes(7),
+            vec![None; 7]
+        );
+    }
 }
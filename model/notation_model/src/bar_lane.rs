@@ -109,6 +109,24 @@ impl BarLane {
     pub fn bar_props(&self) -> TabBarProps {
         self.bar().map(|x| x.props).unwrap_or_default()
     }
+    /// Verses with lines in this lane, sorted.
+    pub fn verses(&self) -> Vec<u8> {
+        let mut verses: Vec<u8> = self.entries.iter().filter_map(|x| x.props.verse).collect();
+        verses.sort();
+        verses.dedup();
+        verses
+    }
+    /// Row of the verse when the lines are stacked, entries without verse are on the first row.
+    pub fn calc_verse_row(&self, verse: Option<u8>) -> usize {
+        match verse {
+            Some(verse) => self
+                .verses()
+                .iter()
+                .position(|x| *x == verse)
+                .unwrap_or(0),
+            None => 0,
+        }
+    }
     pub fn get_entry_at<T, F: Fn(&LaneEntry) -> Option<T>>(
         &self,
         in_bar_pos: Units,
@@ -133,7 +151,7 @@ impl BarLane {
         predicate: &F,
     ) -> Option<T> {
         self.get_entry(&|x: &LaneEntry| {
-            if x.props.in_bar_pos.is_bigger_than(&in_bar_pos) {
+            if x.props.in_bar_pos > in_bar_pos {
                 predicate(x)
             } else {
                 None
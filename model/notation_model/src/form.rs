@@ -18,7 +18,7 @@ impl Form {
         let mut add_section =
             |section_id: String| match tab_section.iter().find(|x| x.id == section_id).cloned() {
                 Some(section) => sections.push(section),
-                None => println!("Form::from(), bad section: {}", section_id),
+                None => log::warn!("Form::from(), bad section: {}", section_id),
             };
         if add_ready_section {
             add_section(notation_proto::prelude::Section::READY_ID.to_string());
@@ -1,7 +1,9 @@
 use std::fmt::Display;
 use std::sync::{Arc, Weak};
 
-use crate::prelude::{BarLane, BarLaneProps, ModelEntry, Tab, TabBar, TabBarProps, Track};
+use crate::prelude::{
+    BarLane, BarLaneProps, LaneKind, ModelEntry, Tab, TabBar, TabBarProps, Track,
+};
 use notation_proto::prelude::{
     BarPosition, Duration, Entry, EntryPassMode, ProtoEntry, TrackKind, Units, Slice,
 };
@@ -14,6 +16,8 @@ pub struct LaneEntryProps {
     pub in_bar_pos: Units,
     pub tied_units: Units,
     pub duration: Duration,
+    /// Verse of the lyrics line the entry belongs to.
+    pub verse: Option<u8>,
 }
 
 #[derive(Clone, Debug)]
@@ -43,9 +47,16 @@ impl LaneEntry {
             in_bar_pos,
             tied_units: model.tied_units(),
             duration: model.duration(),
+            verse: None,
         };
         Self { lane, model, props }
     }
+    pub fn with_verse(self, verse: Option<u8>) -> Self {
+        Self {
+            props: LaneEntryProps { verse, ..self.props },
+            ..self
+        }
+    }
 }
 impl Entry for LaneEntry {
     fn duration(&self) -> notation_proto::prelude::Duration {
@@ -75,6 +86,44 @@ impl LaneEntry {
             self.props.in_bar_pos,
         )
     }
+    /// Where and how long the entry is played, grace notes take no bar time, so they are
+    /// played around the beat of the following note.
+    pub fn sounding_position(&self) -> (BarPosition, Units) {
+        let offset = self.calc_grace_offset();
+        let units = if self.props.duration.is_grace() {
+            self.props.duration.grace_units()
+        } else {
+            (self.props.tied_units - offset).max(Units::ZERO)
+        };
+        (self.bar_position().with_delay(offset), units)
+    }
+    /// Acciaccaturas are played right before the beat, but not before the start of the tab,
+    /// appoggiaturas are played on the beat and push back the notes after them.
+    pub fn calc_grace_offset(&self) -> Units {
+        let lane = match self.lane.upgrade() {
+            Some(lane) => lane,
+            None => return Units::ZERO,
+        };
+        let sum_grace_units = |entries: &mut dyn Iterator<Item = &Arc<LaneEntry>>, acciaccatura: bool| {
+            entries
+                .take_while(|x| x.props.duration.is_grace())
+                .filter(|x| matches!(x.props.duration, Duration::Acciaccatura(_)) == acciaccatura)
+                .fold(Units::ZERO, |acc, x| acc + x.props.duration.grace_units())
+        };
+        let index = self.props.index.min(lane.entries.len());
+        match self.props.duration {
+            Duration::Acciaccatura(_) => {
+                let offset = -sum_grace_units(&mut lane.entries[index..].iter(), true);
+                offset.max(-Units::from(self.bar_position()))
+            }
+            _ => sum_grace_units(&mut lane.entries[..index].iter().rev(), false),
+        }
+    }
+    pub fn next_is_fermata(&self) -> bool {
+        self.next()
+            .map(|x| x.proto().is_core_fermata())
+            .unwrap_or(false)
+    }
 }
 impl LaneEntry {
     pub fn lane(&self) -> Option<Arc<BarLane>> {
@@ -126,6 +175,46 @@ impl LaneEntry {
             None
         }
     }
+    /// Units of the melisma entries right after this one, in the same verse.
+    pub fn melisma_units(&self) -> Units {
+        let mut units = Units::ZERO;
+        if let Some(lane) = self.lane.upgrade() {
+            for entry in lane.entries.iter().skip(self.props.index + 1) {
+                if entry.props.verse != self.props.verse {
+                    break;
+                }
+                match entry.proto().as_lyric() {
+                    Some(lyric) if lyric.is_melisma() => {
+                        units = units + Units::from(lyric.duration());
+                    }
+                    _ => break,
+                }
+            }
+        }
+        units
+    }
+    /// Melody entries sung on this syllable, including its melisma.
+    pub fn get_melisma_entries(&self) -> Vec<Arc<LaneEntry>> {
+        let begin = self.props.in_bar_pos;
+        let end = begin + self.props.tied_units + self.melisma_units();
+        let melody = self
+            .bar()
+            .and_then(|bar| bar.get_lane_of_kind(LaneKind::Melody, None));
+        match melody {
+            Some(lane) => lane
+                .entries
+                .iter()
+                .filter(|x| {
+                    x.props.duration != Duration::Zero
+                        && x.proto().as_core().and_then(|x| x.as_tone()).is_some()
+                        && x.props.in_bar_pos >= begin
+                        && x.props.in_bar_pos < end
+                })
+                .cloned()
+                .collect(),
+            None => Vec::new(),
+        }
+    }
     pub fn track_id(&self) -> String {
         self.model.track_id()
     }
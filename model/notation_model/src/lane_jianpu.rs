@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+
+use notation_proto::prelude::{
+    CoreEntry, Duration, Entry, Key, Note, ProtoEntry, Scale, Semitones, Signature, Syllable,
+    TabMeta, Unit, Units,
+};
+
+use crate::prelude::{BarLane, LaneEntry, Track};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct JianpuNote {
+    pub note: Note,
+    /// Dots above the number if positive, below if negative.
+    pub octave: i8,
+}
+
+impl JianpuNote {
+    /// Accidentals are written before the number, as `#4` or `b7`.
+    pub fn text(&self) -> String {
+        let text = self.note.syllable.to_text();
+        let (number, sign) = text.split_at(1);
+        format!("{}{}", sign, number)
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct JianpuEntry {
+    /// Index of the entry in the lane.
+    pub index: usize,
+    pub in_bar_pos: Units,
+    pub duration: Duration,
+    /// Empty for rests, which are written as 0.
+    pub notes: Vec<JianpuNote>,
+    pub underlines: usize,
+    /// Each dash holds the note for another quarter.
+    pub dashes: usize,
+    pub dotted: bool,
+    /// Tied to the next note, which might be in the next bar.
+    pub tie_to_next: bool,
+    pub beam: Option<usize>,
+}
+
+impl JianpuEntry {
+    pub fn is_rest(&self) -> bool {
+        self.notes.is_empty()
+    }
+    pub fn calc_underlines(duration: &Duration) -> usize {
+        match duration.unit() {
+            Some(Unit::Eighth) => 1,
+            Some(Unit::Sixteenth) => 2,
+            Some(Unit::ThirtySecondth) => 3,
+            Some(Unit::SixtyFourth) => 4,
+            _ => 0,
+        }
+    }
+    /// Notes of a half or longer are written with dashes, including the dotted ones.
+    pub fn calc_dashes(duration: &Duration) -> (usize, bool) {
+        let quarters = Units::from(*duration) / Units::from(Unit::Quarter);
+        let dotted = duration.dots() > 0;
+        if quarters >= Units::from(2) {
+            (quarters.floor() as usize - 1, false)
+        } else {
+            (0, dotted)
+        }
+    }
+}
+
+/// Jianpu (numbered musical notation) of one lane in a bar.
+#[derive(Clone, PartialEq, Debug)]
+pub struct LaneJianpu {
+    pub base_octave: i16,
+    pub signature: Signature,
+    pub entries: Vec<JianpuEntry>,
+    /// Entries sharing underlines, as indexes into `entries`.
+    pub beams: Vec<Vec<usize>>,
+}
+
+impl LaneJianpu {
+    /// Octave of the note counted from the `Do` of the key, octave 4 is 0.
+    pub fn calc_octave(scale: &Scale, key: &Key, note: &Note) -> i16 {
+        let do_pitch = scale.calc_pitch(key, &Syllable::Do);
+        let semitones = Semitones::from(note.octave).0 as i16 + Semitones::from(note.pitch).0 as i16
+            - Semitones::from(do_pitch).0 as i16
+            - Semitones::from(note.syllable).0 as i16;
+        (semitones as f32 / 12.0).round() as i16 - 4
+    }
+    /// The octave with most notes of the track is written without dots.
+    pub fn calc_base_octave(track: &Track, meta: &TabMeta) -> i16 {
+        let mut counts: HashMap<i16, usize> = HashMap::new();
+        for tone in track
+            .entries
+            .iter()
+            .filter_map(|x| x.proto.as_core())
+            .filter_map(|x| x.as_tone())
+        {
+            for note in tone.get_notes() {
+                *counts
+                    .entry(Self::calc_octave(&meta.scale, &meta.key, &note))
+                    .or_default() += 1;
+            }
+        }
+        counts
+            .into_iter()
+            .max_by_key(|(octave, count)| (*count, -*octave))
+            .map(|(octave, _)| octave)
+            .unwrap_or(0)
+    }
+    fn new_entry(meta: &TabMeta, base_octave: i16, entry: &LaneEntry) -> Option<JianpuEntry> {
+        let notes = match entry.proto() {
+            ProtoEntry::Core(CoreEntry::Tone(tone, _)) => tone.get_notes(),
+            ProtoEntry::Core(CoreEntry::Rest(_)) => Vec::new(),
+            _ => return None,
+        };
+        let mut notes: Vec<JianpuNote> = notes
+            .into_iter()
+            .map(|note| JianpuNote {
+                note,
+                octave: (Self::calc_octave(&meta.scale, &meta.key, &note) - base_octave) as i8,
+            })
+            .collect();
+        notes.sort_by_key(|x| Semitones::from(x.note).0);
+        let duration = entry.props.duration;
+        let (dashes, dotted) = JianpuEntry::calc_dashes(&duration);
+        Some(JianpuEntry {
+            index: entry.props.index,
+            in_bar_pos: entry.props.in_bar_pos,
+            duration,
+            underlines: JianpuEntry::calc_underlines(&duration),
+            dashes,
+            dotted,
+            tie_to_next: !notes.is_empty() && entry.next_is_tie(),
+            notes,
+            beam: None,
+        })
+    }
+    pub fn new(lane: &BarLane) -> Self {
+        let meta = lane.bar().map(|x| x.tab_meta()).unwrap_or_default();
+        let base_octave = Self::calc_base_octave(&lane.track, &meta);
+        let entries = lane
+            .entries
+            .iter()
+            .filter_map(|x| Self::new_entry(&meta, base_octave, x))
+            .collect();
+        let mut jianpu = Self {
+            base_octave,
+            signature: meta.signature,
+            entries,
+            beams: Vec::new(),
+        };
+        jianpu.calc_beams();
+        jianpu
+    }
+    /// Underlined entries are joined within each beat, rests included.
+    fn calc_beams(&mut self) {
+        let beat_units = Units::from(self.signature.beat_unit);
+        let mut beams: Vec<Vec<usize>> = Vec::new();
+        let mut current: Option<(usize, Vec<usize>)> = None;
+        for (index, entry) in self.entries.iter().enumerate() {
+            let beat = (entry.in_bar_pos / beat_units).floor() as usize;
+            match current.as_mut() {
+                Some((current_beat, indexes)) if entry.underlines > 0 && *current_beat == beat => {
+                    indexes.push(index);
+                }
+                _ => {
+                    if let Some((_, indexes)) = current.take() {
+                        beams.push(indexes);
+                    }
+                    if entry.underlines > 0 {
+                        current = Some((beat, vec![index]));
+                    }
+                }
+            }
+        }
+        if let Some((_, indexes)) = current {
+            beams.push(indexes);
+        }
+        for (beam, indexes) in beams.iter().enumerate() {
+            for index in indexes.iter() {
+                self.entries[*index].beam = Some(beam);
+            }
+        }
+        self.beams = beams;
+    }
+    pub fn get_entry(&self, index: usize) -> Option<&JianpuEntry> {
+        self.entries.iter().find(|x| x.index == index)
+    }
+}
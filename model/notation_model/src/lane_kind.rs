@@ -1,7 +1,7 @@
 use std::fmt::Display;
 use std::sync::Arc;
 
-use notation_proto::prelude::{FrettedEntry6, ProtoEntry, TrackKind};
+use notation_proto::prelude::{FrettedEntry, ProtoEntry, TrackKind};
 
 use crate::prelude::ModelEntry;
 
@@ -10,9 +10,11 @@ pub enum LaneKind {
     None,
     Meta,
     Chord,
+    Jianpu,
     Lyrics,
     Melody,
     Harmony,
+    Staff,
     Keyboard,
     Shapes,
     Strings,
@@ -28,18 +30,20 @@ impl Default for LaneKind {
     }
 }
 impl LaneKind {
-    pub const LEN: usize = 9;
+    pub const LEN: usize = 11;
     pub fn order(&self) -> usize {
         match self {
             LaneKind::None => 0,
             LaneKind::Meta => 1,
             LaneKind::Chord => 2,
-            LaneKind::Lyrics => 3,
-            LaneKind::Melody => 4,
-            LaneKind::Harmony => 5,
-            LaneKind::Keyboard => 6,
-            LaneKind::Shapes => 7,
-            LaneKind::Strings => 8,
+            LaneKind::Jianpu => 3,
+            LaneKind::Lyrics => 4,
+            LaneKind::Melody => 5,
+            LaneKind::Harmony => 6,
+            LaneKind::Staff => 7,
+            LaneKind::Keyboard => 8,
+            LaneKind::Shapes => 9,
+            LaneKind::Strings => 10,
         }
     }
     pub fn of_entry(track_kind: &TrackKind, entry: &ProtoEntry) -> Self {
@@ -49,19 +53,18 @@ impl LaneKind {
             TrackKind::Chord => Self::Chord,
             TrackKind::Lyrics => Self::Lyrics,
             TrackKind::Vocal => Self::Melody,
-            TrackKind::Guitar => match entry {
-                ProtoEntry::Fretted6(entry) => match entry {
-                    FrettedEntry6::Pick(_, _) => Self::Strings,
-                    FrettedEntry6::Strum(_, _) => Self::Strings,
-                    FrettedEntry6::Shape(_, _) => Self::Shapes,
-                    FrettedEntry6::Fretboard(_) => Self::None,
+            TrackKind::Guitar | TrackKind::Bass => match entry {
+                ProtoEntry::Fretted(entry) => match entry {
+                    FrettedEntry::Pick(_, _) => Self::Strings,
+                    FrettedEntry::Strum(_, _) => Self::Strings,
+                    FrettedEntry::Shape(_, _) => Self::Shapes,
+                    FrettedEntry::Fretboard(_) => Self::None,
                 },
                 _ => Self::None,
             },
             TrackKind::Synth => Self::Keyboard,
             TrackKind::Piano => Self::Keyboard,
-            TrackKind::Drums => Self::None,
-            TrackKind::Bass => Self::None,
+            TrackKind::Drums => Self::Keyboard,
         }
     }
     pub fn of_entries(track_kind: &TrackKind, entries: &Vec<Arc<ModelEntry>>) -> LaneKind {
@@ -95,6 +98,13 @@ impl LaneKind {
         matches!(self, Self::Chord)
     }
 
+    /// Returns `true` if the lane kind is [`Jianpu`].
+    ///
+    /// [`Jianpu`]: LaneKind::Jianpu
+    pub fn is_jianpu(&self) -> bool {
+        matches!(self, Self::Jianpu)
+    }
+
     /// Returns `true` if the lane kind is [`Lyrics`].
     ///
     /// [`Lyrics`]: LaneKind::Lyrics
@@ -116,6 +126,13 @@ impl LaneKind {
         matches!(self, Self::Harmony)
     }
 
+    /// Returns `true` if the lane kind is [`Staff`].
+    ///
+    /// [`Staff`]: LaneKind::Staff
+    pub fn is_staff(&self) -> bool {
+        matches!(self, Self::Staff)
+    }
+
     /// Returns `true` if the lane kind is [`Keyboard`].
     ///
     /// [`Keyboard`]: LaneKind::Keyboard
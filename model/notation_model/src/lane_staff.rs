@@ -0,0 +1,308 @@
+use notation_proto::prelude::{
+    Clef, CoreEntry, Duration, Entry, FrettedEntry, KeySignature, Note, PitchSign,
+    ProtoEntry, Signature, StaffAccidentals, TabMeta, Tone, TrackKind, Unit, Units,
+};
+
+use crate::prelude::{BarLane, LaneEntry, TabBar, Track};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct StaffNote {
+    pub note: Note,
+    /// Steps above the middle line of the staff.
+    pub step: i16,
+    pub accidental: Option<PitchSign>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct StaffEntry {
+    /// Index of the entry in the lane.
+    pub index: usize,
+    pub in_bar_pos: Units,
+    pub duration: Duration,
+    /// Empty for rests.
+    pub notes: Vec<StaffNote>,
+    /// Tied to the next note, which might be in the next bar.
+    pub tie_to_next: bool,
+    /// Held by a fermata when playing.
+    pub fermata: bool,
+    pub beam: Option<usize>,
+    pub tuplet: Option<usize>,
+}
+
+impl StaffEntry {
+    pub fn is_rest(&self) -> bool {
+        self.notes.is_empty()
+    }
+    pub fn unit(&self) -> Option<Unit> {
+        self.duration.unit()
+    }
+    pub fn is_dotted(&self) -> bool {
+        self.duration.dots() > 0
+    }
+    pub fn dots(&self) -> u8 {
+        self.duration.dots()
+    }
+    /// Grace notes are drawn smaller, before the note they lead to.
+    pub fn is_grace(&self) -> bool {
+        self.duration.is_grace()
+    }
+    /// Number of flags, or beams when beamed.
+    pub fn flags(&self) -> usize {
+        match self.unit() {
+            Some(Unit::Eighth) => 1,
+            Some(Unit::Sixteenth) => 2,
+            Some(Unit::ThirtySecondth) => 3,
+            Some(Unit::SixtyFourth) => 4,
+            _ => 0,
+        }
+    }
+    pub fn has_stem(&self) -> bool {
+        !self.is_rest() && self.unit() != Some(Unit::Whole)
+    }
+    pub fn is_hollow(&self) -> bool {
+        matches!(self.unit(), Some(Unit::Whole) | Some(Unit::Half))
+    }
+    pub fn lowest_step(&self) -> i16 {
+        self.notes.iter().map(|x| x.step).min().unwrap_or(0)
+    }
+    pub fn highest_step(&self) -> i16 {
+        self.notes.iter().map(|x| x.step).max().unwrap_or(0)
+    }
+    /// Stem goes up when the notes are mostly below the middle line.
+    pub fn is_stem_up(&self) -> bool {
+        self.lowest_step() + self.highest_step() < 0
+    }
+}
+
+/// Unit and n:m ratio shared by the notes of a tuplet.
+type TupletKey = (Unit, u8, u8);
+
+/// Staff notation of one lane in a bar, the view only needs to draw these.
+#[derive(Clone, PartialEq, Debug)]
+pub struct LaneStaff {
+    pub clef: Clef,
+    pub key_signature: KeySignature,
+    pub signature: Signature,
+    pub entries: Vec<StaffEntry>,
+    /// Entries beamed together, as indexes into `entries`.
+    pub beams: Vec<Vec<usize>>,
+    /// Entries under the same tuplet bracket, as indexes into `entries`.
+    pub tuplets: Vec<Vec<usize>>,
+}
+
+impl LaneStaff {
+    /// Length of the stem, in staff steps.
+    pub const STEM_STEPS: i16 = 7;
+
+    pub fn calc_clef(track: &Track) -> Clef {
+        match track.kind {
+            TrackKind::Guitar => Clef::Treble8vb,
+            TrackKind::Bass => Clef::Bass,
+            _ => {
+                let notes: Vec<_> = track
+                    .entries
+                    .iter()
+                    .filter_map(|x| x.proto.as_core())
+                    .filter_map(|x| x.as_tone())
+                    .flat_map(|x| x.get_notes())
+                    .collect();
+                Clef::of_notes(&notes)
+            }
+        }
+    }
+    pub fn get_entry_tone(bar: &TabBar, meta: &TabMeta, entry: &LaneEntry) -> Option<Tone> {
+        match entry.proto() {
+            ProtoEntry::Core(CoreEntry::Tone(tone, _)) => Some(tone.clone()),
+            ProtoEntry::Fretted(FrettedEntry::Pick(pick, _)) => bar
+                .get_fretted_shape(entry)
+                .map(|(fretboard, shape)| fretboard.pick_tone(&meta.scale, &meta.key, &shape, pick)),
+            _ => None,
+        }
+    }
+    pub fn new(lane: &BarLane) -> Self {
+        let meta = lane
+            .bar()
+            .map(|x| x.tab_meta())
+            .unwrap_or_default();
+        let clef = Self::calc_clef(&lane.track);
+        let key_signature = KeySignature::new(&meta.scale, &meta.key);
+        let mut accidentals = StaffAccidentals::new(key_signature);
+        let mut entries = Vec::new();
+        if let Some(bar) = lane.bar() {
+            for entry in lane.entries.iter() {
+                let is_rest = entry
+                    .proto()
+                    .as_core()
+                    .map(|x| x.is_rest())
+                    .unwrap_or(false);
+                let tone = Self::get_entry_tone(&bar, &meta, entry);
+                if !is_rest && tone.is_none() {
+                    continue;
+                }
+                let mut notes: Vec<StaffNote> = tone
+                    .map(|x| x.get_notes())
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|note| StaffNote {
+                        note,
+                        step: clef.calc_step(&note),
+                        accidental: accidentals.calc_accidental(&note),
+                    })
+                    .collect();
+                notes.sort_by_key(|x| x.step);
+                entries.push(StaffEntry {
+                    index: entry.props.index,
+                    in_bar_pos: entry.props.in_bar_pos,
+                    duration: entry.props.duration,
+                    tie_to_next: !notes.is_empty() && entry.next_is_tie(),
+                    fermata: entry.next_is_fermata(),
+                    notes,
+                    beam: None,
+                    tuplet: None,
+                });
+            }
+        }
+        Self::remove_covered_rests(&mut entries);
+        let mut staff = Self {
+            clef,
+            key_signature,
+            signature: meta.signature,
+            entries,
+            beams: Vec::new(),
+            tuplets: Vec::new(),
+        };
+        staff.calc_beams();
+        staff.calc_tuplets();
+        staff
+    }
+    /// Only one voice is drawn, rests of other voices are skipped while notes are sounding.
+    fn remove_covered_rests(entries: &mut Vec<StaffEntry>) {
+        let sounding: Vec<(Units, Units)> = entries
+            .iter()
+            .filter(|x| !x.is_rest())
+            .map(|x| (x.in_bar_pos, x.in_bar_pos + Units::from(x.duration)))
+            .collect();
+        entries.retain(|entry| {
+            !entry.is_rest()
+                || !sounding.iter().any(|(begin, end)| {
+                    entry.in_bar_pos >= *begin && entry.in_bar_pos < *end
+                })
+        });
+    }
+    /// Notes shorter than a quarter are beamed within each beat, or each group of 3 beats
+    /// for compound meters.
+    fn calc_beams(&mut self) {
+        let mut group_units = Units::from(self.signature.beat_unit);
+        if self.signature.is_compound() {
+            group_units = group_units * 3;
+        }
+        let mut beams: Vec<Vec<usize>> = Vec::new();
+        let mut current: Option<(usize, Vec<usize>)> = None;
+        for (index, entry) in self.entries.iter().enumerate() {
+            let beat = (entry.in_bar_pos / group_units).floor() as usize;
+            let beamable = !entry.is_rest() && !entry.is_grace() && entry.flags() > 0;
+            match current.as_mut() {
+                Some((current_beat, indexes)) if beamable && *current_beat == beat => {
+                    indexes.push(index);
+                }
+                _ => {
+                    if let Some((_, indexes)) = current.take() {
+                        if indexes.len() > 1 {
+                            beams.push(indexes);
+                        }
+                    }
+                    if beamable {
+                        current = Some((beat, vec![index]));
+                    }
+                }
+            }
+        }
+        if let Some((_, indexes)) = current {
+            if indexes.len() > 1 {
+                beams.push(indexes);
+            }
+        }
+        for (beam, indexes) in beams.iter().enumerate() {
+            for index in indexes.iter() {
+                self.entries[*index].beam = Some(beam);
+            }
+        }
+        self.beams = beams;
+    }
+    /// Tuplets of the same unit and ratio are grouped until they fill m of the unit,
+    /// grace notes are skipped.
+    fn calc_tuplets(&mut self) {
+        let mut tuplets: Vec<Vec<usize>> = Vec::new();
+        let mut current: Option<(TupletKey, Units, Vec<usize>)> = None;
+        for (index, entry) in self.entries.iter().enumerate() {
+            if entry.is_grace() {
+                continue;
+            }
+            let key = match (entry.unit(), entry.duration.tuplet()) {
+                (Some(unit), Some((n, m))) => Some((unit, n, m)),
+                _ => None,
+            };
+            if current.as_ref().map(|x| Some(x.0) != key).unwrap_or(false) {
+                tuplets.push(current.take().unwrap().2);
+            }
+            if let Some(key) = key {
+                let (_, units, indexes) =
+                    current.get_or_insert((key, Units::ZERO, Vec::new()));
+                *units = *units + Units::from(entry.duration);
+                indexes.push(index);
+                let full = Units::from(key.0) * key.2 as i64;
+                if *units >= full {
+                    tuplets.push(current.take().unwrap().2);
+                }
+            }
+        }
+        if let Some((_, _, indexes)) = current {
+            tuplets.push(indexes);
+        }
+        for (tuplet, indexes) in tuplets.iter().enumerate() {
+            for index in indexes.iter() {
+                self.entries[*index].tuplet = Some(tuplet);
+            }
+        }
+        self.tuplets = tuplets;
+    }
+    pub fn get_entry(&self, index: usize) -> Option<&StaffEntry> {
+        self.entries.iter().find(|x| x.index == index)
+    }
+    pub fn is_stem_up(&self, entry: &StaffEntry) -> bool {
+        match entry.beam {
+            Some(beam) => {
+                let (lowest, highest) = self.calc_beam_range(beam);
+                lowest + highest < 0
+            }
+            None => entry.is_stem_up(),
+        }
+    }
+    fn calc_beam_range(&self, beam: usize) -> (i16, i16) {
+        let indexes = &self.beams[beam];
+        let lowest = indexes
+            .iter()
+            .map(|x| self.entries[*x].lowest_step())
+            .min()
+            .unwrap_or(0);
+        let highest = indexes
+            .iter()
+            .map(|x| self.entries[*x].highest_step())
+            .max()
+            .unwrap_or(0);
+        (lowest, highest)
+    }
+    /// Step of the end of the stem, beamed entries share a flat beam.
+    pub fn calc_stem_end_step(&self, entry: &StaffEntry) -> i16 {
+        let stem_up = self.is_stem_up(entry);
+        let (lowest, highest) = match entry.beam {
+            Some(beam) => self.calc_beam_range(beam),
+            None => (entry.lowest_step(), entry.highest_step()),
+        };
+        if stem_up {
+            (highest + Self::STEM_STEPS).max(0)
+        } else {
+            (lowest - Self::STEM_STEPS).min(0)
+        }
+    }
+}
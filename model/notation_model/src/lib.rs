@@ -4,13 +4,17 @@ pub mod bar;
 pub mod bar_lane;
 pub mod form;
 pub mod lane_entry;
+pub mod lane_jianpu;
 pub mod lane_kind;
+pub mod lane_staff;
 pub mod model_entry;
 pub mod parse;
 pub mod section;
 pub mod tab;
 pub mod tab_bar;
 pub mod tab_chord;
+pub mod tab_timeline;
+pub mod timed_lyrics;
 pub mod track;
 pub mod util;
 
@@ -26,8 +30,12 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::lane_entry::{LaneEntry, LaneEntryProps};
     #[doc(hidden)]
+    pub use crate::lane_jianpu::{JianpuEntry, JianpuNote, LaneJianpu};
+    #[doc(hidden)]
     pub use crate::lane_kind::LaneKind;
     #[doc(hidden)]
+    pub use crate::lane_staff::{LaneStaff, StaffEntry, StaffNote};
+    #[doc(hidden)]
     pub use crate::model_entry::{ModelEntry, ModelEntryProps};
     #[doc(hidden)]
     pub use crate::parse::ParseError;
@@ -40,6 +48,10 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::tab_chord::TabChord;
     #[doc(hidden)]
+    pub use crate::tab_timeline::{TabTimeMap, TabTimeline, TimelineEntry};
+    #[doc(hidden)]
+    pub use crate::timed_lyrics::{TimedLyricLine, TimedLyricWord, TimedLyrics};
+    #[doc(hidden)]
     pub use crate::track::{Track, TrackProps};
     #[doc(hidden)]
     pub use notation_proto::prelude::Bar as ProtoBar;
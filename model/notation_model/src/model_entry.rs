@@ -2,7 +2,7 @@ use std::sync::{Arc, Weak};
 
 use crate::prelude::{Tab, Track};
 use notation_proto::prelude::{
-    Duration, Entry, EntryPassMode, FrettedEntry4, FrettedEntry6, ProtoEntry, TrackKind, Units,
+    Duration, Entry, EntryPassMode, FrettedEntry, ProtoEntry, TrackKind, Units,
 };
 
 #[derive(Copy, Clone, Debug)]
@@ -56,11 +56,8 @@ impl ModelEntry {
     pub fn tab(&self) -> Option<Arc<Tab>> {
         self.track().and_then(|x| x.tab())
     }
-    pub fn as_fretted6(&self) -> Option<&FrettedEntry6> {
-        self.proto.as_fretted6()
-    }
-    pub fn as_fretted4(&self) -> Option<&FrettedEntry4> {
-        self.proto.as_fretted4()
+    pub fn as_fretted(&self) -> Option<&FrettedEntry> {
+        self.proto.as_fretted()
     }
     pub fn prev(&self) -> Option<Arc<ModelEntry>> {
         if self.props.index == 0 {
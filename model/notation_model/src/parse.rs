@@ -21,6 +21,7 @@ impl Tab {
         Arc::<Tab>::new_cyclic(|weak_self| {
             let uuid = proto.uuid;
             let meta = Arc::new(proto.meta);
+            let info = Arc::new(proto.info);
             let tracks = proto
                 .tracks
                 .into_iter()
@@ -32,7 +33,7 @@ impl Tab {
                 let section_id = section.id.clone();
                 match Section::try_new(weak_self.clone(), index, section, &tracks).map(Arc::new) {
                     Ok(section) => sections.push(section),
-                    Err(err) => println!(
+                    Err(err) => log::warn!(
                         "Tab::try_parse_arc(), bad section: {} {} -> {}",
                         index, section_id, err
                     ),
@@ -83,6 +84,7 @@ impl Tab {
             Self {
                 uuid,
                 meta,
+                info,
                 tracks,
                 sections,
                 form,
@@ -112,14 +114,14 @@ impl Tab {
             ));
             section_ordinal += 1;
             bar_ordinal += section.bars.len();
-            println!(
+            log::debug!(
                 "new_tab_bars() section: {} <{}> -> {:?} bars",
                 section.id,
                 section.kind,
                 section.bars.len()
             );
         }
-        println!("new_tab_bars() -> {:?} bars", bars.len());
+        log::debug!("new_tab_bars() -> {:?} bars", bars.len());
         bars
     }
 }
@@ -161,7 +163,7 @@ impl Section {
 }
 impl ModelEntry {
     pub fn calc_tied_units(entries: &Vec<ProtoEntry>, index: usize) -> Units {
-        let mut units = Units(0.0);
+        let mut units = Units::ZERO;
         if let Some(entry) = entries.get(index) {
             units = units + Units::from(entry.duration());
             if let Some(next_entry) = entries.get(index + 1) {
@@ -192,13 +194,19 @@ impl ModelEntry {
 }
 impl LaneEntry {
     pub fn new_entries(v: Vec<Arc<ModelEntry>>, lane: &Weak<BarLane>, slice: Slice) -> Vec<Arc<LaneEntry>> {
-        let mut pos = 0.0;
+        let mut pos = Units::ZERO;
+        let mut verse = None;
         v.into_iter()
             .enumerate()
             .map(|(index, entry)| {
+                if let Some(v) = entry.proto.as_lyric().and_then(|x| x.as_verse()) {
+                    pos = Units::ZERO;
+                    verse = Some(v);
+                }
                 let in_bar_pos = pos;
-                pos += Units::from(entry.as_ref().duration()).0;
-                LaneEntry::new(lane.clone(), slice.clone(), index, index, entry, Units(in_bar_pos))
+                pos = pos + Units::from(entry.as_ref().duration());
+                LaneEntry::new(lane.clone(), slice.clone(), index, index, entry, in_bar_pos)
+                    .with_verse(verse)
             })
             .map(Arc::new)
             .collect()
@@ -1,17 +1,19 @@
 use std::fmt::Display;
 use std::sync::Arc;
 
-use notation_proto::prelude::{BarPosition, Note, TrackKind, Octave};
+use notation_proto::prelude::{BarPosition, Entry, Note, TrackKind, Octave};
 use uuid::Uuid;
 
 use crate::prelude::{
-    Form, Pitch, Section, Signature, Syllable, TabBar, TabMeta, Track, Unit, Units,
+    Form, Pitch, Section, Signature, Syllable, TabBar, TabInfo, TabMeta, TabTimeline, Track, Unit,
+    Units,
 };
 
 #[derive(Debug)]
 pub struct Tab {
     pub uuid: Uuid,
     pub meta: Arc<TabMeta>,
+    pub info: Arc<TabInfo>,
     pub tracks: Vec<Arc<Track>>,
     pub sections: Vec<Arc<Section>>,
     pub form: Form,
@@ -31,6 +33,12 @@ impl Display for Tab {
     }
 }
 impl Tab {
+    pub fn title(&self) -> String {
+        match self.info.title.is_empty() {
+            true => self.uuid.to_string(),
+            false => self.info.title.clone(),
+        }
+    }
     pub fn bar_units(&self) -> Units {
         self.meta.bar_units()
     }
@@ -66,4 +74,37 @@ impl Tab {
     pub fn get_bar(&self, pos: BarPosition) -> Option<Arc<TabBar>> {
         self.get_bar_of_ordinal(pos.bar_ordinal)
     }
+    /// Begin and end of the entries held by fermatas as played with the groove, in tab
+    /// units, overlapping spans are merged.
+    pub fn get_fermata_spans(&self) -> Vec<(Units, Units)> {
+        let mut spans = Vec::new();
+        for bar in self.bars.iter() {
+            for (_, lane) in bar.lanes.iter() {
+                for entry in lane.entries.iter() {
+                    if entry.next_is_fermata() {
+                        let (pos, units) =
+                            self.meta.apply_groove(&entry.bar_position(), entry.tied_units());
+                        let begin = Units::from(pos);
+                        let end = begin + units;
+                        if end > begin {
+                            spans.push((begin, end));
+                        }
+                    }
+                }
+            }
+        }
+        spans.sort();
+        let mut merged: Vec<(Units, Units)> = Vec::new();
+        for (begin, end) in spans {
+            match merged.last_mut() {
+                Some(last) if begin <= last.1 => last.1 = last.1.max(end),
+                _ => merged.push((begin, end)),
+            }
+        }
+        merged
+    }
+    /// All the entries in playback order, with their notes and timing.
+    pub fn timeline(&self) -> TabTimeline<'_> {
+        TabTimeline::new(self)
+    }
 }
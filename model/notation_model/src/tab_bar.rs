@@ -3,7 +3,7 @@ use std::sync::{Arc, Weak};
 use std::collections::HashMap;
 
 use notation_proto::prelude::{
-    BarPosition, Chord, Fretboard4, Fretboard6, HandShape4, HandShape6, Note, Position,
+    BarPosition, Chord, Fretboard, HandShape, Note, Position,
     TabPosition, Octave,
 };
 
@@ -25,7 +25,7 @@ pub struct TabBarProps {
 
 impl From<TabBarProps> for BarPosition {
     fn from(v: TabBarProps) -> Self {
-        BarPosition::new(v.bar_units, v.bar_ordinal, Units(0.0))
+        BarPosition::new(v.bar_units, v.bar_ordinal, Units::ZERO)
     }
 }
 
@@ -119,13 +119,13 @@ impl TabBar {
         })
     }
     pub fn tab_position(&self) -> TabPosition {
-        TabPosition::new(Units(self.props.bar_ordinal as f32 * self.bar_units().0))
+        TabPosition::new(self.bar_units() * self.props.bar_ordinal as i64)
     }
     pub fn tab_meta(&self) -> Arc<TabMeta> {
         match self.tab.upgrade() {
             Some(tab) => tab.meta.clone(),
             None => {
-                println!("<TabBar>.bar_units() tab_meta missing: {}", self);
+                log::warn!("<TabBar>.bar_units() tab_meta missing: {}", self);
                 Arc::new(TabMeta::default())
             }
         }
@@ -153,6 +153,13 @@ impl TabBar {
     pub fn tab(&self) -> Option<Arc<Tab>> {
         self.tab.upgrade().map(|x| x.clone())
     }
+    /// Verse to be sung in this round of the section, entries without verse are always active.
+    pub fn is_active_verse(&self, verse: Option<u8>) -> bool {
+        match verse {
+            Some(verse) => verse as usize == self.props.section_round,
+            None => true,
+        }
+    }
     pub fn get_lane_of_kind(
         &self,
         kind: LaneKind,
@@ -182,7 +189,7 @@ impl TabBar {
             .iter()
             .filter_map(| ((_k, _i), lane) | {
                 lane.get_entry(&|x: &LaneEntry| {
-                    if x.props.in_bar_pos.is_bigger_than(&in_bar_pos) {
+                    if x.props.in_bar_pos > in_bar_pos {
                         predicate(x)
                     } else {
                         None
@@ -196,13 +203,13 @@ impl TabBar {
         in_bar_pos: Units,
         predicate: &F,
     ) -> Option<T> {
-        let mut result_in_bar_pos = Units(f32::MAX);
+        let mut result_in_bar_pos = Units::MAX;
         let mut result = None;
         for ((_k, _i), lane) in self.lanes.iter() {
             if let Some((in_bar_pos, entry)) = lane.get_entry(&|x: &LaneEntry| {
                 if result_in_bar_pos < x.props.in_bar_pos {
                     None
-                } else if x.props.in_bar_pos.is_bigger_than(&in_bar_pos) {
+                } else if x.props.in_bar_pos > in_bar_pos {
                     if let Some(t) = predicate(x) {
                         Some((x.props.in_bar_pos, t))
                     } else {
@@ -258,47 +265,27 @@ impl TabBar {
     }
 }
 
-macro_rules! impl_get_fretted_shape {
-    ($name:ident, $strings:literal, $as_fretted:ident, $get_fretboard:ident, $fretboard:ident, $hand_shape:ident) => {
-        impl TabBar {
-            pub fn $name(&self, entry: &LaneEntry) -> Option<($fretboard, $hand_shape)> {
-                entry
-                    .track()
-                    .and_then(|t| t.$get_fretboard())
-                    .map(|fretboard| {
-                        let shape = self
-                            .get_entry_in_other_lane(
-                                LaneKind::Shapes,
-                                Some(entry.lane_props().track.index),
-                                Some(entry.props.in_bar_pos),
-                                &|x: &LaneEntry| {
-                                    x.model()
-                                        .$as_fretted()
-                                        .and_then(|y| y.as_shape())
-                                        .map(|y| y.clone())
-                                },
-                            )
-                            .unwrap_or_default();
-                        (fretboard, shape)
-                    })
-            }
-        }
-    };
+impl TabBar {
+    /// Shape of the pick entry in the shapes lane, all strings open if there is none.
+    pub fn get_fretted_shape(&self, entry: &LaneEntry) -> Option<(Fretboard, HandShape)> {
+        entry
+            .track()
+            .and_then(|t| t.get_fretboard())
+            .map(|fretboard| {
+                let shape = self
+                    .get_entry_in_other_lane(
+                        LaneKind::Shapes,
+                        Some(entry.lane_props().track.index),
+                        Some(entry.props.in_bar_pos),
+                        &|x: &LaneEntry| {
+                            x.model()
+                                .as_fretted()
+                                .and_then(|y| y.as_shape())
+                                .cloned()
+                        },
+                    )
+                    .unwrap_or_else(|| HandShape::open(fretboard.string_num()));
+                (fretboard, shape)
+            })
+    }
 }
-
-impl_get_fretted_shape!(
-    get_fretted_shape6,
-    6,
-    as_fretted6,
-    get_fretboard6,
-    Fretboard6,
-    HandShape6
-);
-impl_get_fretted_shape!(
-    get_fretted_shape4,
-    4,
-    as_fretted4,
-    get_fretboard4,
-    Fretboard4,
-    HandShape4
-);
@@ -1,7 +1,7 @@
 use std::fmt::Display;
 use std::sync::Arc;
 
-use notation_proto::prelude::{Chord, Position};
+use notation_proto::prelude::{Chord, ChordAnalysis, Position};
 
 use crate::prelude::{ModelEntry, Tab};
 use crate::tab_bar::TabBar;
@@ -31,6 +31,11 @@ impl TabChord {
     pub fn first_entry(&self) -> Option<Arc<ModelEntry>> {
         self.entries.first().map(|x| x.clone())
     }
+    /// Analysis against the scale of the tab.
+    pub fn calc_analysis(&self) -> Option<ChordAnalysis> {
+        self.tab()
+            .map(|tab| ChordAnalysis::new(&self.chord, &tab.meta.scale))
+    }
     pub fn calc_bars(tab: Option<Arc<Tab>>, chord: Chord) -> Vec<Arc<TabBar>> {
         let mut bars = Vec::new();
         if tab.is_some() {
@@ -0,0 +1,337 @@
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::sync::Arc;
+
+use notation_proto::prelude::{
+    BarPosition, Bpm, CoreEntry, Entry, EntryPassMode, FrettedEntry, Note, ProtoEntry, TabMeta,
+    TabPosition, Units,
+};
+
+use crate::prelude::{LaneEntry, Tab, TabBar};
+
+/// Converts tab positions to seconds at normal speed, all the seconds of the timeline
+/// are calculated here. The groove is applied to the positions of the entries, and the
+/// notes held by fermatas are played slower, the same way as the player does.
+#[derive(Clone, Debug)]
+pub struct TabTimeMap {
+    pub meta: TabMeta,
+    pub seconds_per_unit: f32,
+    /// Begin and end of the notes held by fermatas, in tab units.
+    pub fermatas: Vec<(Units, Units)>,
+    /// Playing speed factor while a note held by a fermata is sounding.
+    pub fermata_speed: f32,
+}
+
+impl TabTimeMap {
+    pub const FERMATA_SPEED: f32 = 0.5;
+
+    pub fn new(tab: &Tab) -> Self {
+        let meta = *tab.meta;
+        let units_per_second =
+            Bpm::from(meta.tempo) as f32 / 60.0 * Units::from(meta.signature.beat_unit).to_f32();
+        Self {
+            meta,
+            seconds_per_unit: 1.0 / units_per_second,
+            fermatas: tab.get_fermata_spans(),
+            fermata_speed: Self::FERMATA_SPEED,
+        }
+    }
+    pub fn with_fermata_speed(self, fermata_speed: f32) -> Self {
+        if fermata_speed <= 0.0 {
+            return self;
+        }
+        Self {
+            fermata_speed,
+            ..self
+        }
+    }
+    pub fn is_held(&self, pos: Units) -> bool {
+        self.fermatas
+            .iter()
+            .any(|(begin, end)| pos >= *begin && pos < *end)
+    }
+    /// Seconds of a position already grooved, as the player reaches it.
+    pub fn calc_seconds(&self, pos: Units) -> f32 {
+        let held = self
+            .fermatas
+            .iter()
+            .filter(|(begin, _)| *begin < pos)
+            .fold(Units::ZERO, |acc, (begin, end)| acc + (*end).min(pos) - *begin);
+        (pos.to_f32() + held.to_f32() * (1.0 / self.fermata_speed - 1.0)) * self.seconds_per_unit
+    }
+    /// Begin and end seconds of written notes, with the groove applied.
+    pub fn calc_played_seconds(&self, pos: &BarPosition, units: Units) -> (f32, f32) {
+        let (pos, units) = self.meta.apply_groove(pos, units);
+        let begin = Units::from(pos);
+        (self.calc_seconds(begin), self.calc_seconds(begin + units))
+    }
+}
+
+/// An entry of the tab at its place in the playback, with the notes it plays.
+#[derive(Clone, Debug)]
+pub struct TimelineEntry {
+    pub bar: Arc<TabBar>,
+    pub entry: Arc<LaneEntry>,
+    pub position: TabPosition,
+    /// Seconds of `position` at normal speed.
+    pub seconds: f32,
+    pub section_round: usize,
+    /// Notes of tones, picks and shapes, empty for other entries.
+    pub notes: Vec<Note>,
+    /// Grace notes are played around the beat, so they don't start at `position`.
+    pub sounding_delay: Units,
+    /// How long the notes sound through the tied entries, zero for the entries continued
+    /// from a tie, since they are not played again.
+    pub sounding_units: Units,
+    pub sounding_seconds: f32,
+}
+impl Display for TimelineEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "<TimelineEntry>({} {} R:{} N:{} {})",
+            self.position.in_tab_pos,
+            self.entry.track_id(),
+            self.section_round,
+            self.notes.len(),
+            self.entry.proto(),
+        )
+    }
+}
+impl TimelineEntry {
+    pub fn sounding_position(&self) -> TabPosition {
+        TabPosition::new(self.position.in_tab_pos + self.sounding_delay)
+    }
+    /// Tones and picks to be played, shapes got notes too, but they are only held by the hand.
+    pub fn is_played(&self) -> bool {
+        matches!(self.entry.pass_mode(), EntryPassMode::Delayed)
+            && !self.notes.is_empty()
+            && self.sounding_units > Units::ZERO
+    }
+    fn new(
+        meta: &TabMeta,
+        time_map: &TabTimeMap,
+        bar: &Arc<TabBar>,
+        entry: &Arc<LaneEntry>,
+    ) -> Self {
+        let position = TabPosition::new(Units::from(entry.bar_position()));
+        let (sounding_position, units) = entry.sounding_position();
+        let sounding_units = if entry.prev_is_tie() {
+            Units::ZERO
+        } else {
+            units
+        };
+        let sounding_begin = Units::from(sounding_position);
+        let (begin_seconds, end_seconds) =
+            time_map.calc_played_seconds(&sounding_position, sounding_units);
+        Self {
+            bar: bar.clone(),
+            entry: entry.clone(),
+            position,
+            seconds: time_map.calc_played_seconds(&entry.bar_position(), Units::ZERO).0,
+            section_round: bar.props.section_round,
+            notes: Self::get_notes(meta, bar, entry),
+            sounding_delay: sounding_begin - position.in_tab_pos,
+            sounding_units,
+            sounding_seconds: end_seconds - begin_seconds,
+        }
+    }
+    fn get_notes(meta: &TabMeta, bar: &TabBar, entry: &LaneEntry) -> Vec<Note> {
+        match entry.proto() {
+            ProtoEntry::Core(CoreEntry::Tone(tone, _)) => tone.get_notes(),
+            ProtoEntry::Fretted(x) => Self::get_fretted_notes(meta, bar, entry, x),
+            _ => Vec::new(),
+        }
+    }
+    /// Picks are played with the shape of the bar at their position, shapes got the
+    /// notes of all the strings not muted.
+    fn get_fretted_notes(
+        meta: &TabMeta,
+        bar: &TabBar,
+        entry: &LaneEntry,
+        fretted_entry: &FrettedEntry,
+    ) -> Vec<Note> {
+        match fretted_entry {
+            FrettedEntry::Pick(pick, _) => match bar.get_fretted_shape(entry) {
+                Some((fretboard, shape)) => {
+                    let mut notes = fretboard
+                        .pick_tone(&meta.scale, &meta.key, &shape, pick)
+                        .get_notes();
+                    notes.extend(fretboard.pick_course_notes(&meta.scale, &meta.key, &shape, pick));
+                    notes
+                }
+                None => Vec::new(),
+            },
+            FrettedEntry::Shape(shape, _) => match entry.track().and_then(|x| x.get_fretboard()) {
+                Some(fretboard) => (1..=fretboard.string_num() as u8)
+                    .filter_map(|string| fretboard.shape_note(&meta.scale, &meta.key, shape, string))
+                    .collect(),
+                None => Vec::new(),
+            },
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Entries of all the tracks in playback order, bar by bar, entries at the same position
+/// are ordered by track, then by lane. Lyrics of verses not sung in the round are skipped.
+pub struct TabTimeline<'a> {
+    tab: &'a Tab,
+    time_map: TabTimeMap,
+    bar_ordinal: usize,
+    pending: VecDeque<TimelineEntry>,
+}
+
+impl<'a> TabTimeline<'a> {
+    pub fn new(tab: &'a Tab) -> Self {
+        Self {
+            tab,
+            time_map: TabTimeMap::new(tab),
+            bar_ordinal: 0,
+            pending: VecDeque::new(),
+        }
+    }
+    pub fn time_map(&self) -> &TabTimeMap {
+        &self.time_map
+    }
+    fn get_bar_entries(&self, bar: &Arc<TabBar>) -> Vec<TimelineEntry> {
+        let mut entries: Vec<(Units, usize, usize, usize, TimelineEntry)> = Vec::new();
+        for (_, lane) in bar.lanes.iter() {
+            for entry in lane.entries.iter() {
+                if !bar.is_active_verse(entry.props.verse) {
+                    continue;
+                }
+                entries.push((
+                    entry.props.in_bar_pos,
+                    lane.track.props.index,
+                    lane.props.index,
+                    entry.props.index,
+                    TimelineEntry::new(&self.tab.meta, &self.time_map, bar, entry),
+                ));
+            }
+        }
+        entries.sort_by_key(|(in_bar_pos, track_index, lane_index, index, _)| {
+            (*in_bar_pos, *track_index, *lane_index, *index)
+        });
+        entries.into_iter().map(|x| x.4).collect()
+    }
+}
+
+impl<'a> Iterator for TabTimeline<'a> {
+    type Item = TimelineEntry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(entry) = self.pending.pop_front() {
+                return Some(entry);
+            }
+            let bar = self.tab.bars.get(self.bar_ordinal)?;
+            self.bar_ordinal += 1;
+            self.pending = self.get_bar_entries(bar).into();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use notation_dsl::prelude::parse_tab;
+    use notation_proto::prelude::Units;
+
+    use crate::prelude::Tab;
+
+    /// A quarter note is a second, the pairs of eighths are played 2:1, the third note
+    /// is held by a fermata.
+    const TAB: &str = r#"
+    tab! {
+        "3e0c5b7a-2f4d-4c1e-8b6a-9d7f1e2c3b4a"
+        Meta: C Major 4 _4 60 Swing8 67
+        Tracks: [
+            {vocal Vocal [
+                $duration = _1_8
+                "v" Tone [ 1 2 3 ! 4 5 6 7 1 ] |
+            ]}
+        ]
+        Sections: [
+            {"A" Verse [
+                {
+                    vocal [ "v" | ]
+                }
+            ]}
+        ]
+        Form: "A"
+    }
+    "#;
+
+    fn new_tab() -> std::sync::Arc<Tab> {
+        Tab::try_parse_arc(parse_tab(TAB).unwrap(), false, None).unwrap()
+    }
+
+    fn assert_seconds(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 0.001,
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_fermata_spans() {
+        let tab = new_tab();
+        let time_map = tab.timeline().time_map().clone();
+        assert_eq!(
+            time_map.fermatas,
+            vec![(Units::new(1, 4), Units::new(167, 400))]
+        );
+        assert!(time_map.is_held(Units::new(1, 4)));
+        assert!(!time_map.is_held(Units::new(167, 400)));
+        assert_seconds(time_map.calc_seconds(Units::new(1, 1)), 4.67);
+        assert_seconds(
+            time_map
+                .with_fermata_speed(0.25)
+                .calc_seconds(Units::new(1, 1)),
+            6.01,
+        );
+    }
+
+    #[test]
+    fn test_seconds() {
+        let played: Vec<(f32, f32)> = new_tab()
+            .timeline()
+            .filter(|x| x.is_played())
+            .map(|x| (x.seconds, x.sounding_seconds))
+            .collect();
+        let expected = [
+            (0.0, 0.67),
+            (0.67, 0.33),
+            (1.0, 1.34),
+            (2.34, 0.33),
+            (2.67, 0.67),
+            (3.34, 0.33),
+            (3.67, 0.67),
+            (4.34, 0.33),
+        ];
+        assert_eq!(played.len(), expected.len());
+        for ((seconds, sounding), (expected_seconds, expected_sounding)) in
+            played.into_iter().zip(expected)
+        {
+            assert_seconds(seconds, expected_seconds);
+            assert_seconds(sounding, expected_sounding);
+        }
+    }
+
+    #[test]
+    fn test_grace_at_start() {
+        let text = TAB
+            .replace(" Swing8 67", "")
+            .replace("[ 1 2 3 ! 4 5 6 7 1 ]", "[ 1? 2 3 ]");
+        let tab = Tab::try_parse_arc(parse_tab(&text).unwrap(), false, None).unwrap();
+        let played: Vec<(Units, f32)> = tab
+            .timeline()
+            .filter(|x| x.is_played())
+            .map(|x| (x.sounding_position().in_tab_pos, x.seconds))
+            .collect();
+        assert_eq!(played[0], (Units::ZERO, 0.0));
+        assert_eq!(played[1].0, Units::ZERO);
+    }
+}
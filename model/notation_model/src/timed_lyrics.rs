@@ -0,0 +1,267 @@
+use std::fmt::Write;
+
+use notation_proto::prelude::{LyricJoin, TabInfo, TrackKind, Units};
+
+use crate::prelude::Tab;
+
+/// A syllable with its position in the tab, both in units and in seconds at normal speed.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TimedLyricWord {
+    pub text: String,
+    /// Joined with the next syllable without space.
+    pub joined: bool,
+    pub begin_units: Units,
+    pub end_units: Units,
+    pub begin_seconds: f32,
+    pub end_seconds: f32,
+}
+
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct TimedLyricLine {
+    pub words: Vec<TimedLyricWord>,
+}
+impl TimedLyricLine {
+    pub fn begin_units(&self) -> Units {
+        self.words.first().map(|x| x.begin_units).unwrap_or(Units::ZERO)
+    }
+    pub fn end_units(&self) -> Units {
+        self.words.last().map(|x| x.end_units).unwrap_or(Units::ZERO)
+    }
+    pub fn begin_seconds(&self) -> f32 {
+        self.words.first().map(|x| x.begin_seconds).unwrap_or(0.0)
+    }
+    pub fn end_seconds(&self) -> f32 {
+        self.words.last().map(|x| x.end_seconds).unwrap_or(0.0)
+    }
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        for word in self.words.iter() {
+            text.push_str(&word.text);
+            if !word.joined {
+                text.push(' ');
+            }
+        }
+        text.trim_end().to_owned()
+    }
+}
+
+/// Lyrics of a tab as timed lines, for karaoke and subtitles.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct TimedLyrics {
+    pub lines: Vec<TimedLyricLine>,
+}
+
+impl TimedLyrics {
+    /// Words of the first lyrics track, timed by the timeline of the tab.
+    pub fn new(tab: &Tab) -> Self {
+        let track_index = match tab.get_track_of_kind(TrackKind::Lyrics) {
+            Some(track) => track.props.index,
+            None => return Self::default(),
+        };
+        let line_gap = Units::from(tab.meta.signature.beat_unit);
+        let mut lines = Vec::new();
+        let mut line = TimedLyricLine::default();
+        let mut section_ordinal = None;
+        let mut timeline = tab.timeline();
+        let time_map = timeline.time_map().clone();
+        for item in timeline.by_ref() {
+            if item.entry.track_index() != Some(track_index) {
+                continue;
+            }
+            let word = match item.entry.proto().as_lyric().and_then(|x| x.as_word()) {
+                Some(word) => word,
+                None => continue,
+            };
+            let text = word.text.trim().trim_end_matches('-');
+            if text.is_empty() {
+                continue;
+            }
+            let begin_units = item.position.in_tab_pos;
+            let end_units = begin_units + item.entry.props.tied_units + item.entry.melisma_units();
+            let new_section = section_ordinal.is_some()
+                && section_ordinal != Some(item.bar.props.section_ordinal);
+            let has_gap = !line.words.is_empty()
+                && begin_units >= line.end_units() + line_gap;
+            if new_section || has_gap {
+                lines.push(std::mem::take(&mut line));
+            }
+            section_ordinal = Some(item.bar.props.section_ordinal);
+            line.words.push(TimedLyricWord {
+                text: text.to_owned(),
+                joined: word.join == LyricJoin::Hyphen || word.text.trim().ends_with('-'),
+                begin_units,
+                end_units,
+                begin_seconds: item.seconds,
+                end_seconds: time_map
+                    .calc_played_seconds(&item.entry.bar_position(), end_units - begin_units)
+                    .1,
+            });
+        }
+        if !line.words.is_empty() {
+            lines.push(line);
+        }
+        Self { lines }
+    }
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+    /// Index of the line being sung at the position, or the next one during gaps.
+    pub fn find_line(&self, pos: Units) -> Option<usize> {
+        self.lines
+            .iter()
+            .position(|x| x.end_units() > pos)
+    }
+}
+
+impl TimedLyrics {
+    fn lrc_time(seconds: f32) -> String {
+        let centis = (seconds.max(0.0) * 100.0).round() as u64;
+        format!("{:02}:{:02}.{:02}", centis / 6000, centis / 100 % 60, centis % 100)
+    }
+    fn vtt_time(seconds: f32) -> String {
+        let millis = (seconds.max(0.0) * 1000.0).round() as u64;
+        format!(
+            "{:02}:{:02}:{:02}.{:03}",
+            millis / 3_600_000,
+            millis / 60_000 % 60,
+            millis / 1000 % 60,
+            millis % 1000
+        )
+    }
+    /// Enhanced LRC, with a timestamp before each word.
+    pub fn to_lrc(&self, info: &TabInfo) -> String {
+        let mut lrc = String::new();
+        let tags = [
+            ("ti", &info.title),
+            ("ar", &info.artist),
+            ("al", &info.album),
+            ("au", &info.composer),
+        ];
+        for (tag, value) in tags {
+            if !value.is_empty() {
+                let _ = writeln!(lrc, "[{}:{}]", tag, value);
+            }
+        }
+        for line in self.lines.iter() {
+            let _ = write!(lrc, "[{}]", Self::lrc_time(line.begin_seconds()));
+            for word in line.words.iter() {
+                let space = if word.joined { "" } else { " " };
+                let _ = write!(
+                    lrc,
+                    "<{}>{}{}",
+                    Self::lrc_time(word.begin_seconds),
+                    word.text,
+                    space
+                );
+            }
+            let _ = writeln!(lrc, "<{}>", Self::lrc_time(line.end_seconds()));
+        }
+        lrc
+    }
+    /// WebVTT cues, one for each line, with timestamp tags for the words.
+    pub fn to_webvtt(&self) -> String {
+        let mut vtt = String::from("WEBVTT\n");
+        for (index, line) in self.lines.iter().enumerate() {
+            let _ = writeln!(
+                vtt,
+                "\n{}\n{} --> {}",
+                index + 1,
+                Self::vtt_time(line.begin_seconds()),
+                Self::vtt_time(line.end_seconds())
+            );
+            for (word_index, word) in line.words.iter().enumerate() {
+                if word_index > 0 {
+                    let _ = write!(vtt, "<{}>", Self::vtt_time(word.begin_seconds));
+                }
+                let space = if word.joined { "" } else { " " };
+                let _ = write!(vtt, "{}{}", word.text, space);
+            }
+            vtt = vtt.trim_end_matches(' ').to_owned();
+            vtt.push('\n');
+        }
+        vtt
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use notation_dsl::prelude::parse_tab;
+    use notation_proto::prelude::{TabInfo, Units};
+
+    use crate::prelude::{Tab, TimedLyrics};
+
+    /// A quarter note is a second, a bar is 4 seconds.
+    const TAB: &str = r#"
+    tab! {
+        "8f4e3c1a-6d2b-4b7e-9a1f-5c3d2e1f0a9b"
+        Meta: C Major 4 _4 60
+        Tracks: [
+            {lyrics Lyrics [
+                $duration = _1_4
+                "l1" Word [ "hel"~Hyphen "lo" "world" _ ] |
+                "l2" Word [ _ _ "a-" "gain" ] |
+            ]}
+        ]
+        Sections: [
+            {"A" Verse [
+                {
+                    lyrics [ "l1" | ]
+                } {
+                    lyrics [ "l2" | ]
+                }
+            ]}
+        ]
+        Form: "A"
+    }
+    "#;
+
+    fn new_lyrics() -> TimedLyrics {
+        let tab = Tab::try_parse_arc(parse_tab(TAB).unwrap(), false, None).unwrap();
+        TimedLyrics::new(&tab)
+    }
+
+    #[test]
+    fn test_lines() {
+        let lyrics = new_lyrics();
+        assert_eq!(lyrics.lines.len(), 2);
+        assert_eq!(lyrics.lines[0].text(), "hello world");
+        assert_eq!(lyrics.lines[1].text(), "again");
+        let seconds: Vec<(f32, f32)> = lyrics
+            .lines
+            .iter()
+            .flat_map(|x| x.words.iter())
+            .map(|x| (x.begin_seconds, x.end_seconds))
+            .collect();
+        assert_eq!(
+            seconds,
+            vec![(0.0, 1.0), (1.0, 2.0), (2.0, 3.0), (6.0, 7.0), (7.0, 8.0)]
+        );
+        assert_eq!(lyrics.find_line(Units::ZERO), Some(0));
+        assert_eq!(lyrics.find_line(Units::new(1, 1)), Some(1));
+        assert_eq!(lyrics.find_line(Units::new(2, 1)), None);
+    }
+
+    #[test]
+    fn test_lrc() {
+        let info = TabInfo {
+            title: "Test".to_owned(),
+            ..TabInfo::default()
+        };
+        assert_eq!(
+            new_lyrics().to_lrc(&info),
+            "[ti:Test]\n\
+             [00:00.00]<00:00.00>hel<00:01.00>lo <00:02.00>world <00:03.00>\n\
+             [00:06.00]<00:06.00>a<00:07.00>gain <00:08.00>\n"
+        );
+    }
+
+    #[test]
+    fn test_webvtt() {
+        assert_eq!(
+            new_lyrics().to_webvtt(),
+            "WEBVTT\n\
+             \n1\n00:00:00.000 --> 00:00:03.000\nhel<00:00:01.000>lo <00:00:02.000>world\n\
+             \n2\n00:00:06.000 --> 00:00:08.000\na<00:00:07.000>gain\n"
+        );
+    }
+}
@@ -5,9 +5,11 @@ pub mod bar;
 pub mod lyric_entry;
 pub mod position;
 pub mod proto_entry;
+mod ron_value;
 pub mod section;
 pub mod slice;
 pub mod tab;
+pub mod tab_file;
 pub mod track;
 pub mod prelude {
     #[doc(hidden)]
@@ -15,7 +17,7 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::bar::{Bar, BarLayer};
     #[doc(hidden)]
-    pub use crate::lyric_entry::{LyricEntry, LyricWord};
+    pub use crate::lyric_entry::{LyricEntry, LyricJoin, LyricWord};
     #[doc(hidden)]
     pub use crate::position::{BarPosition, Position, TabPosition};
     #[doc(hidden)]
@@ -25,7 +27,9 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::slice::{Slice, SliceBegin, SliceEnd};
     #[doc(hidden)]
-    pub use crate::tab::{Tab, TabMeta};
+    pub use crate::tab::{Difficulty, Tab, TabInfo, TabMeta};
+    #[doc(hidden)]
+    pub use crate::tab_file::{TabEncoding, TabFile, TabFileError, TAB_FORMAT_VERSION};
     #[doc(hidden)]
     pub use crate::track::{Track, TrackKind};
     #[doc(hidden)]
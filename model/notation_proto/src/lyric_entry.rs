@@ -5,18 +5,59 @@ use serde::{Deserialize, Serialize};
 
 use crate::prelude::{Duration, Entry};
 
+/// How a syllable is connected to what follows it.
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug, Default)]
+pub enum LyricJoin {
+    #[default]
+    None,
+    /// The next syllable is part of the same word.
+    Hyphen,
+    /// The syllable is held over the following melisma.
+    Extender,
+}
+impl Display for LyricJoin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl LyricJoin {
+    pub fn is_none(&self) -> bool {
+        *self == Self::None
+    }
+    pub fn to_ident(&self) -> String {
+        format!("{}", self)
+    }
+    pub fn from_ident(ident: &str) -> Option<Self> {
+        match ident {
+            "None" => Some(Self::None),
+            "Hyphen" => Some(Self::Hyphen),
+            "Extender" => Some(Self::Extender),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub struct LyricWord {
     pub text: String,
+    /// Not written when `None`, which would be read back as null from RON.
+    #[serde(default, skip_serializing_if = "LyricJoin::is_none")]
+    pub join: LyricJoin,
 }
 impl Display for LyricWord {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.text)
+        match self.join {
+            LyricJoin::None => write!(f, "{}", self.text),
+            _ => write!(f, "{}~{}", self.text, self.join),
+        }
     }
 }
 impl From<String> for LyricWord {
     fn from(v: String) -> Self {
-        LyricWord { text: v }
+        LyricWord {
+            text: v,
+            join: LyricJoin::None,
+        }
     }
 }
 
@@ -26,14 +67,34 @@ impl From<&str> for LyricWord {
     }
 }
 
+impl LyricWord {
+    pub fn with_join(self, join: LyricJoin) -> Self {
+        Self { join, ..self }
+    }
+    /// Text to be shown, with the hyphen added if not already in the text.
+    pub fn display_text(&self) -> String {
+        if self.join == LyricJoin::Hyphen && !self.text.ends_with('-') {
+            format!("{}-", self.text)
+        } else {
+            self.text.clone()
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub enum LyricEntry {
     Word(LyricWord, Duration),
+    /// The previous syllable is held over more melody notes.
+    Melisma(Duration),
+    /// Following entries are the lines of this verse, starting from the beginning of the bar.
+    Verse(u8),
 }
 impl Display for LyricEntry {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             LyricEntry::Word(word, duration) => write!(f, "Word({}, {})", word, duration),
+            LyricEntry::Melisma(duration) => write!(f, "Melisma({})", duration),
+            LyricEntry::Verse(verse) => write!(f, "Verse({})", verse),
         }
     }
 }
@@ -41,6 +102,26 @@ impl LyricEntry {
     pub fn duration(&self) -> Duration {
         match self {
             Self::Word(_, duration) => *duration,
+            Self::Melisma(duration) => *duration,
+            Self::Verse(_) => Duration::Zero,
+        }
+    }
+    pub fn as_word(&self) -> Option<&LyricWord> {
+        if let Self::Word(word, _) = self {
+            Some(word)
+        } else {
+            None
+        }
+    }
+    /// Returns `true` if the lyric entry is [`Melisma`].
+    pub fn is_melisma(&self) -> bool {
+        matches!(self, Self::Melisma(..))
+    }
+    pub fn as_verse(&self) -> Option<u8> {
+        if let Self::Verse(verse) = self {
+            Some(*verse)
+        } else {
+            None
         }
     }
     pub fn pass_mode(&self) -> EntryPassMode {
@@ -5,18 +5,18 @@ use serde::{Deserialize, Serialize};
 
 use crate::prelude::Units;
 
-#[derive(Copy, Clone, PartialEq, Serialize, Deserialize, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub struct TabPosition {
     pub in_tab_pos: Units,
 }
 impl Display for TabPosition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "<TabPosition>({})", self.in_tab_pos.0)
+        write!(f, "<TabPosition>({})", self.in_tab_pos)
     }
 }
 impl TabPosition {
     pub const ZERO: Self = Self {
-        in_tab_pos: Units(0.0),
+        in_tab_pos: Units::ZERO,
     };
     pub fn new(in_tab_pos: Units) -> Self {
         Self { in_tab_pos }
@@ -28,7 +28,7 @@ impl From<TabPosition> for Units {
     }
 }
 
-#[derive(Copy, Clone, PartialEq, Serialize, Deserialize, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub struct BarPosition {
     pub bar_units: Units,
     pub bar_ordinal: usize,
@@ -39,7 +39,7 @@ impl Display for BarPosition {
         write!(
             f,
             "<BarPosition>({}:{})",
-            self.bar_ordinal, self.in_bar_pos.0
+            self.bar_ordinal, self.in_bar_pos
         )
     }
 }
@@ -50,9 +50,9 @@ impl From<BarPosition> for Units {
 }
 impl BarPosition {
     pub const ZERO: Self = Self {
-        bar_units: Units(0.0),
+        bar_units: Units::ZERO,
         bar_ordinal: 0,
-        in_bar_pos: Units(0.0),
+        in_bar_pos: Units::ZERO,
     };
     pub fn new(bar_units: Units, bar_ordinal: usize, in_bar_pos: Units) -> Self {
         Self {
@@ -68,11 +68,11 @@ impl BarPosition {
         Self::new(self.bar_units, self.bar_ordinal, self.in_bar_pos + delay)
     }
     pub fn bar_pos(&self) -> Units {
-        Units(self.bar_ordinal as f32 * self.bar_units.0)
+        self.bar_units * self.bar_ordinal as i64
     }
 }
 
-#[derive(Copy, Clone, PartialEq, Serialize, Deserialize, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub struct Position {
     pub tab: TabPosition,
     pub bar: BarPosition,
@@ -82,7 +82,7 @@ impl Display for Position {
         write!(
             f,
             "<Position>(tab:{}, bar:{}:{})",
-            self.tab.in_tab_pos.0, self.bar.bar_ordinal, self.bar.in_bar_pos.0
+            self.tab.in_tab_pos, self.bar.bar_ordinal, self.bar.in_bar_pos
         )
     }
 }
@@ -95,16 +95,18 @@ impl From<Position> for Units {
 impl Position {
     pub fn new(bar_units: Units) -> Self {
         Self {
-            tab: TabPosition::new(Units(0.0)),
-            bar: BarPosition::new(bar_units, 0, Units(0.0)),
+            tab: TabPosition::new(Units::ZERO),
+            bar: BarPosition::new(bar_units, 0, Units::ZERO),
         }
     }
     pub fn calc_bar_ordinal(&self, pos: Units) -> usize {
-        let bar = pos.0 / self.bar.bar_units.0;
-        bar.trunc() as usize
+        if self.bar.bar_units.is_zero() {
+            return 0;
+        }
+        (pos / self.bar.bar_units).floor().max(0) as usize
     }
     pub fn cal_bar_pos(&self, bar_ordinal: usize) -> Units {
-        Units(bar_ordinal as f32 * self.bar.bar_units.0)
+        self.bar.bar_units * bar_ordinal as i64
     }
     pub fn set_in_tab(&mut self, pos: Units) {
         self.tab = TabPosition::new(pos);
@@ -118,8 +120,8 @@ impl Position {
     }
     pub fn _is_passed(&self, pass_mode: EntryPassMode, in_tab_pos: Units) -> bool {
         match pass_mode {
-            EntryPassMode::Immediate => in_tab_pos.0 <= self.tab.in_tab_pos.0,
-            EntryPassMode::Delayed => in_tab_pos.0 < self.tab.in_tab_pos.0,
+            EntryPassMode::Immediate => in_tab_pos <= self.tab.in_tab_pos,
+            EntryPassMode::Delayed => in_tab_pos < self.tab.in_tab_pos,
         }
     }
     pub fn is_passed(&self, pass_mode: EntryPassMode, pos: &BarPosition) -> bool {
@@ -3,7 +3,7 @@ use std::fmt::Display;
 use serde::{Deserialize, Serialize};
 
 use notation_core::prelude::{CoreEntry, Duration, Entry, EntryPassMode, MetaEntry};
-use notation_fretted::prelude::{FrettedEntry4, FrettedEntry6};
+use notation_fretted::prelude::FrettedEntry;
 
 use crate::prelude::LyricEntry;
 
@@ -13,8 +13,15 @@ pub enum ProtoEntry {
     Meta(MetaEntry),
     Core(CoreEntry),
     Lyric(LyricEntry),
-    Fretted6(FrettedEntry6),
-    Fretted4(FrettedEntry4),
+    /// The string count is in the fretboard, tabs before it got one variant per count.
+    #[serde(
+        alias = "Fretted6",
+        alias = "Fretted4",
+        alias = "Fretted5",
+        alias = "Fretted7",
+        alias = "Fretted8"
+    )]
+    Fretted(FrettedEntry),
     Extra(String, String),
 }
 impl Display for ProtoEntry {
@@ -24,8 +31,7 @@ impl Display for ProtoEntry {
             ProtoEntry::Meta(x) => write!(f, "Meta({})", x),
             ProtoEntry::Core(x) => write!(f, "Core({})", x),
             ProtoEntry::Lyric(x) => write!(f, "Lyric({})", x),
-            ProtoEntry::Fretted6(x) => write!(f, "Fretted6({})", x),
-            ProtoEntry::Fretted4(x) => write!(f, "Fretted4({})", x),
+            ProtoEntry::Fretted(x) => write!(f, "Fretted({})", x),
             ProtoEntry::Extra(x, y) => write!(f, "Extra({}, {})", x, y),
         }
     }
@@ -37,8 +43,7 @@ impl ProtoEntry {
             ProtoEntry::Meta(entry) => entry.duration(),
             ProtoEntry::Core(entry) => entry.duration(),
             ProtoEntry::Lyric(entry) => entry.duration(),
-            ProtoEntry::Fretted6(entry) => entry.duration(),
-            ProtoEntry::Fretted4(entry) => entry.duration(),
+            ProtoEntry::Fretted(entry) => entry.duration(),
             ProtoEntry::Extra(_, _) => Duration::Zero,
         }
     }
@@ -48,8 +53,7 @@ impl ProtoEntry {
             ProtoEntry::Meta(entry) => entry.pass_mode(),
             ProtoEntry::Core(entry) => entry.pass_mode(),
             ProtoEntry::Lyric(entry) => entry.pass_mode(),
-            ProtoEntry::Fretted6(entry) => entry.pass_mode(),
-            ProtoEntry::Fretted4(entry) => entry.pass_mode(),
+            ProtoEntry::Fretted(entry) => entry.pass_mode(),
             ProtoEntry::Extra(_, _) => EntryPassMode::Immediate,
         }
     }
@@ -97,51 +101,40 @@ impl ProtoEntry {
             None
         }
     }
-    pub fn as_core(&self) -> Option<&CoreEntry> {
-        if let Self::Core(v) = self {
+    pub fn as_lyric(&self) -> Option<&LyricEntry> {
+        if let Self::Lyric(v) = self {
             Some(v)
         } else {
             None
         }
     }
-    pub fn try_into_core(self) -> Result<CoreEntry, Self> {
+    pub fn as_core(&self) -> Option<&CoreEntry> {
         if let Self::Core(v) = self {
-            Ok(v)
-        } else {
-            Err(self)
-        }
-    }
-    /// Returns `true` if the proto_entry is [`FrettedSix`].
-    pub fn is_fretted6(&self) -> bool {
-        matches!(self, Self::Fretted6(..))
-    }
-    pub fn as_fretted6(&self) -> Option<&FrettedEntry6> {
-        if let Self::Fretted6(v) = self {
             Some(v)
         } else {
             None
         }
     }
-    pub fn try_into_fretted6(self) -> Result<FrettedEntry6, Self> {
-        if let Self::Fretted6(v) = self {
+    pub fn try_into_core(self) -> Result<CoreEntry, Self> {
+        if let Self::Core(v) = self {
             Ok(v)
         } else {
             Err(self)
         }
     }
-    /// Returns `true` if the proto_entry is [`FrettedFour`].
-    pub fn is_fretted4(&self) -> bool {
-        matches!(self, Self::Fretted4(..))
+    /// Returns `true` if the proto_entry is [`Fretted`].
+    pub fn is_fretted(&self) -> bool {
+        matches!(self, Self::Fretted(..))
     }
-    pub fn as_fretted4(&self) -> Option<&FrettedEntry4> {
-        if let Self::Fretted4(v) = self {
+    pub fn as_fretted(&self) -> Option<&FrettedEntry> {
+        if let Self::Fretted(v) = self {
             Some(v)
         } else {
             None
         }
     }
-    pub fn try_into_fretted4(self) -> Result<FrettedEntry4, Self> {
-        if let Self::Fretted4(v) = self {
+    pub fn try_into_fretted(self) -> Result<FrettedEntry, Self> {
+        if let Self::Fretted(v) = self {
             Ok(v)
         } else {
             Err(self)
@@ -153,6 +146,9 @@ impl ProtoEntry {
     pub fn is_core_tie(&self) -> bool {
         self.as_core().map(|x| x.is_tie()).unwrap_or(false)
     }
+    pub fn is_core_fermata(&self) -> bool {
+        self.as_core().map(|x| x.is_fermata()).unwrap_or(false)
+    }
     pub fn trim_comments(mark: &String) -> String {
         if let Some(index) = mark.find(Self::COMMENT_CHAR) {
             return mark[..index].to_string();
@@ -223,14 +219,8 @@ impl From<LyricEntry> for ProtoEntry {
     }
 }
 
-impl From<FrettedEntry6> for ProtoEntry {
-    fn from(v: FrettedEntry6) -> Self {
-        ProtoEntry::Fretted6(v)
-    }
-}
-
-impl From<FrettedEntry4> for ProtoEntry {
-    fn from(v: FrettedEntry4) -> Self {
-        ProtoEntry::Fretted4(v)
+impl From<FrettedEntry> for ProtoEntry {
+    fn from(v: FrettedEntry) -> Self {
+        ProtoEntry::Fretted(v)
     }
 }
@@ -0,0 +1,395 @@
+use serde_json::{Map, Number, Value};
+
+/// Reads RON into the same value as `serde_json::to_value()` of the typed data, so that
+/// migrations can work on RON, JSON and binary files the same way.
+///
+/// `ron::Value` can't be used here, it drops the names of enum variants. Struct names are
+/// not supported, as `TabFile::encode()` never writes them, so `Name(...)` is always read
+/// as an enum variant.
+pub fn parse_ron(text: &str) -> Result<Value, String> {
+    let mut reader = RonReader {
+        chars: text.chars().collect(),
+        pos: 0,
+    };
+    reader.skip_attributes()?;
+    let value = reader.parse_value()?;
+    reader.skip_ws()?;
+    if reader.pos < reader.chars.len() {
+        return Err(reader.error("trailing characters"));
+    }
+    Ok(value)
+}
+
+struct RonReader {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl RonReader {
+    fn error(&self, message: &str) -> String {
+        let consumed: String = self.chars[..self.pos.min(self.chars.len())]
+            .iter()
+            .collect();
+        let line = consumed.matches('\n').count() + 1;
+        let col = consumed.len() - consumed.rfind('\n').map(|x| x + 1).unwrap_or(0) + 1;
+        format!("{}:{}: {}", line, col, message)
+    }
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+    fn next(&mut self) -> Result<char, String> {
+        let c = self.peek().ok_or_else(|| self.error("unexpected end"))?;
+        self.pos += 1;
+        Ok(c)
+    }
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        self.skip_ws()?;
+        match self.next()? {
+            c if c == expected => Ok(()),
+            c => Err(self.error(&format!("expecting '{}', found '{}'", expected, c))),
+        }
+    }
+    fn skip_ws(&mut self) -> Result<(), String> {
+        loop {
+            match (self.peek(), self.peek_at(1)) {
+                (Some(c), _) if c.is_whitespace() => self.pos += 1,
+                (Some('/'), Some('/')) => {
+                    while !matches!(self.peek(), None | Some('\n')) {
+                        self.pos += 1;
+                    }
+                }
+                (Some('/'), Some('*')) => {
+                    let mut depth = 0;
+                    loop {
+                        match (self.next()?, self.peek()) {
+                            ('/', Some('*')) => {
+                                self.pos += 1;
+                                depth += 1;
+                            }
+                            ('*', Some('/')) => {
+                                self.pos += 1;
+                                depth -= 1;
+                                if depth == 0 {
+                                    break;
+                                }
+                            }
+                            _ => (),
+                        }
+                    }
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+    /// Extensions as `#![enable(implicit_some)]` are not supported, but skipped.
+    fn skip_attributes(&mut self) -> Result<(), String> {
+        self.skip_ws()?;
+        while self.peek() == Some('#') {
+            while self.next()? != ']' {}
+            self.skip_ws()?;
+        }
+        Ok(())
+    }
+    /// Skips the comma after an item, returns `true` if the list is closed.
+    fn end_item(&mut self, close: char) -> Result<bool, String> {
+        self.skip_ws()?;
+        if self.peek() == Some(',') {
+            self.pos += 1;
+            self.skip_ws()?;
+        } else if self.peek() != Some(close) {
+            return Err(self.error(&format!("expecting ',' or '{}'", close)));
+        }
+        if self.peek() == Some(close) {
+            self.pos += 1;
+            return Ok(true);
+        }
+        Ok(false)
+    }
+    fn parse_value(&mut self) -> Result<Value, String> {
+        self.skip_ws()?;
+        match self.peek().ok_or_else(|| self.error("unexpected end"))? {
+            '"' => self.parse_string().map(Value::String),
+            'r' if matches!(self.peek_at(1), Some('"') | Some('#')) => {
+                self.parse_raw_string().map(Value::String)
+            }
+            '\'' => self.parse_char().map(|x| Value::String(x.to_string())),
+            '[' => {
+                self.pos += 1;
+                self.parse_seq(']').map(Value::Array)
+            }
+            '{' => {
+                self.pos += 1;
+                self.parse_map()
+            }
+            '(' => {
+                self.pos += 1;
+                self.parse_parens()
+            }
+            c if c.is_ascii_digit() || c == '-' || c == '+' || c == '.' => self.parse_number(),
+            c if c.is_alphabetic() || c == '_' => self.parse_ident_value(),
+            c => Err(self.error(&format!("unexpected '{}'", c))),
+        }
+    }
+    fn parse_ident(&mut self) -> String {
+        let begin = self.pos;
+        while matches!(self.peek(), Some(c) if c.is_alphanumeric() || c == '_') {
+            self.pos += 1;
+        }
+        self.chars[begin..self.pos].iter().collect()
+    }
+    /// Unit variants as strings, other variants as single entry objects, same as serde_json.
+    fn parse_ident_value(&mut self) -> Result<Value, String> {
+        let ident = self.parse_ident();
+        match ident.as_str() {
+            "true" => return Ok(Value::Bool(true)),
+            "false" => return Ok(Value::Bool(false)),
+            "None" => return Ok(Value::Null),
+            _ => (),
+        }
+        self.skip_ws()?;
+        if self.peek() != Some('(') {
+            return Ok(Value::String(ident));
+        }
+        self.pos += 1;
+        let content = self.parse_parens()?;
+        if ident == "Some" {
+            return Ok(content);
+        }
+        let mut map = Map::new();
+        map.insert(ident, content);
+        Ok(Value::Object(map))
+    }
+    /// Struct fields as an object, a single value as itself (newtypes), others as an array.
+    fn parse_parens(&mut self) -> Result<Value, String> {
+        self.skip_ws()?;
+        if self.peek() == Some(')') {
+            self.pos += 1;
+            return Ok(Value::Null);
+        }
+        if self.peek_field() {
+            let mut map = Map::new();
+            loop {
+                self.skip_ws()?;
+                let key = self.parse_ident();
+                self.expect(':')?;
+                let value = self.parse_value()?;
+                map.insert(key, value);
+                if self.end_item(')')? {
+                    return Ok(Value::Object(map));
+                }
+            }
+        }
+        let mut items = self.parse_seq(')')?;
+        if items.len() == 1 {
+            return Ok(items.remove(0));
+        }
+        Ok(Value::Array(items))
+    }
+    fn peek_field(&self) -> bool {
+        let mut pos = self.pos;
+        while matches!(self.chars.get(pos), Some(c) if c.is_alphanumeric() || *c == '_') {
+            pos += 1;
+        }
+        if pos == self.pos {
+            return false;
+        }
+        while matches!(self.chars.get(pos), Some(c) if c.is_whitespace()) {
+            pos += 1;
+        }
+        self.chars.get(pos) == Some(&':')
+    }
+    fn parse_seq(&mut self, close: char) -> Result<Vec<Value>, String> {
+        let mut items = Vec::new();
+        self.skip_ws()?;
+        if self.peek() == Some(close) {
+            self.pos += 1;
+            return Ok(items);
+        }
+        loop {
+            items.push(self.parse_value()?);
+            if self.end_item(close)? {
+                return Ok(items);
+            }
+        }
+    }
+    fn parse_map(&mut self) -> Result<Value, String> {
+        let mut map = Map::new();
+        self.skip_ws()?;
+        if self.peek() == Some('}') {
+            self.pos += 1;
+            return Ok(Value::Object(map));
+        }
+        loop {
+            let key = match self.parse_value()? {
+                Value::String(key) => key,
+                key => key.to_string(),
+            };
+            self.expect(':')?;
+            let value = self.parse_value()?;
+            map.insert(key, value);
+            if self.end_item('}')? {
+                return Ok(Value::Object(map));
+            }
+        }
+    }
+    fn parse_number(&mut self) -> Result<Value, String> {
+        let begin = self.pos;
+        while matches!(self.peek(), Some(c) if c.is_ascii_alphanumeric() || "+-._".contains(c)) {
+            self.pos += 1;
+        }
+        let text: String = self.chars[begin..self.pos]
+            .iter()
+            .filter(|x| **x != '_')
+            .collect();
+        let text = text.trim_start_matches('+');
+        if let Ok(x) = text.parse::<u64>() {
+            return Ok(Value::Number(Number::from(x)));
+        }
+        if let Ok(x) = text.parse::<i64>() {
+            return Ok(Value::Number(Number::from(x)));
+        }
+        text.parse::<f64>()
+            .ok()
+            .and_then(Number::from_f64)
+            .map(Value::Number)
+            .ok_or_else(|| self.error(&format!("invalid number: {}", text)))
+    }
+    fn parse_escape(&mut self) -> Result<char, String> {
+        Ok(match self.next()? {
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            '0' => '\0',
+            'u' => {
+                self.expect('{')?;
+                let mut code = String::new();
+                loop {
+                    match self.next()? {
+                        '}' => break,
+                        c => code.push(c),
+                    }
+                }
+                u32::from_str_radix(&code, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| self.error("invalid unicode escape"))?
+            }
+            c => c,
+        })
+    }
+    fn parse_string(&mut self) -> Result<String, String> {
+        self.pos += 1;
+        let mut text = String::new();
+        loop {
+            match self.next()? {
+                '"' => return Ok(text),
+                '\\' => text.push(self.parse_escape()?),
+                c => text.push(c),
+            }
+        }
+    }
+    fn parse_raw_string(&mut self) -> Result<String, String> {
+        self.pos += 1;
+        let mut hashes = 0;
+        while self.peek() == Some('#') {
+            self.pos += 1;
+            hashes += 1;
+        }
+        if self.next()? != '"' {
+            return Err(self.error("invalid raw string"));
+        }
+        let mut text = String::new();
+        loop {
+            let c = self.next()?;
+            if c == '"' && (0..hashes).all(|x| self.peek_at(x) == Some('#')) {
+                self.pos += hashes;
+                return Ok(text);
+            }
+            text.push(c);
+        }
+    }
+    fn parse_char(&mut self) -> Result<char, String> {
+        self.pos += 1;
+        let c = match self.next()? {
+            '\\' => self.parse_escape()?,
+            c => c,
+        };
+        if self.next()? != '\'' {
+            return Err(self.error("invalid char"));
+        }
+        Ok(c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::parse_ron;
+    use crate::prelude::{LyricJoin, LyricWord};
+
+    #[test]
+    fn test_parse_ron() {
+        let text = r##"
+        #![enable(implicit_some)]
+        (
+            // line comment
+            name: "a \"b\"\n", /* block /* nested */ comment */
+            raw: r#"c "d""#,
+            char: 'e',
+            unit: Quarter,
+            newtype: (52),
+            tuple: ((1), (-2), 3.5),
+            variant: Bpm(60),
+            tuple_variant: Triad(Major3nd, Perfect5th,),
+            struct_variant: Pick(string: 1, fret: None),
+            option: Some(Natural(G)),
+            none: None,
+            list: [true, false],
+            map: {"k": 1},
+            empty: (),
+        )
+        "##;
+        assert_eq!(
+            parse_ron(text).unwrap(),
+            json!({
+                "name": "a \"b\"\n",
+                "raw": "c \"d\"",
+                "char": "e",
+                "unit": "Quarter",
+                "newtype": 52,
+                "tuple": [1, -2, 3.5],
+                "variant": { "Bpm": 60 },
+                "tuple_variant": { "Triad": ["Major3nd", "Perfect5th"] },
+                "struct_variant": { "Pick": { "string": 1, "fret": null } },
+                "option": { "Natural": "G" },
+                "none": null,
+                "list": [true, false],
+                "map": { "k": 1 },
+                "empty": null,
+            })
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(parse_ron("(a: 1")
+            .unwrap_err()
+            .contains("expecting ',' or ')'"));
+        assert!(parse_ron("(a: 1) )").unwrap_err().starts_with("1:8: "));
+    }
+
+    #[test]
+    fn test_lyric_word() {
+        for word in [
+            LyricWord::from("a"),
+            LyricWord::from("b").with_join(LyricJoin::Hyphen),
+        ] {
+            let value = parse_ron(&ron::to_string(&word).unwrap()).unwrap();
+            assert_eq!(serde_json::from_value::<LyricWord>(value).unwrap(), word);
+        }
+    }
+}
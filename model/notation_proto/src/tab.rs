@@ -3,9 +3,9 @@ use uuid::Uuid;
 
 use std::fmt::Display;
 
-use crate::prelude::{Form, Section, Track};
+use crate::prelude::{BarPosition, Form, Section, Track};
 use notation_core::prelude::{
-    Key, Note, Pitch, Scale, Signature, Syllable, Tempo, Units, Octave,
+    Groove, Key, Note, Pitch, Scale, Signature, Syllable, Tempo, Units, Octave,
 };
 
 #[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
@@ -14,6 +14,8 @@ pub struct TabMeta {
     pub scale: Scale,
     pub signature: Signature,
     pub tempo: Tempo,
+    #[serde(default)]
+    pub groove: Groove,
 }
 impl TabMeta {
     pub fn bar_units(&self) -> Units {
@@ -28,13 +30,22 @@ impl TabMeta {
     pub fn calc_note_from_syllable(&self, syllable: &Syllable, octave: &Octave) -> Note {
         self.scale.calc_note_from_syllable(&self.key, syllable, octave)
     }
+    /// Where and how long an entry is actually played with the groove.
+    pub fn apply_groove(&self, pos: &BarPosition, duration: Units) -> (BarPosition, Units) {
+        if self.groove.is_straight() {
+            return (*pos, duration);
+        }
+        let begin = self.groove.apply(pos.in_bar_pos);
+        let end = self.groove.apply(pos.in_bar_pos + duration);
+        (pos.with_in_bar_pos(begin), end - begin)
+    }
 }
 impl Display for TabMeta {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{} {} {} {:?}",
-            self.key, self.scale, self.signature, self.tempo,
+            "{} {} {} {:?} {}",
+            self.key, self.scale, self.signature, self.tempo, self.groove,
         )
     }
 }
@@ -45,8 +56,12 @@ impl TabMeta {
             scale,
             signature,
             tempo,
+            groove: Groove::default(),
         }
     }
+    pub fn with_groove(&self, groove: Groove) -> Self {
+        Self { groove, ..*self }
+    }
 }
 impl Default for TabMeta {
     fn default() -> Self {
@@ -55,14 +70,93 @@ impl Default for TabMeta {
             scale: Scale::Major,
             signature: Signature::_4_4,
             tempo: Tempo::Moderato,
+            groove: Groove::default(),
         }
     }
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub enum Difficulty {
+    Beginner,
+    Intermediate,
+    Advanced,
+    Expert,
+}
+impl Display for Difficulty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl Difficulty {
+    pub const ALL: [Difficulty; 4] = [
+        Difficulty::Beginner,
+        Difficulty::Intermediate,
+        Difficulty::Advanced,
+        Difficulty::Expert,
+    ];
+    pub fn to_ident(&self) -> String {
+        format!("{}", self)
+    }
+    pub fn from_ident(ident: &str) -> Option<Self> {
+        Self::ALL.iter().find(|x| x.to_ident() == ident).copied()
+    }
+}
+
+/// Song level information, all optional, not used for playing.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct TabInfo {
+    pub title: String,
+    pub artist: String,
+    pub composer: String,
+    pub lyricist: String,
+    pub album: String,
+    pub year: Option<u16>,
+    pub source_url: String,
+    pub copyright: String,
+    pub difficulty: Option<Difficulty>,
+    pub tags: Vec<String>,
+}
+impl Display for TabInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.artist.is_empty() {
+            true => write!(f, "{}", self.title),
+            false => write!(f, "{} - {}", self.title, self.artist),
+        }
+    }
+}
+impl TabInfo {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|x| x == tag)
+    }
+    /// Composer, lyricist, album and year in one line, skipping the empty ones.
+    pub fn credits(&self) -> String {
+        let mut credits = Vec::new();
+        if !self.composer.is_empty() {
+            credits.push(format!("Music: {}", self.composer));
+        }
+        if !self.lyricist.is_empty() {
+            credits.push(format!("Lyrics: {}", self.lyricist));
+        }
+        if !self.album.is_empty() {
+            credits.push(self.album.clone());
+        }
+        if let Some(year) = self.year {
+            credits.push(year.to_string());
+        }
+        credits.join(" / ")
+    }
+}
+
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct Tab {
     pub uuid: Uuid,
     pub meta: TabMeta,
+    #[serde(default)]
+    pub info: TabInfo,
     pub tracks: Vec<Track>,
     pub sections: Vec<Section>,
     pub form: Form,
@@ -94,11 +188,22 @@ impl Tab {
         Self {
             uuid,
             meta,
+            info: TabInfo::default(),
             tracks,
             sections,
             form,
         }
     }
+    pub fn with_info(self, info: TabInfo) -> Self {
+        Self { info, ..self }
+    }
+    /// Title of the song, or the uuid if not set.
+    pub fn title(&self) -> String {
+        match self.info.title.is_empty() {
+            true => self.uuid.to_string(),
+            false => self.info.title.clone(),
+        }
+    }
     pub fn new_empty() -> Self {
         Self::new(
             Self::new_uuid().as_str(),
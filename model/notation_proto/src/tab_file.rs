@@ -0,0 +1,283 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+
+use notation_core::prelude::{Octave, Pitch, Semitones};
+
+use crate::prelude::Tab;
+use crate::ron_value::parse_ron;
+
+/// Version of the tab file format, bump it when the schema changes in a way that old files
+/// can't be read directly, and add a step to `TabFile::MIGRATIONS`.
+///
+/// Renamed variants and fields should still be readable with `#[serde(alias = "...")]` on
+/// the current types, migrations are for changes that need to touch the values.
+pub const TAB_FORMAT_VERSION: u32 = 1;
+
+#[derive(Clone, Error, Debug)]
+pub enum TabFileError {
+    #[error("unsupported extension: {0}")]
+    UnsupportedExtension(String),
+    #[error(
+        "tab format version {0} is newer than supported {}",
+        TAB_FORMAT_VERSION
+    )]
+    UnsupportedVersion(u32),
+    #[error("decode ron failed: {0}")]
+    DecodeRonFailed(String),
+    #[error("decode json failed: {0}")]
+    DecodeJsonFailed(String),
+    #[error("decode binary failed: {0}")]
+    DecodeBinaryFailed(String),
+    #[error("encode failed: {0}")]
+    EncodeFailed(String),
+    #[error("migrate from version {0} failed: {1}")]
+    MigrateFailed(u32, String),
+    #[error("decode tab failed: {0}")]
+    DecodeTabFailed(String),
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum TabEncoding {
+    Ron,
+    /// For web tooling.
+    Json,
+    /// MessagePack with a magic and version header, for wasm downloads.
+    Binary,
+}
+
+impl TabEncoding {
+    pub const EXTENSIONS: [&'static str; 3] = ["ron", "json", "ntab"];
+    pub const BINARY_MAGIC: [u8; 4] = *b"NTAB";
+
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension {
+            "ron" => Some(Self::Ron),
+            "json" => Some(Self::Json),
+            "ntab" => Some(Self::Binary),
+            _ => None,
+        }
+    }
+    pub fn to_extension(self) -> &'static str {
+        match self {
+            Self::Ron => "ron",
+            Self::Json => "json",
+            Self::Binary => "ntab",
+        }
+    }
+    pub fn from_path(path: &str) -> Result<Self, TabFileError> {
+        std::path::Path::new(path)
+            .extension()
+            .and_then(|x| x.to_str())
+            .and_then(Self::from_extension)
+            .ok_or_else(|| TabFileError::UnsupportedExtension(path.to_owned()))
+    }
+}
+
+#[derive(Serialize)]
+struct TabFileRef<'a> {
+    version: u32,
+    tab: &'a Tab,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct TabFile {
+    pub version: u32,
+    pub tab: Tab,
+}
+
+/// Upgrades a tab read from `version` to `version + 1`, the tab is untyped here, as
+/// `serde_json::to_value()` of the `Tab` of the version, since the old one can't be
+/// deserialized into the current types any more.
+pub type TabMigration = fn(Value) -> Result<Value, String>;
+
+/// Version 0 lacks the header, and may got the open notes of strings as pitch and octave
+/// `("E", "P4")`, which are semitones since version 1.
+fn migrate_v0(mut tab: Value) -> Result<Value, String> {
+    migrate_string_notes(&mut tab)?;
+    Ok(tab)
+}
+
+fn migrate_string_notes(value: &mut Value) -> Result<(), String> {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if key == "string_notes" {
+                    for note in value.as_array_mut().into_iter().flatten() {
+                        if let Some([Value::String(pitch), Value::String(octave)]) =
+                            note.as_array().map(|x| x.as_slice())
+                        {
+                            let semitones = Semitones::from((
+                                Pitch::from_text(pitch.as_str()),
+                                Octave::from_ident(octave.as_str()),
+                            ));
+                            *note = Value::from(semitones.0);
+                        } else if !note.is_i64() {
+                            return Err(format!("invalid string note: {}", note));
+                        }
+                    }
+                } else {
+                    migrate_string_notes(value)?;
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items.iter_mut() {
+                migrate_string_notes(item)?;
+            }
+        }
+        _ => (),
+    }
+    Ok(())
+}
+
+impl TabFile {
+    /// Indexed by the version to migrate from.
+    pub const MIGRATIONS: [TabMigration; TAB_FORMAT_VERSION as usize] = [migrate_v0];
+
+    pub fn migrate(version: u32, tab: Value) -> Result<Value, TabFileError> {
+        if version > TAB_FORMAT_VERSION {
+            return Err(TabFileError::UnsupportedVersion(version));
+        }
+        let mut tab = tab;
+        for from in version..TAB_FORMAT_VERSION {
+            tab = Self::MIGRATIONS[from as usize](tab)
+                .map_err(|err| TabFileError::MigrateFailed(from, err))?;
+        }
+        Ok(tab)
+    }
+    pub fn decode(encoding: TabEncoding, bytes: &[u8]) -> Result<Tab, TabFileError> {
+        let (version, tab) = match encoding {
+            TabEncoding::Ron => Self::split_header(Self::decode_ron(bytes)?)?,
+            TabEncoding::Json => Self::split_header(Self::decode_json(bytes)?)?,
+            TabEncoding::Binary => Self::decode_binary(bytes)?,
+        };
+        let tab = Self::migrate(version, tab)?;
+        serde_json::from_value::<Tab>(tab)
+            .map_err(|err| TabFileError::DecodeTabFailed(err.to_string()))
+    }
+    pub fn decode_path(path: &str, bytes: &[u8]) -> Result<Tab, TabFileError> {
+        Self::decode(TabEncoding::from_path(path)?, bytes)
+    }
+    /// Files from before the header got the tab only, which is version 0.
+    fn split_header(value: Value) -> Result<(u32, Value), TabFileError> {
+        let mut file = match value {
+            Value::Object(file) if file.contains_key("version") && file.contains_key("tab") => file,
+            tab => return Ok((0, tab)),
+        };
+        let version = file
+            .get("version")
+            .and_then(|x| x.as_u64())
+            .and_then(|x| u32::try_from(x).ok())
+            .ok_or_else(|| TabFileError::DecodeTabFailed("invalid version".to_owned()))?;
+        Ok((version, file.remove("tab").unwrap_or_default()))
+    }
+    fn decode_ron(bytes: &[u8]) -> Result<Value, TabFileError> {
+        let text = std::str::from_utf8(bytes)
+            .map_err(|err| TabFileError::DecodeRonFailed(err.to_string()))?;
+        parse_ron(text).map_err(TabFileError::DecodeRonFailed)
+    }
+    fn decode_json(bytes: &[u8]) -> Result<Value, TabFileError> {
+        serde_json::from_slice::<Value>(bytes)
+            .map_err(|err| TabFileError::DecodeJsonFailed(err.to_string()))
+    }
+    fn decode_binary(bytes: &[u8]) -> Result<(u32, Value), TabFileError> {
+        let header_len = TabEncoding::BINARY_MAGIC.len() + 4;
+        if bytes.len() < header_len || bytes[..4] != TabEncoding::BINARY_MAGIC {
+            return Err(TabFileError::DecodeBinaryFailed(
+                "invalid header".to_owned(),
+            ));
+        }
+        let version = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+        let tab = rmp_serde::from_slice::<Value>(&bytes[header_len..])
+            .map_err(|err| TabFileError::DecodeBinaryFailed(err.to_string()))?;
+        Ok((version, tab))
+    }
+    pub fn encode(encoding: TabEncoding, tab: &Tab) -> Result<Vec<u8>, TabFileError> {
+        let file = TabFileRef {
+            version: TAB_FORMAT_VERSION,
+            tab,
+        };
+        match encoding {
+            TabEncoding::Ron => {
+                let pretty = ron::ser::PrettyConfig::new()
+                    .separate_tuple_members(true)
+                    .enumerate_arrays(true);
+                ron::ser::to_string_pretty(&file, pretty)
+                    .map(|x| x.into_bytes())
+                    .map_err(|err| TabFileError::EncodeFailed(err.to_string()))
+            }
+            TabEncoding::Json => serde_json::to_vec_pretty(&file)
+                .map_err(|err| TabFileError::EncodeFailed(err.to_string())),
+            TabEncoding::Binary => {
+                let mut bytes = TabEncoding::BINARY_MAGIC.to_vec();
+                bytes.extend_from_slice(&TAB_FORMAT_VERSION.to_le_bytes());
+                // Written as the untyped value, so that it can be read back for migrations,
+                // `serde_json::Value` can't hold the raw bytes of a typed uuid.
+                let value = serde_json::to_value(tab)
+                    .map_err(|err| TabFileError::EncodeFailed(err.to_string()))?;
+                rmp_serde::encode::write_named(&mut bytes, &value)
+                    .map_err(|err| TabFileError::EncodeFailed(err.to_string()))?;
+                Ok(bytes)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use crate::prelude::{Tab, TabEncoding, TabFile, TabFileError, TAB_FORMAT_VERSION};
+
+    /// Written before the header was added.
+    const LEGACY_RON: &str = include_str!("../tests/legacy_test.ron");
+
+    fn to_value(tab: &Tab) -> Value {
+        serde_json::to_value(tab).unwrap()
+    }
+
+    #[test]
+    fn test_legacy_ron() {
+        let tab = TabFile::decode(TabEncoding::Ron, LEGACY_RON.as_bytes()).unwrap();
+        assert_eq!(tab.uuid.to_string(), "c430733f-46c3-4db2-9685-a72c05027e62");
+        assert_eq!(tab.tracks.len(), 2);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let tab = TabFile::decode(TabEncoding::Ron, LEGACY_RON.as_bytes()).unwrap();
+        for encoding in [TabEncoding::Ron, TabEncoding::Json, TabEncoding::Binary] {
+            let bytes = TabFile::encode(encoding, &tab).unwrap();
+            let decoded = TabFile::decode(encoding, &bytes).unwrap();
+            assert_eq!(to_value(&decoded), to_value(&tab), "{:?}", encoding);
+        }
+    }
+
+    #[test]
+    fn test_migrate_string_notes() {
+        let text = LEGACY_RON.replacen("(52)", "(\"E\", \"P4\")", 1);
+        let tab = TabFile::decode(TabEncoding::Ron, text.as_bytes()).unwrap();
+        assert_eq!(
+            to_value(&tab),
+            to_value(&TabFile::decode(TabEncoding::Ron, LEGACY_RON.as_bytes()).unwrap())
+        );
+
+        let file =
+            json!({ "version": 0, "tab": { "string_notes": [["E", "P4"], 47, ["Bb", "P2"]] } });
+        let tab = TabFile::migrate(0, file["tab"].clone()).unwrap();
+        assert_eq!(tab, json!({ "string_notes": [52, 47, 34] }));
+        assert!(TabFile::migrate(0, json!({ "string_notes": ["E"] })).is_err());
+        assert_eq!(TabFile::migrate(1, file.clone()).unwrap(), file);
+    }
+
+    #[test]
+    fn test_newer_version() {
+        let file = json!({ "version": TAB_FORMAT_VERSION + 1, "tab": {} });
+        let result = TabFile::decode(TabEncoding::Json, file.to_string().as_bytes());
+        assert!(matches!(
+            result,
+            Err(TabFileError::UnsupportedVersion(version)) if version == TAB_FORMAT_VERSION + 1
+        ));
+    }
+}
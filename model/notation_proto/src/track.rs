@@ -35,7 +35,7 @@ impl TrackKind {
             "Drums" => Self::Drums,
             "Bass" => Self::Bass,
             _ => {
-                println!("TrackKind::from_ident() Unsupported ident: {}", ident);
+                log::warn!("TrackKind::from_ident() Unsupported ident: {}", ident);
                 Self::Unsupported
             }
         }
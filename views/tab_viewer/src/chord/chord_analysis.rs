@@ -0,0 +1,132 @@
+use std::fmt::Display;
+
+use edger_bevy::bevy_prelude::*;
+use edger_bevy::prelude::{entity, offscreen, ShapeOp, StrokeLine};
+use notation_model::prelude::{ChordAnalysis, LaneEntry};
+use notation_midi::prelude::PlayingState;
+
+use crate::prelude::{
+    EntryData, EntryPlaying, NotationAssets, NotationSettings, NotationTheme, SingleBundle,
+};
+
+#[derive(Clone, Debug, Component)]
+pub struct ChordAnalysisValue {
+    pub analysis: ChordAnalysis,
+    pub playing_state: PlayingState,
+    pub bar_size: f32,
+}
+impl ChordAnalysisValue {
+    pub fn new(analysis: ChordAnalysis) -> Self {
+        Self {
+            analysis,
+            playing_state: PlayingState::Idle,
+            bar_size: 0.0,
+        }
+    }
+}
+impl Display for ChordAnalysisValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+pub type ChordAnalysisData = EntryData<ChordAnalysisValue>;
+
+impl ShapeOp<NotationTheme, StrokeLine> for ChordAnalysisData {
+    fn get_shape(&self, theme: &NotationTheme) -> StrokeLine {
+        let width = self.value.bar_size / self.bar_props.bar_units.to_f32()
+            * self.entry_props.tied_units.to_f32()
+            - theme.sizes.chord.analysis_gap;
+        let line_width = theme.sizes.chord.analysis_height.of_state(&self.value.playing_state);
+        let offset = if self.value.bar_size <= 0.0 {
+            offscreen::offset()
+        } else {
+            let x =
+                self.value.bar_size / self.bar_props.bar_units.to_f32() * self.entry_props.in_bar_pos.to_f32();
+            Vec3::new(x, 0.0, theme.z.word)
+        };
+        let y = -theme.sizes.chord.analysis_layout_height() / 2.0;
+        StrokeLine {
+            from: Vec2::new(0.0, y),
+            to: Vec2::new(width, y),
+            line_width,
+            color: theme.colors.chord.analysis_line.of_state(&self.value.playing_state),
+            offset,
+        }
+    }
+}
+
+/// Marker for the label in the chords view, which is moved separately from the bars text.
+#[derive(Clone, Debug, Component)]
+pub struct ChordAnalysisLabel {
+    pub analysis: ChordAnalysis,
+}
+impl Display for ChordAnalysisLabel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<ChordAnalysisLabel>({})", self.analysis)
+    }
+}
+
+impl ChordAnalysisLabel {
+    pub fn spawn(
+        commands: &mut Commands,
+        assets: &NotationAssets,
+        theme: &NotationTheme,
+        settings: &NotationSettings,
+        entity: Entity,
+        analysis: &ChordAnalysis,
+    ) -> Entity {
+        let label_entity = entity::spawn_child_bundle(
+            commands,
+            entity,
+            SingleBundle::<ChordAnalysisLabel>::from(ChordAnalysisLabel {
+                analysis: analysis.clone(),
+            }),
+        );
+        theme.texts.chord.spawn_diagram_analysis_text(
+            commands,
+            assets,
+            settings,
+            label_entity,
+            analysis,
+            theme.z.chord_text,
+        );
+        label_entity
+    }
+}
+
+pub fn create_chord_analysis(
+    commands: &mut Commands,
+    assets: &NotationAssets,
+    theme: &NotationTheme,
+    settings: &NotationSettings,
+    entity: Entity,
+    entry: &LaneEntry,
+    analysis: ChordAnalysis,
+) {
+    let data = ChordAnalysisData::new(entry, ChordAnalysisValue::new(analysis.clone()));
+    let line_entity = data.create(commands, theme, entity);
+    theme
+        .texts
+        .chord
+        .spawn_analysis_text(commands, assets, settings, line_entity, &analysis);
+}
+
+pub fn on_entry_playing_changed(
+    mut commands: Commands,
+    theme: Res<NotationTheme>,
+    query: Query<(Entity, &EntryPlaying, &Children), Changed<EntryPlaying>>,
+    mut analysis_query: Query<(Entity, &mut ChordAnalysisData)>,
+) {
+    if theme._bypass_systems {
+        return;
+    }
+    for (_entity, playing, children) in query.iter() {
+        for child in children.iter() {
+            if let Ok((entity, mut data)) = analysis_query.get_mut(*child) {
+                data.value.playing_state = playing.value;
+                data.update(&mut commands, &theme, entity);
+            }
+        }
+    }
+}
@@ -7,9 +7,10 @@ use edger_bevy::prelude::{
 };
 use notation_model::prelude::TabChord;
 
-use crate::prelude::{NotationAssets, NotationTheme};
+use crate::prelude::{NotationAssets, NotationSettings, NotationTheme};
 use crate::prelude::NotationLayout;
 
+use super::chord_analysis::ChordAnalysisLabel;
 use super::chord_base::ChordBaseData;
 use super::chord_diagram::ChordDiagramData;
 use super::chord_interval::ChordIntervalData;
@@ -44,6 +45,7 @@ impl ChordView {
         mut base_query: Query<(Entity, &mut ChordBaseData, &Children)>,
         mut dot_query: Query<(Entity, &mut IntervalDotData)>,
         mut text_query: Query<&mut Transform, With<Text>>,
+        mut label_query: Query<&mut Transform, (With<ChordAnalysisLabel>, Without<Text>)>,
     ) {
         if theme._bypass_systems {
             return;
@@ -68,6 +70,9 @@ impl ChordView {
                 if let Ok(mut transform) = text_query.get_mut(*child) {
                     theme.texts.chord.update_bars_xy(&mut transform, layout);
                 }
+                if let Ok(mut transform) = label_query.get_mut(*child) {
+                    theme.texts.chord.update_diagram_analysis_xy(&mut transform, layout);
+                }
             }
         }
     }
@@ -75,6 +80,7 @@ impl ChordView {
         commands: &mut Commands,
         assets: &NotationAssets,
         theme: &NotationTheme,
+        settings: &NotationSettings,
         entity: Entity,
         chord: &TabChord,
     ) -> Entity {
@@ -108,6 +114,11 @@ impl ChordView {
                 theme.z.chord_text,
             );
         }
+        if settings.show_chord_analysis {
+            if let Some(analysis) = chord.calc_analysis() {
+                ChordAnalysisLabel::spawn(commands, assets, theme, settings, chord_entity, &analysis);
+            }
+        }
         chord_entity
     }
     pub fn on_chord_playing_changed(
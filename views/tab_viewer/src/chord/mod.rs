@@ -1,3 +1,4 @@
+pub mod chord_analysis;
 pub mod chord_base;
 pub mod chord_bundle;
 pub mod chord_color_background;
@@ -18,7 +18,7 @@ impl EntryPlaying {
                 if tab_state.is_bar_in_range(entry_playing.bar_props.bar_ordinal) {
                     if entry.0.bar_props().bar_ordinal
                         == tab_state.play_control.position.bar.bar_ordinal
-                        && entry.0.props.in_bar_pos.0 == 0.0
+                        && entry.0.props.in_bar_pos.is_zero()
                     {
                         entry_playing.value = PlayingState::Current;
                     } else {
@@ -46,11 +46,16 @@ impl EntryPlaying {
         for (_entity, entry, mut entry_playing) in query.iter_mut() {
             let bar_ordinal = entry_playing.bar_props.bar_ordinal;
             if tab_state.is_bar_in_range(bar_ordinal) {
+                // Follow the groove, so the highlight is in sync with the audio.
+                let (bar_position, tied_units) = tab_state
+                    .tab
+                    .meta
+                    .apply_groove(&entry_playing.bar_position(), entry.0.tied_units());
                 if entry_playing.value.is_current()
                     && new_position.is_passed_with(
                         entry.0.pass_mode(),
-                        &entry_playing.bar_position(),
-                        entry.0.tied_units(),
+                        &bar_position,
+                        tied_units,
                     )
                 {
                     if entry_playing.value != PlayingState::Played {
@@ -59,7 +64,7 @@ impl EntryPlaying {
                 }
                 if bar_ordinal == playing_bar_ordinal
                     && entry_playing.value.is_idle()
-                    && new_position.is_passed(entry.0.pass_mode(), &entry_playing.bar_position())
+                    && new_position.is_passed(entry.0.pass_mode(), &bar_position)
                 {
                     if entry_playing.value != PlayingState::Current {
                         entry_playing.value = PlayingState::Current;
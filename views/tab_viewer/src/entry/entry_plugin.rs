@@ -5,20 +5,21 @@ use edger_bevy::prelude::ShapeOp;
 use edger_bevy::prelude::AssetsStates;
 use notation_model::lane_kind::LaneKind;
 
+use crate::chord::chord_analysis::ChordAnalysisData;
 use crate::chord::chord_view::ChordView;
 use crate::tone::tone_line::ToneLineData;
 use crate::lane::lane_layout::LaneLayoutData;
 use crate::prelude::{
-    entity, ChordBundle, EntryBundle, LyricsPlugin, NotationAssets,
-    NotationSettings, NotationTheme, ShapesPlugin, StringsPlugin, ToneBundle,
+    entity, ChordBundle, EntryBundle, JianpuPlugin, LyricsPlugin, NotationAssets,
+    NotationSettings, NotationTheme, ShapesPlugin, StaffPlugin, StringsPlugin, ToneBundle,
 };
-use crate::shapes::shape_diagram::{ShapeDiagramData4, ShapeDiagramData6};
+use crate::shapes::shape_diagram::ShapeDiagramData;
 use crate::strings::pick_note::PickNoteData;
 use crate::strings::single_string::SingleStringData;
 use crate::tab::tab_events::TabBarsResizedEvent;
 use crate::tone::tone_note::ToneNoteData;
 use crate::word::word_text::WordTextData;
-use notation_model::prelude::{CoreEntry, LaneEntry, ProtoEntry};
+use notation_model::prelude::{ChordAnalysis, CoreEntry, LaneEntry, ProtoEntry};
 
 pub struct EntryPlugin;
 
@@ -27,6 +28,7 @@ impl Plugin for EntryPlugin {
         app.add_systems(Update, (
             crate::tone::tone_systems::on_entry_playing_changed,
             crate::word::word_systems::on_entry_playing_changed,
+            crate::chord::chord_analysis::on_entry_playing_changed,
             ChordView::on_layout_changed,
             ChordView::on_chord_playing_changed,
             on_tab_bars_resized,
@@ -57,6 +59,14 @@ fn insert_entry_extra(
     entry_entity: Entity,
     entry: &LaneEntry,
 ) {
+    if lane_layout.lane_kind == LaneKind::Jianpu {
+        JianpuPlugin::insert_entry_extra(commands, assets, theme, entry_entity, entry);
+        return;
+    }
+    if lane_layout.lane_kind == LaneKind::Staff {
+        StaffPlugin::insert_entry_extra(commands, theme, entry_entity, entry);
+        return;
+    }
     match entry.model.proto.as_ref() {
         ProtoEntry::Core(core_entry) => {
             insert_core_entry_extra(commands, assets, theme, settings, lane_layout.lane_kind, entry_entity, entry, core_entry)
@@ -70,8 +80,8 @@ fn insert_entry_extra(
             entry,
             lyric_entry,
         ),
-        ProtoEntry::Fretted6(fretted_entry) => {
-            ShapesPlugin::insert_entry_extra6(
+        ProtoEntry::Fretted(fretted_entry) => {
+            ShapesPlugin::insert_entry_extra(
                 commands,
                 assets,
                 theme,
@@ -80,28 +90,7 @@ fn insert_entry_extra(
                 entry,
                 fretted_entry,
             );
-            StringsPlugin::insert_entry_extra6(
-                commands,
-                assets,
-                theme,
-                settings,
-                lane_layout.lane_kind,
-                entry_entity,
-                entry,
-                fretted_entry,
-            );
-        }
-        ProtoEntry::Fretted4(fretted_entry) => {
-            ShapesPlugin::insert_entry_extra4(
-                commands,
-                assets,
-                theme,
-                settings,
-                entry_entity,
-                entry,
-                fretted_entry,
-            );
-            StringsPlugin::insert_entry_extra4(
+            StringsPlugin::insert_entry_extra(
                 commands,
                 assets,
                 theme,
@@ -129,10 +118,11 @@ fn insert_core_entry_extra(
     match core_entry {
         CoreEntry::Tie => (),
         CoreEntry::Rest(_) => (),
+        CoreEntry::Fermata => (),
         CoreEntry::Tone(tone, _) => {
             commands
                 .entity(entity)
-                .insert(ToneBundle::from(*tone));
+                .insert(ToneBundle::from(tone.clone()));
             crate::tone::tone_systems::create_tone_notes(
                 commands, assets, theme, settings, lane_kind.into(), entity, entry, tone,
             );
@@ -141,6 +131,14 @@ fn insert_core_entry_extra(
             commands
                 .entity(entity)
                 .insert(ChordBundle::from(*chord));
+            if lane_kind == LaneKind::Chord && settings.show_chord_analysis {
+                if let Some(bar) = entry.bar() {
+                    let analysis = ChordAnalysis::new(chord, &bar.tab_meta().scale);
+                    crate::chord::chord_analysis::create_chord_analysis(
+                        commands, assets, theme, settings, entity, entry, analysis,
+                    );
+                }
+            }
         }
     };
 }
@@ -155,8 +153,8 @@ fn on_tab_bars_resized(
     mut pick_note_query: Query<(Entity, &mut PickNoteData), With<PickNoteData>>,
     mut single_string_query: Query<(Entity, &mut SingleStringData), With<SingleStringData>>,
     mut word_text_query: Query<(Entity, &mut WordTextData), With<WordTextData>>,
-    mut shape_diagram_6_query: Query<(Entity, &mut ShapeDiagramData6), With<ShapeDiagramData6>>,
-    mut shape_diagram_4_query: Query<(Entity, &mut ShapeDiagramData4), With<ShapeDiagramData4>>,
+    mut chord_analysis_query: Query<(Entity, &mut ChordAnalysisData), With<ChordAnalysisData>>,
+    mut shape_diagram_query: Query<(Entity, &mut ShapeDiagramData), With<ShapeDiagramData>>,
 ) {
     if theme._bypass_systems {
         return;
@@ -187,8 +185,8 @@ fn on_tab_bars_resized(
                 }
             }
         }
-        if !settings.hide_shapes_lane {
-            for (entity, mut data) in shape_diagram_6_query.iter_mut() {
+        if settings.show_chord_analysis {
+            for (entity, mut data) in chord_analysis_query.iter_mut() {
                 for (view, layout) in bars.iter() {
                     if data.bar_props.bar_ordinal == view.bar_props.bar_ordinal {
                         data.value.bar_size = layout.size.width;
@@ -196,7 +194,9 @@ fn on_tab_bars_resized(
                     }
                 }
             }
-            for (entity, mut data) in shape_diagram_4_query.iter_mut() {
+        }
+        if !settings.hide_shapes_lane {
+            for (entity, mut data) in shape_diagram_query.iter_mut() {
                 for (view, layout) in bars.iter() {
                     if data.bar_props.bar_ordinal == view.bar_props.bar_ordinal {
                         data.value.bar_size = layout.size.width;
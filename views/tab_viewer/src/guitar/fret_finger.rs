@@ -3,7 +3,7 @@ use std::sync::Arc;
 use edger_bevy::bevy_prelude::*;
 use edger_bevy::prelude::{offscreen, LayoutSize, ShapeOp};
 use notation_model::prelude::{
-    Chord, Finger, Fretboard6, HandShape6, Interval, ModelEntryProps, Note, Pick, Syllable, TabMeta, Semitones,
+    Chord, Finger, Fretboard, HandShape, Interval, ModelEntryProps, Note, Pick, Syllable, TabMeta, Semitones,
 };
 
 use crate::chord::chord_note::{ChordNoteData, ChordNoteExtra, ChordNoteValue};
@@ -13,6 +13,7 @@ use crate::prelude::{NotationAssets, NotationSettings, NotationTheme};
 pub struct FretFingerExtra {
     pub visible: bool,
     pub string: u8,
+    pub string_num: usize,
     pub pick: bool,
     pub fret: Option<u8>,
     pub finger: Option<Finger>,
@@ -24,10 +25,11 @@ pub struct FretFingerExtra {
 pub type FretFingerData = ChordNoteData<FretFingerExtra>;
 
 impl FretFingerExtra {
-    pub fn new(string: u8, pick: bool, fret: Option<u8>, finger: Option<Finger>) -> Self {
+    pub fn new(string: u8, string_num: usize, pick: bool, fret: Option<u8>, finger: Option<Finger>) -> Self {
         Self {
             visible: false,
             string,
+            string_num,
             pick,
             fret,
             finger,
@@ -44,11 +46,12 @@ impl FretFingerData {
         root: Syllable,
         interval: Interval,
         string: u8,
+        string_num: usize,
         pick: bool,
         fret: Option<u8>,
         finger: Option<Finger>,
     ) -> Self {
-        let extra = FretFingerExtra::new(string, pick, fret, finger);
+        let extra = FretFingerExtra::new(string, string_num, pick, fret, finger);
         Self::from((
             entry_props,
             ChordNoteValue::<FretFingerExtra>::new(root, interval, extra),
@@ -98,9 +101,9 @@ impl FretFingerData {
     }
     pub fn update_pick(
         &mut self,
-        fretboard: Option<Fretboard6>,
+        fretboard: Option<&Fretboard>,
         chord: Option<Chord>,
-        pick: Pick,
+        pick: &Pick,
         meta: Option<Arc<TabMeta>>,
     ) -> bool {
         let visible = self.value.extra.visible;
@@ -132,10 +135,10 @@ impl FretFingerData {
     }
     pub fn update_value(
         &mut self,
-        shape: &HandShape6,
-        fretboard: Option<Fretboard6>,
+        shape: &HandShape,
+        fretboard: Option<&Fretboard>,
         chord: Option<Chord>,
-        pick: Option<Pick>,
+        pick: Option<&Pick>,
         meta: Option<Arc<TabMeta>>,
     ) {
         self.reset();
@@ -200,7 +203,7 @@ impl FretFingerData {
 impl ChordNoteExtra for FretFingerExtra {
     fn set_diagram_radius(&mut self, _diagram_radius: f32) {}
     fn radius(&self, theme: &NotationTheme) -> f32 {
-        theme.guitar.string_x_factor * self.guitar_size.width / 2.0
+        theme.guitar.calc_finger_radius(self.string_num, self.guitar_size.width)
     }
     fn offset(&self, theme: &NotationTheme) -> Vec2 {
         if !self.visible || self.guitar_size.width <= 0.0 {
@@ -208,7 +211,7 @@ impl ChordNoteExtra for FretFingerExtra {
         }
         let x = theme
             .guitar
-            .calc_string_x(self.string, self.guitar_size.width);
+            .calc_string_x(self.string, self.string_num, self.guitar_size.width);
         let fret = self.fret.unwrap_or(0);
         let y = theme
             .guitar
@@ -1,14 +1,14 @@
 use edger_bevy::bevy_prelude::*;
 use edger_bevy::bevy_prototype_lyon::prelude::*;
 use edger_bevy::prelude::{offscreen, FillRectangle, LayoutSize, ShapeOp};
-use notation_model::prelude::{HandShape6, Pick};
+use notation_model::prelude::{HandShape, Pick};
 
 use crate::prelude::NotationTheme;
 
 #[derive(Clone, Debug, Component)]
 pub struct GuitarBarreData {
     pub capo: u8,
-    pub shape: Option<HandShape6>,
+    pub shape: Option<HandShape>,
     pub pick: Option<Pick>,
     pub view_size: LayoutSize,
     pub guitar_size: LayoutSize,
@@ -27,16 +27,16 @@ impl Default for GuitarBarreData {
 }
 impl GuitarBarreData {
     pub fn update_pick(&mut self, pick: &Pick) {
-        if self.pick.is_none() || self.pick.unwrap().max_fret() < pick.max_fret() {
+        if self.pick.is_none() || self.pick.as_ref().unwrap().max_fret() < pick.max_fret() {
             self.pick = Some(pick.clone());
         }
     }
     pub fn barre(&self) -> u8 {
-        self.shape.and_then(|x| x.barre).unwrap_or(0)
+        self.shape.as_ref().and_then(|x| x.barre).unwrap_or(0)
     }
     pub fn max_fret(&self) -> u8 {
-        let mut max = self.shape.map(|x| x.max_fret_with_barre()).unwrap_or(0);
-        if let Some(pick) = self.pick {
+        let mut max = self.shape.as_ref().map(|x| x.max_fret_with_barre()).unwrap_or(0);
+        if let Some(pick) = &self.pick {
             let pick_max = pick.max_fret();
             if pick_max > max {
                 max = pick_max
@@ -4,7 +4,7 @@ use edger_bevy::bevy_prelude::*;
 use edger_bevy::bevy_prototype_lyon::prelude::*;
 use edger_bevy::prelude::{math, LayoutSize, OutlineRectangle, ShapeOp};
 use notation_model::prelude::{
-    Duration, Fretboard6, HandShape6, Pick, Note, TabMeta, Units,
+    Duration, Fretboard, HandShape, Pick, Note, TabMeta, Units,
 };
 use notation_midi::prelude::{PlaySpeed, PlayingState};
 
@@ -13,6 +13,7 @@ use crate::prelude::NotationTheme;
 #[derive(Clone, Debug, Component)]
 pub struct GuitarStringData {
     pub string: u8,
+    pub string_num: usize,
     pub upper: bool,
     pub fret: Option<u8>,
     pub pick_fret: Option<u8>,
@@ -27,10 +28,11 @@ pub struct GuitarStringData {
 }
 
 impl GuitarStringData {
-    pub fn new(string: u8, upper: bool, fretboard: Option<Fretboard6>) -> Self {
+    pub fn new(string: u8, string_num: usize, upper: bool, fretboard: Option<&Fretboard>) -> Self {
         let capo = fretboard.map(|x| x.capo).unwrap_or(0);
         Self {
             string,
+            string_num,
             upper,
             fret: Some(0),
             pick_fret: None,
@@ -100,7 +102,7 @@ impl GuitarStringData {
         };
         self.hit_expired_seconds = time.elapsed_secs_f64() + self.hit_seconds as f64;
     }
-    fn set_note(&mut self, fretboard: Option<Fretboard6>, meta: Option<Arc<TabMeta>>) {
+    fn set_note(&mut self, fretboard: Option<&Fretboard>, meta: Option<Arc<TabMeta>>) {
         self.note = None;
         if let (Some(meta), Some(fretboard)) = (meta, fretboard) {
             if let Some(note) = fretboard.fretted_note(&meta.scale, &meta.key, self.string, self.fret()) {
@@ -110,8 +112,8 @@ impl GuitarStringData {
     }
     pub fn update_pick(
         &mut self,
-        fretboard: Option<Fretboard6>,
-        pick: Pick,
+        fretboard: Option<&Fretboard>,
+        pick: &Pick,
         meta: Option<Arc<TabMeta>>,
     ) {
         let pick_note = pick.get_pick_note(self.string);
@@ -120,9 +122,9 @@ impl GuitarStringData {
     }
     pub fn update_value(
         &mut self,
-        shape: &HandShape6,
-        fretboard: Option<Fretboard6>,
-        pick: Option<Pick>,
+        shape: &HandShape,
+        fretboard: Option<&Fretboard>,
+        pick: Option<&Pick>,
         meta: Option<Arc<TabMeta>>,
     ) {
         let pick_note = pick.and_then(|x| x.get_pick_note(self.string));
@@ -175,7 +177,7 @@ impl ShapeOp<NotationTheme, OutlineRectangle> for GuitarStringData {
         let outline_width = self.outline(theme);
         let x = theme
             .guitar
-            .calc_string_x(self.string, self.guitar_size.width);
+            .calc_string_x(self.string, self.string_num, self.guitar_size.width);
         let fret_y = theme
             .guitar
             .calc_fret_y(self.fret() + self.capo, self.guitar_size.height);
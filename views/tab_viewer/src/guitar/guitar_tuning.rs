@@ -0,0 +1,60 @@
+use std::fmt::Display;
+
+use edger_bevy::bevy::sprite::Anchor;
+use edger_bevy::bevy_prelude::*;
+use edger_bevy::prelude::{entity, text, LayoutSize};
+use notation_model::prelude::{Fretboard, TabMeta};
+
+use crate::prelude::{NotationAssets, NotationTheme, SingleBundle};
+
+/// Open note of the string, above the nut.
+#[derive(Clone, Debug, Component)]
+pub struct GuitarTuningLabel {
+    pub string: u8,
+    pub string_num: usize,
+}
+impl Display for GuitarTuningLabel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<GuitarTuningLabel>({})", self.string)
+    }
+}
+
+impl GuitarTuningLabel {
+    pub fn spawn(
+        commands: &mut Commands,
+        assets: &NotationAssets,
+        theme: &NotationTheme,
+        entity: Entity,
+        fretboard: &Fretboard,
+        meta: &TabMeta,
+    ) {
+        let string_num = fretboard.string_num();
+        for string in 1..=string_num as u8 {
+            if let Some(note) = fretboard.tuning_note(&meta.scale, &meta.key, string) {
+                let label_entity = entity::spawn_child_bundle(
+                    commands,
+                    entity,
+                    SingleBundle::<GuitarTuningLabel>::from(GuitarTuningLabel { string, string_num }),
+                );
+                text::spawn(
+                    commands,
+                    label_entity,
+                    note.pitch.to_text().as_str(),
+                    assets.latin_font.clone(),
+                    theme.guitar.tuning_font_size,
+                    theme.guitar.tuning_font_color,
+                    JustifyText::Center,
+                    Anchor::BottomCenter,
+                    0.0,
+                    0.0,
+                    theme.z.guitar_string,
+                );
+            }
+        }
+    }
+    pub fn update_xy(&self, theme: &NotationTheme, transform: &mut Transform, guitar_size: LayoutSize) {
+        transform.translation.x = theme.guitar.calc_string_x(self.string, self.string_num, guitar_size.width);
+        transform.translation.y =
+            theme.guitar.calc_fret_y(0, guitar_size.height) + theme.guitar.tuning_text_y;
+    }
+}
@@ -7,7 +7,7 @@ use edger_bevy::prelude::{
     entity, offscreen, LayoutAnchor, LayoutChangedQuery, LayoutSize, ShapeOp, View, ViewBundle, SingleData,
 };
 use notation_model::prelude::{
-    Duration, Entry, HandShape6, Interval, LaneEntry, LaneKind, ModelEntryProps, Pick, Syllable,
+    Duration, Entry, HandShape, Interval, LaneEntry, LaneKind, ModelEntryProps, Pick, Syllable,
     Tab, TrackKind, Units,
 };
 
@@ -19,6 +19,7 @@ use super::fret_finger::FretFingerData;
 use super::guitar_barre::GuitarBarreData;
 use super::guitar_capo::GuitarCapoData;
 use super::guitar_string::GuitarStringData;
+use super::guitar_tuning::GuitarTuningLabel;
 
 #[cfg(feature = "midi")]
 use notation_midi::prelude::MidiState;
@@ -63,20 +64,26 @@ impl GuitarView {
             theme.guitar.image_size.1,
         ));
         sprite.image = assets.fretboard.clone();
-        let fretboard = tab
+        let track = tab
             .get_track_of_kind(TrackKind::Guitar)
-            .and_then(|x| x.get_fretboard6());
+            .or_else(|| tab.get_track_of_kind(TrackKind::Bass));
+        let fretboard = track.as_ref().and_then(|x| x.get_fretboard());
+        let string_num = track.and_then(|x| x.get_string_num()).unwrap_or(6);
 
         entity::spawn_child_bundle(commands, guitar_entity, (
             sprite,
             offscreen::transform(),
         ));
-        for string in 1..=6 {
+        for string in 1..=string_num {
             for upper in [true, false] {
-                let string_data = GuitarStringData::new(string as u8, upper, fretboard);
+                let string_data =
+                    GuitarStringData::new(string as u8, string_num, upper, fretboard.as_ref());
                 string_data.create(commands, theme, guitar_entity);
             }
         }
+        if let Some(fretboard) = fretboard.as_ref() {
+            GuitarTuningLabel::spawn(commands, assets, theme, guitar_entity, fretboard, &tab.meta);
+        }
         let capo_data = GuitarCapoData::default();
         capo_data.create(commands, theme, guitar_entity);
         let barre_data = GuitarBarreData::default();
@@ -88,11 +95,12 @@ impl GuitarView {
                 let mut finger_data = FretFingerData::new_data(
                     ModelEntryProps {
                         index: 0,
-                        tied_units: Units(0.0),
+                        tied_units: Units::ZERO,
                     },
                     Syllable::Do,
                     Interval::Unison,
                     string as u8,
+                    string_num,
                     false,
                     Some(fret as u8),
                     None,
@@ -100,22 +108,23 @@ impl GuitarView {
                 finger_data.value.extra.visible = true;
                 finger_data.spawn(commands, theme, guitar_entity);
                 string = string + 1;
-                if string > 6 {
+                if string > string_num {
                     string = 1;
                 }
                 fret = fret + 1;
             }
         } else {
-            for index in 1..=6 {
+            for index in 1..=string_num {
                 for pick in [true, false] {
                     let finger_data = FretFingerData::new_data(
                         ModelEntryProps {
                             index: 0,
-                            tied_units: Units(0.0),
+                            tied_units: Units::ZERO,
                         },
                         Syllable::Do,
                         Interval::Unison,
                         index as u8,
+                        string_num,
                         pick,
                         None,
                         None,
@@ -135,6 +144,7 @@ impl GuitarView {
         mut capo_query: Query<(&Parent, Entity, &mut GuitarCapoData), With<GuitarCapoData>>,
         mut barre_query: Query<(&Parent, Entity, &mut GuitarBarreData), With<GuitarBarreData>>,
         mut finger_query: Query<(&Parent, Entity, &mut FretFingerData), With<FretFingerData>>,
+        mut label_query: Query<(&Parent, &GuitarTuningLabel, &mut Transform), Without<Sprite>>,
     ) {
         if theme._bypass_systems {
             return;
@@ -162,6 +172,11 @@ impl GuitarView {
                     finger_data.update(&mut commands, &theme, finger_entity);
                 }
             }
+            for (parent, label, mut transform) in label_query.iter_mut() {
+                if parent.get() == entity {
+                    label.update_xy(&theme, &mut transform, guitar_size);
+                }
+            }
             for (parent, capo_entity, mut capo_data) in capo_query.iter_mut() {
                 if parent.get() == entity {
                     capo_data.view_size = layout.size;
@@ -203,14 +218,15 @@ impl GuitarView {
             return;
         }
         let mut current_entry_pick = None;
-        let mut string_states = [None; 6];
-        let mut hit_strings = [(false, Duration::Zero); 6];
+        let string_num = string_query.iter().map(|(_, x)| x.string_num).max().unwrap_or(0);
+        let mut string_states = vec![None; string_num];
+        let mut hit_strings = vec![(false, Duration::Zero); string_num];
         for (entry, pick, playing) in query.iter() {
             if playing.value.is_current() {
-                current_entry_pick = Some((entry.0.clone(), pick.0));
+                current_entry_pick = Some((entry.0.clone(), pick.0.clone()));
             }
             for pick_note in pick.0.get_notes() {
-                if pick_note.string >= 1 && pick_note.string <= 6 {
+                if pick_note.string >= 1 && pick_note.string as usize <= string_num {
                     string_states[(pick_note.string - 1) as usize] = Some(playing.value);
                     hit_strings[(pick_note.string - 1) as usize] =
                         (playing.value.is_current(), entry.0.duration());
@@ -218,11 +234,11 @@ impl GuitarView {
             }
         }
         let fretboard = current_entry_pick.clone()
-            .and_then(|(entry, _)| entry.track().and_then(|x| x.get_fretboard6()));
+            .and_then(|(entry, _)| entry.track().and_then(|x| x.get_fretboard()));
         let meta = current_entry_pick.clone().and_then(|(entry, _)| entry.bar().map(|x| x.tab_meta()));
 
         for (string_entity, mut string_data) in string_query.iter_mut() {
-            if string_data.string >= 1 && string_data.string <= 6 {
+            if string_data.string >= 1 && string_data.string as usize <= string_num {
                 let (hit, hit_duration) = hit_strings[(string_data.string - 1) as usize];
                 #[cfg(feature = "midi")]
                 string_data.set_hit(
@@ -235,8 +251,8 @@ impl GuitarView {
                 if let Some(state) = string_states[(string_data.string - 1) as usize] {
                     string_data.state = state;
                 }
-                if let Some((_, pick)) = current_entry_pick {
-                    string_data.update_pick(fretboard, pick, meta.clone());
+                if let Some((_, pick)) = &current_entry_pick {
+                    string_data.update_pick(fretboard.as_ref(), pick, meta.clone());
                 }
                 string_data.update(&mut commands, &theme, string_entity);
             }
@@ -244,7 +260,7 @@ impl GuitarView {
         if let Some((entry, pick)) = current_entry_pick {
             let chord = entry.bar().and_then(|x| x.get_chord_of_entry(&entry));
             for (finger_entity, mut finger_data) in finger_query.iter_mut() {
-                let changed = finger_data.update_pick(fretboard, chord, pick, meta.clone());
+                let changed = finger_data.update_pick(fretboard.as_ref(), chord, &pick, meta.clone());
                 if changed {
                     if finger_data.value.extra.pick {
                         finger_data.respawn_dots(
@@ -270,12 +286,13 @@ impl GuitarView {
             }
         }
     }
-    pub fn update_hand_shape6(
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    pub fn update_hand_shape(
         mut commands: Commands,
         assets: Res<NotationAssets>,
         theme: Res<NotationTheme>,
         settings: Res<NotationSettings>,
-        query: Query<(&SingleData<LaneEntry>, &SingleData<HandShape6>, &EntryPlaying), Changed<EntryPlaying>>,
+        query: Query<(&SingleData<LaneEntry>, &SingleData<HandShape>, &EntryPlaying), Changed<EntryPlaying>>,
         mut finger_query: Query<(Entity, &mut FretFingerData), With<FretFingerData>>,
         mut string_query: Query<(Entity, &mut GuitarStringData), With<GuitarStringData>>,
         mut capo_query: Query<(Entity, &mut GuitarCapoData), With<GuitarCapoData>>,
@@ -296,12 +313,12 @@ impl GuitarView {
         let mut current_shape = None;
         for (entry, shape, playing) in query.iter() {
             if playing.value.is_current() {
-                //println!("GuitarView::update_hand_shape6(): found changed playing shape: {}", shape);
-                current_shape = Some((entry.0.clone(), shape.0));
+                //println!("GuitarView::update_hand_shape(): found changed playing shape: {}", shape);
+                current_shape = Some((entry.0.clone(), shape.0.clone()));
             }
         }
         if let Some((entry, shape)) = current_shape {
-            let fretboard = entry.track().and_then(|x| x.get_fretboard6());
+            let fretboard = entry.track().and_then(|x| x.get_fretboard());
             let chord = entry.bar().and_then(|x| x.get_chord_of_entry(&entry));
             let pick = entry.bar().and_then(|x| {
                 x.get_entry_in_other_lane(
@@ -310,16 +327,16 @@ impl GuitarView {
                     Some(entry.in_bar_pos()),
                     &|x: &LaneEntry| {
                         x.proto()
-                            .as_fretted6()
+                            .as_fretted()
                             .and_then(|y| y.as_pick())
                             .map(|z| z.to_owned())
                     },
                 )
             });
             let meta = entry.bar().map(|x| x.tab_meta());
-            //println!("GuitarView::update_hand_shape6(): {}, {:#?}, {:#?}", shape, fretboard, chord);
+            //println!("GuitarView::update_hand_shape(): {}, {:#?}, {:#?}", shape, fretboard, chord);
             for (finger_entity, mut finger_data) in finger_query.iter_mut() {
-                finger_data.update_value(&shape, fretboard, chord, pick, meta.clone());
+                finger_data.update_value(&shape, fretboard.as_ref(), chord, pick.as_ref(), meta.clone());
                 finger_data.respawn_dots(&mut commands, &theme, Some(&mut dot_query), finger_entity);
                 finger_data.update_with_syllable(
                     &mut commands,
@@ -332,7 +349,7 @@ impl GuitarView {
                 );
             }
             for (string_entity, mut string_data) in string_query.iter_mut() {
-                string_data.update_value(&shape, fretboard, pick, meta.clone());
+                string_data.update_value(&shape, fretboard.as_ref(), pick.as_ref(), meta.clone());
                 string_data.update(&mut commands, &theme, string_entity);
             }
             if let Some(fretboard) = fretboard {
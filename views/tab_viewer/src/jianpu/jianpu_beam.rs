@@ -0,0 +1,62 @@
+use edger_bevy::bevy_prelude::*;
+use edger_bevy::prelude::{offscreen, ShapeOp, StrokePath};
+use notation_model::prelude::Units;
+
+use crate::prelude::{LaneData, NotationTheme};
+use crate::staff::staff_path::StaffPath;
+
+/// Underlines shared by the entries of a beat.
+#[derive(Clone, Debug)]
+pub struct JianpuBeamValue {
+    /// Position and number of underlines of each entry.
+    pub notes: Vec<(Units, usize)>,
+    pub bar_size: f32,
+}
+
+pub type JianpuBeamData = LaneData<JianpuBeamValue>;
+
+impl ShapeOp<NotationTheme, StrokePath> for JianpuBeamData {
+    fn get_shape(&self, theme: &NotationTheme) -> StrokePath {
+        let sizes = theme.sizes.jianpu;
+        let mut path = StaffPath::default();
+        let offset = if self.value.bar_size <= 0.0 || self.bar_props.bar_units.to_f32() <= 0.0 {
+            offscreen::offset()
+        } else {
+            let unit_size = self.value.bar_size / self.bar_props.bar_units.to_f32();
+            let half = sizes.number_width / 2.0;
+            let notes = &self.value.notes;
+            let max_underlines = notes.iter().map(|x| x.1).max().unwrap_or(0);
+            for level in 0..max_underlines {
+                let y = sizes.calc_underline_y(level);
+                let mut index = 0;
+                while index < notes.len() {
+                    if notes[index].1 <= level {
+                        index += 1;
+                        continue;
+                    }
+                    let begin = index;
+                    while index + 1 < notes.len() && notes[index + 1].1 > level {
+                        index += 1;
+                    }
+                    path.line(
+                        notes[begin].0.to_f32() * unit_size - half,
+                        y,
+                        notes[index].0.to_f32() * unit_size + half,
+                        y,
+                    );
+                    index += 1;
+                }
+            }
+            Vec3::new(sizes.note_x, sizes.calc_number_y(), theme.z.tone)
+        };
+        StrokePath {
+            size: Vec2::ZERO,
+            path: path.path,
+            color: theme.colors.jianpu.underline,
+            line_width: sizes.line_width,
+            offset,
+            scale: 1.0,
+            angle: 0.0,
+        }
+    }
+}
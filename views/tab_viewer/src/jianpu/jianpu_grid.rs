@@ -0,0 +1,33 @@
+use edger_bevy::bevy_prelude::*;
+
+use crate::prelude::NotationTheme;
+use edger_bevy::prelude::ShapeOp;
+use notation_model::prelude::{BarLane, LaneJianpu};
+
+use super::jianpu_beam::{JianpuBeamData, JianpuBeamValue};
+
+#[derive(Debug, Default, Component)]
+pub struct JianpuGrid();
+
+impl JianpuGrid {
+    pub fn add_underlines(
+        &self,
+        commands: &mut Commands,
+        theme: &NotationTheme,
+        entity: Entity,
+        lane: &BarLane,
+    ) {
+        let jianpu = LaneJianpu::new(lane);
+        for indexes in jianpu.beams.iter() {
+            let data = JianpuBeamData::new(lane, JianpuBeamValue {
+                notes: indexes
+                    .iter()
+                    .map(|x| &jianpu.entries[*x])
+                    .map(|x| (x.in_bar_pos, x.underlines))
+                    .collect(),
+                bar_size: 0.0,
+            });
+            data.create(commands, theme, entity);
+        }
+    }
+}
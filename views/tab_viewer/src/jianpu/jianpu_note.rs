@@ -0,0 +1,98 @@
+use edger_bevy::bevy_prelude::*;
+use edger_bevy::prelude::{offscreen, ShapeOp, StrokePath};
+use notation_midi::prelude::PlayingState;
+use notation_model::prelude::{JianpuEntry, Unit, Units};
+
+use crate::prelude::{EntryData, NotationTheme};
+use crate::staff::staff_path::StaffPath;
+use crate::theme::theme_sizes::JianpuSizes;
+
+/// Everything but the numbers: octave dots, dashes, augmentation dot and tie.
+#[derive(Clone, Debug)]
+pub struct JianpuNoteValue {
+    pub entry: JianpuEntry,
+    pub playing_state: PlayingState,
+    pub bar_size: f32,
+}
+
+pub type JianpuNoteData = EntryData<JianpuNoteValue>;
+
+impl JianpuNoteValue {
+    pub fn new(entry: JianpuEntry) -> Self {
+        Self {
+            entry,
+            playing_state: PlayingState::Idle,
+            bar_size: 0.0,
+        }
+    }
+    /// Relative to the center of the lowest number.
+    pub fn calc_number_y(sizes: &JianpuSizes, row: usize) -> f32 {
+        row as f32 * sizes.row_height
+    }
+    fn add_dot(path: &mut StaffPath, sizes: &JianpuSizes, x: f32, y: f32) {
+        path.ellipse(x, y, sizes.dot_radius, sizes.dot_radius);
+    }
+    fn add_lines(&self, path: &mut StaffPath, sizes: &JianpuSizes, unit_size: f32, tie_end: f32) {
+        let entry = &self.entry;
+        let rows = entry.notes.len().max(1);
+        for (row, note) in entry.notes.iter().enumerate() {
+            let y = Self::calc_number_y(sizes, row);
+            for dot in 0..note.octave.unsigned_abs() as usize {
+                let dot_y = if note.octave > 0 {
+                    y + sizes.number_half_height + sizes.dot_space * (dot as f32 + 0.5)
+                } else if row == 0 {
+                    sizes.calc_underline_y(entry.underlines.max(1) - 1)
+                        - sizes.dot_space * (dot as f32 + 0.75)
+                } else {
+                    y - sizes.number_half_height - sizes.dot_space * (dot as f32 + 0.5)
+                };
+                Self::add_dot(path, sizes, 0.0, dot_y);
+            }
+        }
+        if entry.dotted {
+            Self::add_dot(path, sizes, sizes.number_width * 0.5 + sizes.dot_space, 0.0);
+        }
+        let quarter = unit_size * Units::from(Unit::Quarter).to_f32();
+        for dash in 0..entry.dashes {
+            let x = quarter * (dash + 1) as f32;
+            path.line(x - sizes.dash_width / 2.0, 0.0, x + sizes.dash_width / 2.0, 0.0);
+        }
+        if entry.tie_to_next {
+            let top = Self::calc_number_y(sizes, rows - 1) + sizes.number_half_height + 2.0;
+            let end = tie_end.max(sizes.number_width);
+            path.move_to(2.0, top)
+                .quad_to(end / 2.0, top + sizes.number_half_height, end - 2.0, top);
+        }
+    }
+}
+
+impl ShapeOp<NotationTheme, StrokePath> for JianpuNoteData {
+    fn get_shape(&self, theme: &NotationTheme) -> StrokePath {
+        let sizes = theme.sizes.jianpu;
+        let unit_size = if self.bar_props.bar_units.to_f32() > 0.0 {
+            self.value.bar_size / self.bar_props.bar_units.to_f32()
+        } else {
+            0.0
+        };
+        let mut path = StaffPath::default();
+        let in_bar_pos = self.value.entry.in_bar_pos;
+        let end = (in_bar_pos + Units::from(self.value.entry.duration)).min(self.bar_props.bar_units);
+        self.value
+            .add_lines(&mut path, &sizes, unit_size, unit_size * (end - in_bar_pos).to_f32());
+        let offset = if self.value.bar_size <= 0.0 {
+            offscreen::offset()
+        } else {
+            let x = unit_size * in_bar_pos.to_f32() + sizes.note_x;
+            Vec3::new(x, sizes.calc_number_y(), theme.z.tone)
+        };
+        StrokePath {
+            size: Vec2::ZERO,
+            path: path.path,
+            color: theme.colors.jianpu.note.of_state(&self.value.playing_state),
+            line_width: sizes.line_width,
+            offset,
+            scale: 1.0,
+            angle: 0.0,
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use edger_bevy::bevy::ecs::system::EntityCommands;
+use edger_bevy::bevy_prelude::*;
+use edger_bevy::prelude::{AssetsStates, ShapeOp, SingleData};
+use notation_model::prelude::{BarLane, LaneEntry, LaneJianpu};
+
+use crate::prelude::{EntryPlaying, NotationAssets, NotationSettings, NotationTheme};
+use crate::tab::tab_events::TabBarsResizedEvent;
+
+use super::jianpu_beam::JianpuBeamData;
+use super::jianpu_grid::JianpuGrid;
+use super::jianpu_note::{JianpuNoteData, JianpuNoteValue};
+
+pub struct JianpuPlugin;
+
+impl Plugin for JianpuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (
+            Self::on_add_jianpu_grid,
+            Self::on_entry_playing_changed,
+            Self::on_tab_bars_resized,
+        ).run_if(in_state(AssetsStates::Loaded)));
+    }
+}
+
+impl JianpuPlugin {
+    pub fn on_add_jianpu_grid(
+        mut commands: Commands,
+        theme: Res<NotationTheme>,
+        query: Query<(Entity, &SingleData<BarLane>, &JianpuGrid), Added<JianpuGrid>>,
+    ) {
+        if theme._bypass_systems {
+            return;
+        }
+        for (entity, lane, grid) in query.iter() {
+            grid.add_underlines(&mut commands, &theme, entity, &lane.0);
+        }
+    }
+    pub fn on_entry_playing_changed(
+        mut commands: Commands,
+        theme: Res<NotationTheme>,
+        query: Query<(Entity, &EntryPlaying, &Children), Changed<EntryPlaying>>,
+        mut note_query: Query<(Entity, &mut JianpuNoteData)>,
+    ) {
+        if theme._bypass_systems {
+            return;
+        }
+        for (_entity, playing, children) in query.iter() {
+            for child in children.iter() {
+                if let Ok((entity, mut data)) = note_query.get_mut(*child) {
+                    data.value.playing_state = playing.value;
+                    data.update(&mut commands, &theme, entity);
+                }
+            }
+        }
+    }
+    pub fn on_tab_bars_resized(
+        mut evts: EventReader<TabBarsResizedEvent>,
+        mut commands: Commands,
+        settings: Res<NotationSettings>,
+        theme: Res<NotationTheme>,
+        mut note_query: Query<(Entity, &mut JianpuNoteData), With<JianpuNoteData>>,
+        mut beam_query: Query<(Entity, &mut JianpuBeamData), With<JianpuBeamData>>,
+    ) {
+        if theme._bypass_systems || settings.hide_jianpu_lane {
+            return;
+        }
+        for evt in evts.read() {
+            let bars = &evt.0;
+            for (entity, mut data) in note_query.iter_mut() {
+                for (view, layout) in bars.iter() {
+                    if data.bar_props.bar_ordinal == view.bar_props.bar_ordinal {
+                        data.value.bar_size = layout.size.width;
+                        data.update(&mut commands, &theme, entity);
+                    }
+                }
+            }
+            for (entity, mut data) in beam_query.iter_mut() {
+                for (view, layout) in bars.iter() {
+                    if data.bar_props.bar_ordinal == view.bar_props.bar_ordinal {
+                        data.value.bar_size = layout.size.width;
+                        data.update(&mut commands, &theme, entity);
+                    }
+                }
+            }
+        }
+    }
+    pub fn insert_lane_extra(commands: &mut EntityCommands, _lane: &BarLane) {
+        commands.insert(JianpuGrid::default());
+    }
+    pub fn insert_entry_extra(
+        commands: &mut Commands,
+        assets: &NotationAssets,
+        theme: &NotationTheme,
+        entity: Entity,
+        entry: &LaneEntry,
+    ) {
+        let lane = match entry.lane() {
+            Some(lane) => lane,
+            None => return,
+        };
+        let jianpu = LaneJianpu::new(&lane);
+        if let Some(jianpu_entry) = jianpu.get_entry(entry.props.index) {
+            let data = JianpuNoteData::new(entry, JianpuNoteValue::new(jianpu_entry.clone()));
+            let note_entity = data.create(commands, theme, entity);
+            if jianpu_entry.is_rest() {
+                theme.texts.jianpu.spawn_number_text(commands, note_entity, assets, "0", 0.0);
+            }
+            for (row, note) in jianpu_entry.notes.iter().enumerate() {
+                let y = JianpuNoteValue::calc_number_y(&theme.sizes.jianpu, row);
+                theme.texts.jianpu.spawn_number_text(
+                    commands,
+                    note_entity,
+                    assets,
+                    note.text().as_str(),
+                    y,
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod jianpu_beam;
+pub mod jianpu_grid;
+pub mod jianpu_note;
+pub mod jianpu_plugin;
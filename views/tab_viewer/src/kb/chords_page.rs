@@ -5,7 +5,7 @@ use edger_bevy::prelude::MarkDownAsset;
 use edger_bevy::prelude::EasyLinkEvent;
 use notation_model::prelude::TrackKind;
 
-use crate::prelude::{NotationState, NotationAssets, NotationTheme};
+use crate::prelude::{NotationState, NotationLocale, NotationTheme};
 
 use super::kb_page::{KbPage};
 use super::page_helper::PageHelper;
@@ -20,11 +20,12 @@ impl KbPage for ChordsPage {
         _texts: &Assets<MarkDownAsset>,
         _app_state: &AppState,
         state: &NotationState,
+        locale: &NotationLocale,
         theme: &NotationTheme,
         _link_evts: &mut EventWriter<EasyLinkEvent>,
     ) {
         if state.tab.is_none() {
-            ui.label("Tab not loaded...");
+            ui.label(locale.text("kb-tab-not-loaded"));
             return;
         }
         let chords = state
@@ -44,7 +45,7 @@ impl KbPage for ChordsPage {
             .as_ref()
             .map(|x| x.meta.key.clone())
             .unwrap_or_default();
-        PageHelper::add_scale_key(ui, &scale, &key);
+        PageHelper::add_scale_key(ui, locale, &scale, &key);
         ui.separator();
         egui::Grid::new("chords").show(ui, |ui| {
             for chord in chords.iter() {
@@ -78,7 +79,7 @@ impl KbPage for ChordsPage {
                 }
                 ui.end_row();
                 ui.label("");
-                ui.label("root");
+                ui.label(locale.text("kb-chord-root"));
                 PageHelper::add_syllable_pitch(ui, theme, &scale, &key, &chord.chord.root, true);
                 index = 0;
                 for interval in chord.chord.intervals.get_intervals().iter() {
@@ -92,7 +93,7 @@ impl KbPage for ChordsPage {
                     ui.label("");
                 }
                 if let Some(bass) = chord.chord.bass {
-                    ui.label("bass");
+                    ui.label(locale.text("kb-chord-bass"));
                     let syllable = bass.syllable_on_root(&chord.chord.root);
                     PageHelper::add_syllable_pitch(ui, theme, &scale, &key, &syllable, true);
                 }
@@ -2,7 +2,7 @@ use edger_bevy::bevy_prelude::*;
 use edger_bevy::egui::Ui;
 use edger_bevy::prelude::{MarkDownAsset, EasyLinkEvent, AppState};
 
-use crate::prelude::{NotationState, NotationAssets, NotationTheme};
+use crate::prelude::{NotationState, NotationLocale, NotationTheme};
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum KbPageId {
@@ -24,6 +24,7 @@ pub trait KbPage {
         texts: &Assets<MarkDownAsset>,
         app_state: &AppState,
         state: &NotationState,
+        locale: &NotationLocale,
         theme: &NotationTheme,
         link_evts: &mut EventWriter<EasyLinkEvent>,
     );
@@ -36,6 +37,7 @@ pub trait KbContent {
         texts: &Assets<MarkDownAsset>,
         app_state: &AppState,
         state: &NotationState,
+        locale: &NotationLocale,
         theme: &NotationTheme,
         link_evts: &mut EventWriter<EasyLinkEvent>,
     );
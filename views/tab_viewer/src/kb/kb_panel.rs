@@ -3,7 +3,7 @@ use edger_bevy::egui::{self, Ui};
 use edger_bevy::prelude::MarkDownAsset;
 use edger_bevy::prelude::{EasyLinkEvent, EguiContexts, AppState};
 
-use crate::prelude::{NotationState, NotationAssets, NotationTheme, KbPage, DockSide};
+use crate::prelude::{NotationState, NotationLocale, NotationTheme, KbPage, DockSide};
 
 use super::kb_page::KbPageId;
 
@@ -11,20 +11,22 @@ pub trait KbPanel {
     fn window_id() -> egui::Id {
         egui::Id::new("KbPanel")
     }
+    /// Id of the title in the locale bundle, also used as the id of the side panel.
     fn get_title(&self) -> &str {
-        "Fun Notation - Knowledge Base"
+        "kb-title"
     }
     fn get_current_page_id(&self) -> KbPageId;
     fn set_current_page_id(&mut self, page_id: KbPageId);
+    /// Pages with ids of their labels in the locale bundle.
     fn get_page_tabs(&self) -> Vec<(KbPageId, &'static str)>;
     fn get_page_mut(&mut self, page_id: KbPageId) -> &mut dyn KbPage;
     fn on_close(&mut self) {}
-    fn topic_tabs_ui(&mut self, ui: &mut Ui) {
+    fn topic_tabs_ui(&mut self, ui: &mut Ui, locale: &NotationLocale) {
         let mut new_current = None;
         let current_page_id = self.get_current_page_id();
         for (page_id, tab_label) in self.get_page_tabs() {
             if ui
-                .selectable_label(page_id == current_page_id, tab_label)
+                .selectable_label(page_id == current_page_id, locale.text(tab_label))
                 .clicked()
             {
                 new_current = Some(page_id.clone());
@@ -40,11 +42,12 @@ pub trait KbPanel {
         texts: &Assets<MarkDownAsset>,
         app_state: &AppState,
         state: &mut NotationState,
+        locale: &NotationLocale,
         theme: &NotationTheme,
         link_evts: &mut EventWriter<EasyLinkEvent>,
     ) {
         ui.horizontal(|ui| {
-            self.topic_tabs_ui(ui);
+            self.topic_tabs_ui(ui, locale);
             ui.with_layout(egui::Layout::right_to_left(egui::Align::BOTTOM), |ui| {
                 egui::warn_if_debug_build(ui);
             });
@@ -53,7 +56,7 @@ pub trait KbPanel {
         egui::ScrollArea::vertical().show(ui, |ui| {
             let page_id = self.get_current_page_id();
             self.get_page_mut(page_id)
-                .page_ui(ui, texts, app_state, state, theme, link_evts);
+                .page_ui(ui, texts, app_state, state, locale, theme, link_evts);
         });
     }
     fn window_ui(
@@ -62,6 +65,7 @@ pub trait KbPanel {
         texts: &Assets<MarkDownAsset>,
         app_state: &AppState,
         state: &mut NotationState,
+        locale: &NotationLocale,
         theme: &NotationTheme,
         link_evts: &mut EventWriter<EasyLinkEvent>,
     ) {
@@ -69,12 +73,12 @@ pub trait KbPanel {
             return;
         }
         let mut window_open = true;
-        let mut window = egui::Window::new(self.get_title())
+        let mut window = egui::Window::new(locale.text(self.get_title()))
             .collapsible(false)
             .id(Self::window_id());
         window = window.open(&mut window_open);
         window.show(egui_ctx.ctx_mut(), |ui| {
-            self.kb_panel_ui(ui, texts, app_state, state, theme, link_evts);
+            self.kb_panel_ui(ui, texts, app_state, state, locale, theme, link_evts);
         });
         if !window_open {
             self.on_close();
@@ -87,6 +91,7 @@ pub trait KbPanel {
         texts: &Assets<MarkDownAsset>,
         app_state: &AppState,
         state: &mut NotationState,
+        locale: &NotationLocale,
         theme: &NotationTheme,
         link_evts: &mut EventWriter<EasyLinkEvent>,
         side: DockSide,
@@ -102,28 +107,28 @@ pub trait KbPanel {
                     .min_height(size.0)
                     .max_height(size.1)
                     .show(egui_ctx.ctx_mut(), |ui|{
-                        self.kb_panel_ui(ui, texts, app_state, state, theme, link_evts);
+                        self.kb_panel_ui(ui, texts, app_state, state, locale, theme, link_evts);
                     }),
             DockSide::Bottom =>
                 egui::TopBottomPanel::bottom(title)
                     .min_height(size.0)
                     .max_height(size.1)
                     .show(egui_ctx.ctx_mut(), |ui|{
-                        self.kb_panel_ui(ui, texts, app_state, state, theme, link_evts);
+                        self.kb_panel_ui(ui, texts, app_state, state, locale, theme, link_evts);
                     }),
             DockSide::Left =>
                 egui::SidePanel::left(title)
                     .min_width(size.0)
                     .max_width(size.1)
                     .show(egui_ctx.ctx_mut(), |ui|{
-                        self.kb_panel_ui(ui, texts, app_state, state, theme, link_evts);
+                        self.kb_panel_ui(ui, texts, app_state, state, locale, theme, link_evts);
                     }),
             DockSide::Right =>
                 egui::SidePanel::right(title)
                     .min_width(size.0)
                     .max_width(size.1)
                     .show(egui_ctx.ctx_mut(), |ui|{
-                        self.kb_panel_ui(ui, texts, app_state, state, theme, link_evts);
+                        self.kb_panel_ui(ui, texts, app_state, state, locale, theme, link_evts);
                     }),
         };
     }
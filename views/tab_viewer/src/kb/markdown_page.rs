@@ -3,7 +3,7 @@ use edger_bevy::bevy_prelude::*;
 use edger_bevy::egui::Ui;
 use edger_bevy::prelude::AppState;
 
-use crate::prelude::{NotationState, NotationAssets, NotationTheme};
+use crate::prelude::{NotationState, NotationLocale, NotationTheme};
 
 use super::kb_page::{KbPage};
 use edger_bevy::prelude::{MarkDownAsset, easy_mark, EasyLinkEvent};
@@ -20,10 +20,11 @@ impl KbPage for MarkDownPage {
         texts: &Assets<MarkDownAsset>,
         app_state: &AppState,
         state: &NotationState,
+        locale: &NotationLocale,
         theme: &NotationTheme,
         link_evts: &mut EventWriter<EasyLinkEvent>,
     ) {
-        Self::markdown_ui(ui, texts, app_state, state, theme, link_evts, self.path.as_str());
+        Self::markdown_ui(ui, texts, app_state, state, locale, theme, link_evts, self.path.as_str());
     }
 }
 
@@ -36,6 +37,7 @@ impl MarkDownPage {
         texts: &Assets<MarkDownAsset>,
         app_state: &AppState,
         _state: &NotationState,
+        locale: &NotationLocale,
         _theme: &NotationTheme,
         link_evts: &mut EventWriter<EasyLinkEvent>,
         path: &str,
@@ -48,6 +50,6 @@ impl MarkDownPage {
                 return;
             }
         }
-        ui.label(format!("Asset Not Found: {}", path));
+        ui.label(locale.text_args("kb-asset-not-found", &[("path", path.into())]));
     }
 }
\ No newline at end of file
@@ -6,7 +6,7 @@ use edger_bevy::prelude::{label_from_style, EasyMarkStyle};
 use edger_bevy::prelude::EasyLinkEvent;
 use notation_model::prelude::{TrackKind, Scale, Key};
 
-use crate::prelude::{NotationState, NotationAssets, NotationTheme};
+use crate::prelude::{NotationState, NotationLocale, NotationTheme};
 
 use super::kb_page::{KbPage};
 use super::page_helper::PageHelper;
@@ -21,6 +21,7 @@ impl KbPage for NotesPage {
         texts: &Assets<MarkDownAsset>,
         app_state: &AppState,
         state: &NotationState,
+        locale: &NotationLocale,
         theme: &NotationTheme,
         link_evts: &mut EventWriter<EasyLinkEvent>,
     ) {
@@ -34,15 +35,15 @@ impl KbPage for NotesPage {
             .as_ref()
             .map(|x| x.meta.key.clone())
             .unwrap_or_default();
-        PageHelper::add_scale_key(ui, &scale, &key);
+        PageHelper::add_scale_key(ui, locale, &scale, &key);
         ui.separator();
 
         let capo = state.tab.as_ref().and_then(|tab| {
             tab.get_track_of_kind(TrackKind::Guitar)
-                .and_then(|x| x.get_fretboard6())
+                .and_then(|x| x.get_fretboard())
         }).map(|x| x.capo).unwrap_or(0);
         let transpose = capo as i8;
-        Self::notes_ui(ui, texts, app_state, state, theme, link_evts, scale, key, transpose);
+        Self::notes_ui(ui, texts, app_state, state, locale, theme, link_evts, scale, key, transpose);
     }
 }
 
@@ -52,6 +53,7 @@ impl NotesPage {
         _texts: &Assets<MarkDownAsset>,
         _app_state: &AppState,
         _state: &NotationState,
+        locale: &NotationLocale,
         theme: &NotationTheme,
         _link_evts: &mut EventWriter<EasyLinkEvent>,
         scale: Scale,
@@ -82,15 +84,15 @@ impl NotesPage {
             ui.end_row();
             if transpose != 0 {
                 ui.separator();
-                ui.add(label_from_style("with", &strong_style));
-                ui.add(label_from_style("capo", &strong_style));
-                ui.add(label_from_style("at", &strong_style));
+                ui.add(label_from_style(&locale.text("kb-with"), &strong_style));
+                ui.add(label_from_style(&locale.text("kb-capo"), &strong_style));
+                ui.add(label_from_style(&locale.text("kb-at"), &strong_style));
                 ui.add(label_from_style(
                     transpose.to_string().as_str(),
                     &strong_style,
                 ));
-                let frets = "fret";
-                ui.add(label_from_style(frets, &strong_style));
+                let frets = locale.text("kb-fret");
+                ui.add(label_from_style(&frets, &strong_style));
                 ui.separator();
                 ui.end_row();
                 for (index, syllable) in syllables.iter().enumerate() {
@@ -3,7 +3,7 @@ use edger_bevy::egui::{Ui};
 use edger_bevy::prelude::{color_to_hsva, label_from_style, EasyMarkStyle};
 use notation_model::prelude::{Interval, Key, Scale, Semitones, Syllable};
 
-use crate::prelude::NotationTheme;
+use crate::prelude::{NotationLocale, NotationTheme};
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
 pub struct PageHelper {}
@@ -23,11 +23,11 @@ impl PageHelper {
             ui.label(text);
         }
     }
-    pub fn add_scale_key(ui: &mut Ui, scale: &Scale, key: &Key) {
+    pub fn add_scale_key(ui: &mut Ui, locale: &NotationLocale, scale: &Scale, key: &Key) {
         ui.horizontal(|ui| {
-            ui.label("Scale:");
+            ui.label(locale.text("kb-scale"));
             Self::add_strong_text(ui, &scale.to_string());
-            ui.label("Key:");
+            ui.label(locale.text("kb-key"));
             Self::add_strong_text(ui, &key.to_string());
         });
     }
@@ -7,6 +7,8 @@ use notation_model::prelude::{BarLane, LaneKind, TrackProps};
 pub struct LaneLayoutData {
     pub track_id: String,
     pub track_props: TrackProps,
+    /// Index of the track the lane is sorted with, usually its own track.
+    pub order_track: usize,
     pub lane_kind: LaneKind,
     pub height: f32,
     pub margin: f32,
@@ -18,6 +20,7 @@ impl LaneLayoutData {
         Self {
             track_id: lane.track.id.clone(),
             track_props: lane.track.props,
+            order_track: lane.track.props.index,
             lane_kind: lane.kind,
             height,
             margin,
@@ -29,6 +32,7 @@ impl LaneLayoutData {
         Self {
             track_id: lane.track.id.clone(),
             track_props: lane.track.props,
+            order_track: lane.track.props.index,
             lane_kind,
             height,
             margin,
@@ -36,11 +40,15 @@ impl LaneLayoutData {
             visible: Arc::new(RwLock::new(false)),
         }
     }
+    pub fn with_order_track(mut self, order_track: usize) -> Self {
+        self.order_track = order_track;
+        self
+    }
     pub fn id(&self) -> String {
         format!("{}:{}", self.track_id, self.lane_kind)
     }
     pub fn order(&self) -> usize {
-        self.track_props.index * LaneKind::LEN + self.lane_kind.order()
+        self.order_track * LaneKind::LEN + self.lane_kind.order()
     }
     pub fn is_ghost(&self) -> bool {
         self.lane.is_none()
@@ -2,12 +2,14 @@ use edger_bevy::bevy_prelude::*;
 
 use crate::entry::entry_plugin;
 use crate::harmony::harmony_plugin::HarmonyPlugin;
+use crate::jianpu::jianpu_plugin::JianpuPlugin;
 use crate::lyrics::lyrics_plugin::LyricsPlugin;
 use crate::prelude::{
     entity, LaneBundle, LaneLayoutData, MelodyPlugin, NotationAssets, NotationSettings,
     NotationTheme, ThemeColors,
 };
 use crate::shapes::shapes_plugin::ShapesPlugin;
+use crate::staff::staff_plugin::StaffPlugin;
 use crate::strings::strings_plugin::StringsPlugin;
 use crate::prelude::NotationLayout;
 use edger_bevy::prelude::{
@@ -90,6 +92,7 @@ impl LaneView {
         lane_entity: Entity,
     ) -> bool {
         match lane_layout.lane_kind {
+            LaneKind::Chord => settings.show_chord_analysis,
             LaneKind::Lyrics => {
                 if !settings.hide_lyrics_lane {
                     LyricsPlugin::insert_lane_extra(&mut commands.entity(lane_entity), lane)
@@ -114,6 +117,18 @@ impl LaneView {
                 }
                 true
             }
+            LaneKind::Jianpu => {
+                if !settings.hide_jianpu_lane {
+                    JianpuPlugin::insert_lane_extra(&mut commands.entity(lane_entity), lane)
+                }
+                !settings.hide_jianpu_lane
+            }
+            LaneKind::Staff => {
+                if !settings.hide_staff_lane {
+                    StaffPlugin::insert_lane_extra(&mut commands.entity(lane_entity), lane)
+                }
+                !settings.hide_staff_lane
+            }
             LaneKind::Shapes => {
                 if !settings.hide_shapes_lane {
                     ShapesPlugin::insert_lane_extra(&mut commands.entity(lane_entity), lane)
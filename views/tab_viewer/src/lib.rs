@@ -26,11 +26,15 @@ pub mod guitar;
 pub mod lyrics;
 pub mod melody;
 pub mod harmony;
+pub mod jianpu;
 pub mod rhythm;
 pub mod shapes;
 pub mod strings;
+pub mod staff;
 
 pub mod data;
+pub mod library;
+pub mod locale;
 pub mod settings;
 pub mod theme;
 pub mod notation;
@@ -100,6 +104,20 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::harmony::harmony_plugin::HarmonyPlugin;
     #[doc(hidden)]
+    pub use crate::jianpu::jianpu_grid::JianpuGrid;
+    #[doc(hidden)]
+    pub use crate::jianpu::jianpu_plugin::JianpuPlugin;
+    #[doc(hidden)]
+    pub use crate::library::library_entry::LibraryEntry;
+    #[doc(hidden)]
+    pub use crate::library::library_filter::LibraryFilter;
+    #[doc(hidden)]
+    pub use crate::library::library_index::{LibraryIndex, LibraryScan};
+    #[doc(hidden)]
+    pub use crate::locale::ftl_asset::{FtlAsset, FtlAssetLoader};
+    #[doc(hidden)]
+    pub use crate::locale::notation_locale::NotationLocale;
+    #[doc(hidden)]
     pub use crate::mini::mini_plugin::MiniPlugin;
     #[doc(hidden)]
     pub use crate::play::play_plugin::PlayPlugin;
@@ -108,10 +126,14 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::shapes::shapes_plugin::ShapesPlugin;
     #[doc(hidden)]
-    pub use crate::strings::strings_grid::{StringsGrid4, StringsGrid6};
+    pub use crate::strings::strings_grid::StringsGrid;
     #[doc(hidden)]
     pub use crate::strings::strings_plugin::StringsPlugin;
     #[doc(hidden)]
+    pub use crate::staff::staff_grid::StaffGrid;
+    #[doc(hidden)]
+    pub use crate::staff::staff_plugin::StaffPlugin;
+    #[doc(hidden)]
     pub use crate::tab::tab_asset::{TabAsset, TabAssetHandle, TabError};
     #[doc(hidden)]
     pub use crate::tab::tab_bars::TabBars;
@@ -149,11 +171,20 @@ pub mod prelude {
     pub use crate::notation::tab_viewer::TabViewer;
     #[doc(hidden)]
     pub use crate::notation::control::Control;
+    #[cfg(not(target_arch = "wasm32"))]
+    #[doc(hidden)]
+    pub use crate::notation::tab_watcher::TabWatcher;
     #[cfg(feature = "with_egui")]
     #[doc(hidden)]
     pub use crate::notation::egui_control_panel::EguiControlPanel;
     #[cfg(feature = "with_egui")]
     #[doc(hidden)]
+    pub use crate::library::egui_library_browser::EguiLibraryBrowser;
+    #[cfg(feature = "with_egui")]
+    #[doc(hidden)]
+    pub use crate::lyrics::egui_karaoke::EguiKaraoke;
+    #[cfg(feature = "with_egui")]
+    #[doc(hidden)]
     pub use crate::kb::kb_page::{KbPage, KbPageId, KbContent};
     #[cfg(feature = "with_egui")]
     #[doc(hidden)]
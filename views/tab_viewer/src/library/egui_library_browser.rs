@@ -0,0 +1,159 @@
+use edger_bevy::bevy_egui::egui::{self, ComboBox, Grid, ScrollArea, Slider, Ui};
+use edger_bevy::bevy_egui::EguiContexts;
+use edger_bevy::bevy_prelude::*;
+
+use crate::prelude::{NotationArgs, NotationLocale, NotationState, NotationTheme};
+
+use super::library_entry::LibraryEntry;
+use super::library_filter::LibraryFilter;
+use super::library_index::{LibraryIndex, LibraryScan};
+
+pub struct EguiLibraryBrowser {}
+
+impl EguiLibraryBrowser {
+    pub const MAX_CHORDS_TEXT: usize = 48;
+
+    pub fn window_id() -> egui::Id {
+        egui::Id::new("library")
+    }
+    fn filter_ui(
+        ui: &mut Ui,
+        locale: &NotationLocale,
+        index: &LibraryIndex,
+        filter: &mut LibraryFilter,
+    ) {
+        ui.horizontal(|ui| {
+            ui.label(locale.text("library-search"));
+            ui.text_edit_singleline(&mut filter.text);
+            if ui.button(locale.text("library-clear")).clicked() {
+                *filter = LibraryFilter::default();
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.label(locale.text("library-key"));
+            ComboBox::from_id_salt("library_key")
+                .selected_text(filter.key.map(|x| x.to_text()).unwrap_or_else(|| locale.text("library-any")))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut filter.key, None, locale.text("library-any"));
+                    for key in index.all_keys() {
+                        ui.selectable_value(&mut filter.key, Some(key), key.to_text());
+                    }
+                });
+            ui.label(locale.text("library-chords"));
+            ui.text_edit_singleline(&mut filter.chords)
+                .on_hover_text(index.all_chords().join(" "));
+        });
+        ui.horizontal(|ui| {
+            let (min_bpm, max_bpm) = LibraryFilter::TEMPO_RANGE;
+            ui.add(
+                Slider::new(&mut filter.tempo_range.0, min_bpm..=max_bpm)
+                    .text(locale.text("library-min-bpm")),
+            );
+            ui.add(
+                Slider::new(&mut filter.tempo_range.1, min_bpm..=max_bpm)
+                    .text(locale.text("library-max-bpm")),
+            );
+            if filter.tempo_range.1 < filter.tempo_range.0 {
+                filter.tempo_range.1 = filter.tempo_range.0;
+            }
+        });
+    }
+    fn chords_text(entry: &LibraryEntry) -> String {
+        let text = entry.chords.join(" ");
+        if text.chars().count() > Self::MAX_CHORDS_TEXT {
+            let text: String = text.chars().take(Self::MAX_CHORDS_TEXT).collect();
+            format!("{} ...", text)
+        } else {
+            text
+        }
+    }
+    fn entries_ui(
+        ui: &mut Ui,
+        locale: &NotationLocale,
+        entries: &[&LibraryEntry],
+        current_path: &str,
+    ) -> Option<String> {
+        let mut selected = None;
+        ScrollArea::vertical().show(ui, |ui| {
+            Grid::new("library_entries")
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.strong(locale.text("library-title"));
+                    ui.strong(locale.text("library-artist"));
+                    ui.strong(locale.text("library-key"));
+                    ui.strong(locale.text("library-bpm"));
+                    ui.strong(locale.text("library-bars"));
+                    ui.strong(locale.text("library-chords"));
+                    ui.end_row();
+                    for entry in entries.iter() {
+                        if ui
+                            .selectable_label(entry.path == current_path, entry.title())
+                            .on_hover_text(entry.path.as_str())
+                            .clicked()
+                        {
+                            selected = Some(entry.path.clone());
+                        }
+                        ui.label(entry.info.artist.as_str());
+                        ui.label(format!("{} {}", entry.key.to_text(), entry.scale));
+                        ui.label(entry.bpm().to_string());
+                        ui.label(entry.bar_count.to_string());
+                        ui.label(Self::chords_text(entry));
+                        ui.end_row();
+                    }
+                });
+        });
+        selected
+    }
+    #[allow(clippy::too_many_arguments)]
+    pub fn library_ui(
+        mut egui_ctx: EguiContexts,
+        mut args: ResMut<NotationArgs>,
+        mut state: ResMut<NotationState>,
+        mut theme: ResMut<NotationTheme>,
+        index: Res<LibraryIndex>,
+        mut scan: ResMut<LibraryScan>,
+        locale: Res<NotationLocale>,
+        mut filter: Local<LibraryFilter>,
+    ) {
+        if !state.show_library {
+            return;
+        }
+        let mut window_open = true;
+        let mut selected = None;
+        egui::Window::new(locale.text("library-window"))
+            .collapsible(false)
+            .id(Self::window_id())
+            .open(&mut window_open)
+            .show(egui_ctx.ctx_mut(), |ui| {
+                Self::filter_ui(ui, &locale, &index, &mut filter);
+                ui.separator();
+                let matched = filter.filter(&index.entries).len();
+                ui.horizontal(|ui| {
+                    ui.label(locale.text_args(
+                        "library-matched-tabs",
+                        &[("matched", matched.into()), ("total", index.entries.len().into())],
+                    ));
+                    if scan.is_scanning() {
+                        ui.label(locale.text("library-scanning"));
+                    } else {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        if ui.button(locale.text("library-rescan")).clicked() {
+                            *scan = LibraryScan::start(&index);
+                        }
+                    }
+                });
+                let entries = filter.filter(&index.entries);
+                selected = Self::entries_ui(ui, &locale, &entries, &state.tab_path);
+            });
+        if let Some(path) = selected {
+            if !args.tab.contains(&path) {
+                args.tab.insert(0, path.clone());
+            }
+            state.change_tab(&mut theme, path);
+            window_open = false;
+        }
+        if !window_open {
+            state.show_library = false;
+        }
+    }
+}
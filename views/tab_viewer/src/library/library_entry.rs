@@ -0,0 +1,105 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use notation_model::prelude::{
+    Bpm, Key, ProtoTab, Scale, Tab, TabEncoding, TabFile, TabInfo, Tempo, TrackKind,
+};
+
+use crate::prelude::TabAsset;
+
+/// What the library knows about a tab file, without keeping the tab in memory.
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug)]
+pub struct LibraryEntry {
+    pub path: String,
+    pub modified: u64,
+    pub info: TabInfo,
+    pub key: Key,
+    pub scale: Scale,
+    pub tempo: Tempo,
+    pub track_kinds: Vec<TrackKind>,
+    pub chords: Vec<String>,
+    pub bar_count: usize,
+    pub error: Option<String>,
+}
+
+impl LibraryEntry {
+    pub fn is_tab_file(path: &Path) -> bool {
+        path.extension()
+            .and_then(|x| x.to_str())
+            .map(|x| TabAsset::EXTENSIONS.contains(&x))
+            .unwrap_or(false)
+    }
+    pub fn title(&self) -> String {
+        if !self.info.title.is_empty() {
+            return self.info.title.clone();
+        }
+        Path::new(&self.path)
+            .file_stem()
+            .and_then(|x| x.to_str())
+            .unwrap_or(&self.path)
+            .to_owned()
+    }
+    pub fn bpm(&self) -> Bpm {
+        Bpm::from(self.tempo)
+    }
+    fn load_proto(path: &str, content: &[u8]) -> Result<ProtoTab, String> {
+        if let Ok(encoding) = TabEncoding::from_path(path) {
+            return TabFile::decode(encoding, content).map_err(|e| e.to_string());
+        }
+        #[cfg(feature = "dsl")]
+        if path.ends_with(".rs") {
+            let content = std::str::from_utf8(content).map_err(|e| e.to_string())?;
+            return notation_dsl::prelude::parse_get_tab(content).map_err(|e| e.to_string());
+        }
+        Err(format!("Not supported: {}", path))
+    }
+    fn new_error(path: &str, modified: u64, error: String) -> Self {
+        Self {
+            path: path.to_owned(),
+            modified,
+            info: TabInfo::default(),
+            key: Key::default(),
+            scale: Scale::default(),
+            tempo: Tempo::Moderato,
+            track_kinds: vec![],
+            chords: vec![],
+            bar_count: 0,
+            error: Some(error),
+        }
+    }
+    pub fn parse(path: &str, modified: u64, content: &[u8]) -> Self {
+        let proto = match Self::load_proto(path, content) {
+            Ok(proto) => proto,
+            Err(err) => return Self::new_error(path, modified, err),
+        };
+        let info = proto.info.clone();
+        let meta = proto.meta;
+        let track_kinds = proto.tracks.iter().map(|x| x.kind).collect();
+        let tab = match Tab::try_parse_arc(proto, false, None) {
+            Ok(tab) => tab,
+            Err(err) => return Self::new_error(path, modified, err.to_string()),
+        };
+        let mut chords: Vec<String> = tab
+            .get_track_of_kind(TrackKind::Chord)
+            .map(|x| x.get_tab_chords())
+            .unwrap_or_default()
+            .iter()
+            .map(|x| x.chord.calc_name(&meta.scale, &meta.key))
+            .collect();
+        chords.sort();
+        chords.dedup();
+        Self {
+            path: path.to_owned(),
+            modified,
+            info,
+            key: meta.key,
+            scale: meta.scale,
+            tempo: meta.tempo,
+            track_kinds,
+            chords,
+            bar_count: tab.bars.len(),
+            error: None,
+        }
+    }
+}
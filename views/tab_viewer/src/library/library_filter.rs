@@ -0,0 +1,66 @@
+use notation_model::prelude::{BpmRange, Key};
+
+use super::library_entry::LibraryEntry;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct LibraryFilter {
+    pub text: String,
+    pub key: Option<Key>,
+    /// Chords the player knows, separated by spaces or commas, only the tabs using
+    /// nothing but these chords are matched.
+    pub chords: String,
+    pub tempo_range: BpmRange,
+}
+
+impl Default for LibraryFilter {
+    fn default() -> Self {
+        Self {
+            text: "".to_owned(),
+            key: None,
+            chords: "".to_owned(),
+            tempo_range: Self::TEMPO_RANGE,
+        }
+    }
+}
+
+impl LibraryFilter {
+    pub const TEMPO_RANGE: BpmRange = (20, 300);
+
+    pub fn chord_set(&self) -> Vec<&str> {
+        self.chords
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|x| !x.is_empty())
+            .collect()
+    }
+    fn match_text(&self, entry: &LibraryEntry) -> bool {
+        let text = self.text.trim().to_lowercase();
+        if text.is_empty() {
+            return true;
+        }
+        let info = &entry.info;
+        entry.title().to_lowercase().contains(&text)
+            || info.artist.to_lowercase().contains(&text)
+            || info.album.to_lowercase().contains(&text)
+            || info.tags.iter().any(|x| x.to_lowercase().contains(&text))
+    }
+    fn match_chords(&self, entry: &LibraryEntry) -> bool {
+        let chord_set = self.chord_set();
+        chord_set.is_empty()
+            || entry
+                .chords
+                .iter()
+                .all(|x| chord_set.contains(&x.as_str()))
+    }
+    pub fn is_matched(&self, entry: &LibraryEntry) -> bool {
+        let bpm = entry.bpm();
+        entry.error.is_none()
+            && self.key.map(|x| x == entry.key).unwrap_or(true)
+            && bpm >= self.tempo_range.0
+            && bpm <= self.tempo_range.1
+            && self.match_text(entry)
+            && self.match_chords(entry)
+    }
+    pub fn filter<'a>(&self, entries: &'a [LibraryEntry]) -> Vec<&'a LibraryEntry> {
+        entries.iter().filter(|x| self.is_matched(x)).collect()
+    }
+}
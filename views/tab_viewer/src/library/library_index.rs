@@ -0,0 +1,217 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{Receiver, TryRecvError};
+use std::sync::Mutex;
+
+use edger_bevy::bevy_prelude::*;
+use serde::{Deserialize, Serialize};
+
+use notation_model::prelude::Key;
+
+use super::library_entry::LibraryEntry;
+
+/// Index of the tabs in the library folders, cached in a file so that only changed
+/// files need to be parsed again.
+#[derive(Clone, Serialize, Deserialize, Debug, Default, Resource)]
+pub struct LibraryIndex {
+    pub folders: Vec<String>,
+    pub entries: Vec<LibraryEntry>,
+}
+
+impl LibraryIndex {
+    pub const CACHE_FOLDER: &'static str = "fun_notation";
+    pub const CACHE_FILE: &'static str = "library.idx";
+
+    pub fn new(folders: Vec<String>) -> Self {
+        Self {
+            folders,
+            entries: vec![],
+        }
+    }
+    /// The cache is per user, under the platform cache folder.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn cache_path() -> Option<PathBuf> {
+        let env_path = |key: &str| {
+            std::env::var_os(key)
+                .filter(|x| !x.is_empty())
+                .map(PathBuf::from)
+        };
+        let root = if cfg!(target_os = "windows") {
+            env_path("LOCALAPPDATA")
+        } else if cfg!(target_os = "macos") {
+            env_path("HOME").map(|x| x.join("Library").join("Caches"))
+        } else {
+            env_path("XDG_CACHE_HOME").or_else(|| env_path("HOME").map(|x| x.join(".cache")))
+        };
+        root.map(|x| x.join(Self::CACHE_FOLDER).join(Self::CACHE_FILE))
+    }
+    /// Only the cached entries, the folders are scanned by [`LibraryScan`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_cached(folders: Vec<String>) -> Self {
+        let mut index = Self::new(folders);
+        if let Some(cache) = Self::cache_path().and_then(|x| Self::load_cache(&x)) {
+            index.entries = cache.entries;
+        }
+        index
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_cache(path: &Path) -> Option<Self> {
+        let content = std::fs::read_to_string(path).ok()?;
+        match ron::from_str::<Self>(&content) {
+            Ok(index) => Some(index),
+            Err(err) => {
+                println!("LibraryIndex::load_cache() failed: {:?} -> {}", path, err);
+                None
+            }
+        }
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save_cache(&self) {
+        let path = match Self::cache_path() {
+            Some(path) => path,
+            None => {
+                println!("LibraryIndex::save_cache() no cache folder");
+                return;
+            }
+        };
+        let result = ron::to_string(self)
+            .map_err(|e| e.to_string())
+            .and_then(|x| {
+                if let Some(folder) = path.parent() {
+                    std::fs::create_dir_all(folder).map_err(|e| e.to_string())?;
+                }
+                std::fs::write(&path, x).map_err(|e| e.to_string())
+            });
+        if let Err(err) = result {
+            println!("LibraryIndex::save_cache() failed: {:?} -> {}", path, err);
+        }
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    fn scan_folder(path: &std::path::Path, files: &mut Vec<(String, u64)>) {
+        let dir = match std::fs::read_dir(path) {
+            Ok(dir) => dir,
+            Err(err) => {
+                println!("LibraryIndex::scan_folder() failed: {:?} -> {}", path, err);
+                return;
+            }
+        };
+        for item in dir.flatten() {
+            let path = item.path();
+            if path.is_dir() {
+                Self::scan_folder(&path, files);
+            } else if LibraryEntry::is_tab_file(&path) {
+                let modified = item
+                    .metadata()
+                    .and_then(|x| x.modified())
+                    .ok()
+                    .and_then(|x| x.duration_since(std::time::UNIX_EPOCH).ok())
+                    .map(|x| x.as_secs())
+                    .unwrap_or(0);
+                let path = std::fs::canonicalize(&path).unwrap_or(path);
+                if let Some(path) = path.to_str() {
+                    files.push((path.to_owned(), modified));
+                }
+            }
+        }
+    }
+    /// Find all the tab files in the folders, files not changed since last scan are not
+    /// parsed again.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn scan(&mut self) {
+        let mut files = Vec::new();
+        for folder in self.folders.iter() {
+            Self::scan_folder(std::path::Path::new(folder), &mut files);
+        }
+        let mut cached: std::collections::HashMap<String, LibraryEntry> = self
+            .entries
+            .drain(..)
+            .map(|x| (x.path.clone(), x))
+            .collect();
+        for (path, modified) in files {
+            let entry = match cached.remove(&path) {
+                Some(entry) if entry.modified == modified => entry,
+                _ => match std::fs::read(&path) {
+                    Ok(content) => LibraryEntry::parse(&path, modified, &content),
+                    Err(err) => {
+                        println!("LibraryIndex::scan() failed: {} -> {}", path, err);
+                        continue;
+                    }
+                },
+            };
+            self.entries.push(entry);
+        }
+        self.entries.sort_by_key(|x| x.title().to_lowercase());
+        println!(
+            "LibraryIndex::scan() {:?} -> {} tabs",
+            self.folders,
+            self.entries.len()
+        );
+    }
+    pub fn all_keys(&self) -> Vec<Key> {
+        Key::ALL
+            .iter()
+            .filter(|key| self.entries.iter().any(|x| x.key == **key))
+            .copied()
+            .collect()
+    }
+    pub fn all_chords(&self) -> Vec<String> {
+        let mut chords: Vec<String> = self
+            .entries
+            .iter()
+            .flat_map(|x| x.chords.iter().cloned())
+            .collect();
+        chords.sort();
+        chords.dedup();
+        chords
+    }
+}
+
+/// Scanning the library folders in a background thread, the scanned index replaces
+/// the resource when done, so the app doesn't wait for it on startup.
+#[derive(Default, Resource)]
+pub struct LibraryScan {
+    receiver: Option<Mutex<Receiver<LibraryIndex>>>,
+}
+
+impl LibraryScan {
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn start(index: &LibraryIndex) -> Self {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let mut index = index.clone();
+        let spawned = std::thread::Builder::new()
+            .name("library_scan".to_owned())
+            .spawn(move || {
+                index.scan();
+                index.save_cache();
+                let _ = sender.send(index);
+            });
+        match spawned {
+            Ok(_) => Self {
+                receiver: Some(Mutex::new(receiver)),
+            },
+            Err(err) => {
+                println!("LibraryScan::start() failed: {}", err);
+                Self::default()
+            }
+        }
+    }
+    pub fn is_scanning(&self) -> bool {
+        self.receiver.is_some()
+    }
+    pub fn on_scanned(mut scan: ResMut<Self>, mut index: ResMut<LibraryIndex>) {
+        let result = match scan.receiver.as_ref() {
+            Some(receiver) => receiver.lock().unwrap().try_recv(),
+            None => return,
+        };
+        match result {
+            Ok(scanned) => {
+                *index = scanned;
+                scan.receiver = None;
+            }
+            Err(TryRecvError::Empty) => (),
+            Err(TryRecvError::Disconnected) => {
+                scan.receiver = None;
+            }
+        }
+    }
+}
@@ -0,0 +1,6 @@
+pub mod library_entry;
+pub mod library_filter;
+pub mod library_index;
+
+#[cfg(feature = "with_egui")]
+pub mod egui_library_browser;
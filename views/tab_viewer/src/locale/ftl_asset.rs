@@ -0,0 +1,31 @@
+use edger_bevy::bevy::asset::{Asset, AssetLoader, AsyncReadExt, LoadContext, io::Reader};
+use edger_bevy::bevy::reflect::TypePath;
+
+/// Source of a Fluent resource bundle, e.g. `kb/en-US/notation.ftl`.
+#[derive(Clone, Debug, Asset, TypePath)]
+pub struct FtlAsset {
+    pub source: String,
+}
+
+#[derive(Default)]
+pub struct FtlAssetLoader;
+
+impl AssetLoader for FtlAssetLoader {
+    type Asset = FtlAsset;
+    type Settings = ();
+    type Error = anyhow::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut source = String::new();
+        reader.read_to_string(&mut source).await?;
+        Ok(FtlAsset { source })
+    }
+    fn extensions(&self) -> &[&str] {
+        &["ftl"]
+    }
+}
@@ -0,0 +1,2 @@
+pub mod ftl_asset;
+pub mod notation_locale;
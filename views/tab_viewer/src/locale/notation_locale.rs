@@ -0,0 +1,87 @@
+use edger_bevy::bevy_prelude::*;
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource, FluentValue};
+use unic_langid::LanguageIdentifier;
+
+#[cfg(feature = "with_egui")]
+use edger_bevy::prelude::EguiFontSizes;
+
+/// UI texts of the current language, with the en-US bundle as fallback for missing messages.
+///
+/// Besides the labels, a few `locale-*` messages in the bundle are settings of the language
+/// itself, e.g. `locale-lyrics-font`, so that adding a language doesn't need any code change.
+#[derive(Resource, Default)]
+pub struct NotationLocale {
+    pub lang: LanguageIdentifier,
+    bundles: Vec<FluentBundle<FluentResource>>,
+}
+
+impl NotationLocale {
+    pub const FTL_NAME: &'static str = "notation.ftl";
+    pub const LYRICS_FONT: &'static str = "locale-lyrics-font";
+    pub const EGUI_FONT_SIZES: &'static str = "locale-egui-font-sizes";
+
+    pub fn get_ftl_path(lang: &LanguageIdentifier) -> String {
+        format!("kb/{}/{}", lang, Self::FTL_NAME)
+    }
+    fn new_bundle(lang: &LanguageIdentifier, source: &str) -> FluentBundle<FluentResource> {
+        let resource = match FluentResource::try_new(source.to_owned()) {
+            Ok(resource) => resource,
+            Err((resource, errors)) => {
+                println!("NotationLocale: parse ftl failed: {} -> {:?}", lang, errors);
+                resource
+            }
+        };
+        let mut bundle = FluentBundle::new_concurrent(vec![lang.clone()]);
+        // The unicode isolation marks are shown as boxes by egui.
+        bundle.set_use_isolating(false);
+        if let Err(errors) = bundle.add_resource(resource) {
+            println!("NotationLocale: add ftl failed: {} -> {:?}", lang, errors);
+        }
+        bundle
+    }
+    /// The sources are in lookup order, the first one is for `lang`.
+    pub fn new(lang: LanguageIdentifier, sources: Vec<(LanguageIdentifier, &str)>) -> Self {
+        let bundles = sources
+            .iter()
+            .map(|(lang, source)| Self::new_bundle(lang, source))
+            .collect();
+        Self { lang, bundles }
+    }
+    pub fn get(&self, id: &str, args: Option<&FluentArgs>) -> Option<String> {
+        for bundle in self.bundles.iter() {
+            let pattern = match bundle.get_message(id).and_then(|x| x.value()) {
+                Some(pattern) => pattern,
+                None => continue,
+            };
+            let mut errors = vec![];
+            let text = bundle.format_pattern(pattern, args, &mut errors);
+            if !errors.is_empty() {
+                println!("NotationLocale: format failed: {} -> {:?}", id, errors);
+            }
+            return Some(text.to_string());
+        }
+        None
+    }
+    /// Falls back to the id itself, so a missing message is easy to spot in the ui.
+    pub fn text(&self, id: &str) -> String {
+        self.get(id, None).unwrap_or(id.to_owned())
+    }
+    pub fn text_args<'a>(&self, id: &str, args: &[(&'a str, FluentValue<'a>)]) -> String {
+        let mut fluent_args = FluentArgs::new();
+        for (key, value) in args.iter() {
+            fluent_args.set(*key, value.clone());
+        }
+        self.get(id, Some(&fluent_args)).unwrap_or(id.to_owned())
+    }
+    pub fn get_lyrics_font(&self) -> Option<String> {
+        self.get(Self::LYRICS_FONT, None)
+    }
+    #[cfg(feature = "with_egui")]
+    pub fn get_egui_font_sizes(&self) -> EguiFontSizes {
+        match self.get(Self::EGUI_FONT_SIZES, None).as_deref() {
+            Some("bigger") => EguiFontSizes::BIGGER,
+            _ => EguiFontSizes::default(),
+        }
+    }
+}
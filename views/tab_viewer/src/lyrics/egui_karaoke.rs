@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use edger_bevy::bevy_egui::egui::{self, text::LayoutJob, Color32, FontId, TextFormat, Ui};
+use edger_bevy::bevy_egui::EguiContexts;
+use edger_bevy::bevy_prelude::*;
+use notation_model::prelude::{Tab, TimedLyricLine, TimedLyrics, Units};
+
+use crate::prelude::{NotationLocale, NotationState, TabState};
+
+pub struct EguiKaraoke {}
+
+impl EguiKaraoke {
+    pub const CURRENT_FONT_SIZE: f32 = 56.0;
+    pub const NEXT_FONT_SIZE: f32 = 36.0;
+    pub const BACKGROUND: Color32 = Color32::from_rgb(16, 16, 24);
+    pub const SUNG_COLOR: Color32 = Color32::from_rgb(255, 196, 64);
+    pub const CURRENT_COLOR: Color32 = Color32::WHITE;
+    pub const NEXT_COLOR: Color32 = Color32::GRAY;
+
+    fn line_ui(ui: &mut Ui, line: &TimedLyricLine, pos: Option<Units>, font_size: f32) {
+        let mut job = LayoutJob::default();
+        for word in line.words.iter() {
+            let sung = pos
+                .map(|pos| word.begin_units <= pos)
+                .unwrap_or(false);
+            let color = match (pos.is_some(), sung) {
+                (true, true) => Self::SUNG_COLOR,
+                (true, false) => Self::CURRENT_COLOR,
+                (false, _) => Self::NEXT_COLOR,
+            };
+            let text = if word.joined {
+                word.text.clone()
+            } else {
+                format!("{} ", word.text)
+            };
+            job.append(
+                text.as_str(),
+                0.0,
+                TextFormat {
+                    font_id: FontId::proportional(font_size),
+                    color,
+                    ..Default::default()
+                },
+            );
+        }
+        ui.label(job);
+    }
+    pub fn karaoke_ui(
+        mut egui_ctx: EguiContexts,
+        mut state: ResMut<NotationState>,
+        locale: Res<NotationLocale>,
+        tab_state_query: Query<&TabState>,
+        mut lyrics: Local<Option<(Arc<Tab>, TimedLyrics)>>,
+    ) {
+        if !state.show_karaoke {
+            return;
+        }
+        let tab = match state.tab.as_ref() {
+            Some(tab) => tab.clone(),
+            None => return,
+        };
+        let outdated = match lyrics.as_ref() {
+            Some((lyrics_tab, _)) => !Arc::ptr_eq(lyrics_tab, &tab),
+            None => true,
+        };
+        if outdated {
+            *lyrics = Some((tab.clone(), TimedLyrics::new(&tab)));
+        }
+        let timed_lyrics = &lyrics.as_ref().unwrap().1;
+        let pos = tab_state_query
+            .iter()
+            .find(|x| x.under_control)
+            .map(|x| x.play_control.position.tab.in_tab_pos)
+            .unwrap_or(Units::ZERO);
+        let mut close = false;
+        egui::CentralPanel::default()
+            .frame(egui::Frame::default().fill(Self::BACKGROUND))
+            .show(egui_ctx.ctx_mut(), |ui| {
+                ui.vertical_centered(|ui| {
+                    ui.add_space(ui.available_height() / 3.0);
+                    match timed_lyrics.find_line(pos) {
+                        Some(index) => {
+                            let line = &timed_lyrics.lines[index];
+                            Self::line_ui(ui, line, Some(pos), Self::CURRENT_FONT_SIZE);
+                            ui.add_space(Self::NEXT_FONT_SIZE);
+                            if let Some(next) = timed_lyrics.lines.get(index + 1) {
+                                Self::line_ui(ui, next, None, Self::NEXT_FONT_SIZE);
+                            }
+                        }
+                        None => {
+                            let text = if timed_lyrics.is_empty() {
+                                locale.text("karaoke-no-lyrics")
+                            } else {
+                                tab.info.title.clone()
+                            };
+                            ui.label(
+                                egui::RichText::new(text)
+                                    .size(Self::NEXT_FONT_SIZE)
+                                    .color(Self::NEXT_COLOR),
+                            );
+                        }
+                    }
+                });
+                ui.with_layout(egui::Layout::bottom_up(egui::Align::Center), |ui| {
+                    if ui.button(locale.text("karaoke-close")).clicked() {
+                        close = true;
+                    }
+                });
+            });
+        if close {
+            state.show_karaoke = false;
+        }
+    }
+}
@@ -28,7 +28,12 @@ impl LyricsPlugin {
     ) {
         match lyric_entry {
             LyricEntry::Word(word, _duration) => {
-                let value = WordTextValue::new(word.clone());
+                let verse = entry.props.verse;
+                let verse_row = entry.lane().map(|x| x.calc_verse_row(verse)).unwrap_or(0);
+                let active = entry.bar().map(|x| x.is_active_verse(verse)).unwrap_or(true);
+                let value = WordTextValue::new(word.clone())
+                    .with_verse(verse_row, active)
+                    .with_melisma(entry.melisma_units());
                 commands
                     .entity(entity)
                     .insert(WordText::from(value.clone()));
@@ -36,6 +41,7 @@ impl LyricsPlugin {
                     commands, assets, theme, settings, entity, entry, &value,
                 );
             }
+            LyricEntry::Melisma(_) | LyricEntry::Verse(_) => {}
         }
     }
 }
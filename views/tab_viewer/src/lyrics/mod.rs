@@ -1,2 +1,5 @@
 pub mod lyrics_grid;
 pub mod lyrics_plugin;
+
+#[cfg(feature = "with_egui")]
+pub mod egui_karaoke;
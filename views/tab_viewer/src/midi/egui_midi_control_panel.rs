@@ -1,51 +1,52 @@
 use edger_bevy::bevy_prelude::*;
-use notation_midi::prelude::{MidiSettings, MidiState};
-use edger_bevy::egui::{CollapsingHeader, Slider, Ui};
+use notation_midi::prelude::{ClickSubdivision, Humanize, MidiSettings, MidiState};
+use edger_bevy::egui::{CollapsingHeader, ComboBox, Slider, Ui};
 use float_eq::float_ne;
 
-use crate::prelude::{NotationSettings, PlayControlEvent, Control, EguiControlPanel, NotationState, NotationTheme, Octave};
+use crate::prelude::{NotationLocale, NotationSettings, PlayControlEvent, Control, EguiControlPanel, NotationState, NotationTheme, Octave};
 use super::midi_control::MidiControl;
 
 impl EguiControlPanel {
     pub fn play_control_ui(
         ui: &mut Ui,
+        locale: &NotationLocale,
         state: &mut NotationState,
         theme: &mut NotationTheme,
         settings: &mut NotationSettings,
         midi_state: &mut MidiState,
         play_control_evts: &mut EventWriter<PlayControlEvent>,
     ) {
-        CollapsingHeader::new("Play Control")
+        CollapsingHeader::new(locale.text("midi-play-control"))
             .default_open(true)
             .show(ui, |ui| {
                 let add_ready_section = settings.add_ready_section;
-                ui.checkbox(&mut settings.add_ready_section, "Add Empty Bar");
+                ui.checkbox(&mut settings.add_ready_section, locale.text("midi-add-empty-bar"));
                 if add_ready_section != settings.add_ready_section {
                     state.bars_range = None;
                     Control::reload_tab(state, theme);
                 }
                 let new_row_for_section = settings.new_row_for_section;
-                ui.checkbox(&mut settings.new_row_for_section, "New Row for Section");
+                ui.checkbox(&mut settings.new_row_for_section, locale.text("midi-new-row-for-section"));
                 if new_row_for_section != settings.new_row_for_section {
                     state.bars_range = None;
                     Control::reload_tab(state, theme);
                 }
                 let play_title = if midi_state.play_control.play_state.is_playing() {
-                    "Pause"
+                    locale.text("midi-pause")
                 } else {
-                    "Play"
+                    locale.text("midi-play")
                 };
                 ui.horizontal(|ui| {
                     if ui.button(play_title).clicked() {
                         MidiControl::play_or_pause(midi_state, play_control_evts);
                     }
-                    if ui.button("Stop").clicked() {
-                        if midi_state.play_control.stop() {
-                            MidiControl::send_play_state_evt(midi_state, play_control_evts);
-                        }
+                    if ui.button(locale.text("midi-stop")).clicked()
+                        && midi_state.play_control.stop()
+                    {
+                        MidiControl::send_play_state_evt(midi_state, play_control_evts);
                     }
                     let should_loop = settings.should_loop;
-                    ui.checkbox(&mut settings.should_loop, "Loop");
+                    ui.checkbox(&mut settings.should_loop, locale.text("midi-loop"));
                     if should_loop != settings.should_loop {
                         MidiControl::sync_should_loop(settings, midi_state, play_control_evts)
                     }
@@ -54,21 +55,24 @@ impl EguiControlPanel {
                 let end_bar_number = state.calc_bar_number(settings.add_ready_section, midi_state.play_control.end_bar_ordinal);
                 ui.horizontal(|ui| {
                     if ui
-                        .button(format!(
-                            "Begin: {}",
-                            begin_bar_number
+                        .button(locale.text_args(
+                            "midi-begin-bar",
+                            &[("bar", begin_bar_number.into())],
                         ))
                         .clicked()
                     {
                         MidiControl::set_begin_bar_ordinal(midi_state, play_control_evts);
                     }
                     if ui
-                        .button(format!("End: {}", end_bar_number))
+                        .button(locale.text_args(
+                            "midi-end-bar",
+                            &[("bar", end_bar_number.into())],
+                        ))
                         .clicked()
                     {
                         MidiControl::set_end_bar_ordinal(midi_state, play_control_evts);
                     }
-                    if ui.button("Clear").clicked() {
+                    if ui.button(locale.text("midi-clear")).clicked() {
                         MidiControl::clear_begin_end(midi_state, play_control_evts);
                     }
                 });
@@ -78,20 +82,28 @@ impl EguiControlPanel {
                     } else {
                         (begin + 1, end + 1)
                     };
-                    if ui.button(format!("Clear Visible Bars: {} - {}", begin, end)).clicked() {
+                    let text = locale.text_args(
+                        "midi-clear-visible-bars",
+                        &[("begin", begin.into()), ("end", end.into())],
+                    );
+                    if ui.button(text).clicked() {
                         state.bars_range = None;
                         Control::reload_tab(state, theme);
                     }
                 } else if midi_state.play_control.has_selection(settings.add_ready_section) {
                     let bars_range = (midi_state.play_control.begin_bar_ordinal, midi_state.play_control.end_bar_ordinal);
-                    if ui.button(format!("Set Visible Bars: {} - {}", begin_bar_number, end_bar_number)).clicked() {
+                    let text = locale.text_args(
+                        "midi-set-visible-bars",
+                        &[("begin", begin_bar_number.into()), ("end", end_bar_number.into())],
+                    );
+                    if ui.button(text).clicked() {
                         state.bars_range = Some(bars_range);
                         Control::reload_tab(state, theme);
                     }
                 }
                 ui.separator();
                 let mut speed_factor = settings.speed_factor;
-                ui.add(Slider::new(&mut speed_factor, 0.1..=2.0).text("Speed"));
+                ui.add(Slider::new(&mut speed_factor, 0.1..=2.0).text(locale.text("midi-speed")));
                 ui.horizontal(|ui| {
                     if ui.button("1/4").clicked() {
                         speed_factor = 0.25;
@@ -110,20 +122,80 @@ impl EguiControlPanel {
                     MidiControl::set_speed_factor(settings, midi_state, play_control_evts, speed_factor)
                 }
             });
+        Self::speed_trainer_ui(ui, locale, settings, midi_state, play_control_evts);
+    }
+    pub fn speed_trainer_ui(
+        ui: &mut Ui,
+        locale: &NotationLocale,
+        settings: &mut NotationSettings,
+        midi_state: &mut MidiState,
+        play_control_evts: &mut EventWriter<PlayControlEvent>,
+    ) {
+        CollapsingHeader::new(locale.text("midi-speed-trainer"))
+            .default_open(false)
+            .show(ui, |ui| {
+                let mut speed_trainer = midi_state.play_control.speed_trainer;
+                ui.add(Slider::new(&mut speed_trainer.start_percent, 10.0..=200.0).text(locale.text("midi-trainer-start-percent")));
+                ui.add(Slider::new(&mut speed_trainer.step_percent, 1.0..=25.0).text(locale.text("midi-trainer-step-percent")));
+                ui.add(Slider::new(&mut speed_trainer.target_percent, 10.0..=200.0).text(locale.text("midi-trainer-target-percent")));
+                let mut max_loops = speed_trainer.max_loops.unwrap_or(0);
+                ui.add(Slider::new(&mut max_loops, 0..=100).text(locale.text("midi-trainer-max-loops")));
+                speed_trainer.max_loops = if max_loops > 0 { Some(max_loops) } else { None };
+                if speed_trainer != midi_state.play_control.speed_trainer {
+                    MidiControl::update_speed_trainer(midi_state, play_control_evts, speed_trainer);
+                }
+                ui.horizontal(|ui| {
+                    if speed_trainer.enabled {
+                        if ui.button(locale.text("midi-trainer-stop")).clicked() {
+                            MidiControl::stop_speed_trainer(settings, midi_state, play_control_evts);
+                        }
+                        if ui.button(locale.text("midi-trainer-restart")).clicked() {
+                            MidiControl::start_speed_trainer(settings, midi_state, play_control_evts, speed_trainer);
+                        }
+                    } else if ui.button(locale.text("midi-trainer-start")).clicked() {
+                        MidiControl::start_speed_trainer(settings, midi_state, play_control_evts, speed_trainer);
+                    }
+                });
+                if let Some(tab) = midi_state.tab.clone() {
+                    let speed_trainer = midi_state.play_control.speed_trainer;
+                    if speed_trainer.enabled {
+                        let id = if speed_trainer.finished {
+                            "midi-trainer-finished"
+                        } else {
+                            "midi-trainer-loop"
+                        };
+                        ui.label(locale.text_args(
+                            id,
+                            &[
+                                ("loop", (speed_trainer.loops + 1).into()),
+                                ("percent", format!("{:.0}", speed_trainer.current_percent()).into()),
+                                ("bpm", format!("{:.0}", speed_trainer.current_bpm(&tab.meta)).into()),
+                            ],
+                        ));
+                    }
+                    if let Some(best_bpm) = midi_state.get_best_bpm() {
+                        ui.label(locale.text_args(
+                            "midi-trainer-best",
+                            &[("bpm", format!("{:.0}", best_bpm).into())],
+                        ));
+                    }
+                }
+            });
     }
     pub fn midi_settings_ui(
         ui: &mut Ui,
+        locale: &NotationLocale,
         state: &mut NotationState,
         theme: &mut NotationTheme,
         midi_settings: &mut MidiSettings,
         midi_state: &mut MidiState,
         play_control_evts: &mut EventWriter<PlayControlEvent>,
     ) {
-        CollapsingHeader::new("Midi & Audio")
+        CollapsingHeader::new(locale.text("midi-audio"))
             .default_open(true)
             .show(ui, |ui| {
                 let mut bypass_hub = midi_settings.bypass_hub;
-                ui.checkbox(&mut bypass_hub, "Bypass Midi Hub");
+                ui.checkbox(&mut bypass_hub, locale.text("midi-bypass-hub"));
                 if midi_settings.bypass_hub != bypass_hub {
                     if midi_state.play_control.play_state.is_playing() {
                         MidiControl::pause(midi_state, play_control_evts);
@@ -134,46 +206,168 @@ impl EguiControlPanel {
                 if !midi_settings.bypass_hub {
                     ui.separator();
                     ui.horizontal(|ui| {
-                        ui.label(format!("Click Octave: {}", midi_settings.click_octave));
+                        ui.label(locale.text_args(
+                            "midi-click-octave",
+                            &[("octave", midi_settings.click_octave.to_string().into())],
+                        ));
                         ui.separator();
-                        if midi_settings.click_octave > Octave::P1 && ui.button("lower").clicked() {
+                        if midi_settings.click_octave > Octave::P1 && ui.button(locale.text("midi-click-octave-lower")).clicked() {
                             midi_settings.click_octave = midi_settings.click_octave.get_lower();
                             Control::reload_tab(state, theme);
                         }
-                        if midi_settings.click_octave < Octave::P7 && ui.button("higher").clicked()
+                        if midi_settings.click_octave < Octave::P7 && ui.button(locale.text("midi-click-octave-higher")).clicked()
                         {
                             midi_settings.click_octave = midi_settings.click_octave.get_higher();
                             Control::reload_tab(state, theme);
                         }
                     });
                     ui.horizontal(|ui| {
-                        ui.checkbox(&mut midi_settings.click_mute, "Mute");
+                        ui.checkbox(&mut midi_settings.click_mute, locale.text("midi-mute"));
                         ui.add(
-                            Slider::new(&mut midi_settings.click_velocity, 0..=127).text("Click"),
+                            Slider::new(&mut midi_settings.click_velocity, 0..=127).text(locale.text("midi-click")),
                         );
                     });
+                    ui.add(
+                        Slider::new(&mut midi_settings.click_accent_velocity, 0..=127)
+                            .text(locale.text("midi-accent")),
+                    );
                     ui.horizontal(|ui| {
-                        ui.checkbox(&mut midi_settings.vocal_mute, "Mute");
+                        let sounds = (midi_settings.click_sound, midi_settings.click_accent_sound);
                         ui.add(
-                            Slider::new(&mut midi_settings.vocal_velocity, 0..=127).text("Vocal"),
+                            Slider::new(&mut midi_settings.click_sound, 0..=127)
+                                .text(locale.text("midi-click-sound")),
                         );
+                        ui.add(
+                            Slider::new(&mut midi_settings.click_accent_sound, 0..=127)
+                                .text(locale.text("midi-accent-sound")),
+                        );
+                        if sounds != (midi_settings.click_sound, midi_settings.click_accent_sound) {
+                            Control::reload_tab(state, theme);
+                        }
                     });
                     ui.horizontal(|ui| {
-                        ui.checkbox(&mut midi_settings.guitar_mute, "Mute");
+                        let click_subdivision = midi_settings.click_subdivision;
+                        let subdivision_text = |subdivision: ClickSubdivision| {
+                            locale.text(&format!("midi-subdivision-{:?}", subdivision).to_lowercase())
+                        };
+                        ComboBox::from_id_salt("click_subdivision")
+                            .selected_text(subdivision_text(midi_settings.click_subdivision))
+                            .show_ui(ui, |ui| {
+                                for subdivision in ClickSubdivision::ALL {
+                                    if ui
+                                        .selectable_label(
+                                            midi_settings.click_subdivision == subdivision,
+                                            subdivision_text(subdivision),
+                                        )
+                                        .clicked()
+                                    {
+                                        midi_settings.click_subdivision = subdivision;
+                                    }
+                                }
+                            });
+                        if click_subdivision != midi_settings.click_subdivision {
+                            Control::reload_tab(state, theme);
+                        }
                         ui.add(
-                            Slider::new(&mut midi_settings.guitar_velocity, 0..=127).text("Guitar"),
+                            Slider::new(&mut midi_settings.click_subdivision_velocity, 0..=127)
+                                .text(locale.text("midi-subdivision")),
                         );
                     });
-                    if ui.button("Reset Audio").clicked() {
+                    ui.add(Slider::new(&mut midi_settings.count_in_bars, 0..=4).text(locale.text("midi-count-in-bars")));
+                    let fermata_speed = midi_settings.fermata_speed;
+                    ui.add(Slider::new(&mut midi_settings.fermata_speed, 0.1..=1.0).text(locale.text("midi-fermata-speed")));
+                    if fermata_speed != midi_settings.fermata_speed {
+                        midi_state.play_control.fermata_speed = midi_settings.fermata_speed;
+                    }
+                    // The midi messages are scheduled again when humanize changed, so the
+                    // value being dragged is kept in egui memory till the drag is stopped.
+                    let humanize_id = ui.id().with("humanize");
+                    let mut humanize = ui
+                        .data(|x| x.get_temp::<Humanize>(humanize_id))
+                        .unwrap_or(midi_settings.humanize);
+                    let dragged = ui
+                        .horizontal(|ui| {
+                            ui.checkbox(&mut humanize.enabled, locale.text("midi-humanize"));
+                            let timing = ui.add(
+                                Slider::new(&mut humanize.timing_percent, 0..=50)
+                                    .text(locale.text("midi-humanize-timing")),
+                            );
+                            let velocity = ui.add(
+                                Slider::new(&mut humanize.velocity, 0..=32)
+                                    .text(locale.text("midi-humanize-velocity")),
+                            );
+                            timing.dragged() || velocity.dragged()
+                        })
+                        .inner;
+                    if dragged {
+                        ui.data_mut(|x| x.insert_temp(humanize_id, humanize));
+                    } else {
+                        ui.data_mut(|x| x.remove::<Humanize>(humanize_id));
+                        midi_settings.humanize = humanize;
+                    }
+                    Self::mixer_ui(ui, locale, state, theme, midi_settings, midi_state);
+                    if ui.button(locale.text("midi-reset-audio")).clicked() {
                         let default = MidiSettings::default();
                         midi_settings.click_mute = default.click_mute;
+                        midi_settings.click_octave = default.click_octave;
                         midi_settings.click_velocity = default.click_velocity;
-                        midi_settings.vocal_mute = default.vocal_mute;
-                        midi_settings.vocal_velocity = default.vocal_velocity;
-                        midi_settings.guitar_mute = default.guitar_mute;
-                        midi_settings.guitar_velocity = default.guitar_velocity;
+                        midi_settings.click_accent_velocity = default.click_accent_velocity;
+                        midi_settings.click_sound = default.click_sound;
+                        midi_settings.click_accent_sound = default.click_accent_sound;
+                        midi_settings.click_subdivision = default.click_subdivision;
+                        midi_settings.click_subdivision_velocity = default.click_subdivision_velocity;
+                        midi_settings.count_in_bars = default.count_in_bars;
+                        midi_settings.fermata_speed = default.fermata_speed;
+                        midi_state.play_control.fermata_speed = default.fermata_speed;
+                        midi_settings.humanize = default.humanize;
+                        midi_settings.mixer = default.mixer;
+                        Control::reload_tab(state, theme);
                     }
                 }
             });
     }
+    pub fn mixer_ui(
+        ui: &mut Ui,
+        locale: &NotationLocale,
+        state: &mut NotationState,
+        theme: &mut NotationTheme,
+        midi_settings: &mut MidiSettings,
+        midi_state: &MidiState,
+    ) {
+        let tab = match midi_state.tab.clone() {
+            Some(tab) => tab,
+            None => return,
+        };
+        let mut program_changed = false;
+        for track in tab.tracks.iter() {
+            let shared_percussion = midi_state.is_shared_percussion(track);
+            if let Some(mixer) = midi_settings.mixer.get_track_mut(track) {
+                CollapsingHeader::new(format!("{}: {}", track.kind, track.id))
+                    .default_open(false)
+                    .show(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut mixer.mute, locale.text("midi-mute"));
+                            ui.checkbox(&mut mixer.solo, locale.text("midi-solo"));
+                        });
+                        ui.add(Slider::new(&mut mixer.velocity, 0..=127).text(locale.text("midi-velocity")));
+                        if shared_percussion {
+                            ui.label(locale.text("midi-shared-percussion"));
+                            return;
+                        }
+                        ui.add(Slider::new(&mut mixer.volume, 0..=127).text(locale.text("midi-volume")));
+                        ui.add(Slider::new(&mut mixer.pan, 0..=127).text(locale.text("midi-pan")));
+                        ui.add(Slider::new(&mut mixer.reverb, 0..=127).text(locale.text("midi-reverb")));
+                        ui.add(Slider::new(&mut mixer.chorus, 0..=127).text(locale.text("midi-chorus")));
+                        let program = mixer.program;
+                        ui.add(Slider::new(&mut mixer.program, 0..=127).text(locale.text("midi-program")));
+                        if program != mixer.program {
+                            program_changed = true;
+                        }
+                    });
+            }
+        }
+        if program_changed {
+            Control::reload_tab(state, theme);
+        }
+    }
 }
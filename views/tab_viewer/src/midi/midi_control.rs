@@ -3,7 +3,7 @@ use std::sync::Arc;
 use edger_bevy::bevy_prelude::*;
 use edger_bevy::prelude::GridData;
 use notation_model::prelude::{BarPosition, Tab, Units, TabBar};
-use notation_midi::prelude::{MidiSettings, MidiState, TickResult, JumpToBarEvent, PlayControlEvent};
+use notation_midi::prelude::{MidiClock, MidiSettings, MidiState, PlayClockSource, SpeedTrainer, TickResult, JumpToBarEvent, PlayControlEvent};
 
 use crate::tab::tab_bars::TabBars;
 use crate::tab::tab_plugin::{TabPlugin};
@@ -26,6 +26,18 @@ impl MidiControl {
             midi_state.play_control.play_speed.factor(),
         ));
     }
+    pub fn sync_clock_source(settings: &NotationSettings, midi_clock: &mut MidiClock) {
+        let source = if settings.layout.video_recording_mode {
+            PlayClockSource::video()
+        } else {
+            PlayClockSource::WallClock
+        };
+        midi_clock.set_source(source);
+    }
+    /// Runs every frame, so the video recording mode takes effect without the control panel.
+    pub fn on_clock_settings(settings: Res<NotationSettings>, mut midi_clock: ResMut<MidiClock>) {
+        Self::sync_clock_source(&settings, &mut midi_clock);
+    }
     pub fn sync_should_loop(
         settings: &NotationSettings,
         midi_state: &mut MidiState,
@@ -101,7 +113,7 @@ impl MidiControl {
         if midi_state.play_control.stop() {
             midi_state.play_control.position.bar.bar_ordinal =
                 midi_state.play_control.begin_bar_ordinal;
-            midi_state.play_control.position.bar.in_bar_pos = Units(0.0);
+            midi_state.play_control.position.bar.in_bar_pos = Units::ZERO;
             Self::send_play_state_evt(midi_state, play_control_evts);
         }
     }
@@ -147,7 +159,7 @@ impl MidiControl {
         jump_to_bar_evts: &mut EventWriter<JumpToBarEvent>,
     ) {
         Self::jump_to_bar(midi_state, jump_to_bar_evts, &|tab, pos| {
-            if pos.in_bar_pos.0 > 0.0 {
+            if !pos.in_bar_pos.is_zero() {
                 tab.get_bar_of_ordinal(pos.bar_ordinal)
             } else if pos.bar_ordinal > 0 {
                 tab.get_bar_of_ordinal(pos.bar_ordinal - 1)
@@ -321,4 +333,50 @@ impl MidiControl {
         settings.speed_factor = speed_factor;
         Self::sync_speed_factor(settings, midi_state, play_control_evts)
     }
+    pub fn send_speed_trainer_evt(
+        midi_state: &MidiState,
+        play_control_evts: &mut EventWriter<PlayControlEvent>,
+    ) {
+        play_control_evts.send(PlayControlEvent::on_speed_trainer(
+            midi_state.play_control.speed_trainer,
+        ));
+        play_control_evts.send(PlayControlEvent::on_speed_factor(
+            midi_state.play_control.play_speed.factor(),
+        ));
+    }
+    pub fn start_speed_trainer(
+        settings: &mut NotationSettings,
+        midi_state: &mut MidiState,
+        play_control_evts: &mut EventWriter<PlayControlEvent>,
+        speed_trainer: SpeedTrainer,
+    ) {
+        midi_state.play_control.start_speed_trainer(speed_trainer);
+        settings.should_loop = true;
+        Self::sync_should_loop(settings, midi_state, play_control_evts);
+        Self::send_speed_trainer_evt(midi_state, play_control_evts);
+    }
+    pub fn stop_speed_trainer(
+        settings: &NotationSettings,
+        midi_state: &mut MidiState,
+        play_control_evts: &mut EventWriter<PlayControlEvent>,
+    ) {
+        midi_state
+            .play_control
+            .stop_speed_trainer(settings.speed_factor);
+        Self::send_speed_trainer_evt(midi_state, play_control_evts);
+    }
+    pub fn update_speed_trainer(
+        midi_state: &mut MidiState,
+        play_control_evts: &mut EventWriter<PlayControlEvent>,
+        speed_trainer: SpeedTrainer,
+    ) {
+        midi_state.play_control.speed_trainer = speed_trainer;
+        if speed_trainer.is_running() {
+            midi_state
+                .play_control
+                .play_speed
+                .set_factor(speed_trainer.current_factor());
+        }
+        Self::send_speed_trainer_evt(midi_state, play_control_evts);
+    }
 }
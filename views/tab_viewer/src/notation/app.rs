@@ -11,6 +11,15 @@ use super::tab_viewer::TabViewerPlugin;
 #[cfg(feature = "with_egui")]
 use super::egui_control_panel::EguiControlPanel;
 
+#[cfg(feature = "with_egui")]
+use crate::library::egui_library_browser::EguiLibraryBrowser;
+#[cfg(feature = "with_egui")]
+use crate::lyrics::egui_karaoke::EguiKaraoke;
+use crate::library::library_index::{LibraryIndex, LibraryScan};
+
+#[cfg(not(target_arch = "wasm32"))]
+use super::tab_watcher::TabWatcher;
+
 #[cfg(feature = "midi")]
 use notation_midi::prelude::{
     MidiPlugin,
@@ -28,6 +37,8 @@ impl PluginGroup for NotationPlugins {
             .add(MelodyPlugin)
             .add(HarmonyPlugin)
             .add(StringsPlugin)
+            .add(StaffPlugin)
+            .add(JianpuPlugin)
             .add(ShapesPlugin)
             .add(MiniPlugin)
             .add(TabPlugin)
@@ -53,6 +64,9 @@ impl NotationApp {
 
         app.init_resource::<NotationTheme>();
         app.init_resource::<NotationSettings>();
+        app.init_resource::<NotationLocale>();
+        app.register_asset_loader(FtlAssetLoader)
+            .init_asset::<FtlAsset>();
         app.add_plugins(NotationPlugins);
 
         #[cfg(feature = "dev")]
@@ -69,19 +83,51 @@ impl NotationApp {
             Self::setup(app);
 
             app.init_resource::<NotationState>();
+            #[cfg(not(target_arch = "wasm32"))]
+            {
+                let index = LibraryIndex::load_cached(args.library.clone());
+                app.insert_resource(LibraryScan::start(&index));
+                app.insert_resource(index);
+            }
+            #[cfg(target_arch = "wasm32")]
+            {
+                app.insert_resource(LibraryIndex::new(args.library.clone()));
+                app.init_resource::<LibraryScan>();
+            }
+            app.add_systems(Update, LibraryScan::on_scanned);
 
             init_preload_assets::<NotationAssets>(app);
             init_preload_assets::<A>(app);
 
-            #[cfg(feature = "with_egui")]
-            add_assets_loaded_systems(app, NotationAssets::setup_egui_context);
+            add_assets_loaded_systems(app, NotationAssets::setup_locale);
 
             app.add_systems(Update, (
                 Self::on_tab_asset,
             ).run_if(in_state(AssetsStates::Loaded)));
+            #[cfg(feature = "midi")]
+            app.add_systems(Update, (
+                MidiControl::on_clock_settings,
+            ).run_if(in_state(AssetsStates::Loaded)));
             #[cfg(feature = "with_egui")]
-            app.add_systems(Update, EguiControlPanel::control_ui
-                .run_if(in_state(AssetsStates::Loaded)));
+            app.add_systems(Update, (
+                EguiControlPanel::control_ui,
+                EguiLibraryBrowser::library_ui,
+                EguiKaraoke::karaoke_ui
+                    .after(EguiControlPanel::control_ui),
+            ).run_if(in_state(AssetsStates::Loaded)));
+
+            #[cfg(not(target_arch = "wasm32"))]
+            {
+                app.init_resource::<TabWatcher>();
+                app.add_systems(Update, (
+                    TabWatcher::check_tab_file,
+                    TabWatcher::on_tab_asset_modified,
+                ).run_if(in_state(AssetsStates::Loaded)));
+                #[cfg(feature = "with_egui")]
+                app.add_systems(Update, (
+                    TabWatcher::reload_error_ui,
+                ).run_if(in_state(AssetsStates::Loaded)));
+            }
 
             extra(app);
         });
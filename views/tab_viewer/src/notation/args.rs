@@ -12,6 +12,10 @@ pub struct NotationArgs {
 
     #[cfg_attr(feature = "native", clap(short, long))]
     pub tab: Vec<String>,
+
+    /// Folders to look for tabs in the library
+    #[cfg_attr(feature = "native", clap(long))]
+    pub library: Vec<String>,
 }
 
 impl NotationArgs {
@@ -22,7 +26,8 @@ impl NotationArgs {
         return Self::parse_wasm();
         Self {
             lang: "en-US".to_owned(),
-            tab: vec![ "tabs/test.ron".to_owned() ]
+            tab: vec![ "tabs/test.ron".to_owned() ],
+            library: vec![],
         }
     }
     #[cfg(feature = "native")]
@@ -38,6 +43,9 @@ impl NotationArgs {
                 args.tab.push("tabs/zh-CN/long_juan_feng.ron".to_owned());
             }
         }
+        if args.library.is_empty() {
+            args.library.push("assets/tabs".to_owned());
+        }
         args
     }
     #[cfg(target_arch = "wasm32")]
@@ -70,6 +78,7 @@ impl NotationArgs {
         Self {
             lang,
             tab,
+            library: vec![],
         }
     }
 }
\ No newline at end of file
@@ -3,7 +3,7 @@ use bevy_asset_loader::prelude::*;
 use edger_bevy::prelude::*;
 use unic_langid::LanguageIdentifier;
 
-use crate::prelude::{NotationArgs, NotationSettings};
+use crate::prelude::{FtlAsset, NotationArgs, NotationLocale, NotationSettings};
 
 #[derive(AssetCollection, Resource)]
 pub struct NotationAssets {
@@ -23,6 +23,12 @@ pub struct NotationAssets {
 
     #[asset(key = "fretboard_image")]
     pub fretboard: Handle<Image>,
+
+    #[asset(key = "locale_ftl")]
+    pub locale_ftl: Handle<FtlAsset>,
+
+    #[asset(key = "fallback_ftl")]
+    pub fallback_ftl: Handle<FtlAsset>,
 }
 
 impl FromWorld for NotationAssets {
@@ -35,6 +41,8 @@ impl FromWorld for NotationAssets {
             latin_font: default(),
             lyrics_font: default(),
             fretboard: default(),
+            locale_ftl: default(),
+            fallback_ftl: default(),
         }
     }
 }
@@ -49,38 +57,44 @@ impl NotationAssets {
     fn get_latin_font(&self) -> String {
         "fonts/FiraMono-Medium.ttf".to_owned()
     }
+    /// Default lyrics font, languages can override it with `locale-lyrics-font`.
     fn get_lyrics_font(&self) -> String {
-        if self.lang == NotationSettings::ZH_CN {
-            #[cfg(feature = "with_egui")]
-            return "fonts/zh-CN/NotoSansSC-Medium.otf.egui".to_owned();
-
-            return "fonts/zh-CN/NotoSansSC-Medium.otf".to_owned();
-        }
-
+        Self::get_font_path("fonts/en-US/FiraMono-Medium.ttf")
+    }
+    fn get_font_path(path: &str) -> String {
         #[cfg(feature = "with_egui")]
-        return "fonts/en-US/FiraMono-Medium.ttf.egui".to_owned();
+        return format!("{}.egui", path);
 
-        return "fonts/en-US/FiraMono-Medium.ttf".to_owned();
+        #[cfg(not(feature = "with_egui"))]
+        return path.to_owned();
     }
-
     fn get_fretboard_image(&self) -> String {
         "png/fretboard.png".to_owned()
     }
 
-    #[cfg(feature = "with_egui")]
-    fn get_egui_font_sizes(&self) -> EguiFontSizes {
-        if self.lang == NotationSettings::ZH_CN {
-            return EguiFontSizes::BIGGER;
-        }
-        EguiFontSizes::default()
-    }
-
-    #[cfg(feature = "with_egui")]
-    pub fn setup_egui_context(
-        assets: Res<NotationAssets>,
+    pub fn setup_locale(
+        asset_server: Res<AssetServer>,
+        ftl_assets: Res<Assets<FtlAsset>>,
+        mut assets: ResMut<NotationAssets>,
+        mut locale: ResMut<NotationLocale>,
+        #[cfg(feature = "with_egui")]
         mut egui_ctx: EguiContexts,
     ) {
-        assets.get_egui_font_sizes().apply_context(&mut egui_ctx);
+        let mut sources = vec![];
+        if let Some(ftl) = ftl_assets.get(&assets.locale_ftl) {
+            sources.push((assets.lang.clone(), ftl.source.as_str()));
+        }
+        if assets.lang != NotationSettings::EN_US {
+            if let Some(ftl) = ftl_assets.get(&assets.fallback_ftl) {
+                sources.push((NotationSettings::EN_US, ftl.source.as_str()));
+            }
+        }
+        *locale = NotationLocale::new(assets.lang.clone(), sources);
+        if let Some(font) = locale.get_lyrics_font() {
+            assets.lyrics_font = asset_server.load(Self::get_font_path(&font));
+        }
+        #[cfg(feature = "with_egui")]
+        locale.get_egui_font_sizes().apply_context(&mut egui_ctx);
     }
 }
 
@@ -91,5 +105,7 @@ impl PreloadAssets for NotationAssets {
         register_file_asset(asset_keys, "latin_font", self.get_latin_font());
         register_file_asset(asset_keys, "lyrics_font", self.get_lyrics_font());
         register_file_asset(asset_keys, "fretboard_image", self.get_fretboard_image());
+        register_file_asset(asset_keys, "locale_ftl", NotationLocale::get_ftl_path(&self.lang));
+        register_file_asset(asset_keys, "fallback_ftl", NotationLocale::get_ftl_path(&NotationSettings::EN_US));
     }
 }
\ No newline at end of file
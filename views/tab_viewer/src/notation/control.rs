@@ -5,6 +5,11 @@ use crate::settings::layout_settings::LayoutMode;
 
 use crate::prelude::{NotationState, NotationSettings, NotationTheme};
 
+#[cfg(not(target_arch = "wasm32"))]
+use notation_model::prelude::TimedLyrics;
+#[cfg(not(target_arch = "wasm32"))]
+use super::tab_watcher::TabWatcher;
+
 use edger_bevy::prelude::WindowResizedEvent;
 
 pub struct Control();
@@ -27,6 +32,26 @@ impl Control {
         state.reload_tab();
         theme._bypass_systems = true;
     }
+    pub fn toggle_karaoke(state: &mut NotationState) {
+        state.show_karaoke = !state.show_karaoke;
+    }
+    /// Write the lyrics as .lrc and .vtt files next to the tab file.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn export_lyrics(state: &NotationState) {
+        let tab = match state.tab.as_ref() {
+            Some(tab) => tab,
+            None => return,
+        };
+        let lyrics = TimedLyrics::new(tab);
+        let path = TabWatcher::get_file_path(&state.tab_path);
+        for (extension, content) in [("lrc", lyrics.to_lrc(&tab.info)), ("vtt", lyrics.to_webvtt())] {
+            let path = path.with_extension(extension);
+            match std::fs::write(&path, content) {
+                Ok(_) => println!("export_lyrics(): {:?}", path),
+                Err(err) => println!("export_lyrics(): Failed: {:?} -> {:?}", path, err),
+            }
+        }
+    }
     pub fn toggle_layout_mode(
         state: &mut NotationState,
         settings: &mut NotationSettings,
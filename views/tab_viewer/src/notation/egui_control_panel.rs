@@ -10,7 +10,7 @@ use crate::settings::layout_settings::{GridAlignMode, LayoutMode};
 use super::control::Control;
 
 use crate::prelude::{
-    GuitarView, NotationState, NotationSettings, NotationTheme, TabAsset,
+    GuitarView, NotationLocale, NotationState, NotationSettings, NotationTheme, TabAsset,
     NotationArgs, WindowResizedEvent,
 };
 
@@ -43,6 +43,7 @@ impl EguiControlPanel {
     }
     pub fn overrides_ui(
         ui: &mut Ui,
+        locale: &NotationLocale,
         app_state: &AppState,
         state: &NotationState,
         settings: &mut NotationSettings,
@@ -52,15 +53,15 @@ impl EguiControlPanel {
         midi_state: &MidiState,
         jump_to_bar_evts: &mut EventWriter<JumpToBarEvent>,
     ) {
-        CollapsingHeader::new("Override Sizes")
+        CollapsingHeader::new(locale.text("control-override-sizes"))
             .default_open(true)
             .show(ui, |ui| {
                 let mut override_tab_width = settings.layout.override_tab_width.is_some();
-                ui.checkbox(&mut override_tab_width, "Override Tab Width");
+                ui.checkbox(&mut override_tab_width, locale.text("control-override-tab-width"));
                 if override_tab_width {
                     let mut tab_width = settings.layout.override_tab_width.unwrap_or(512.0);
                     let last_tab_width = tab_width;
-                    ui.add(Slider::new(&mut tab_width, 256.0..=1024.0).text("Tab Width"));
+                    ui.add(Slider::new(&mut tab_width, 256.0..=1024.0).text(locale.text("control-tab-width")));
                     if settings.layout.override_tab_width.is_none()
                         || float_ne!(tab_width, last_tab_width, abs <= 1.0)
                     {
@@ -72,11 +73,11 @@ impl EguiControlPanel {
                     window_resized_evts.send(WindowResizedEvent::new(&app_state));
                 }
                 let mut override_beat_size = settings.override_beat_size.is_some();
-                ui.checkbox(&mut override_beat_size, "Override Beat Size");
+                ui.checkbox(&mut override_beat_size, locale.text("control-override-beat-size"));
                 if override_beat_size {
                     let mut beat_size = settings.override_beat_size.unwrap_or(80.0);
                     let last_beat_size = beat_size;
-                    ui.add(Slider::new(&mut beat_size, 16.0..=512.0).text("Beat Size"));
+                    ui.add(Slider::new(&mut beat_size, 16.0..=512.0).text(locale.text("control-beat-size")));
                     if settings.override_beat_size.is_none()
                         || float_ne!(beat_size, last_beat_size, abs <= 1.0)
                     {
@@ -88,11 +89,11 @@ impl EguiControlPanel {
                     window_resized_evts.send(WindowResizedEvent::new(&app_state));
                 }
                 let mut override_chord_size = settings.override_chord_size.is_some();
-                ui.checkbox(&mut override_chord_size, "Override Chord Size");
+                ui.checkbox(&mut override_chord_size, locale.text("control-override-chord-size"));
                 if override_chord_size {
                     let mut chord_size = settings.override_chord_size.unwrap_or(128.0);
                     let last_chord_size = chord_size;
-                    ui.add(Slider::new(&mut chord_size, 48.0..=256.0).text("Chord Size"));
+                    ui.add(Slider::new(&mut chord_size, 48.0..=256.0).text(locale.text("control-chord-size")));
                     if settings.override_chord_size.is_none()
                         || float_ne!(chord_size, last_chord_size, abs <= 1.0)
                     {
@@ -104,11 +105,11 @@ impl EguiControlPanel {
                     window_resized_evts.send(WindowResizedEvent::new(&app_state));
                 }
                 let mut override_guitar_width = settings.override_guitar_width.is_some();
-                ui.checkbox(&mut override_guitar_width, "Override Guitar Width");
+                ui.checkbox(&mut override_guitar_width, locale.text("control-override-guitar-width"));
                 if override_guitar_width {
                     let mut guitar_width = settings.override_guitar_width.unwrap_or(256.0);
                     let last_guitar_width = guitar_width;
-                    ui.add(Slider::new(&mut guitar_width, 72.0..=1024.0).text("Guitar Width"));
+                    ui.add(Slider::new(&mut guitar_width, 72.0..=1024.0).text(locale.text("control-guitar-width")));
                     if settings.override_guitar_width.is_none()
                         || float_ne!(guitar_width, last_guitar_width, abs <= 1.0)
                     {
@@ -120,11 +121,11 @@ impl EguiControlPanel {
                     window_resized_evts.send(WindowResizedEvent::new(&app_state));
                 }
                 let mut override_focus_offset_y = settings.layout.override_focus_offset_y.is_some();
-                ui.checkbox(&mut override_focus_offset_y, "Override Focus Offset Y");
+                ui.checkbox(&mut override_focus_offset_y, locale.text("control-override-focus-offset-y"));
                 if override_focus_offset_y {
                     let mut offset_y = settings.layout.override_focus_offset_y.unwrap_or(0.0);
                     let last_offset_y = offset_y;
-                    ui.add(Slider::new(&mut offset_y, -512.0..=512.0).text("Focus Offset Y"));
+                    ui.add(Slider::new(&mut offset_y, -512.0..=512.0).text(locale.text("control-focus-offset-y")));
                     if settings.layout.override_focus_offset_y.is_none()
                         || float_ne!(offset_y, last_offset_y, abs <= 1.0)
                     {
@@ -138,11 +139,11 @@ impl EguiControlPanel {
                     MidiControl::jump_to_center_bar(midi_state, jump_to_bar_evts);
                 }
                 let mut override_guitar_y = settings.override_guitar_y.is_some();
-                ui.checkbox(&mut override_guitar_y, "Override Guitar Y");
+                ui.checkbox(&mut override_guitar_y, locale.text("control-override-guitar-y"));
                 if override_guitar_y {
                     let mut guitar_y = settings.override_guitar_y.unwrap_or(0.0);
                     let last_guitar_y = guitar_y;
-                    ui.add(Slider::new(&mut guitar_y, -4096.0..=4096.0).text("Guitar Y"));
+                    ui.add(Slider::new(&mut guitar_y, -4096.0..=4096.0).text(locale.text("control-guitar-y")));
                     if settings.override_guitar_y.is_none()
                         || float_ne!(guitar_y, last_guitar_y, abs <= 1.0)
                     {
@@ -157,95 +158,124 @@ impl EguiControlPanel {
     }
     pub fn display_ui(
         ui: &mut Ui,
+        locale: &NotationLocale,
         state: &mut NotationState,
         settings: &mut NotationSettings,
         theme: &mut NotationTheme,
     ) {
-        CollapsingHeader::new("Display Options")
+        CollapsingHeader::new(locale.text("control-display-options"))
             .default_open(true)
             .show(ui, |ui| {
                 let mut hide_harmony_lane = settings.hide_harmony_lane;
-                ui.checkbox(&mut hide_harmony_lane, "Hide Guitar Notes");
+                ui.checkbox(&mut hide_harmony_lane, locale.text("control-hide-guitar-notes"));
                 if settings.hide_harmony_lane != hide_harmony_lane {
                     settings.hide_harmony_lane = hide_harmony_lane;
                     Control::reload_tab(state, theme);
                 }
                 let mut hide_shapes_lane = settings.hide_shapes_lane;
-                ui.checkbox(&mut hide_shapes_lane, "Hide Guitar Chords");
+                ui.checkbox(&mut hide_shapes_lane, locale.text("control-hide-guitar-chords"));
                 if settings.hide_shapes_lane != hide_shapes_lane {
                     settings.hide_shapes_lane = hide_shapes_lane;
                     Control::reload_tab(state, theme);
                 }
                 let mut hide_strings_lane = settings.hide_strings_lane;
-                ui.checkbox(&mut hide_strings_lane, "Hide Guitar Strings");
+                ui.checkbox(&mut hide_strings_lane, locale.text("control-hide-guitar-strings"));
                 if settings.hide_strings_lane != hide_strings_lane {
                     settings.hide_strings_lane = hide_strings_lane;
                     Control::reload_tab(state, theme);
                 }
                 let mut hide_lyrics_lane = settings.hide_lyrics_lane;
-                ui.checkbox(&mut hide_lyrics_lane, "Hide Lyrics ");
+                ui.checkbox(&mut hide_lyrics_lane, locale.text("control-hide-lyrics"));
                 if settings.hide_lyrics_lane != hide_lyrics_lane {
                     settings.hide_lyrics_lane = hide_lyrics_lane;
                     Control::reload_tab(state, theme);
                 }
                 let mut hide_melody_lane = settings.hide_melody_lane;
-                ui.checkbox(&mut hide_melody_lane, "Hide Melody");
+                ui.checkbox(&mut hide_melody_lane, locale.text("control-hide-melody"));
                 if settings.hide_melody_lane != hide_melody_lane {
                     settings.hide_melody_lane = hide_melody_lane;
                     Control::reload_tab(state, theme);
                 }
+                let mut hide_staff_lane = settings.hide_staff_lane;
+                ui.checkbox(&mut hide_staff_lane, locale.text("control-hide-staff"));
+                if settings.hide_staff_lane != hide_staff_lane {
+                    settings.hide_staff_lane = hide_staff_lane;
+                    Control::reload_tab(state, theme);
+                }
+                let mut hide_jianpu_lane = settings.hide_jianpu_lane;
+                ui.checkbox(&mut hide_jianpu_lane, locale.text("control-hide-jianpu"));
+                if settings.hide_jianpu_lane != hide_jianpu_lane {
+                    settings.hide_jianpu_lane = hide_jianpu_lane;
+                    Control::reload_tab(state, theme);
+                }
+                let show_chord_analysis = settings.show_chord_analysis;
+                ui.checkbox(
+                    &mut settings.show_chord_analysis,
+                    locale.text("control-show-chord-analysis"),
+                );
+                if show_chord_analysis != settings.show_chord_analysis {
+                    Control::reload_tab(state, theme);
+                }
+                let use_nashville_numbers = settings.use_nashville_numbers;
+                ui.checkbox(
+                    &mut settings.use_nashville_numbers,
+                    locale.text("control-use-nashville-numbers"),
+                );
+                if use_nashville_numbers != settings.use_nashville_numbers {
+                    Control::reload_tab(state, theme);
+                }
                 ui.separator();
                 let show_note_pitch = settings.show_note_pitch;
                 ui.checkbox(
                     &mut settings.show_note_pitch,
-                    "Show Note Pitch",
+                    locale.text("control-show-note-pitch"),
                 );
                 if show_note_pitch != settings.show_note_pitch {
                     Control::reload_tab(state, theme);
                 }
                 let show_note_syllable = settings.show_note_syllable;
-                ui.checkbox(&mut settings.show_note_syllable, "Show Note Syllable");
+                ui.checkbox(&mut settings.show_note_syllable, locale.text("control-show-note-syllable"));
                 if show_note_syllable != settings.show_note_syllable {
                     Control::reload_tab(state, theme);
                 }
                 let show_syllable_as_num = settings.show_syllable_as_num;
                 ui.checkbox(
                     &mut settings.show_syllable_as_num,
-                    "Show Syllable as Numbers",
+                    locale.text("control-show-syllable-as-numbers"),
                 );
                 if show_syllable_as_num != settings.show_syllable_as_num {
                     Control::reload_tab(state, theme);
                 }
                 let hide_bar_number = settings.hide_bar_number;
-                ui.checkbox(&mut settings.hide_bar_number, "Hide Bar Number");
+                ui.checkbox(&mut settings.hide_bar_number, locale.text("control-hide-bar-number"));
                 if hide_bar_number != settings.hide_bar_number {
                     Control::reload_tab(state, theme);
                 }
                 let hide_indicators = settings.hide_indicators;
-                ui.checkbox(&mut settings.hide_indicators, "Hide Indicators");
+                ui.checkbox(&mut settings.hide_indicators, locale.text("control-hide-indicators"));
                 if hide_indicators != settings.hide_indicators {
                     Control::reload_tab(state, theme);
                 }
                 let always_show_fret = settings.always_show_fret;
-                ui.checkbox(&mut settings.always_show_fret, "Always Show Fret");
+                ui.checkbox(&mut settings.always_show_fret, locale.text("control-always-show-fret"));
                 if always_show_fret != settings.always_show_fret {
                     Control::reload_tab(state, theme);
                 }
                 ui.separator();
                 let mut hide_guitar_view = settings.hide_guitar_view;
-                ui.checkbox(&mut hide_guitar_view, "Hide Guitar View");
+                ui.checkbox(&mut hide_guitar_view, locale.text("control-hide-guitar-view"));
                 if settings.hide_guitar_view != hide_guitar_view {
                     settings.hide_guitar_view = hide_guitar_view;
                     Control::reload_tab(state, theme);
                 }
                 let mut hide_chords_view = settings.hide_chords_view;
-                ui.checkbox(&mut hide_chords_view, "Hide Chords View");
+                ui.checkbox(&mut hide_chords_view, locale.text("control-hide-chords-view"));
                 if settings.hide_chords_view != hide_chords_view {
                     settings.hide_chords_view = hide_chords_view;
                     Control::reload_tab(state, theme);
                 }
                 let mut hide_mini_map = settings.hide_mini_map;
-                ui.checkbox(&mut hide_mini_map, "Hide Mini Map");
+                ui.checkbox(&mut hide_mini_map, locale.text("control-hide-mini-map"));
                 if settings.hide_mini_map != hide_mini_map {
                     settings.hide_mini_map = hide_mini_map;
                     Control::reload_tab(state, theme);
@@ -254,65 +284,66 @@ impl EguiControlPanel {
     }
     pub fn layout_ui(
         ui: &mut Ui,
+        locale: &NotationLocale,
         state: &mut NotationState,
         settings: &mut NotationSettings,
         theme: &mut NotationTheme,
     ) {
-        CollapsingHeader::new("Layout Options")
+        CollapsingHeader::new(locale.text("control-layout-options"))
             .default_open(true)
             .show(ui, |ui| {
                 let mut video_recording_mode = settings.layout.video_recording_mode;
-                ui.checkbox(&mut video_recording_mode, "Video Recording Mode");
+                ui.checkbox(&mut video_recording_mode, locale.text("control-video-recording-mode"));
                 if settings.layout.video_recording_mode != video_recording_mode {
                     settings.layout.video_recording_mode = video_recording_mode;
                     Control::reload_tab(state, theme);
                 }
                 ui.separator();
                 let mode_text = if settings.layout.mode == LayoutMode::Grid {
-                    "Switch to Line Mode"
+                    locale.text("control-switch-to-line-mode")
                 } else {
-                    "Switch to Grid Mode"
+                    locale.text("control-switch-to-grid-mode")
                 };
                 if ui.button(mode_text).clicked() {
                     Control::toggle_layout_mode(state, settings, theme);
                 }
                 if !settings.layout.video_recording_mode && settings.layout.mode == LayoutMode::Grid
                 {
-                    ui.label("Switch Align Mode");
+                    ui.label(locale.text("control-switch-align-mode"));
                     ui.horizontal(|ui| {
                         if settings.layout.grid_align_mode != GridAlignMode::Top {
-                            if ui.button("Top").clicked() {
+                            if ui.button(locale.text("control-align-top")).clicked() {
                                 settings.layout.grid_align_mode = GridAlignMode::Top;
                                 Control::reload_tab(state, theme);
                             }
                         } else {
-                            ui.label("Top");
+                            ui.label(locale.text("control-align-top"));
                         }
                         if settings.layout.grid_align_mode != GridAlignMode::ForceTop {
-                            if ui.button("Force Top").clicked() {
+                            if ui.button(locale.text("control-align-force-top")).clicked() {
                                 settings.layout.grid_align_mode = GridAlignMode::ForceTop;
                                 Control::reload_tab(state, theme);
                             }
                         } else {
-                            ui.label("Force Top");
+                            ui.label(locale.text("control-align-force-top"));
                         }
                     });
                     ui.horizontal(|ui| {
                         if settings.layout.grid_align_mode != GridAlignMode::Center {
-                            if ui.button("Center").clicked() {
+                            if ui.button(locale.text("control-align-center")).clicked() {
                                 settings.layout.grid_align_mode = GridAlignMode::Center;
                                 Control::reload_tab(state, theme);
                             }
                         } else {
-                            ui.label("Center");
+                            ui.label(locale.text("control-align-center"));
                         }
                         if settings.layout.grid_align_mode != GridAlignMode::ForceCenter {
-                            if ui.button("Force Center").clicked() {
+                            if ui.button(locale.text("control-align-force-center")).clicked() {
                                 settings.layout.grid_align_mode = GridAlignMode::ForceCenter;
                                 Control::reload_tab(state, theme);
                             }
                         } else {
-                            ui.label("Force Center");
+                            ui.label(locale.text("control-align-force-center"));
                         }
                     });
                 }
@@ -320,6 +351,7 @@ impl EguiControlPanel {
     }
     pub fn tab_ui(
         ui: &mut Ui,
+        locale: &NotationLocale,
         args: &mut NotationArgs,
         app_state: &AppState,
         state: &mut NotationState,
@@ -328,28 +360,38 @@ impl EguiControlPanel {
     ) {
         if theme._bypass_systems {
             if state.tab_error.is_some() {
-                ui.label("Load Tab Failed");
+                ui.label(locale.text("control-load-tab-failed"));
                 ui.separator();
                 ui.label(format!("{:?}", state.tab_error.as_ref().unwrap()));
             } else {
-                ui.label("Loading Tab ...");
+                ui.label(locale.text("control-loading-tab"));
             }
             ui.separator();
         }
         ui.horizontal(|ui| {
-            if ui.button("Reload Tab").clicked() {
+            if ui.button(locale.text("control-reload-tab")).clicked() {
                 state.bars_range = None;
                 Control::reload_tab(state, theme);
             }
             ui.separator();
-            if ui.button("Help").clicked() {
+            if ui.button(locale.text("control-help")).clicked() {
                 state.show_kb = true;
             }
+            if ui.button(locale.text("control-library")).clicked() {
+                state.show_library = true;
+            }
+            if ui.button(locale.text("control-karaoke")).clicked() {
+                state.show_karaoke = true;
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            if ui.button(locale.text("control-export-lyrics")).clicked() {
+                Control::export_lyrics(state);
+            }
             egui::warn_if_debug_build(ui);
             #[cfg(not(target_arch = "wasm32"))]
             ui.with_layout(egui::Layout::right_to_left(egui::Align::BOTTOM), |ui| {
                 ui.separator();
-                if ui.button("Open Tab").clicked() {
+                if ui.button(locale.text("control-open-tab")).clicked() {
                     if let Some(path) = rfd::FileDialog::new()
                         .add_filter("Fun Notation", &TabAsset::EXTENSIONS)
                         .pick_file()
@@ -371,7 +413,10 @@ impl EguiControlPanel {
         if args.tab.len() > 1 {
             let width = Self::calc_width(app_state.window_width);
             egui::ComboBox::from_id_source("tab")
-                .selected_text(state.tab_path.clone())
+                .selected_text(match &state.tab {
+                    Some(tab) if !tab.info.title.is_empty() => tab.info.to_string(),
+                    _ => state.tab_path.clone(),
+                })
                 .width(width - 24.0)
                 .show_ui(ui, |ui| {
                     for path in args.tab.iter() {
@@ -384,18 +429,19 @@ impl EguiControlPanel {
     }
     pub fn guitar_tab_display_ui(
         ui: &mut Ui,
+        locale: &NotationLocale,
         app_state: &AppState,
         state: &mut NotationState,
         theme: &mut NotationTheme,
         window_resized_evts: &mut EventWriter<WindowResizedEvent>,
     ) {
-        CollapsingHeader::new("Guitar Tab")
+        CollapsingHeader::new(locale.text("control-guitar-tab"))
             .default_open(true)
             .show(ui, |ui| {
                 let last_string_space = theme.sizes.strings.string_space;
                 ui.add(
                     Slider::new(&mut theme.sizes.strings.string_space, 6.0..=32.0)
-                        .text("String Space"),
+                        .text(locale.text("control-string-space")),
                 );
                 if float_ne!(
                     theme.sizes.strings.string_space,
@@ -407,7 +453,7 @@ impl EguiControlPanel {
                 let last_note_height = theme.sizes.strings.note_height;
                 ui.add(
                     Slider::new(&mut theme.sizes.strings.note_height, 6.0..=32.0)
-                        .text("Note Height"),
+                        .text(locale.text("control-note-height")),
                 );
                 if float_ne!(
                     theme.sizes.strings.note_height,
@@ -420,7 +466,7 @@ impl EguiControlPanel {
                 let last_word_font_size = theme.texts.strings.fret_font_size;
                 ui.add(
                     Slider::new(&mut theme.texts.strings.fret_font_size, 6.0..=64.0)
-                        .text("Fret Font Size"),
+                        .text(locale.text("control-fret-font-size")),
                 );
                 if float_ne!(
                     theme.texts.strings.fret_font_size,
@@ -432,7 +478,7 @@ impl EguiControlPanel {
                 let last_word_text_x = theme.texts.strings.text_x;
                 ui.add(
                     Slider::new(&mut theme.texts.strings.text_x, -24.0..=24.0)
-                        .text("Fret Offset X"),
+                        .text(locale.text("control-fret-offset-x")),
                 );
                 if float_ne!(theme.texts.strings.text_x, last_word_text_x, abs <= 0.5) {
                     changed = true;
@@ -440,7 +486,7 @@ impl EguiControlPanel {
                 let last_word_text_y = theme.texts.strings.text_y;
                 ui.add(
                     Slider::new(&mut theme.texts.strings.text_y, -24.0..=24.0)
-                        .text("Fret Offset Y"),
+                        .text(locale.text("control-fret-offset-y")),
                 );
                 if float_ne!(theme.texts.strings.text_y, last_word_text_y, abs <= 0.5) {
                     changed = true;
@@ -452,18 +498,19 @@ impl EguiControlPanel {
     }
     pub fn lyrics_display_ui(
         ui: &mut Ui,
+        locale: &NotationLocale,
         app_state: &AppState,
         state: &mut NotationState,
         theme: &mut NotationTheme,
         window_resized_evts: &mut EventWriter<WindowResizedEvent>,
     ) {
-        CollapsingHeader::new("Lyrics")
+        CollapsingHeader::new(locale.text("control-lyrics"))
             .default_open(true)
             .show(ui, |ui| {
                 let last_line_height = theme.sizes.lyrics.line_height.idle;
                 ui.add(
                     Slider::new(&mut theme.sizes.lyrics.line_height.idle, 2.0..=64.0)
-                        .text("Line Height (Idle)"),
+                        .text(locale.text("control-line-height-idle")),
                 );
                 if float_ne!(
                     theme.sizes.lyrics.line_height.idle,
@@ -475,7 +522,7 @@ impl EguiControlPanel {
                 let last_line_height = theme.sizes.lyrics.line_height.current;
                 ui.add(
                     Slider::new(&mut theme.sizes.lyrics.line_height.current, 2.0..=64.0)
-                        .text("Line Height (Current)"),
+                        .text(locale.text("control-line-height-current")),
                 );
                 if float_ne!(
                     theme.sizes.lyrics.line_height.current,
@@ -487,7 +534,7 @@ impl EguiControlPanel {
                 let last_line_height = theme.sizes.lyrics.line_height.played;
                 ui.add(
                     Slider::new(&mut theme.sizes.lyrics.line_height.played, 2.0..=64.0)
-                        .text("Line Height (Played)"),
+                        .text(locale.text("control-line-height-played")),
                 );
                 if float_ne!(
                     theme.sizes.lyrics.line_height.played,
@@ -497,7 +544,7 @@ impl EguiControlPanel {
                     window_resized_evts.send(WindowResizedEvent::new(&app_state));
                 }
                 let last_word_gap = theme.sizes.lyrics.word_gap;
-                ui.add(Slider::new(&mut theme.sizes.lyrics.word_gap, 0.0..=8.0).text("Word Gap"));
+                ui.add(Slider::new(&mut theme.sizes.lyrics.word_gap, 0.0..=8.0).text(locale.text("control-word-gap")));
                 if float_ne!(theme.sizes.lyrics.word_gap, last_word_gap, abs <= 0.5) {
                     window_resized_evts.send(WindowResizedEvent::new(&app_state));
                 }
@@ -505,7 +552,7 @@ impl EguiControlPanel {
                 let last_word_font_size = theme.texts.lyrics.word_font_size;
                 ui.add(
                     Slider::new(&mut theme.texts.lyrics.word_font_size, 6.0..=64.0)
-                        .text("Word Font Size"),
+                        .text(locale.text("control-word-font-size")),
                 );
                 if float_ne!(
                     theme.texts.lyrics.word_font_size,
@@ -516,14 +563,14 @@ impl EguiControlPanel {
                 }
                 let last_word_text_x = theme.texts.lyrics.text_x;
                 ui.add(
-                    Slider::new(&mut theme.texts.lyrics.text_x, -24.0..=24.0).text("Word Offset X"),
+                    Slider::new(&mut theme.texts.lyrics.text_x, -24.0..=24.0).text(locale.text("control-word-offset-x")),
                 );
                 if float_ne!(theme.texts.lyrics.text_x, last_word_text_x, abs <= 0.5) {
                     changed = true;
                 }
                 let last_word_text_y = theme.texts.lyrics.text_y;
                 ui.add(
-                    Slider::new(&mut theme.texts.lyrics.text_y, -24.0..=24.0).text("Word Offset Y"),
+                    Slider::new(&mut theme.texts.lyrics.text_y, -24.0..=24.0).text(locale.text("control-word-offset-y")),
                 );
                 if float_ne!(theme.texts.lyrics.text_y, last_word_text_y, abs <= 0.5) {
                     changed = true;
@@ -535,18 +582,19 @@ impl EguiControlPanel {
     }
     pub fn melody_display_ui(
         ui: &mut Ui,
+        locale: &NotationLocale,
         app_state: &mut AppState,
         state: &mut NotationState,
         theme: &mut NotationTheme,
         window_resized_evts: &mut EventWriter<WindowResizedEvent>,
     ) {
-        CollapsingHeader::new("Melody")
+        CollapsingHeader::new(locale.text("control-melody"))
             .default_open(true)
             .show(ui, |ui| {
                 let last_note_height = theme.sizes.melody.note_height;
                 ui.add(
                     Slider::new(&mut theme.sizes.melody.note_height, 1.0..=32.0)
-                        .text("Note Height"),
+                        .text(locale.text("control-note-height")),
                 );
                 if float_ne!(theme.sizes.melody.note_height, last_note_height, abs <= 0.5) {
                     window_resized_evts.send(WindowResizedEvent::new(&app_state));
@@ -555,7 +603,7 @@ impl EguiControlPanel {
                 let last_syllable_font_size = theme.texts.melody.syllable_font_size;
                 ui.add(
                     Slider::new(&mut theme.texts.melody.syllable_font_size, 6.0..=64.0)
-                        .text("Syllable Font Size"),
+                        .text(locale.text("control-syllable-font-size")),
                 );
                 if float_ne!(
                     theme.texts.melody.syllable_font_size,
@@ -567,7 +615,7 @@ impl EguiControlPanel {
                 let last_syllable_text_x = theme.texts.melody.text_x;
                 ui.add(
                     Slider::new(&mut theme.texts.melody.text_x, -24.0..=24.0)
-                        .text("Syllable Offset X"),
+                        .text(locale.text("control-syllable-offset-x")),
                 );
                 if float_ne!(theme.texts.melody.text_x, last_syllable_text_x, abs <= 0.5) {
                     changed = true;
@@ -575,7 +623,7 @@ impl EguiControlPanel {
                 let last_syllable_text_y = theme.texts.melody.text_y;
                 ui.add(
                     Slider::new(&mut theme.texts.melody.text_y, -24.0..=24.0)
-                        .text("Syllable Offset Y"),
+                        .text(locale.text("control-syllable-offset-y")),
                 );
                 if float_ne!(theme.texts.melody.text_y, last_syllable_text_y, abs <= 0.5) {
                     changed = true;
@@ -585,14 +633,20 @@ impl EguiControlPanel {
                 }
             });
     }
-    pub fn window_size_ui(ui: &mut Ui, window_query: &mut Query<&mut Window, With<PrimaryWindow>>) {
+    pub fn window_size_ui(
+        ui: &mut Ui,
+        locale: &NotationLocale,
+        window_query: &mut Query<&mut Window, With<PrimaryWindow>>,
+    ) {
         let Ok(mut window) = window_query.get_single_mut() else {
             return;
         };
-        CollapsingHeader::new(format!(
-            "Window: {} x {}",
-            window.width() as i32,
-            window.height() as i32
+        CollapsingHeader::new(locale.text_args(
+            "control-window-size",
+            &[
+                ("width", (window.width() as i32).into()),
+                ("height", (window.height() as i32).into()),
+            ],
         ))
         .default_open(true)
         .show(ui, |ui| {
@@ -624,20 +678,22 @@ impl EguiControlPanel {
     }
     pub fn presets_ui(
         ui: &mut Ui,
+        locale: &NotationLocale,
         state: &mut NotationState,
         settings: &mut NotationSettings,
         theme: &mut NotationTheme,
         window_query: &mut Query<&mut Window, With<PrimaryWindow>>,
         window_resized_evts: &mut EventWriter<WindowResizedEvent>,
     ) {
-        CollapsingHeader::new(format!(
-            "Preset: {}",
-            state.preset.clone().unwrap_or("".to_string())
+        CollapsingHeader::new(locale.text_args(
+            "control-preset",
+            &[("preset", state.preset.clone().unwrap_or("".to_string()).into())],
         ))
         .default_open(true)
         .show(ui, |ui| {
             for preset in Control::ALL_PRESETS.iter() {
-                if ui.button(*preset).clicked() {
+                let preset_id = format!("preset-{}", preset.replace('_', "-"));
+                if ui.button(locale.text(&preset_id)).clicked() {
                     Control::set_preset(state, settings, theme, window_query, window_resized_evts, *preset);
                 }
             }
@@ -651,6 +707,7 @@ impl EguiControlPanel {
         mut state: ResMut<NotationState>,
         mut settings: ResMut<NotationSettings>,
         mut theme: ResMut<NotationTheme>,
+        locale: Res<NotationLocale>,
         #[cfg(feature = "midi")]
         mut midi_settings: ResMut<MidiSettings>,
         #[cfg(feature = "midi")]
@@ -676,12 +733,13 @@ impl EguiControlPanel {
                     }
                     ui.separator();
                      */
-                    Self::tab_ui(ui, &mut args, &app_state, &mut state, &mut settings, &mut theme);
+                    Self::tab_ui(ui, &locale, &mut args, &app_state, &mut state, &mut settings, &mut theme);
                     ui.separator();
                     #[cfg(feature = "midi")]
                     {
                         Self::play_control_ui(
                             ui,
+                            &locale,
                             &mut state,
                             &mut theme,
                             &mut settings,
@@ -695,17 +753,19 @@ impl EguiControlPanel {
                             #[cfg(feature = "midi")]
                             Self::midi_settings_ui(
                                 ui,
+                                &locale,
                                 &mut state,
                                 &mut theme,
                                 &mut midi_settings,
                                 &mut midi_state,
                                 &mut play_control_evts,
                             );
-                            Self::display_ui(ui, &mut state, &mut settings, &mut theme);
+                            Self::display_ui(ui, &locale, &mut state, &mut settings, &mut theme);
                             ui.separator();
-                            Self::layout_ui(ui, &mut state, &mut settings, &mut theme);
+                            Self::layout_ui(ui, &locale, &mut state, &mut settings, &mut theme);
                             Self::overrides_ui(
                                 ui,
+                                &locale,
                                 &app_state,
                                 &state,
                                 &mut settings,
@@ -717,10 +777,11 @@ impl EguiControlPanel {
                             );
                             ui.separator();
                             #[cfg(not(target_arch = "wasm32"))]
-                            Self::window_size_ui(ui, &mut window_query);
-                            ui.label("Override Theme");
+                            Self::window_size_ui(ui, &locale, &mut window_query);
+                            ui.label(locale.text("control-override-theme"));
                             Self::guitar_tab_display_ui(
                                 ui,
+                                &locale,
                                 &app_state,
                                 &mut state,
                                 &mut theme,
@@ -728,6 +789,7 @@ impl EguiControlPanel {
                             );
                             Self::lyrics_display_ui(
                                 ui,
+                                &locale,
                                 &app_state,
                                 &mut state,
                                 &mut theme,
@@ -735,18 +797,19 @@ impl EguiControlPanel {
                             );
                             Self::melody_display_ui(
                                 ui,
+                                &locale,
                                 &mut app_state,
                                 &mut state,
                                 &mut theme,
                                 &mut window_resized_evts,
                             );
                             ui.separator();
-                            if ui.button("Reset Theme").clicked() {
+                            if ui.button(locale.text("control-reset-theme")).clicked() {
                                 *theme = NotationTheme::default();
                                 Control::reload_tab(&mut state, &mut theme);
                             }
                             ui.separator();
-                            Self::presets_ui(ui, &mut state, &mut settings, &mut theme, &mut window_query, &mut window_resized_evts);
+                            Self::presets_ui(ui, &locale, &mut state, &mut settings, &mut theme, &mut window_query, &mut window_resized_evts);
                         });
                     });
                 });
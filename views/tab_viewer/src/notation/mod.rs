@@ -6,5 +6,8 @@ pub mod layout;
 pub mod tab_viewer;
 pub mod control;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub mod tab_watcher;
+
 #[cfg(feature = "with_egui")]
 pub mod egui_control_panel;
\ No newline at end of file
@@ -11,8 +11,12 @@ pub struct NotationState {
     pub bars_range: Option<(usize, usize)>,
     pub show_control: bool,
     pub show_kb: bool,
+    pub show_library: bool,
+    pub show_karaoke: bool,
     pub preset: Option<String>,
     pub tab_error: Option<TabError>,
+    pub reload_error: Option<TabError>,
+    pub keep_position: bool,
     pub debug_str: Option<String>,
     pub _despawn_delay_seconds: f32,
     pub _load_tab_delay_seconds: f32,
@@ -32,8 +36,13 @@ impl NotationState {
             #[cfg(not(debug_assertions))]
             show_kb: true,
 
+            show_library: false,
+            show_karaoke: false,
+
             preset: None,
             tab_error: None,
+            reload_error: None,
+            keep_position: false,
             debug_str: None,
             _despawn_delay_seconds: 0.0,
             _load_tab_delay_seconds: 0.0,
@@ -43,6 +52,8 @@ impl NotationState {
         theme._bypass_systems = true;
         self.tab_path = tab_path;
         self.bars_range = None;
+        self.reload_error = None;
+        self.keep_position = false;
         self.reload_tab()
     }
     pub fn reload_tab(&mut self) {
@@ -51,7 +51,7 @@ impl Plugin for TabViewerPlugin {
             TabViewer::do_root_layout,
             TabViewer::on_add_tab,
             GuitarView::on_layout_changed,
-            GuitarView::update_hand_shape6
+            GuitarView::update_hand_shape
                     .in_set(GuitarViewLabel::UpdateHandShapes),
             GuitarView::update_string_state
                     .in_set(GuitarViewLabel::UpdateStringStates)
@@ -115,6 +115,7 @@ impl TabViewer {
         assets: Res<NotationAssets>,
         mut theme: ResMut<NotationTheme>,
         mut settings: ResMut<NotationSettings>,
+        mut state: ResMut<NotationState>,
         mut switch_tab_evts: EventWriter<SwitchTabEvent>,
     ) {
         let mut tab = None;
@@ -131,8 +132,13 @@ impl TabViewer {
                 &settings,
                 &tab,
             );
-            settings.layout.focusing_bar_ordinal = usize::MAX;
-            switch_tab_evts.send(SwitchTabEvent::new(tab));
+            if state.keep_position {
+                state.keep_position = false;
+                switch_tab_evts.send(SwitchTabEvent::reload(tab));
+            } else {
+                settings.layout.focusing_bar_ordinal = usize::MAX;
+                switch_tab_evts.send(SwitchTabEvent::new(tab));
+            }
         }
     }
 }
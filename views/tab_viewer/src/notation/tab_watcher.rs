@@ -0,0 +1,167 @@
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use edger_bevy::bevy::asset::io::file::FileAssetReader;
+use edger_bevy::bevy::asset::AssetPath;
+use edger_bevy::bevy_prelude::*;
+use notation_model::prelude::Tab;
+
+use crate::prelude::{NotationSettings, NotationState, NotationTheme, TabAsset, TabError};
+
+use super::control::Control;
+
+#[cfg(feature = "with_egui")]
+use edger_bevy::bevy_egui::egui;
+#[cfg(feature = "with_egui")]
+use edger_bevy::bevy_egui::EguiContexts;
+#[cfg(feature = "with_egui")]
+use crate::prelude::NotationLocale;
+
+/// Watch the file of the current tab, reload it when changed, keeping the play position.
+#[derive(Resource, Debug, Default)]
+pub struct TabWatcher {
+    pub tab_path: String,
+    pub modified: Option<SystemTime>,
+    pub check_seconds: f32,
+    pub reloading: bool,
+    /// Put back into the assets when the reloaded file is broken, so that the tab can
+    /// still be laid out again, e.g. after the window is resized.
+    pub last_good: Option<TabAsset>,
+}
+
+impl TabWatcher {
+    pub const CHECK_INTERVAL_SECONDS: f32 = 0.5;
+
+    /// Tab paths are asset paths, relative to the assets folder unless absolute.
+    pub fn get_file_path(tab_path: &str) -> PathBuf {
+        let path = PathBuf::from(tab_path);
+        if path.is_absolute() {
+            path
+        } else {
+            FileAssetReader::get_base_path().join("assets").join(path)
+        }
+    }
+    pub fn get_modified(tab_path: &str) -> Option<SystemTime> {
+        std::fs::metadata(Self::get_file_path(tab_path))
+            .and_then(|x| x.modified())
+            .ok()
+    }
+    pub fn check_tab_file(
+        time: Res<Time>,
+        asset_server: Res<AssetServer>,
+        state: Res<NotationState>,
+        mut watcher: ResMut<TabWatcher>,
+    ) {
+        if state.tab_path.is_empty() {
+            return;
+        }
+        if watcher.tab_path != state.tab_path {
+            watcher.tab_path = state.tab_path.clone();
+            watcher.modified = Self::get_modified(&state.tab_path);
+            watcher.check_seconds = Self::CHECK_INTERVAL_SECONDS;
+            watcher.reloading = false;
+            watcher.last_good = None;
+            return;
+        }
+        watcher.check_seconds -= time.delta_secs();
+        if watcher.check_seconds > 0.0 {
+            return;
+        }
+        watcher.check_seconds = Self::CHECK_INTERVAL_SECONDS;
+        let modified = Self::get_modified(&state.tab_path);
+        if modified.is_some() && modified != watcher.modified {
+            println!("TabWatcher: tab file changed: {}", state.tab_path);
+            watcher.modified = modified;
+            watcher.reloading = true;
+            asset_server.reload(AssetPath::from(state.tab_path.clone()));
+        }
+    }
+    pub fn on_tab_asset_modified(
+        mut evts: EventReader<AssetEvent<TabAsset>>,
+        asset_server: Res<AssetServer>,
+        mut assets: ResMut<Assets<TabAsset>>,
+        settings: Res<NotationSettings>,
+        mut watcher: ResMut<TabWatcher>,
+        mut state: ResMut<NotationState>,
+        mut theme: ResMut<NotationTheme>,
+    ) {
+        for evt in evts.read() {
+            let (id, modified) = match evt {
+                AssetEvent::LoadedWithDependencies { id } => (id, false),
+                AssetEvent::Modified { id } if watcher.reloading => (id, true),
+                _ => continue,
+            };
+            let is_current = asset_server
+                .get_path(*id)
+                .map(|x| x.path().to_string_lossy() == state.tab_path.as_str())
+                .unwrap_or(false);
+            if !is_current {
+                continue;
+            }
+            let asset = match assets.get(*id) {
+                Some(asset) => asset.clone(),
+                None => continue,
+            };
+            if !modified {
+                if asset.tab.is_ok() {
+                    watcher.last_good = Some(asset);
+                }
+                continue;
+            }
+            watcher.reloading = false;
+            let checked = asset.tab.clone().and_then(|tab| {
+                Tab::try_parse_arc(tab, settings.add_ready_section, state.bars_range)
+                    .map_err(TabError::ParseFailed)
+            });
+            match checked {
+                Ok(_) => {
+                    println!("TabWatcher: reloading tab: {}", state.tab_path);
+                    watcher.last_good = Some(asset);
+                    state.reload_error = None;
+                    state.keep_position = true;
+                    Control::reload_tab(&mut state, &mut theme);
+                }
+                Err(err) => {
+                    println!("TabWatcher: reload tab failed: {:?}", err);
+                    state.reload_error = Some(err);
+                    if let Some(last_good) = watcher.last_good.clone() {
+                        assets.insert(*id, last_good);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "with_egui")]
+impl TabWatcher {
+    /// Keep the last good tab on screen, with the error shown above it.
+    pub fn reload_error_ui(
+        mut egui_ctx: EguiContexts,
+        locale: Res<NotationLocale>,
+        mut state: ResMut<NotationState>,
+    ) {
+        let mut dismissed = false;
+        if let Some(err) = state.reload_error.as_ref() {
+            egui::Window::new(locale.text("reload-failed"))
+                .id(egui::Id::new("reload_error"))
+                .anchor(egui::Align2::CENTER_BOTTOM, egui::Vec2::new(0.0, -16.0))
+                .collapsible(false)
+                .resizable(false)
+                .show(egui_ctx.ctx_mut(), |ui| {
+                    ui.colored_label(egui::Color32::LIGHT_RED, format!("{}", err));
+                    ui.label(format!("{:?}", err));
+                    ui.separator();
+                    ui.horizontal(|ui| {
+                        ui.label(locale.text("reload-showing-last-version"));
+                        if ui.button(locale.text("reload-dismiss")).clicked() {
+                            dismissed = true;
+                        }
+                    });
+                });
+        }
+        if dismissed {
+            state.reload_error = None;
+        }
+    }
+}
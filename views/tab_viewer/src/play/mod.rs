@@ -3,3 +3,4 @@ pub mod play_button;
 pub mod play_panel;
 pub mod play_plugin;
 pub mod pos_indicator;
+pub mod speed_trainer_progress;
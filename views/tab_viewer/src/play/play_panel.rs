@@ -7,13 +7,14 @@ use edger_bevy::prelude::{
     LayoutConstraint, LayoutQuery, LayoutSize, View, ViewBundle, ViewQuery,
 };
 use notation_model::prelude::Tab;
-use notation_midi::prelude::{PlayControlEvent, PlayState};
+use notation_midi::prelude::{PlayControlEvent, PlayState, SpeedTrainer};
 
 use crate::prelude::{NotationState, NotationAssets, NotationSettings, NotationTheme};
 use crate::prelude::NotationLayout;
 
 use super::play_button::{PlayButton, PlayButtonShape};
 use super::play_plugin::PlayPanelDoLayoutEvent;
+use super::speed_trainer_progress::SpeedTrainerProgress;
 
 
 #[derive(Clone, Debug, Component)]
@@ -94,6 +95,7 @@ impl PlayPanel {
             theme.z.play_panel,
             theme.colors.ui.control_background,
         );
+        SpeedTrainerProgress::spawn(commands, theme, panel_entity, SpeedTrainer::default());
         for i in 0..=5 {
             PlayButton::spawn(
                 commands,
@@ -136,6 +138,7 @@ impl PlayPanel {
         theme: Res<NotationTheme>,
         mut evts: EventReader<PlayControlEvent>,
         mut shape_query: Query<(Entity, &mut PlayButtonShape)>,
+        mut progress_query: Query<(Entity, &mut SpeedTrainerProgress)>,
     ) {
         if theme._bypass_systems {
             return;
@@ -176,6 +179,14 @@ impl PlayPanel {
                         *end_bar_ordinal,
                     )
                 }
+                PlayControlEvent::OnSpeedTrainer(speed_trainer) => {
+                    SpeedTrainerProgress::on_speed_trainer(
+                        &mut commands,
+                        &theme,
+                        &mut progress_query,
+                        speed_trainer,
+                    );
+                }
                 PlayControlEvent::OnSpeedFactor(_) => {}
             }
         }
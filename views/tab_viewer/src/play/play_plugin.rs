@@ -25,6 +25,7 @@ use super::bar_indicator::BarIndicatorData;
 use super::play_button::PlayButton;
 use super::play_panel::PlayPanel;
 use super::pos_indicator::PosIndicatorData;
+use super::speed_trainer_progress::SpeedTrainerProgress;
 
 pub type PlayPanelDoLayoutEvent = DoLayoutEvent<NotationLayout<'static>, PlayPanel>;
 
@@ -40,6 +41,7 @@ impl Plugin for PlayPlugin {
             PlayPanel::do_layout,
             PlayPanel::on_play_control_evt,
             PlayButton::on_layout_changed,
+            SpeedTrainerProgress::on_layout_changed,
             on_bar_playing_changed,
             on_tab_play_state_changed,
             on_play_control_evt,
@@ -395,6 +397,9 @@ fn on_play_control_evt(
                 PlayControlEvent::OnShouldLoop(should_loop) => {
                     tab_state.set_should_loop(*should_loop);
                 }
+                PlayControlEvent::OnSpeedTrainer(speed_trainer) => {
+                    tab_state.set_speed_trainer(*speed_trainer);
+                }
             }
         }
     }
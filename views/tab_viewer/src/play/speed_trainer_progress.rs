@@ -0,0 +1,83 @@
+use std::fmt::Display;
+
+use edger_bevy::bevy_prelude::*;
+use edger_bevy::bevy_prototype_lyon::prelude::*;
+use edger_bevy::prelude::{offscreen, FillRectangle, LayoutChangedWithChildrenQuery, ShapeOp};
+use notation_midi::prelude::SpeedTrainer;
+
+use crate::prelude::NotationTheme;
+
+use super::play_panel::PlayPanel;
+
+#[derive(Clone, Debug, Component)]
+pub struct SpeedTrainerProgress {
+    pub speed_trainer: SpeedTrainer,
+    pub panel_width: f32,
+}
+impl Display for SpeedTrainerProgress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl ShapeOp<NotationTheme, FillRectangle> for SpeedTrainerProgress {
+    fn get_shape(&self, theme: &NotationTheme) -> FillRectangle {
+        let width = self.panel_width * self.speed_trainer.progress();
+        let offset = if !self.speed_trainer.enabled || width <= 0.0 {
+            offscreen::offset()
+        } else {
+            Vec3::new(0.0, 0.0, theme.z.speed_trainer_progress)
+        };
+        FillRectangle {
+            width,
+            height: theme.sizes.tab_control.speed_trainer_progress_height,
+            origin: shapes::RectangleOrigin::TopLeft,
+            color: theme.colors.ui.of_button(!self.speed_trainer.finished),
+            offset,
+        }
+    }
+}
+
+impl SpeedTrainerProgress {
+    pub fn spawn(
+        commands: &mut Commands,
+        theme: &NotationTheme,
+        entity: Entity,
+        speed_trainer: SpeedTrainer,
+    ) -> Entity {
+        let data = SpeedTrainerProgress {
+            speed_trainer,
+            panel_width: 0.0,
+        };
+        data.create(commands, theme, entity)
+    }
+    pub fn on_layout_changed(
+        mut commands: Commands,
+        theme: Res<NotationTheme>,
+        query: LayoutChangedWithChildrenQuery<PlayPanel>,
+        mut progress_query: Query<(Entity, &mut SpeedTrainerProgress)>,
+    ) {
+        if theme._bypass_systems {
+            return;
+        }
+        for (_entity, _view, layout, children) in query.iter() {
+            for child in children.iter() {
+                if let Ok((entity, mut data)) = progress_query.get_mut(*child) {
+                    data.panel_width = layout.size.width;
+                    data.update(&mut commands, &theme, entity);
+                }
+            }
+        }
+    }
+    pub fn on_speed_trainer(
+        commands: &mut Commands,
+        theme: &NotationTheme,
+        progress_query: &mut Query<(Entity, &mut SpeedTrainerProgress)>,
+        speed_trainer: &SpeedTrainer,
+    ) {
+        for (entity, mut data) in progress_query.iter_mut() {
+            data.speed_trainer = *speed_trainer;
+            data.update(commands, theme, entity);
+        }
+    }
+}
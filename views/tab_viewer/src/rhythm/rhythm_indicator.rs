@@ -24,7 +24,7 @@ pub type RhythmIndicatorData = BarData<RhythmIndicatorValue>;
 impl RhythmIndicatorData {
     pub fn angle(&self) -> f32 {
         let bar_units = Units::from(self.value.signature);
-        -PI * 2.0 * (self.value.in_bar_pos.0 / bar_units.0)
+        -PI * 2.0 * (self.value.in_bar_pos.to_f32() / bar_units.to_f32())
     }
     pub fn shape(&self, theme: &NotationTheme) -> shapes::SvgPathShape {
         let width = self.value.bar_radius * theme.sizes.tab_control.rhythm_indicator_width_factor;
@@ -93,7 +93,7 @@ impl RhythmIndicatorData {
         let indicator_value = RhythmIndicatorValue {
             signature,
             bar_radius: 0.0,
-            in_bar_pos: Units(0.0),
+            in_bar_pos: Units::ZERO,
         };
         let indicator_data = RhythmIndicatorData {
             bar_props,
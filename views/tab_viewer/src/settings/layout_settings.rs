@@ -42,6 +42,10 @@ pub struct LayoutSettings {
     pub grid_align_mode: GridAlignMode,
     pub focus_bar_ease_ms: u64,
     pub focusing_bar_ordinal: usize,
+    /// The bars focused, new bars are spawned when the tab is reloaded, they are moved to
+    /// the focusing bar directly, so the view stays where it was.
+    #[serde(skip)]
+    pub focusing_bars_entity: Option<Entity>,
     pub video_recording_mode: bool,
     pub override_tab_width: Option<f32>,
     pub override_focus_offset_y: Option<f32>,
@@ -54,6 +58,7 @@ impl Default for LayoutSettings {
             grid_align_mode: GridAlignMode::default(),
             focus_bar_ease_ms: 250,
             focusing_bar_ordinal: usize::MAX,
+            focusing_bars_entity: None,
             video_recording_mode: false,
             override_tab_width: None,
             override_focus_offset_y: None,
@@ -272,25 +277,32 @@ impl LayoutSettings {
         )>,
         pos_data: &PosIndicatorData,
     ) {
-        if self.mode == LayoutMode::Grid
-            && self.focusing_bar_ordinal == pos_data.bar_props.bar_ordinal
-        {
-            return;
-        }
         if let Ok((bars_entity, mut bars_transform, bars, layout, grid_data)) =
             tab_bars_query.get_single_mut()
         {
+            let bars_changed = self.focusing_bars_entity != Some(bars_entity);
+            if self.mode == LayoutMode::Grid
+                && !bars_changed
+                && self.focusing_bar_ordinal == pos_data.bar_props.bar_ordinal
+            {
+                return;
+            }
             self.focusing_bar_ordinal = pos_data.bar_props.bar_ordinal;
+            self.focusing_bars_entity = Some(bars_entity);
             match self.mode {
                 LayoutMode::Grid => {
                     let y = self.calc_grid_focus_y(theme, bars, layout, grid_data, pos_data);
-                    self.ease_transform_xy(
-                        commands,
-                        bars_entity,
-                        &mut bars_transform,
-                        None,
-                        Some(-y),
-                    );
+                    if bars_changed {
+                        self.set_transform_xy(&mut bars_transform, None, Some(-y));
+                    } else {
+                        self.ease_transform_xy(
+                            commands,
+                            bars_entity,
+                            &mut bars_transform,
+                            None,
+                            Some(-y),
+                        );
+                    }
                 }
                 LayoutMode::Line => {
                     let (x, y) = self.calc_line_focus_xy(theme, bars, layout, grid_data, pos_data);
@@ -31,6 +31,10 @@ pub struct NotationSettings {
     pub hide_harmony_lane: bool,
     pub hide_lyrics_lane: bool,
     pub hide_melody_lane: bool,
+    pub hide_staff_lane: bool,
+    pub hide_jianpu_lane: bool,
+    pub show_chord_analysis: bool,
+    pub use_nashville_numbers: bool,
     pub override_beat_size: Option<f32>,
     pub override_chord_size: Option<f32>,
     pub override_guitar_width: Option<f32>,
@@ -60,6 +64,10 @@ impl FromWorld for NotationSettings {
             hide_harmony_lane: false,
             hide_lyrics_lane: false,
             hide_melody_lane: false,
+            hide_staff_lane: true,
+            hide_jianpu_lane: true,
+            show_chord_analysis: false,
+            use_nashville_numbers: false,
             hide_guitar_view: false,
             hide_mini_map: false,
             hide_chords_view: false,
@@ -74,12 +82,23 @@ impl FromWorld for NotationSettings {
 impl NotationSettings {
     pub const EN_US: LanguageIdentifier = langid!("en-US");
     pub const ZH_CN: LanguageIdentifier = langid!("zh-CN");
+    /// Languages with a `kb/<lang>/notation.ftl` in the assets.
+    pub const BUNDLED_LANGS: [LanguageIdentifier; 2] = [Self::EN_US, Self::ZH_CN];
+    /// Falls back to a bundled language with the same primary language, then to en-US,
+    /// since the ftl of an unknown language can't be loaded.
     pub fn parse_lang(lang: &str) -> LanguageIdentifier {
-        if lang == "zh-CN" {
-            Self::ZH_CN
-        } else {
-            Self::EN_US
+        let lang = match lang.parse::<LanguageIdentifier>() {
+            Ok(lang) => lang,
+            Err(_) => return Self::EN_US,
+        };
+        if Self::BUNDLED_LANGS.contains(&lang) {
+            return lang;
         }
+        Self::BUNDLED_LANGS
+            .iter()
+            .find(|x| x.language == lang.language)
+            .cloned()
+            .unwrap_or(Self::EN_US)
     }
 
     pub fn lang(&self) -> LanguageIdentifier {
@@ -94,6 +113,9 @@ impl NotationSettings {
         self.hide_harmony_lane = true;
         self.hide_lyrics_lane = true;
         self.hide_melody_lane = true;
+        self.hide_staff_lane = true;
+        self.hide_jianpu_lane = true;
+        self.show_chord_analysis = false;
     }
     pub fn hack_for_screenshot(&mut self) {
         self.layout.mode = LayoutMode::Grid;
@@ -7,14 +7,16 @@ use crate::prelude::NotationTheme;
 #[derive(Clone, Debug, Component)]
 pub struct ShapeFingerData {
     pub string: u8,
+    pub string_num: usize,
     pub fret: Option<u8>,
     pub finger: Option<Finger>,
 }
 
 impl ShapeFingerData {
-    pub fn new(string: u8, fret: Option<u8>, finger: Option<Finger>) -> Self {
+    pub fn new(string: u8, string_num: usize, fret: Option<u8>, finger: Option<Finger>) -> Self {
         ShapeFingerData {
             string,
+            string_num,
             fret,
             finger,
         }
@@ -29,7 +31,7 @@ impl ShapeOp<NotationTheme, FillCircle> for ShapeFingerData {
         } else {
             shapes.shape_finger_color
         };
-        let x = shapes.shape_finger_offset_x - shapes.shape_string_space * self.string as f32;
+        let x = shapes.calc_string_x(self.string, self.string_num);
         let y =
             shapes.shape_finger_offset_y - shapes.shape_fret_space * self.fret.unwrap_or(0) as f32;
         FillCircle {
@@ -0,0 +1,8 @@
+pub mod staff_beam;
+pub mod staff_grid;
+pub mod staff_line;
+pub mod staff_note;
+pub mod staff_path;
+pub mod staff_plugin;
+pub mod staff_signature;
+pub mod staff_tuplet;
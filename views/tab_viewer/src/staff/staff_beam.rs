@@ -0,0 +1,86 @@
+use edger_bevy::bevy_prelude::*;
+use edger_bevy::prelude::{offscreen, ShapeOp, StrokePath};
+use notation_model::prelude::Units;
+
+use crate::prelude::{LaneData, NotationTheme};
+
+use super::staff_note::StaffNoteValue;
+use super::staff_path::StaffPath;
+
+#[derive(Clone, Debug)]
+pub struct StaffBeamValue {
+    /// Position and number of beams of each stem.
+    pub stems: Vec<(Units, usize)>,
+    pub stem_up: bool,
+    pub end_step: i16,
+    pub bar_size: f32,
+}
+
+pub type StaffBeamData = LaneData<StaffBeamValue>;
+
+impl StaffBeamValue {
+    /// Length of the partial beam of a single stem, in line spaces.
+    pub const STUB_LENGTH: f32 = 0.8;
+
+    fn add_beams(&self, path: &mut StaffPath, unit_size: f32) {
+        let stem_x = if self.stem_up {
+            StaffNoteValue::HEAD_X
+        } else {
+            -StaffNoteValue::HEAD_X
+        };
+        let dir = if self.stem_up { -1.0 } else { 1.0 };
+        let xs: Vec<f32> = self
+            .stems
+            .iter()
+            .map(|(pos, _)| pos.to_f32() * unit_size + stem_x)
+            .collect();
+        let max_flags = self.stems.iter().map(|x| x.1).max().unwrap_or(0);
+        for level in 0..max_flags {
+            let y = self.end_step as f32 * 0.5 + dir * level as f32 * StaffNoteValue::FLAG_SPACE;
+            let mut index = 0;
+            while index < self.stems.len() {
+                if self.stems[index].1 <= level {
+                    index += 1;
+                    continue;
+                }
+                let begin = index;
+                while index + 1 < self.stems.len() && self.stems[index + 1].1 > level {
+                    index += 1;
+                }
+                if begin < index {
+                    path.line(xs[begin], y, xs[index], y);
+                } else if begin + 1 < self.stems.len() {
+                    path.line(xs[begin], y, xs[begin] + Self::STUB_LENGTH, y);
+                } else {
+                    path.line(xs[begin] - Self::STUB_LENGTH, y, xs[begin], y);
+                }
+                index += 1;
+            }
+        }
+    }
+}
+
+impl ShapeOp<NotationTheme, StrokePath> for StaffBeamData {
+    fn get_shape(&self, theme: &NotationTheme) -> StrokePath {
+        let sizes = theme.sizes.staff;
+        let space = sizes.line_space;
+        let mut path = StaffPath::default();
+        path.with_origin(0.0, 0.0, space);
+        let offset = if self.value.bar_size <= 0.0 || self.bar_props.bar_units.to_f32() <= 0.0 {
+            offscreen::offset()
+        } else {
+            let unit_size = self.value.bar_size / self.bar_props.bar_units.to_f32() / space;
+            self.value.add_beams(&mut path, unit_size);
+            Vec3::new(sizes.note_x, sizes.calc_step_y(0), theme.z.tone)
+        };
+        StrokePath {
+            size: Vec2::ZERO,
+            path: path.path,
+            color: theme.colors.staff.note.idle,
+            line_width: sizes.beam_width,
+            offset,
+            scale: 1.0,
+            angle: 0.0,
+        }
+    }
+}
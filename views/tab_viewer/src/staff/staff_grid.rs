@@ -0,0 +1,96 @@
+use edger_bevy::bevy_prelude::*;
+
+use crate::prelude::{NotationAssets, NotationTheme};
+use edger_bevy::prelude::ShapeOp;
+use notation_model::prelude::{BarLane, LaneStaff};
+
+use super::staff_beam::{StaffBeamData, StaffBeamValue};
+use super::staff_line::{StaffLineData, StaffLineValue};
+use super::staff_signature::{StaffSignatureData, StaffSignatureValue};
+use super::staff_tuplet::{StaffTupletData, StaffTupletValue};
+
+#[derive(Debug, Default, Component)]
+pub struct StaffGrid();
+
+impl StaffGrid {
+    pub fn add_lines(
+        &self,
+        commands: &mut Commands,
+        theme: &NotationTheme,
+        assets: &NotationAssets,
+        entity: Entity,
+        lane: &BarLane,
+    ) {
+        for index in -2..=2 {
+            let data = StaffLineData::new(lane, StaffLineValue {
+                index,
+                bar_size: 0.0,
+            });
+            data.create(commands, theme, entity);
+        }
+        let staff = LaneStaff::new(lane);
+        if lane.bar_props().bar_index == 0 {
+            let data = StaffSignatureData::new(lane, StaffSignatureValue {
+                clef: staff.clef,
+                key_signature: staff.key_signature,
+            });
+            let signature_entity = data.create(commands, theme, entity);
+            let space = theme.sizes.staff.line_space;
+            theme.texts.staff.spawn_time_signature(
+                commands,
+                signature_entity,
+                assets,
+                &staff.signature,
+                (data.value.calc_width() + 0.6) * space,
+                space,
+            );
+        }
+        for indexes in staff.beams.iter() {
+            let entries: Vec<_> = indexes.iter().map(|x| &staff.entries[*x]).collect();
+            let first = entries[0];
+            let data = StaffBeamData::new(lane, StaffBeamValue {
+                stems: entries.iter().map(|x| (x.in_bar_pos, x.flags())).collect(),
+                stem_up: staff.is_stem_up(first),
+                end_step: staff.calc_stem_end_step(first),
+                bar_size: 0.0,
+            });
+            data.create(commands, theme, entity);
+        }
+        for indexes in staff.tuplets.iter() {
+            let entries: Vec<_> = indexes.iter().map(|x| &staff.entries[*x]).collect();
+            let top_step = entries
+                .iter()
+                .map(|x| {
+                    let top = x.highest_step() + 3;
+                    if x.has_stem() && staff.is_stem_up(x) {
+                        top.max(staff.calc_stem_end_step(x) + 2)
+                    } else {
+                        top
+                    }
+                })
+                .max()
+                .unwrap_or(0)
+                .max(6);
+            let data = StaffTupletData::new(lane, StaffTupletValue {
+                begin: entries[0].in_bar_pos,
+                end: entries[entries.len() - 1].in_bar_pos,
+                top_step,
+                bar_size: 0.0,
+            });
+            let tuplet_entity = data.create(commands, theme, entity);
+            let number = entries[0]
+                .duration
+                .tuplet()
+                .map(|(n, _)| n)
+                .unwrap_or(3)
+                .to_string();
+            theme.texts.staff.spawn_tuplet_text(
+                commands,
+                tuplet_entity,
+                assets,
+                &number,
+                0.0,
+            );
+        }
+    }
+}
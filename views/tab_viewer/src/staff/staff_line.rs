@@ -0,0 +1,26 @@
+use edger_bevy::bevy_prelude::*;
+use edger_bevy::prelude::{ShapeOp, StrokeLine};
+
+use crate::prelude::{LaneData, NotationTheme};
+
+#[derive(Clone, Debug)]
+pub struct StaffLineValue {
+    /// From -2 for the bottom line to 2 for the top line.
+    pub index: i16,
+    pub bar_size: f32,
+}
+
+pub type StaffLineData = LaneData<StaffLineValue>;
+
+impl ShapeOp<NotationTheme, StrokeLine> for StaffLineData {
+    fn get_shape(&self, theme: &NotationTheme) -> StrokeLine {
+        let y = theme.sizes.staff.calc_step_y(self.value.index * 2);
+        StrokeLine {
+            from: Vec2::ZERO,
+            to: Vec2::new(self.value.bar_size, 0.0),
+            line_width: theme.sizes.staff.line_width,
+            color: theme.colors.staff.line,
+            offset: Vec3::new(0.0, y, theme.z.string),
+        }
+    }
+}
@@ -0,0 +1,249 @@
+use edger_bevy::bevy_prelude::*;
+use edger_bevy::prelude::{offscreen, ShapeOp, StrokePath};
+use notation_midi::prelude::PlayingState;
+use notation_model::prelude::{Duration, StaffEntry, Unit, Units};
+
+use crate::prelude::{EntryData, NotationTheme};
+
+use super::staff_path::StaffPath;
+
+/// Filled heads need a much thicker line than the rest of the note, so they are drawn
+/// as a separate shape.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum StaffNotePart {
+    Heads,
+    Lines,
+}
+
+#[derive(Clone, Debug)]
+pub struct StaffNoteValue {
+    pub part: StaffNotePart,
+    pub entry: StaffEntry,
+    pub stem_up: bool,
+    pub stem_end_step: i16,
+    pub beamed: bool,
+    pub playing_state: PlayingState,
+    pub bar_size: f32,
+}
+
+pub type StaffNoteData = EntryData<StaffNoteValue>;
+
+impl StaffNoteValue {
+    pub const HEAD_X: f32 = 0.55;
+    pub const DISPLACED_X: f32 = 1.1;
+    pub const ACCIDENTAL_X: f32 = -1.4;
+    pub const FLAG_SPACE: f32 = 0.75;
+    pub const DOT_SPACE: f32 = 0.4;
+    /// Grace notes are drawn smaller, to the left of the note they lead to, in line spaces.
+    pub const GRACE_SCALE: f32 = 0.6;
+    pub const GRACE_X: f32 = -1.8;
+
+    pub fn new(part: StaffNotePart, entry: StaffEntry, stem_up: bool, stem_end_step: i16) -> Self {
+        let beamed = entry.beam.is_some();
+        Self {
+            part,
+            entry,
+            stem_up,
+            stem_end_step,
+            beamed,
+            playing_state: PlayingState::Idle,
+            bar_size: 0.0,
+        }
+    }
+    fn is_rest_block(&self) -> bool {
+        self.entry.is_rest() && matches!(self.entry.unit(), Some(Unit::Whole) | Some(Unit::Half))
+    }
+    pub fn has_part(&self) -> bool {
+        if self.entry.unit().is_none() {
+            return false;
+        }
+        match self.part {
+            StaffNotePart::Heads => self.is_rest_block() || (!self.entry.is_rest() && !self.entry.is_hollow()),
+            StaffNotePart::Lines => {
+                !self.is_rest_block() || self.entry.is_dotted() || self.entry.fermata
+            }
+        }
+    }
+    /// Heads a second apart can't be drawn on the same side of the stem.
+    fn calc_head_xs(&self) -> Vec<f32> {
+        let mut xs = Vec::new();
+        let mut last: Option<(i16, bool)> = None;
+        for note in self.entry.notes.iter() {
+            let displaced = match last {
+                Some((step, displaced)) => note.step - step == 1 && !displaced,
+                None => false,
+            };
+            xs.push(match (displaced, self.stem_up) {
+                (false, _) => 0.0,
+                (true, true) => Self::DISPLACED_X,
+                (true, false) => -Self::DISPLACED_X,
+            });
+            last = Some((note.step, displaced));
+        }
+        xs
+    }
+    fn add_heads(&self, path: &mut StaffPath) {
+        if self.is_rest_block() {
+            let y = if self.entry.unit() == Some(Unit::Whole) { 0.75 } else { 0.25 };
+            path.line(-0.4, y, 0.4, y);
+            return;
+        }
+        for (note, x) in self.entry.notes.iter().zip(self.calc_head_xs()) {
+            path.ellipse(x, note.step as f32 * 0.5, 0.3, 0.15);
+        }
+    }
+    fn add_rest(&self, path: &mut StaffPath) {
+        let flags = self.entry.flags();
+        if flags == 0 {
+            if !self.is_rest_block() {
+                path.move_to(-0.2, 1.5)
+                    .line_to(0.3, 0.8)
+                    .line_to(-0.2, 0.2)
+                    .line_to(0.3, -0.5)
+                    .quad_to(-0.5, -0.6, 0.1, -1.3);
+            }
+        } else {
+            for flag in 0..flags {
+                let y = 0.6 - flag as f32;
+                path.ellipse(-0.25 - flag as f32 * 0.2, y, 0.12, 0.12)
+                    .move_to(-0.25 - flag as f32 * 0.2, y - 0.15)
+                    .quad_to(0.1, y - 0.2, 0.35 - flag as f32 * 0.2, y + 0.1);
+            }
+            path.line(0.35, 0.7, 0.35 - 0.3 * flags as f32, -0.3 - flags as f32);
+        }
+        for dot in 0..self.entry.dots() {
+            path.ellipse(0.8 + dot as f32 * Self::DOT_SPACE, 0.75, 0.12, 0.12);
+        }
+    }
+    fn add_fermata(&self, path: &mut StaffPath) {
+        let mut top = (self.entry.highest_step().max(4) + 3) as f32 * 0.5;
+        if self.entry.has_stem() && self.stem_up {
+            top = top.max(self.stem_end_step as f32 * 0.5 + 1.0);
+        }
+        path.move_to(-0.7, top)
+            .quad_to(0.0, top + 1.2, 0.7, top)
+            .ellipse(0.0, top + 0.2, 0.1, 0.1);
+    }
+    fn add_lines(&self, path: &mut StaffPath, tie_end: f32) {
+        if self.entry.fermata {
+            self.add_fermata(path);
+        }
+        if self.entry.is_rest() {
+            self.add_rest(path);
+            return;
+        }
+        let head_xs = self.calc_head_xs();
+        let lowest = self.entry.lowest_step();
+        let highest = self.entry.highest_step();
+        let max_x = head_xs.iter().fold(0.0_f32, |acc, x| acc.max(*x));
+        let min_x = head_xs.iter().fold(0.0_f32, |acc, x| acc.min(*x));
+        let mut step = 6;
+        while step <= highest {
+            path.line(min_x - 0.8, step as f32 * 0.5, max_x + 0.8, step as f32 * 0.5);
+            step += 2;
+        }
+        let mut step = -6;
+        while step >= lowest {
+            path.line(min_x - 0.8, step as f32 * 0.5, max_x + 0.8, step as f32 * 0.5);
+            step -= 2;
+        }
+        for (note, x) in self.entry.notes.iter().zip(head_xs.iter()) {
+            let y = note.step as f32 * 0.5;
+            if self.entry.is_hollow() {
+                path.ellipse(*x, y, 0.5, 0.32);
+            }
+            for dot in 0..self.entry.dots() {
+                let dot_y = if note.step % 2 == 0 { y + 0.25 } else { y };
+                path.ellipse(max_x + 0.9 + dot as f32 * Self::DOT_SPACE, dot_y, 0.12, 0.12);
+            }
+            if let Some(sign) = note.accidental {
+                let origin = path.origin;
+                let unit = path.unit;
+                path.with_origin(origin.0 + (min_x + Self::ACCIDENTAL_X) * unit, origin.1 + y * unit, unit)
+                    .accidental(&sign);
+                path.with_origin(origin.0, origin.1, unit);
+            }
+            if self.entry.tie_to_next {
+                let dir = if self.stem_up { -1.0 } else { 1.0 };
+                let tie_y = y + dir * 0.5;
+                let end = tie_end.max(1.2);
+                path.move_to(0.6, tie_y)
+                    .quad_to(end / 2.0, tie_y + dir * 0.8, end - 0.6, tie_y);
+            }
+        }
+        if self.entry.has_stem() {
+            let (x, from) = if self.stem_up {
+                (Self::HEAD_X, lowest)
+            } else {
+                (-Self::HEAD_X, highest)
+            };
+            let end_y = self.stem_end_step as f32 * 0.5;
+            path.line(x, from as f32 * 0.5, x, end_y);
+            if !self.beamed {
+                let dir = if self.stem_up { -1.0 } else { 1.0 };
+                for flag in 0..self.entry.flags() {
+                    let y = end_y + dir * flag as f32 * Self::FLAG_SPACE;
+                    path.move_to(x, y)
+                        .quad_to(x + 0.2, y + dir * 0.8, x + 0.9, y + dir * 1.4)
+                        .quad_to(x + 1.2, y + dir * 1.9, x + 0.9, y + dir * 2.5);
+                }
+            }
+            if matches!(self.entry.duration, Duration::Acciaccatura(_)) {
+                let dir = if self.stem_up { -1.0 } else { 1.0 };
+                let y = end_y - dir * 1.5;
+                path.line(x - 0.6, y - dir * 0.4, x + 0.6, y + dir * 0.4);
+            }
+        }
+    }
+}
+
+impl ShapeOp<NotationTheme, StrokePath> for StaffNoteData {
+    fn get_shape(&self, theme: &NotationTheme) -> StrokePath {
+        let sizes = theme.sizes.staff;
+        let space = sizes.line_space;
+        let unit_size = if self.bar_props.bar_units.to_f32() > 0.0 {
+            self.value.bar_size / self.bar_props.bar_units.to_f32()
+        } else {
+            0.0
+        };
+        let mut path = StaffPath::default();
+        path.with_origin(0.0, 0.0, space);
+        let line_width = match self.value.part {
+            StaffNotePart::Heads => {
+                self.value.add_heads(&mut path);
+                sizes.head_line_width()
+            }
+            StaffNotePart::Lines => {
+                let in_bar_pos = self.value.entry.in_bar_pos;
+                let end = (in_bar_pos + Units::from(self.value.entry.duration))
+                    .min(self.bar_props.bar_units);
+                let tie_end = unit_size * (end - in_bar_pos).to_f32() / space;
+                self.value.add_lines(&mut path, tie_end);
+                sizes.stem_width
+            }
+        };
+        let offset = if self.value.bar_size <= 0.0 {
+            offscreen::offset()
+        } else {
+            let mut x = unit_size * self.value.entry.in_bar_pos.to_f32() + sizes.note_x;
+            if self.value.entry.is_grace() {
+                x += StaffNoteValue::GRACE_X * space;
+            }
+            Vec3::new(x, sizes.calc_step_y(0), theme.z.tone)
+        };
+        let scale = if self.value.entry.is_grace() {
+            StaffNoteValue::GRACE_SCALE
+        } else {
+            1.0
+        };
+        StrokePath {
+            size: Vec2::ZERO,
+            path: path.path,
+            color: theme.colors.staff.note.of_state(&self.value.playing_state),
+            line_width,
+            offset,
+            scale,
+            angle: 0.0,
+        }
+    }
+}
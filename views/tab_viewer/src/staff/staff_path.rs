@@ -0,0 +1,145 @@
+use std::fmt::Write;
+
+use notation_model::prelude::{Clef, PitchSign};
+
+/// Svg path in bevy coordinates, the y axis is flipped when writing, since the path
+/// shape is using svg coordinates.
+///
+/// Points are scaled by `unit` and moved by `origin`, so glyphs can be written in line spaces.
+#[derive(Clone, Debug)]
+pub struct StaffPath {
+    pub path: String,
+    pub origin: (f32, f32),
+    pub unit: f32,
+}
+impl Default for StaffPath {
+    fn default() -> Self {
+        Self {
+            path: String::new(),
+            origin: (0.0, 0.0),
+            unit: 1.0,
+        }
+    }
+}
+
+impl StaffPath {
+    pub fn with_origin(&mut self, x: f32, y: f32, unit: f32) -> &mut Self {
+        self.origin = (x, y);
+        self.unit = unit;
+        self
+    }
+    fn point(&self, x: f32, y: f32) -> (f32, f32) {
+        (
+            self.origin.0 + x * self.unit,
+            -(self.origin.1 + y * self.unit),
+        )
+    }
+    pub fn move_to(&mut self, x: f32, y: f32) -> &mut Self {
+        let (x, y) = self.point(x, y);
+        let _ = write!(self.path, "M {} {} ", x, y);
+        self
+    }
+    pub fn line_to(&mut self, x: f32, y: f32) -> &mut Self {
+        let (x, y) = self.point(x, y);
+        let _ = write!(self.path, "L {} {} ", x, y);
+        self
+    }
+    pub fn quad_to(&mut self, cx: f32, cy: f32, x: f32, y: f32) -> &mut Self {
+        let (cx, cy) = self.point(cx, cy);
+        let (x, y) = self.point(x, y);
+        let _ = write!(self.path, "Q {} {} {} {} ", cx, cy, x, y);
+        self
+    }
+    pub fn line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32) -> &mut Self {
+        self.move_to(x1, y1).line_to(x2, y2)
+    }
+    pub fn ellipse(&mut self, cx: f32, cy: f32, rx: f32, ry: f32) -> &mut Self {
+        let (rx, ry) = (rx * self.unit, ry * self.unit);
+        let (x, y) = self.point(cx, cy);
+        let _ = write!(
+            self.path,
+            "M {} {} A {} {} 0 1 0 {} {} A {} {} 0 1 0 {} {} ",
+            x - rx,
+            y,
+            rx,
+            ry,
+            x + rx,
+            y,
+            rx,
+            ry,
+            x - rx,
+            y
+        );
+        self
+    }
+}
+
+/// Simplified glyphs, all in line spaces, centered at the origin.
+impl StaffPath {
+    pub fn clef(&mut self, clef: &Clef) -> &mut Self {
+        match clef {
+            Clef::Treble => self.treble_clef(),
+            Clef::Treble8vb => self.treble_clef().ellipse(0.1, -4.2, 0.2, 0.2),
+            Clef::Bass => self.bass_clef(),
+        }
+    }
+    /// The spiral is around the G line.
+    pub fn treble_clef(&mut self) -> &mut Self {
+        self.move_to(-0.3, -3.2)
+            .quad_to(0.4, -3.6, 0.5, -2.7)
+            .line_to(0.1, 2.4)
+            .quad_to(0.1, 3.4, 0.6, 3.0)
+            .quad_to(0.9, 2.2, -0.6, 0.6)
+            .quad_to(-1.4, -0.4, -0.6, -1.6)
+            .quad_to(0.4, -2.4, 1.0, -1.2)
+            .quad_to(1.2, 0.0, 0.0, -0.1)
+            .quad_to(-0.6, -0.6, 0.0, -1.3)
+    }
+    /// The dots are around the F line.
+    pub fn bass_clef(&mut self) -> &mut Self {
+        self.ellipse(-0.6, 1.0, 0.2, 0.2)
+            .move_to(-0.6, 1.0)
+            .quad_to(-0.4, 2.0, 0.4, 1.9)
+            .quad_to(1.2, 1.6, 0.9, 0.4)
+            .quad_to(0.5, -1.2, -0.8, -2.0)
+            .ellipse(1.4, 1.5, 0.1, 0.1)
+            .ellipse(1.4, 0.5, 0.1, 0.1)
+    }
+    pub fn accidental(&mut self, sign: &PitchSign) -> &mut Self {
+        match sign {
+            PitchSign::Sharp => self.sharp(),
+            PitchSign::Flat => self.flat(),
+            PitchSign::Natural => self.natural(),
+            PitchSign::DoubleSharp => self
+                .line(-0.3, -0.3, 0.3, 0.3)
+                .line(-0.3, 0.3, 0.3, -0.3),
+            PitchSign::DoubleFlat => {
+                let origin = self.origin;
+                let unit = self.unit;
+                self.with_origin(origin.0 - 0.35 * unit, origin.1, unit).flat();
+                self.with_origin(origin.0 + 0.35 * unit, origin.1, unit).flat();
+                self.with_origin(origin.0, origin.1, unit)
+            }
+        }
+    }
+    pub fn sharp(&mut self) -> &mut Self {
+        self.line(-0.2, -1.1, -0.2, 1.0)
+            .line(0.2, -1.0, 0.2, 1.1)
+            .line(-0.45, -0.4, 0.45, -0.2)
+            .line(-0.45, 0.2, 0.45, 0.4)
+    }
+    pub fn flat(&mut self) -> &mut Self {
+        self.move_to(-0.3, 1.6)
+            .line_to(-0.3, -0.4)
+            .quad_to(0.6, 0.1, 0.2, 0.4)
+            .quad_to(0.0, 0.55, -0.3, 0.2)
+    }
+    pub fn natural(&mut self) -> &mut Self {
+        self.move_to(-0.25, 1.1)
+            .line_to(-0.25, -0.4)
+            .line_to(0.25, -0.25)
+            .move_to(0.25, -1.1)
+            .line_to(0.25, 0.4)
+            .line_to(-0.25, 0.25)
+    }
+}
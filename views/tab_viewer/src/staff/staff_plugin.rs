@@ -0,0 +1,134 @@
+use edger_bevy::bevy::ecs::system::EntityCommands;
+use edger_bevy::bevy_prelude::*;
+use edger_bevy::prelude::{AssetsStates, ShapeOp, SingleData};
+use notation_model::prelude::{BarLane, LaneEntry, LaneStaff};
+
+use crate::prelude::{EntryPlaying, NotationAssets, NotationSettings, NotationTheme};
+use crate::tab::tab_events::TabBarsResizedEvent;
+
+use super::staff_beam::StaffBeamData;
+use super::staff_grid::StaffGrid;
+use super::staff_line::StaffLineData;
+use super::staff_note::{StaffNoteData, StaffNotePart, StaffNoteValue};
+use super::staff_tuplet::StaffTupletData;
+
+pub struct StaffPlugin;
+
+impl Plugin for StaffPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (
+            Self::on_add_staff_grid,
+            Self::on_entry_playing_changed,
+            Self::on_tab_bars_resized,
+        ).run_if(in_state(AssetsStates::Loaded)));
+    }
+}
+
+impl StaffPlugin {
+    pub fn on_add_staff_grid(
+        mut commands: Commands,
+        theme: Res<NotationTheme>,
+        assets: Res<NotationAssets>,
+        query: Query<(Entity, &SingleData<BarLane>, &StaffGrid), Added<StaffGrid>>,
+    ) {
+        if theme._bypass_systems {
+            return;
+        }
+        for (entity, lane, grid) in query.iter() {
+            grid.add_lines(&mut commands, &theme, &assets, entity, &lane.0);
+        }
+    }
+    pub fn on_entry_playing_changed(
+        mut commands: Commands,
+        theme: Res<NotationTheme>,
+        query: Query<(Entity, &EntryPlaying, &Children), Changed<EntryPlaying>>,
+        mut note_query: Query<(Entity, &mut StaffNoteData)>,
+    ) {
+        if theme._bypass_systems {
+            return;
+        }
+        for (_entity, playing, children) in query.iter() {
+            for child in children.iter() {
+                if let Ok((entity, mut data)) = note_query.get_mut(*child) {
+                    data.value.playing_state = playing.value;
+                    data.update(&mut commands, &theme, entity);
+                }
+            }
+        }
+    }
+    #[allow(clippy::too_many_arguments)]
+    pub fn on_tab_bars_resized(
+        mut evts: EventReader<TabBarsResizedEvent>,
+        mut commands: Commands,
+        settings: Res<NotationSettings>,
+        theme: Res<NotationTheme>,
+        mut line_query: Query<(Entity, &mut StaffLineData), With<StaffLineData>>,
+        mut note_query: Query<(Entity, &mut StaffNoteData), With<StaffNoteData>>,
+        mut beam_query: Query<(Entity, &mut StaffBeamData), With<StaffBeamData>>,
+        mut tuplet_query: Query<(Entity, &mut StaffTupletData), With<StaffTupletData>>,
+    ) {
+        if theme._bypass_systems || settings.hide_staff_lane {
+            return;
+        }
+        for evt in evts.read() {
+            let bars = &evt.0;
+            for (entity, mut data) in line_query.iter_mut() {
+                for (view, layout) in bars.iter() {
+                    if data.bar_props.bar_ordinal == view.bar_props.bar_ordinal {
+                        data.value.bar_size = layout.size.width;
+                        data.update(&mut commands, &theme, entity);
+                    }
+                }
+            }
+            for (entity, mut data) in note_query.iter_mut() {
+                for (view, layout) in bars.iter() {
+                    if data.bar_props.bar_ordinal == view.bar_props.bar_ordinal {
+                        data.value.bar_size = layout.size.width;
+                        data.update(&mut commands, &theme, entity);
+                    }
+                }
+            }
+            for (entity, mut data) in beam_query.iter_mut() {
+                for (view, layout) in bars.iter() {
+                    if data.bar_props.bar_ordinal == view.bar_props.bar_ordinal {
+                        data.value.bar_size = layout.size.width;
+                        data.update(&mut commands, &theme, entity);
+                    }
+                }
+            }
+            for (entity, mut data) in tuplet_query.iter_mut() {
+                for (view, layout) in bars.iter() {
+                    if data.bar_props.bar_ordinal == view.bar_props.bar_ordinal {
+                        data.value.bar_size = layout.size.width;
+                        data.update(&mut commands, &theme, entity);
+                    }
+                }
+            }
+        }
+    }
+    pub fn insert_lane_extra(commands: &mut EntityCommands, _lane: &BarLane) {
+        commands.insert(StaffGrid::default());
+    }
+    pub fn insert_entry_extra(
+        commands: &mut Commands,
+        theme: &NotationTheme,
+        entity: Entity,
+        entry: &LaneEntry,
+    ) {
+        let lane = match entry.lane() {
+            Some(lane) => lane,
+            None => return,
+        };
+        let staff = LaneStaff::new(&lane);
+        if let Some(staff_entry) = staff.get_entry(entry.props.index) {
+            let stem_up = staff.is_stem_up(staff_entry);
+            let stem_end_step = staff.calc_stem_end_step(staff_entry);
+            for part in [StaffNotePart::Heads, StaffNotePart::Lines] {
+                let value = StaffNoteValue::new(part, staff_entry.clone(), stem_up, stem_end_step);
+                if value.has_part() {
+                    StaffNoteData::new(entry, value).create(commands, theme, entity);
+                }
+            }
+        }
+    }
+}
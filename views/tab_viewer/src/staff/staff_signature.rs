@@ -0,0 +1,73 @@
+use edger_bevy::bevy_prelude::*;
+use edger_bevy::prelude::{ShapeOp, StrokePath};
+use notation_model::prelude::{Clef, KeySignature, PitchSign};
+
+use crate::prelude::{LaneData, NotationTheme};
+
+use super::staff_path::StaffPath;
+
+/// Clef and key signature, drawn at the beginning of the first bar.
+#[derive(Clone, Debug)]
+pub struct StaffSignatureValue {
+    pub clef: Clef,
+    pub key_signature: KeySignature,
+}
+
+pub type StaffSignatureData = LaneData<StaffSignatureValue>;
+
+impl StaffSignatureValue {
+    /// Steps of the sharps and flats for treble clef, in the order they are written.
+    pub const SHARP_STEPS: [i16; 7] = [4, 1, 5, 2, -1, 3, 0];
+    pub const FLAT_STEPS: [i16; 7] = [0, 3, -1, 2, -3, 1, -4];
+
+    pub fn calc_sign_step(&self, sign: &PitchSign, index: usize) -> i16 {
+        let step = match sign {
+            PitchSign::Flat => Self::FLAT_STEPS[index],
+            _ => Self::SHARP_STEPS[index],
+        };
+        match self.clef {
+            Clef::Bass => step - 2,
+            _ => step,
+        }
+    }
+    /// In line spaces, from the x of the signature.
+    pub fn calc_sign_x(index: usize) -> f32 {
+        2.2 + index as f32 * 0.9
+    }
+    /// In line spaces, where the time signature can be written.
+    pub fn calc_width(&self) -> f32 {
+        Self::calc_sign_x(self.key_signature.get_signs().len()) + 0.4
+    }
+}
+
+impl ShapeOp<NotationTheme, StrokePath> for StaffSignatureData {
+    fn get_shape(&self, theme: &NotationTheme) -> StrokePath {
+        let sizes = theme.sizes.staff;
+        let space = sizes.line_space;
+        let mut path = StaffPath::default();
+        let clef_y = match self.value.clef {
+            Clef::Bass => space,
+            _ => -space,
+        };
+        path.with_origin(space, clef_y, space)
+            .clef(&self.value.clef);
+        for (index, (_name, sign)) in self.value.key_signature.get_signs().iter().enumerate() {
+            let step = self.value.calc_sign_step(sign, index);
+            path.with_origin(
+                StaffSignatureValue::calc_sign_x(index) * space,
+                step as f32 * space / 2.0,
+                space,
+            )
+            .accidental(sign);
+        }
+        StrokePath {
+            size: Vec2::ZERO,
+            path: path.path,
+            color: theme.colors.staff.signature,
+            line_width: sizes.stem_width,
+            offset: Vec3::new(sizes.signature_x, sizes.calc_step_y(0), theme.z.grid),
+            scale: 1.0,
+            angle: 0.0,
+        }
+    }
+}
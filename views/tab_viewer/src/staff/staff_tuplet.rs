@@ -0,0 +1,60 @@
+use edger_bevy::bevy_prelude::*;
+use edger_bevy::prelude::{offscreen, ShapeOp, StrokePath};
+use notation_model::prelude::Units;
+
+use crate::prelude::{LaneData, NotationTheme};
+
+use super::staff_note::StaffNoteValue;
+use super::staff_path::StaffPath;
+
+/// Bracket over a tuplet, the number is spawned as a child at the middle of it.
+#[derive(Clone, Debug)]
+pub struct StaffTupletValue {
+    pub begin: Units,
+    pub end: Units,
+    pub top_step: i16,
+    pub bar_size: f32,
+}
+
+pub type StaffTupletData = LaneData<StaffTupletValue>;
+
+impl StaffTupletValue {
+    /// Half the gap for the number, in line spaces.
+    pub const TEXT_GAP: f32 = 0.8;
+    pub const HOOK_LENGTH: f32 = 0.5;
+}
+
+impl ShapeOp<NotationTheme, StrokePath> for StaffTupletData {
+    fn get_shape(&self, theme: &NotationTheme) -> StrokePath {
+        let sizes = theme.sizes.staff;
+        let space = sizes.line_space;
+        let mut path = StaffPath::default();
+        path.with_origin(0.0, 0.0, space);
+        let offset = if self.value.bar_size <= 0.0 || self.bar_props.bar_units.to_f32() <= 0.0 {
+            offscreen::offset()
+        } else {
+            let unit_size = self.value.bar_size / self.bar_props.bar_units.to_f32();
+            let half = unit_size * (self.value.end - self.value.begin).to_f32() / space / 2.0
+                + StaffNoteValue::HEAD_X;
+            if half > StaffTupletValue::TEXT_GAP + StaffTupletValue::HOOK_LENGTH {
+                path.move_to(-half, -StaffTupletValue::HOOK_LENGTH)
+                    .line_to(-half, 0.0)
+                    .line_to(-StaffTupletValue::TEXT_GAP, 0.0)
+                    .move_to(StaffTupletValue::TEXT_GAP, 0.0)
+                    .line_to(half, 0.0)
+                    .line_to(half, -StaffTupletValue::HOOK_LENGTH);
+            }
+            let x = unit_size * (self.value.begin + self.value.end).to_f32() / 2.0 + sizes.note_x;
+            Vec3::new(x, sizes.calc_step_y(self.value.top_step), theme.z.tone)
+        };
+        StrokePath {
+            size: Vec2::ZERO,
+            path: path.path,
+            color: theme.texts.staff.font_color,
+            line_width: sizes.stem_width,
+            offset,
+            scale: 1.0,
+            angle: 0.0,
+        }
+    }
+}
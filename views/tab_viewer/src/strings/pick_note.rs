@@ -1,7 +1,7 @@
 use edger_bevy::bevy_prelude::*;
 use edger_bevy::bevy_prototype_lyon::prelude::*;
 use edger_bevy::prelude::{offscreen, OutlineRectangle, ShapeOp};
-use notation_model::prelude::{PickNote, Syllable};
+use notation_model::prelude::{PickNote, Syllable, Units};
 use notation_midi::prelude::PlayingState;
 
 use crate::prelude::{EntryData, NotationTheme};
@@ -35,9 +35,19 @@ impl PickNoteData {
             .note_outline
             .of_state(&self.value.playing_state)
     }
+    /// Grace notes take no bar time, they are drawn right before the note they lead to.
+    pub fn calc_begin_units(&self) -> (Units, Units) {
+        let duration = self.entry_props.duration;
+        if duration.is_grace() {
+            let units = duration.grace_units();
+            (self.entry_props.in_bar_pos - units, units)
+        } else {
+            (self.entry_props.in_bar_pos, self.entry_props.tied_units)
+        }
+    }
     pub fn calc_width_height(&self, theme: &NotationTheme) -> (f32, f32) {
-        let width =
-            self.value.bar_size / self.bar_props.bar_units.0 * self.entry_props.tied_units.0;
+        let (_, units) = self.calc_begin_units();
+        let width = self.value.bar_size / self.bar_props.bar_units.to_f32() * units.to_f32();
         let mut height = theme.sizes.strings.note_height;
         let outline = self.calc_outline(theme);
         if self.value.playing_state.is_current() {
@@ -70,8 +80,8 @@ impl ShapeOp<NotationTheme, OutlineRectangle> for PickNoteData {
         let offset = if self.value.bar_size <= 0.0 {
             offscreen::offset()
         } else {
-            let x =
-                self.value.bar_size / self.bar_props.bar_units.0 * self.entry_props.in_bar_pos.0;
+            let (begin, _) = self.calc_begin_units();
+            let x = self.value.bar_size / self.bar_props.bar_units.to_f32() * begin.to_f32();
             let y = theme
                 .sizes
                 .strings
@@ -43,90 +43,81 @@ pub fn on_entry_playing_changed(
     }
 }
 
-macro_rules! impl_pick_system {
-    ($create_pick_notes:ident, $create_pick_tones:ident, $fretboard:ident, $hand_shape:ident, $get_fretted_shape:ident
-    ) => {
-        pub fn $create_pick_notes(
-            commands: &mut Commands,
-            assets: &NotationAssets,
-            theme: &NotationTheme,
-            settings: &NotationSettings,
-            entity: Entity,
-            entry: &LaneEntry,
-            pick: &Pick,
-        ) {
-            /* TODO: check whether is the first bar in row
-            if entry.as_ref().prev_is_tie() {
-                continue;
-            }
-            */
-            if let Some(bar) = entry.bar() {
-                if let Some((fretboard, shape)) = bar.$get_fretted_shape(entry) {
-                    let meta = bar.tab_meta();
-                    for pick_note in pick.get_notes() {
-                        if let Some((fret, note)) =
-                            fretboard.shape_pick_fret_note(&meta.scale, &meta.key, &shape, pick_note)
-                        {
-                            let syllable = bar.calc_syllable(&note.pitch);
-                            let data =
-                                PickNoteData::new(entry, PickNoteValue::new(pick_note, syllable));
-                            let note_entity = data.create(commands, theme, entity);
-                            if !settings.hide_strings_lane
-                                && (settings.always_show_fret || pick_note.fret.is_some())
-                            {
-                                theme.texts.strings.spawn_fret_text(
-                                    commands,
-                                    note_entity,
-                                    &assets,
-                                    fret,
-                                );
-                            }
+pub fn create_pick_notes(
+    commands: &mut Commands,
+    assets: &NotationAssets,
+    theme: &NotationTheme,
+    settings: &NotationSettings,
+    entity: Entity,
+    entry: &LaneEntry,
+    pick: &Pick,
+) {
+    /* TODO: check whether is the first bar in row
+    if entry.as_ref().prev_is_tie() {
+        continue;
+    }
+    */
+    if let Some(bar) = entry.bar() {
+        if let Some((fretboard, shape)) = bar.get_fretted_shape(entry) {
+            let meta = bar.tab_meta();
+            for pick_note in pick.get_notes() {
+                if let Some((fret, note)) =
+                    fretboard.shape_pick_fret_note(&meta.scale, &meta.key, &shape, pick_note)
+                {
+                    let syllable = bar.calc_syllable(&note.pitch);
+                    let data =
+                        PickNoteData::new(entry, PickNoteValue::new(pick_note, syllable));
+                    let note_entity = data.create(commands, theme, entity);
+                    if !settings.hide_strings_lane
+                        && (settings.always_show_fret || pick_note.fret.is_some())
+                    {
+                        theme.texts.strings.spawn_fret_text(
+                            commands,
+                            note_entity,
+                            assets,
+                            fret,
+                        );
+                    }
+                    if !settings.hide_strings_lane {
+                        if let Some(articulation) = pick_note.articulation {
+                            theme.texts.strings.spawn_articulation_text(
+                                commands,
+                                note_entity,
+                                assets,
+                                &articulation,
+                            );
                         }
                     }
                 }
             }
         }
-        pub fn $create_pick_tones(
-            commands: &mut Commands,
-            assets: &NotationAssets,
-            theme: &NotationTheme,
-            settings: &NotationSettings,
-            entity: Entity,
-            entry: &LaneEntry,
-            pick: &Pick,
-        ) {
-            /* TODO: check whether is the first bar in row
-            if entry.as_ref().prev_is_tie() {
-                continue;
-            }
-            */
-            if let Some(bar) = entry.bar() {
-                if let Some((fretboard, shape)) = bar.$get_fretted_shape(entry) {
-                    let meta = bar.tab_meta();
-                    let tone = fretboard.pick_tone(&meta.scale, &meta.key, &shape, pick);
-                    commands
-                        .entity(entity)
-                        .insert(ToneBundle::from(tone));
-                    crate::tone::tone_systems::create_tone_notes(
-                        commands, assets, theme, settings, ToneMode::Harmony, entity, entry, &tone,
-                    );
-                }
-            }
-        }
-    };
+    }
 }
 
-impl_pick_system!(
-    create_pick_notes6,
-    create_pick_tones6,
-    Fretboard6,
-    HandShape6,
-    get_fretted_shape6
-);
-impl_pick_system!(
-    create_pick_notes4,
-    create_pick_tones4,
-    Fretboard4,
-    HandShape4,
-    get_fretted_shape4
-);
+pub fn create_pick_tones(
+    commands: &mut Commands,
+    assets: &NotationAssets,
+    theme: &NotationTheme,
+    settings: &NotationSettings,
+    entity: Entity,
+    entry: &LaneEntry,
+    pick: &Pick,
+) {
+    /* TODO: check whether is the first bar in row
+    if entry.as_ref().prev_is_tie() {
+        continue;
+    }
+    */
+    if let Some(bar) = entry.bar() {
+        if let Some((fretboard, shape)) = bar.get_fretted_shape(entry) {
+            let meta = bar.tab_meta();
+            let tone = fretboard.pick_tone(&meta.scale, &meta.key, &shape, pick);
+            commands
+                .entity(entity)
+                .insert(ToneBundle::from(tone.clone()));
+            crate::tone::tone_systems::create_tone_notes(
+                commands, assets, theme, settings, ToneMode::Harmony, entity, entry, &tone,
+            );
+        }
+    }
+}
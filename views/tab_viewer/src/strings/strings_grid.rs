@@ -1,35 +1,52 @@
 use edger_bevy::bevy_prelude::*;
 
-use crate::prelude::NotationTheme;
+use crate::prelude::{NotationAssets, NotationTheme};
 use edger_bevy::prelude::ShapeOp;
 use notation_model::prelude::BarLane;
 
 use super::single_string::{SingleStringData, SingleStringValue};
 
-macro_rules! impl_strings_grid {
-    ($type:ident, $strings: literal) => {
-        #[derive(Debug, Default, Component)]
-        pub struct $type();
+/// Strings lane of a fretted track, with the string count of its fretboard.
+#[derive(Debug, Component)]
+pub struct StringsGrid {
+    pub string_num: usize,
+}
 
-        impl $type {
-            pub fn add_strings(
-                &self,
-                commands: &mut Commands,
-                theme: &NotationTheme,
-                entity: Entity,
-                lane: &BarLane,
-            ) {
-                for string in 1..=$strings {
-                    let data = SingleStringData::new(lane, SingleStringValue {
-                        string: string as u8,
-                        bar_size: 0.0,
-                    });
-                    data.create(commands, theme, entity);
+impl StringsGrid {
+    pub fn new(string_num: usize) -> Self {
+        Self { string_num }
+    }
+    pub fn add_strings(
+        &self,
+        commands: &mut Commands,
+        theme: &NotationTheme,
+        assets: &NotationAssets,
+        entity: Entity,
+        lane: &BarLane,
+    ) {
+        // Open strings are labeled at the beginning of each section.
+        let fretboard = if lane.bar_props().bar_index == 0 {
+            lane.track.get_fretboard()
+        } else {
+            None
+        };
+        let meta = lane.bar().map(|x| x.tab_meta());
+        for string in 1..=self.string_num {
+            let data = SingleStringData::new(lane, SingleStringValue {
+                string: string as u8,
+                bar_size: 0.0,
+            });
+            let string_entity = data.create(commands, theme, entity);
+            if let (Some(fretboard), Some(meta)) = (fretboard.as_ref(), meta.as_ref()) {
+                if let Some(note) = fretboard.tuning_note(&meta.scale, &meta.key, string as u8) {
+                    theme.texts.strings.spawn_tuning_text(
+                        commands,
+                        string_entity,
+                        assets,
+                        note.pitch.to_text().as_str(),
+                    );
                 }
             }
         }
-    };
+    }
 }
-
-impl_strings_grid!(StringsGrid6, 6);
-impl_strings_grid!(StringsGrid4, 4);
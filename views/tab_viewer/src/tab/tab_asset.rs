@@ -5,7 +5,7 @@ use edger_bevy::bevy::asset::{Asset, AssetLoader, AsyncReadExt, LoadContext, io:
 use edger_bevy::bevy::reflect::TypePath;
 use edger_bevy::bevy::utils::BoxedFuture;
 
-use notation_model::prelude::ProtoTab;
+use notation_model::prelude::{ProtoTab, TabEncoding, TabFile, TabFileError};
 
 #[derive(Clone, Debug, Asset, TypePath)]
 pub struct TabAsset {
@@ -17,8 +17,8 @@ pub struct TabAssetHandle(pub Handle<TabAsset>);
 
 #[derive(Clone, Error, Debug)]
 pub enum TabError {
-    #[error("decode ron failed")]
-    DecodeRonFailed(ron::error::SpannedError),
+    #[error("decode tab failed: {0}")]
+    DecodeFailed(TabFileError),
     #[error("get tab failed")]
     GetTabFailed(String),
     #[error("parse tab failed")]
@@ -39,9 +39,9 @@ impl From<TabError> for TabAsset {
 
 impl TabAsset {
     #[cfg(feature = "dsl")]
-    pub const EXTENSIONS: [&'static str; 2] = ["rs", "ron"];
+    pub const EXTENSIONS: [&'static str; 4] = ["rs", "ron", "json", "ntab"];
     #[cfg(not(feature = "dsl"))]
-    pub const EXTENSIONS: [&'static str; 1] = ["ron"];
+    pub const EXTENSIONS: [&'static str; 3] = TabEncoding::EXTENSIONS;
 }
 
 #[derive(Default)]
@@ -59,17 +59,18 @@ impl AssetLoader for TabAssetLoader {
         &self,
         reader: &mut dyn Reader,
         _settings: &Self::Settings,
-        _load_context: &mut LoadContext<'_>,
+        load_context: &mut LoadContext<'_>,
     ) -> Result<Self::Asset, Self::Error> {
         let mut bytes = Vec::new();
         reader.read_to_end(&mut bytes).await?;
-        let tab_asset = match ron::de::from_bytes::<ProtoTab>(&bytes) {
+        let path = load_context.path().to_string_lossy().to_string();
+        let tab_asset = match TabFile::decode_path(&path, &bytes) {
             Ok(tab) => TabAsset::from(tab),
-            Err(err) => TabAsset::from(TabError::DecodeRonFailed(err)),
+            Err(err) => TabAsset::from(TabError::DecodeFailed(err)),
         };
         Ok(tab_asset)
     }
     fn extensions(&self) -> &[&str] {
-        &["ron"]
+        &TabEncoding::EXTENSIONS
     }
 }
@@ -9,7 +9,7 @@ use edger_bevy::prelude::{
     LayoutSize, View, ViewBundle, ViewQuery,
 };
 use notation_model::lane_kind::LaneKind;
-use notation_model::prelude::{Tab, TabBar};
+use notation_model::prelude::{Tab, TabBar, TrackKind};
 
 use crate::bar::bar_layout::BarLayoutData;
 use crate::bar::bar_view::BarView;
@@ -200,11 +200,32 @@ impl TabBars {
         tab: &Tab,
     ) -> Vec<(LaneKind, LaneLayoutData)> {
         let mut lane_layouts: HashMap<String, (LaneKind, LaneLayoutData)> = HashMap::new();
+        // Jianpu is sorted right above the lyrics, so the words line up underneath.
+        let lyrics_track = tab
+            .tracks
+            .iter()
+            .find(|x| x.kind == TrackKind::Lyrics)
+            .map(|x| x.props.index);
         for bar in tab.bars.iter() {
             for ((_k, _i), lane) in bar.lanes.iter() {
                 let lane_id = lane.id();
-                if !lane_layouts.contains_key(&lane_id) {
-                    let height = theme.sizes.calc_lane_height(settings, lane.kind);
+                if lane.kind == LaneKind::Lyrics {
+                    let height = theme.sizes.calc_lane_height(settings, lane.kind)
+                        * lane.verses().len().max(1) as f32;
+                    if let Some((_, lane_layout)) = lane_layouts.get_mut(&lane_id) {
+                        lane_layout.height = lane_layout.height.max(height);
+                        continue;
+                    }
+                    let margin = theme.sizes.layout.lane_margin;
+                    lane_layouts.insert(lane_id, (lane.kind, LaneLayoutData::new(lane, height, margin)));
+                } else if !lane_layouts.contains_key(&lane_id) {
+                    let height = match lane.kind {
+                        LaneKind::Strings if !settings.hide_strings_lane => theme
+                            .sizes
+                            .strings
+                            .layout_height_of(lane.track.get_string_num().unwrap_or(6)),
+                        _ => theme.sizes.calc_lane_height(settings, lane.kind),
+                    };
                     let margin = theme.sizes.layout.lane_margin;
                     lane_layouts.insert(lane_id, (lane.kind, LaneLayoutData::new(&lane, height, margin)));
                     if lane.kind == LaneKind::Strings && !settings.hide_harmony_lane {
@@ -215,6 +236,23 @@ impl TabBars {
                             lane_layouts.insert(lane_id, (lane.kind, LaneLayoutData::new_virtual(&lane, LaneKind::Harmony, height, margin)));
                         }
                     }
+                    if lane.kind == LaneKind::Melody && !settings.hide_jianpu_lane {
+                        let lane_id = lane.kind_id(LaneKind::Jianpu);
+                        lane_layouts.entry(lane_id).or_insert_with(|| {
+                            let height = theme.sizes.calc_lane_height(settings, LaneKind::Jianpu);
+                            let margin = theme.sizes.layout.lane_margin;
+                            let order_track = lyrics_track.unwrap_or(lane.track.props.index);
+                            (lane.kind, LaneLayoutData::new_virtual(lane, LaneKind::Jianpu, height, margin).with_order_track(order_track))
+                        });
+                    }
+                    if (lane.kind == LaneKind::Strings || lane.kind == LaneKind::Melody) && !settings.hide_staff_lane {
+                        let lane_id = lane.kind_id(LaneKind::Staff);
+                        lane_layouts.entry(lane_id).or_insert_with(|| {
+                            let height = theme.sizes.calc_lane_height(settings, LaneKind::Staff);
+                            let margin = theme.sizes.layout.lane_margin;
+                            (lane.kind, LaneLayoutData::new_virtual(lane, LaneKind::Staff, height, margin))
+                        });
+                    }
                 }
             }
         }
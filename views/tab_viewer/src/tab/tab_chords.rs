@@ -14,6 +14,7 @@ use crate::prelude::{NotationState, NotationAssets, NotationSettings, NotationTh
 use crate::prelude::NotationLayout;
 
 use super::tab_events::TabChordsDoLayoutEvent;
+use super::tab_header::TabHeader;
 
 #[derive(Clone, Debug, Component)]
 pub struct TabChords {
@@ -65,7 +66,16 @@ impl TabChords {
 }
 impl<'a> GridView<NotationLayout<'a>, ChordView> for TabChords {
     fn calc_grid_data(&self, engine: &NotationLayout<'a>, grid_size: LayoutSize) -> GridData {
-        Self::calc_grid_data(engine, grid_size, self.chords.len())
+        let info_height = TabHeader::calc_info_height(engine, &self.tab);
+        let grid_data = Self::calc_grid_data(
+            engine,
+            LayoutSize::new(grid_size.width, grid_size.height - info_height),
+            self.chords.len(),
+        );
+        GridData {
+            offset: grid_data.offset - Vec2::new(0.0, info_height),
+            ..grid_data
+        }
     }
 }
 
@@ -81,6 +91,7 @@ impl TabChords {
         commands: &mut Commands,
         assets: &NotationAssets,
         theme: &NotationTheme,
+        settings: &NotationSettings,
         entity: Entity,
         tab: &Arc<Tab>,
         chords: &Vec<TabChord>,
@@ -89,7 +100,7 @@ impl TabChords {
         let view = view_bundle.view.clone();
         let chords_entity = entity::spawn_child_bundle(commands, entity, view_bundle);
         for chord_view in view.chords.iter() {
-            ChordView::spawn(commands, assets, theme, chords_entity, chord_view);
+            ChordView::spawn(commands, assets, theme, settings, chords_entity, chord_view);
         }
         chords_entity
     }
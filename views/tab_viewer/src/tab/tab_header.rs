@@ -33,6 +33,29 @@ impl TabHeader {
             .unwrap_or_default();
         Self { tab, chords }
     }
+    pub fn calc_info_height<'a>(engine: &NotationLayout<'a>, tab: &Tab) -> f32 {
+        if tab.info.is_empty() {
+            0.0
+        } else {
+            engine.theme.sizes.chord.tab_info_height
+        }
+    }
+    /// Title, artist and credits in one line.
+    pub fn info_text(&self) -> String {
+        let info = &self.tab.info;
+        let mut text = info.to_string();
+        let credits = info.credits();
+        if !credits.is_empty() {
+            text = format!("{}    {}", text, credits);
+        }
+        if let Some(difficulty) = info.difficulty {
+            text = format!("{}    [{}]", text, difficulty);
+        }
+        if !info.tags.is_empty() {
+            text = format!("{}    #{}", text, info.tags.join(" #"));
+        }
+        text
+    }
 }
 impl<'a> View<NotationLayout<'a>> for TabHeader {
     fn calc_size(&self, engine: &NotationLayout, constraint: LayoutConstraint) -> LayoutSize {
@@ -40,7 +63,7 @@ impl<'a> View<NotationLayout<'a>> for TabHeader {
             return LayoutSize::new(constraint.max.width, 0.0);
         }
         let grid_data = TabChords::calc_grid_data(engine, constraint.max, self.chords.len() + 1);
-        let height = grid_data.content_size().height;
+        let height = grid_data.content_size().height + Self::calc_info_height(engine, &self.tab);
         LayoutSize::new(constraint.max.width, height)
     }
 }
@@ -73,7 +96,17 @@ impl TabHeader {
             theme.colors.chord.background,
         );
         RhythmView::spawn(commands, assets, theme, header_entity, tab);
-        TabChords::spawn(commands, assets, theme, header_entity, &tab, &view.chords);
+        let chords_entity =
+            TabChords::spawn(commands, assets, theme, settings, header_entity, tab, &view.chords);
+        if !tab.info.is_empty() {
+            theme.texts.tab.spawn_info_text(
+                commands,
+                assets,
+                chords_entity,
+                view.info_text().as_str(),
+                theme.z.tab_info,
+            );
+        }
         header_entity
     }
     pub fn do_layout(
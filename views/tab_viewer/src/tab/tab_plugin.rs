@@ -112,7 +112,7 @@ impl TabPlugin {
                 if !EguiControlPanel::is_pos_inside(app_state.window_width, pos) {
                     state.show_control = false;
                 }
-            } else if state.show_kb {
+            } else if state.show_kb || state.show_library || state.show_karaoke {
                 //TODO: after #125 done, can pass click event in case of not inside help panel
             } else {
                 println!("tab_plugin::on_mouse_clicked() -> {:?}", pos);
@@ -188,7 +188,7 @@ impl TabPlugin {
             if state.show_control && EguiControlPanel::is_pos_inside(app_state.window_width, pos) {
                 return;
             }
-            if state.show_kb {
+            if state.show_kb || state.show_library || state.show_karaoke {
                 //TODO: after #125 done, can pass drag event in case of not inside help panel
                 return;
             }
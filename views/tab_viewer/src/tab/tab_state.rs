@@ -3,7 +3,7 @@ use std::sync::Arc;
 use edger_bevy::bevy_prelude::*;
 
 use notation_model::prelude::{Uuid, Position, Tab};
-use notation_midi::prelude::{PlayControl, PlayState};
+use notation_midi::prelude::{PlayControl, PlayState, SpeedTrainer};
 
 #[derive(Debug, Component)]
 pub struct TabPlayStateChanged();
@@ -49,6 +49,9 @@ impl TabState {
     pub fn set_should_loop(&mut self, should_loop: bool) {
         self.play_control.should_loop = should_loop;
     }
+    pub fn set_speed_trainer(&mut self, speed_trainer: SpeedTrainer) {
+        self.play_control.speed_trainer = speed_trainer;
+    }
     pub fn set_begin_end(&mut self, begin_bar_ordinal: usize, end_bar_ordinal: usize) {
         self.play_control.begin_bar_ordinal = begin_bar_ordinal;
         self.play_control.end_bar_ordinal = end_bar_ordinal;
@@ -24,6 +24,9 @@ pub struct GuitarTheme {
     pub hit_string_seconds_range: (f32, f32),
     pub syllable_text: NoteTexts,
     pub syllable_base_width: f32,
+    pub tuning_font_size: f32,
+    pub tuning_font_color: Color,
+    pub tuning_text_y: f32,
 }
 
 impl Default for GuitarTheme {
@@ -58,26 +61,41 @@ impl Default for GuitarTheme {
                 syllable_font_color: hex_linear("FFFFFF"),
             },
             syllable_base_width: 256.0,
+            tuning_font_size: 14.0,
+            tuning_font_color: hex_linear("FFFFFF"),
+            tuning_text_y: 2.0,
         }
     }
 }
 
 impl GuitarTheme {
     pub fn get_string_width(&self, string: u8) -> f32 {
-        let index = if string < 1 {
-            0
-        } else if string > 6 {
-            5
-        } else {
-            (string - 1) as usize
-        };
+        let index = (string.max(1) as usize - 1).min(self.string_widthes.len() - 1);
         self.string_widthes[index]
     }
     pub fn calc_scale(&self, guitar_width: f32) -> f32 {
         guitar_width / self.syllable_base_width
     }
-    pub fn calc_string_x(&self, string: u8, guitar_width: f32) -> f32 {
-        -1.0 * (string as f32 - 3.5) * guitar_width * self.string_x_factor
+    /// Strings are spread over the width of the six strings image.
+    pub fn calc_string_space(&self, string_num: usize, guitar_width: f32) -> f32 {
+        if string_num > 1 {
+            self.string_x_factor * 5.0 / (string_num - 1) as f32 * guitar_width
+        } else {
+            0.0
+        }
+    }
+    /// Fingers are sized for six strings, and shrink if the strings are closer.
+    pub fn calc_finger_radius(&self, string_num: usize, guitar_width: f32) -> f32 {
+        if string_num > 6 {
+            self.calc_string_space(string_num, guitar_width) / 2.0
+        } else {
+            self.string_x_factor * guitar_width / 2.0
+        }
+    }
+    /// String is 1-based.
+    pub fn calc_string_x(&self, string: u8, string_num: usize, guitar_width: f32) -> f32 {
+        -(string as f32 - (string_num as f32 + 1.0) / 2.0)
+            * self.calc_string_space(string_num, guitar_width)
     }
     pub fn calc_fret_y(&self, fret: u8, guitar_height: f32) -> f32 {
         let index = if fret as usize >= self.fret_y_factors.len() {
@@ -35,6 +35,9 @@ pub struct ShapesTheme {
     pub barre_text_x: f32,
     pub barre_text_y: f32,
     pub barre_text_z: f32,
+    pub tuning_font_size: f32,
+    pub tuning_font_color: Color,
+    pub tuning_text_y: f32,
 }
 
 impl Default for ShapesTheme {
@@ -67,6 +70,9 @@ impl Default for ShapesTheme {
             barre_text_x: 36.0,
             barre_text_y: 6.0,
             barre_text_z: 1.0,
+            tuning_font_size: 8.0,
+            tuning_font_color: hex_linear("F27D7A"),
+            tuning_text_y: 22.0,
         }
     }
 }
@@ -125,4 +131,40 @@ impl ShapesTheme {
         )).id();
         commands.entity(entity).add_children(&[text_entity]);
     }
+    /// Strings are spread over the width of the six strings diagram, string is 0-based.
+    pub fn calc_string_x(&self, string: u8, string_num: usize) -> f32 {
+        let space = if string_num > 1 {
+            self.shape_string_space * 5.0 / (string_num - 1) as f32
+        } else {
+            0.0
+        };
+        self.shape_finger_offset_x - space * string as f32
+    }
+    /// Open note of the string above the nut, string is 0-based as the fingers.
+    pub fn insert_tuning_text(
+        &self,
+        commands: &mut Commands,
+        assets: &NotationAssets,
+        entity: Entity,
+        string: u8,
+        string_num: usize,
+        text: &str,
+    ) {
+        let font = assets.latin_font.clone();
+        let text_font = TextFont {
+            font,
+            font_size: self.tuning_font_size,
+            ..Default::default()
+        };
+        let x = self.calc_string_x(string, string_num);
+        let text_entity = commands.spawn((
+            Text2d::new(text),
+            TextLayout::new_with_justify(JustifyText::Center),
+            text_font,
+            TextColor::from(self.tuning_font_color),
+            Transform::from_xyz(x, self.tuning_text_y, self.shape_text_z),
+            Anchor::BottomCenter,
+        )).id();
+        commands.entity(entity).add_children(&[text_entity]);
+    }
 }
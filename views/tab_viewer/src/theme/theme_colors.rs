@@ -86,6 +86,8 @@ pub struct ThemeColors {
     pub lyrics: LyricsColors,
     pub section: SectionColors,
     pub strings: StringsColors,
+    pub staff: StaffColors,
+    pub jianpu: JianpuColors,
     pub rhythm: RhythmColors,
     pub mini_map: MiniMapColors,
     pub ui: UiColors,
@@ -215,6 +217,7 @@ pub struct ChordColors {
     pub diagram_outline: PlayingColors,
     pub dot: IntervalColors,
     pub dot_outline: IntervalColors,
+    pub analysis_line: PlayingColors,
 }
 impl Default for ChordColors {
     fn default() -> Self {
@@ -234,6 +237,11 @@ impl Default for ChordColors {
                 diminished: hex_linear("333333"),
                 tritone: hex_linear("FF00FF"),
             },
+            analysis_line: PlayingColors::new(
+                hex_linear("00000033"),
+                hex_linear("FFFFFF88"),
+                hex_linear("FFFFFF33"),
+            ),
         }
     }
 }
@@ -288,6 +296,44 @@ impl Default for StringsColors {
     }
 }
 
+#[derive(Copy, Clone, PartialEq, Serialize, Deserialize, Debug)]
+pub struct StaffColors {
+    pub line: Color,
+    pub signature: Color,
+    pub note: PlayingColors,
+}
+impl Default for StaffColors {
+    fn default() -> Self {
+        Self {
+            line: hex_linear("00000066"),
+            signature: hex_linear("00000055"),
+            note: PlayingColors::new(
+                hex_linear("000000CC"),
+                hex_linear("FFFFFF"),
+                hex_linear("00000088"),
+            ),
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Serialize, Deserialize, Debug)]
+pub struct JianpuColors {
+    pub underline: Color,
+    pub note: PlayingColors,
+}
+impl Default for JianpuColors {
+    fn default() -> Self {
+        Self {
+            underline: hex_linear("000000AA"),
+            note: PlayingColors::new(
+                hex_linear("000000AA"),
+                hex_linear("FFFFFF"),
+                hex_linear("00000066"),
+            ),
+        }
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Serialize, Deserialize, Debug)]
 pub struct SectionColors {
     pub sections: [Color; 12],
@@ -1,5 +1,5 @@
 use edger_bevy::prelude::LayoutSize;
-use notation_model::prelude::{LaneKind, Note, Semitones, Tab, TrackKind};
+use notation_model::prelude::{LaneKind, LaneStaff, Note, Semitones, Tab, TrackKind};
 use notation_midi::prelude::PlayingState;
 
 use serde::{Deserialize, Serialize};
@@ -37,6 +37,8 @@ pub struct ThemeSizes {
     pub harmony: NotesSizes,
     pub lyrics: LyricsSizes,
     pub strings: StringsSizes,
+    pub staff: StaffSizes,
+    pub jianpu: JianpuSizes,
     pub mini_map: MiniMapSizes,
     pub tab_control: TabControlSizes,
     pub layout: LayoutSizes,
@@ -51,6 +53,8 @@ impl Default for ThemeSizes {
             harmony: NotesSizes::default_harmony(),
             lyrics: Default::default(),
             strings: Default::default(),
+            staff: Default::default(),
+            jianpu: Default::default(),
             mini_map: Default::default(),
             tab_control: Default::default(),
             layout: Default::default(),
@@ -103,6 +107,9 @@ pub struct ChordSizes {
     pub interval_dot_offset_3_4_factor: f32,
     pub interval_dot_offset_5_7_factor: f32,
     pub interval_dot_offset_6_factor: (f32, f32),
+    pub tab_info_height: f32,
+    pub analysis_height: PlayingSize,
+    pub analysis_gap: f32,
 }
 impl Default for ChordSizes {
     fn default() -> Self {
@@ -122,9 +129,17 @@ impl Default for ChordSizes {
             interval_dot_offset_3_4_factor: 0.4,
             interval_dot_offset_5_7_factor: 0.6,
             interval_dot_offset_6_factor: (0.4, 0.5),
+            tab_info_height: 24.0,
+            analysis_height: PlayingSize::new(20.0, 24.0, 20.0),
+            analysis_gap: 2.0,
         }
     }
 }
+impl ChordSizes {
+    pub fn analysis_layout_height(&self) -> f32 {
+        self.analysis_height.of_state(&PlayingState::Current)
+    }
+}
 #[derive(Copy, Clone, PartialEq, Serialize, Deserialize, Debug)]
 pub struct NotesSizes {
     pub note_height: f32,
@@ -195,19 +210,14 @@ impl NotesSizes {
         for bar in tab.bars.iter() {
             if let Some(lane) = bar.get_lane_of_kind(LaneKind::Strings, track_index) {
                 for entry in lane.entries.iter() {
-                    if let Some(fretted_entry) = entry.model.proto.as_fretted6() {
-                        if let Some(pick) = fretted_entry.as_pick() {
-                            if let Some((fretboard, shape)) = bar.get_fretted_shape6(entry) {
-                                let tone = fretboard.pick_tone(&tab.meta.scale, &tab.meta.key, &shape, pick);
-                                for note in tone.get_notes() {
-                                    let v = Semitones::from(note);
-                                    if v < self.lowest {
-                                        self.lowest = v
-                                    }
-                                    if v > self.highest {
-                                        self.highest = v
-                                    }
-                                }
+                    if let Some(tone) = LaneStaff::get_entry_tone(bar, &tab.meta, entry) {
+                        for note in tone.get_notes() {
+                            let v = Semitones::from(note);
+                            if v < self.lowest {
+                                self.lowest = v
+                            }
+                            if v > self.highest {
+                                self.highest = v
                             }
                         }
                     }
@@ -270,13 +280,100 @@ impl Default for StringsSizes {
 }
 impl StringsSizes {
     pub fn layout_height(&self) -> f32 {
-        self.string_space * 6.0
+        self.layout_height_of(6)
+    }
+    pub fn layout_height_of(&self, string_num: usize) -> f32 {
+        self.string_space * string_num as f32
     }
     pub fn calc_string_y(&self, string: u8) -> f32 {
         -1.0 * self.string_space * (string as f32 - 0.5)
     }
 }
 
+#[derive(Copy, Clone, PartialEq, Serialize, Deserialize, Debug)]
+pub struct StaffSizes {
+    pub line_space: f32,
+    pub line_width: f32,
+    pub note_x: f32,
+    pub stem_width: f32,
+    pub beam_width: f32,
+    pub signature_x: f32,
+    pub top_margin: f32,
+    pub bottom_margin: f32,
+}
+impl Default for StaffSizes {
+    fn default() -> Self {
+        Self {
+            line_space: 8.0,
+            line_width: 1.0,
+            note_x: 8.0,
+            stem_width: 1.2,
+            beam_width: 3.5,
+            signature_x: 8.0,
+            top_margin: 28.0,
+            bottom_margin: 28.0,
+        }
+    }
+}
+impl StaffSizes {
+    pub fn layout_height(&self) -> f32 {
+        self.top_margin + self.line_space * 4.0 + self.bottom_margin
+    }
+    /// Step 0 is the middle line, each step is half a line space.
+    pub fn calc_step_y(&self, step: i16) -> f32 {
+        -self.top_margin - self.line_space * 2.0 + step as f32 * self.line_space / 2.0
+    }
+    /// Filled note heads are drawn as thick ellipses.
+    pub fn head_line_width(&self) -> f32 {
+        self.line_space * 0.5
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Serialize, Deserialize, Debug)]
+pub struct JianpuSizes {
+    pub top_margin: f32,
+    pub bottom_margin: f32,
+    pub row_height: f32,
+    pub note_x: f32,
+    pub number_width: f32,
+    pub number_half_height: f32,
+    pub line_width: f32,
+    pub underline_space: f32,
+    pub dot_radius: f32,
+    pub dot_space: f32,
+    pub dash_width: f32,
+}
+impl Default for JianpuSizes {
+    fn default() -> Self {
+        Self {
+            top_margin: 14.0,
+            bottom_margin: 18.0,
+            row_height: 22.0,
+            note_x: 6.0,
+            number_width: 10.0,
+            number_half_height: 8.0,
+            line_width: 1.5,
+            underline_space: 3.5,
+            dot_radius: 0.8,
+            dot_space: 4.0,
+            dash_width: 8.0,
+        }
+    }
+}
+impl JianpuSizes {
+    pub fn layout_height(&self) -> f32 {
+        self.top_margin + self.row_height + self.bottom_margin
+    }
+    /// Center of the numbers, stacked numbers of chords go above it.
+    pub fn calc_number_y(&self) -> f32 {
+        -self.top_margin - self.row_height / 2.0
+    }
+    /// Relative to the number y, underlines start right below the numbers.
+    pub fn calc_underline_y(&self, level: usize) -> f32 {
+        -self.number_half_height - 2.0 - level as f32 * self.underline_space
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Serialize, Deserialize, Debug)]
 pub struct TabControlSizes {
     pub control_width_factor: f32,
@@ -291,6 +388,7 @@ pub struct TabControlSizes {
     pub rhythm_indicator_radius_factor: f32,
     pub rhythm_indicator_width_factor: f32,
     pub rhythm_indicator_line_width: f32,
+    pub speed_trainer_progress_height: f32,
 }
 impl Default for TabControlSizes {
     fn default() -> Self {
@@ -307,6 +405,7 @@ impl Default for TabControlSizes {
             rhythm_indicator_radius_factor: 1.05,
             rhythm_indicator_width_factor: 0.2,
             rhythm_indicator_line_width: 2.0,
+            speed_trainer_progress_height: 4.0,
         }
     }
 }
@@ -368,6 +467,7 @@ impl ThemeSizes {
     }
     pub fn calc_lane_height(&self, settings: &NotationSettings, lane_kind: LaneKind) -> f32 {
         match lane_kind {
+            LaneKind::Chord if settings.show_chord_analysis => self.chord.analysis_layout_height(),
             LaneKind::Lyrics => {
                 if settings.hide_lyrics_lane {
                     0.0
@@ -389,6 +489,20 @@ impl ThemeSizes {
                     self.harmony.layout_height(settings)
                 }
             }
+            LaneKind::Jianpu => {
+                if settings.hide_jianpu_lane {
+                    0.0
+                } else {
+                    self.jianpu.layout_height()
+                }
+            }
+            LaneKind::Staff => {
+                if settings.hide_staff_lane {
+                    0.0
+                } else {
+                    self.staff.layout_height()
+                }
+            }
             LaneKind::Strings => {
                 if settings.hide_strings_lane {
                     0.0
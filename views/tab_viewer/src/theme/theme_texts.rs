@@ -1,5 +1,5 @@
 use edger_bevy::prelude::{text, LayoutData};
-use notation_model::prelude::{Syllable, Scale, Key};
+use notation_model::prelude::{Articulation, ChordAnalysis, ChordFunction, Syllable, Scale, Key, Signature};
 use serde::{Deserialize, Serialize};
 
 use edger_bevy::bevy::{prelude::*, sprite::Anchor};
@@ -16,6 +16,8 @@ pub struct ThemeTexts {
     pub melody: NoteTexts,
     pub harmony: NoteTexts,
     pub strings: StringsTexts,
+    pub staff: StaffTexts,
+    pub jianpu: JianpuTexts,
     pub mini_map: MiniMapTexts,
 }
 
@@ -29,6 +31,8 @@ impl Default for ThemeTexts {
             melody: Default::default(),
             harmony: NoteTexts::default_harmony(),
             strings: Default::default(),
+            staff: Default::default(),
+            jianpu: Default::default(),
             mini_map: Default::default(),
         }
     }
@@ -40,6 +44,10 @@ pub struct TabTexts {
     pub bar_font_color: Color,
     pub bar_x: f32,
     pub bar_y: f32,
+    pub info_font_size: f32,
+    pub info_font_color: Color,
+    pub info_x: f32,
+    pub info_y: f32,
 }
 impl Default for TabTexts {
     fn default() -> Self {
@@ -48,6 +56,10 @@ impl Default for TabTexts {
             bar_font_color: ThemeColors::hex_linear("00000066"),
             bar_x: -6.0,
             bar_y: -6.0,
+            info_font_size: 18.0,
+            info_font_color: ThemeColors::hex_linear("FFFFFF"),
+            info_x: 4.0,
+            info_y: -2.0,
         }
     }
 }
@@ -76,6 +88,28 @@ impl TabTexts {
     pub fn update_bar_number_x(&self, transform: &mut Transform, bar_width: f32) {
         transform.translation.x = bar_width + self.bar_x;
     }
+    pub fn spawn_info_text(
+        &self,
+        commands: &mut Commands,
+        assets: &NotationAssets,
+        entity: Entity,
+        text: &str,
+        z: f32,
+    ) {
+        text::spawn(
+            commands,
+            entity,
+            text,
+            assets.latin_font.clone(),
+            self.info_font_size,
+            self.info_font_color,
+            JustifyText::Left,
+            Anchor::TopLeft,
+            self.info_x,
+            self.info_y,
+            z,
+        );
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, Serialize, Deserialize, Debug)]
@@ -84,6 +118,14 @@ pub struct ChordTexts {
     pub bars_font_color: Color,
     pub bars_x: f32,
     pub bars_y: f32,
+    pub analysis_font_size: f32,
+    pub analysis_font_color: Color,
+    pub secondary_dominant_font_color: Color,
+    pub borrowed_font_color: Color,
+    pub chromatic_font_color: Color,
+    pub analysis_x: f32,
+    pub analysis_y: f32,
+    pub analysis_z: f32,
 }
 impl Default for ChordTexts {
     fn default() -> Self {
@@ -92,10 +134,49 @@ impl Default for ChordTexts {
             bars_font_color: ThemeColors::hex_linear("FFFFFF"),
             bars_x: 2.0,
             bars_y: -2.0,
+            analysis_font_size: 18.0,
+            analysis_font_color: ThemeColors::hex_linear("000000"),
+            secondary_dominant_font_color: ThemeColors::hex_linear("AA3300"),
+            borrowed_font_color: ThemeColors::hex_linear("0055AA"),
+            chromatic_font_color: ThemeColors::hex_linear("AA00AA"),
+            analysis_x: 4.0,
+            analysis_y: -8.0,
+            analysis_z: 1.0,
         }
     }
 }
 impl ChordTexts {
+    pub fn analysis_color(&self, analysis: &ChordAnalysis) -> Color {
+        match analysis.function {
+            ChordFunction::Diatonic => self.analysis_font_color,
+            ChordFunction::SecondaryDominant(_) => self.secondary_dominant_font_color,
+            ChordFunction::Borrowed(_) => self.borrowed_font_color,
+            ChordFunction::Chromatic => self.chromatic_font_color,
+        }
+    }
+    pub fn spawn_analysis_text(
+        &self,
+        commands: &mut Commands,
+        assets: &NotationAssets,
+        settings: &NotationSettings,
+        entity: Entity,
+        analysis: &ChordAnalysis,
+    ) {
+        text::spawn(
+            commands,
+            entity,
+            analysis.text(settings.use_nashville_numbers),
+            assets.latin_font.clone(),
+            self.analysis_font_size,
+            self.analysis_color(analysis),
+            JustifyText::Left,
+            Anchor::Center,
+            self.analysis_x,
+            self.analysis_y,
+            self.analysis_z,
+        );
+    }
+
     pub fn spawn_bars_text(
         &self,
         commands: &mut Commands,
@@ -122,6 +203,34 @@ impl ChordTexts {
         transform.translation.x = -layout.size.width / 2.0 + self.bars_x;
         transform.translation.y = layout.size.height / 2.0 + self.bars_y;
     }
+    /// Label at the top right corner of the chord view, the bars count is at the top left.
+    pub fn spawn_diagram_analysis_text(
+        &self,
+        commands: &mut Commands,
+        assets: &NotationAssets,
+        settings: &NotationSettings,
+        entity: Entity,
+        analysis: &ChordAnalysis,
+        z: f32,
+    ) {
+        text::spawn(
+            commands,
+            entity,
+            analysis.text(settings.use_nashville_numbers),
+            assets.latin_font.clone(),
+            self.bars_font_size,
+            self.bars_font_color,
+            JustifyText::Right,
+            Anchor::TopRight,
+            0.0,
+            0.0,
+            z,
+        );
+    }
+    pub fn update_diagram_analysis_xy(&self, transform: &mut Transform, layout: &LayoutData) {
+        transform.translation.x = layout.size.width / 2.0 - self.bars_x;
+        transform.translation.y = layout.size.height / 2.0 + self.bars_y;
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, Serialize, Deserialize, Debug)]
@@ -170,6 +279,7 @@ pub struct LyricsTexts {
     pub text_z: f32,
     pub word_font_size: f32,
     pub word_font_color: Color,
+    pub inactive_verse_font_color: Color,
 }
 impl Default for LyricsTexts {
     fn default() -> Self {
@@ -179,6 +289,7 @@ impl Default for LyricsTexts {
             text_z: 1.0,
             word_font_size: 20.0,
             word_font_color: hex_linear("000000"),
+            inactive_verse_font_color: hex_linear("00000066"),
         }
     }
 }
@@ -189,14 +300,20 @@ impl LyricsTexts {
         entity: Entity,
         assets: &NotationAssets,
         text: &str,
+        active: bool,
     ) {
+        let color = if active {
+            self.word_font_color
+        } else {
+            self.inactive_verse_font_color
+        };
         text::spawn(
             commands,
             entity,
             text,
             assets.lyrics_font.clone(),
             self.word_font_size,
-            self.word_font_color,
+            color,
             JustifyText::Left,
             Anchor::Center,
             self.text_x,
@@ -312,6 +429,12 @@ pub struct StringsTexts {
     pub text_z: f32,
     pub fret_font_size: f32,
     pub fret_font_color: Color,
+    pub articulation_x: f32,
+    pub articulation_y: f32,
+    pub articulation_font_size: f32,
+    pub tuning_x: f32,
+    pub tuning_font_size: f32,
+    pub tuning_font_color: Color,
 }
 impl Default for StringsTexts {
     fn default() -> Self {
@@ -321,10 +444,38 @@ impl Default for StringsTexts {
             text_z: 1.0,
             fret_font_size: 18.0,
             fret_font_color: super::theme_colors::hex_linear("000000"),
+            articulation_x: 14.0,
+            articulation_y: 6.0,
+            articulation_font_size: 12.0,
+            tuning_x: -2.0,
+            tuning_font_size: 10.0,
+            tuning_font_color: super::theme_colors::hex_linear("00000088"),
         }
     }
 }
 impl StringsTexts {
+    /// Open note of the string, at the left of the string.
+    pub fn spawn_tuning_text(
+        &self,
+        commands: &mut Commands,
+        entity: Entity,
+        assets: &NotationAssets,
+        text: &str,
+    ) {
+        text::spawn(
+            commands,
+            entity,
+            text,
+            assets.latin_font.clone(),
+            self.tuning_font_size,
+            self.tuning_font_color,
+            JustifyText::Right,
+            Anchor::CenterRight,
+            self.tuning_x,
+            0.0,
+            self.text_z,
+        );
+    }
     pub fn spawn_fret_text(
         &self,
         commands: &mut Commands,
@@ -347,6 +498,140 @@ impl StringsTexts {
             self.text_z,
         );
     }
+    pub fn spawn_articulation_text(
+        &self,
+        commands: &mut Commands,
+        entity: Entity,
+        assets: &NotationAssets,
+        articulation: &Articulation,
+    ) {
+        let text = articulation.to_mark();
+        text::spawn(
+            commands,
+            entity,
+            text.as_str(),
+            assets.latin_font.clone(),
+            self.articulation_font_size,
+            self.fret_font_color,
+            JustifyText::Left,
+            Anchor::Center,
+            self.articulation_x,
+            self.articulation_y,
+            self.text_z,
+        );
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Serialize, Deserialize, Debug)]
+pub struct StaffTexts {
+    pub text_z: f32,
+    pub time_font_size: f32,
+    pub tuplet_font_size: f32,
+    pub font_color: Color,
+}
+impl Default for StaffTexts {
+    fn default() -> Self {
+        Self {
+            text_z: 1.0,
+            time_font_size: 18.0,
+            tuplet_font_size: 12.0,
+            font_color: super::theme_colors::hex_linear("00000088"),
+        }
+    }
+}
+impl StaffTexts {
+    /// The entity is at the middle line, the numbers are centered in the upper and lower halves.
+    pub fn spawn_time_signature(
+        &self,
+        commands: &mut Commands,
+        entity: Entity,
+        assets: &NotationAssets,
+        signature: &Signature,
+        x: f32,
+        line_space: f32,
+    ) {
+        let text = format!("{}", signature);
+        let mut parts = text.split('/');
+        for y in [line_space, -line_space] {
+            if let Some(part) = parts.next() {
+                text::spawn(
+                    commands,
+                    entity,
+                    part,
+                    assets.latin_font.clone(),
+                    self.time_font_size,
+                    self.font_color,
+                    JustifyText::Center,
+                    Anchor::Center,
+                    x,
+                    y,
+                    self.text_z,
+                );
+            }
+        }
+    }
+    pub fn spawn_tuplet_text(
+        &self,
+        commands: &mut Commands,
+        entity: Entity,
+        assets: &NotationAssets,
+        text: &str,
+        y: f32,
+    ) {
+        text::spawn(
+            commands,
+            entity,
+            text,
+            assets.latin_font.clone(),
+            self.tuplet_font_size,
+            self.font_color,
+            JustifyText::Center,
+            Anchor::Center,
+            0.0,
+            y,
+            self.text_z,
+        );
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Serialize, Deserialize, Debug)]
+pub struct JianpuTexts {
+    pub text_z: f32,
+    pub number_font_size: f32,
+    pub font_color: Color,
+}
+impl Default for JianpuTexts {
+    fn default() -> Self {
+        Self {
+            text_z: 1.0,
+            number_font_size: 20.0,
+            font_color: super::theme_colors::hex_linear("000000CC"),
+        }
+    }
+}
+impl JianpuTexts {
+    pub fn spawn_number_text(
+        &self,
+        commands: &mut Commands,
+        entity: Entity,
+        assets: &NotationAssets,
+        text: &str,
+        y: f32,
+    ) {
+        text::spawn(
+            commands,
+            entity,
+            text,
+            assets.latin_font.clone(),
+            self.number_font_size,
+            self.font_color,
+            JustifyText::Center,
+            Anchor::Center,
+            0.0,
+            y,
+            self.text_z,
+        );
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, Serialize, Deserialize, Debug)]
@@ -14,11 +14,13 @@ pub struct ThemeZ {
     pub tab_control: f32,
     pub play_panel: f32,
     pub play_button: f32,
+    pub speed_trainer_progress: f32,
     pub guitar_view: f32,
     pub guitar_string: f32,
     pub guitar_capo: f32,
     pub guitar_barre: f32,
     pub tab_header: f32,
+    pub tab_info: f32,
     pub rhythm_bar: f32,
     pub chord_diagram: f32,
     pub chord_note: f32,
@@ -42,11 +44,13 @@ impl Default for ThemeZ {
             tab_control: 20.0,
             play_panel: 25.0,
             play_button: 26.0,
+            speed_trainer_progress: 26.0,
             guitar_view: 20.0,
             guitar_string: 23.0,
             guitar_capo: 24.0,
             guitar_barre: 24.0,
             tab_header: 20.0,
+            tab_info: 26.0,
             rhythm_bar: 22.0,
             chord_diagram: 22.0,
             chord_note: 24.0,
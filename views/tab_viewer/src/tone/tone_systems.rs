@@ -27,7 +27,7 @@ pub fn create_tone_notes(
         let mode: ToneMode = lane.kind.into();
         let bar = lane.bar().unwrap();
         let meta = bar.tab_meta();
-        for note in tone.get_notes() {
+        for note in tone.iter().copied() {
             let data = ToneNoteData::new(entry, ToneNoteValue::new(&bar, note, mode));
             let note_entity = data.create(commands, theme, entity);
             if settings.show_note_text() && !entry.prev_is_tie() {
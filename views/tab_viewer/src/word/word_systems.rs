@@ -24,10 +24,13 @@ pub fn create_word_text(
     let data = WordTextData::new(entry, text.clone());
     let text_entity = data.create(commands, theme, entity);
     if text.word.text != "" {
-        theme
-            .texts
-            .lyrics
-            .spawn_word_text(commands, text_entity, &assets, text.word.text.as_str())
+        theme.texts.lyrics.spawn_word_text(
+            commands,
+            text_entity,
+            assets,
+            text.word.display_text().as_str(),
+            text.active,
+        )
     }
 }
 
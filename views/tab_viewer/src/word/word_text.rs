@@ -1,6 +1,6 @@
 use edger_bevy::bevy_prelude::*;
 use edger_bevy::prelude::{offscreen, ShapeOp, StrokeLine};
-use notation_model::prelude::LyricWord;
+use notation_model::prelude::{LyricJoin, LyricWord, Units};
 use notation_midi::prelude::PlayingState;
 
 use std::fmt::Display;
@@ -12,6 +12,9 @@ pub struct WordTextValue {
     pub word: LyricWord,
     pub playing_state: PlayingState,
     pub bar_size: f32,
+    pub verse_row: usize,
+    pub active: bool,
+    pub melisma_units: Units,
 }
 impl WordTextValue {
     pub fn new(word: LyricWord) -> Self {
@@ -19,6 +22,38 @@ impl WordTextValue {
             word,
             playing_state: PlayingState::Idle,
             bar_size: 0.0,
+            verse_row: 0,
+            active: true,
+            melisma_units: Units::ZERO,
+        }
+    }
+    pub fn with_verse(self, verse_row: usize, active: bool) -> Self {
+        Self {
+            verse_row,
+            active,
+            ..self
+        }
+    }
+    pub fn with_melisma(self, melisma_units: Units) -> Self {
+        Self {
+            melisma_units,
+            ..self
+        }
+    }
+    /// Lines of inactive verses are not following the playing state.
+    pub fn line_state(&self) -> PlayingState {
+        if self.active {
+            self.playing_state
+        } else {
+            PlayingState::Idle
+        }
+    }
+    /// Only syllables with extender have the line drawn over the melisma.
+    pub fn line_units(&self, tied_units: Units) -> Units {
+        if self.word.join == LyricJoin::Extender {
+            tied_units + self.melisma_units
+        } else {
+            tied_units
         }
     }
 }
@@ -34,20 +69,17 @@ pub type WordTextData = EntryData<WordTextValue>;
 
 impl ShapeOp<NotationTheme, StrokeLine> for WordTextData {
     fn get_shape(&self, theme: &NotationTheme) -> StrokeLine {
-        let width = self.value.bar_size / self.bar_props.bar_units.0
-            * self.entry_props.tied_units.0
+        let width = self.value.bar_size / self.bar_props.bar_units.to_f32()
+            * self.value.line_units(self.entry_props.tied_units).to_f32()
             - theme.sizes.lyrics.word_gap;
-        let line_width = theme
-            .sizes
-            .lyrics
-            .line_height
-            .of_state(&self.value.playing_state);
+        let line_state = self.value.line_state();
+        let line_width = theme.sizes.lyrics.line_height.of_state(&line_state);
         let offset = if self.value.bar_size <= 0.0 {
             offscreen::offset()
         } else {
             let x =
-                self.value.bar_size / self.bar_props.bar_units.0 * self.entry_props.in_bar_pos.0;
-            let y = 0.0;
+                self.value.bar_size / self.bar_props.bar_units.to_f32() * self.entry_props.in_bar_pos.to_f32();
+            let y = -theme.sizes.lyrics.layout_height() * self.value.verse_row as f32;
             Vec3::new(x, y, theme.z.word)
         };
         let y = -1.0 * theme.sizes.lyrics.layout_height() / 2.0;
@@ -55,7 +87,7 @@ impl ShapeOp<NotationTheme, StrokeLine> for WordTextData {
             from: Vec2::new(0.0, y),
             to: Vec2::new(width, y),
             line_width,
-            color: theme.colors.lyrics.line.of_state(&self.value.playing_state),
+            color: theme.colors.lyrics.line.of_state(&line_state),
             offset,
         }
     }